@@ -0,0 +1,932 @@
+/*
+ * Copyright (c) 2025. The RigelA open source project team and
+ * its contributors reserve all rights.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software distributed under the
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and limitations under the License.
+ */
+
+//! 生成dex文件，只支持本库需要的最小子集：类可以声明父类、接口、字段、本地方法，以及调用父类构造函数的构造函数。
+//! 生成的dex可以直接通过`InMemoryDexClassLoader`加载，不需要javac和d8。
+
+use std::collections::{BTreeMap, BTreeSet};
+
+const ACC_PUBLIC: u32 = 0x1;
+const ACC_PRIVATE: u32 = 0x2;
+const ACC_STATIC: u32 = 0x8;
+const ACC_NATIVE: u32 = 0x100;
+const ACC_CONSTRUCTOR: u32 = 0x10000;
+const NO_INDEX: u32 = 0xffffffff;
+const HEADER_SIZE: u32 = 0x70;
+
+/// 类中的方法。
+#[derive(Clone, Debug)]
+enum DexMethod {
+    /// 本地方法，实现由jni注册。
+    Native {
+        name: String,
+        sig: String,
+        is_static: bool,
+    },
+    /// 构造函数，只调用父类中具有相同描述符的构造函数。
+    Constructor { sig: String },
+}
+
+/// 类中的字段。
+#[derive(Clone, Debug)]
+struct DexField {
+    name: String,
+    sig: String,
+    is_static: bool,
+}
+
+/// dex中的一个类定义。
+///
+/// # 示例
+///
+/// ```
+/// use droid_wrap_build::{DexClass, write_dex};
+/// let class = DexClass::new("rust/Task", "java/lang/Object")
+///     .interface("java/lang/Runnable")
+///     .constructor("()V")
+///     .native_method("run", "()V", false);
+/// let dex = write_dex(&[class]);
+/// assert!(dex.starts_with(b"dex\n035\0"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct DexClass {
+    name: String,
+    super_class: String,
+    interfaces: Vec<String>,
+    fields: Vec<DexField>,
+    methods: Vec<DexMethod>,
+}
+
+impl DexClass {
+    /// 创建类定义。
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: 类名，例如`rust/CallMethodHook`。
+    /// * `super_class`: 父类名，例如`java/lang/Object`。
+    pub fn new(name: &str, super_class: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            super_class: super_class.to_string(),
+            interfaces: vec![],
+            fields: vec![],
+            methods: vec![],
+        }
+    }
+
+    /// 类名。
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 实现一个接口。
+    pub fn interface(mut self, name: &str) -> Self {
+        self.interfaces.push(name.to_string());
+        self
+    }
+
+    /// 声明一个私有字段。
+    pub fn field(mut self, name: &str, sig: &str, is_static: bool) -> Self {
+        self.fields.push(DexField {
+            name: name.to_string(),
+            sig: sig.to_string(),
+            is_static,
+        });
+        self
+    }
+
+    /// 声明一个公开的本地方法。
+    pub fn native_method(mut self, name: &str, sig: &str, is_static: bool) -> Self {
+        self.methods.push(DexMethod::Native {
+            name: name.to_string(),
+            sig: sig.to_string(),
+            is_static,
+        });
+        self
+    }
+
+    /// 声明一个公开的构造函数，它把所有参数传递给父类中具有相同描述符的构造函数。
+    pub fn constructor(mut self, sig: &str) -> Self {
+        self.methods.push(DexMethod::Constructor {
+            sig: sig.to_string(),
+        });
+        self
+    }
+}
+
+/// 把方法描述符拆分为参数类型和返回值类型。
+fn split_method_sig(sig: &str) -> (Vec<String>, String) {
+    let mut params = vec![];
+    let mut rest = sig.trim_start_matches('(');
+    while !rest.is_empty() && !rest.starts_with(')') {
+        let dims = rest.len() - rest.trim_start_matches('[').len();
+        let len = match rest[dims..].chars().next() {
+            Some('L') => rest.find(';').map_or(rest.len(), |i| i + 1),
+            _ => dims + 1,
+        };
+        params.push(rest[..len].to_string());
+        rest = &rest[len..];
+    }
+    (params, rest.trim_start_matches(')').to_string())
+}
+
+fn shorty(ty: &str) -> char {
+    match ty.chars().next() {
+        Some('L') | Some('[') => 'L',
+        Some(c) => c,
+        None => 'V',
+    }
+}
+
+/// 参数占用的寄存器数量，long和double占用两个寄存器。
+fn width(ty: &str) -> u16 {
+    match ty {
+        "J" | "D" => 2,
+        _ => 1,
+    }
+}
+
+fn write_uleb128(out: &mut Vec<u8>, mut value: u32) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            break;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// 编码为dex使用的MUTF-8。
+fn write_mutf8(out: &mut Vec<u8>, s: &str) {
+    for unit in s.encode_utf16() {
+        match unit {
+            0x01..=0x7f => out.push(unit as u8),
+            0x00 | 0x80..=0x7ff => {
+                out.push(0xc0 | (unit >> 6) as u8);
+                out.push(0x80 | (unit & 0x3f) as u8);
+            }
+            _ => {
+                out.push(0xe0 | (unit >> 12) as u8);
+                out.push(0x80 | ((unit >> 6) & 0x3f) as u8);
+                out.push(0x80 | (unit & 0x3f) as u8);
+            }
+        }
+    }
+}
+
+fn align(out: &mut Vec<u8>, n: usize) {
+    while !out.len().is_multiple_of(n) {
+        out.push(0);
+    }
+}
+
+fn put_u16(out: &mut Vec<u8>, value: u16) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_u32(out: &mut Vec<u8>, value: u32) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+fn set_u32(out: &mut [u8], offset: usize, value: u32) {
+    out[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for chunk in data.chunks(5552) {
+        for i in chunk {
+            a += *i as u32;
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    (b << 16) | a
+}
+
+/// 字符串在dex中按UTF-16编码单元排序。
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct DexString(Vec<u16>, String);
+
+impl From<&str> for DexString {
+    fn from(value: &str) -> Self {
+        Self(value.encode_utf16().collect(), value.to_string())
+    }
+}
+
+type Proto = (String, Vec<String>);
+
+/// 生成包含给定类的dex文件（035版本）。
+///
+/// # Arguments
+///
+/// * `classes`: 类定义，同一个dex中的父类需要排在子类之前。
+///
+/// returns: Vec<u8> dex文件的内容
+pub fn write_dex(classes: &[DexClass]) -> Vec<u8> {
+    // 收集所有的字符串、类型、原型、字段和方法，dex要求它们按照规定的顺序排列。
+    let mut types = BTreeSet::<DexString>::new();
+    let mut strings = BTreeSet::<DexString>::new();
+    let mut protos = BTreeSet::<Proto>::new();
+    let mut fields = BTreeSet::<(String, String, String)>::new();
+    let mut methods = BTreeSet::<(String, String, Proto)>::new();
+    let descriptor = |name: &str| format!("L{};", name);
+
+    for class in classes.iter() {
+        types.insert(descriptor(&class.name).as_str().into());
+        types.insert(descriptor(&class.super_class).as_str().into());
+        for i in class.interfaces.iter() {
+            types.insert(descriptor(i).as_str().into());
+        }
+        for f in class.fields.iter() {
+            types.insert(f.sig.as_str().into());
+            strings.insert(f.name.as_str().into());
+            fields.insert((descriptor(&class.name), f.name.clone(), f.sig.clone()));
+        }
+        for m in class.methods.iter() {
+            let (owner, name, sig) = match m {
+                DexMethod::Native { name, sig, .. } => (&class.name, name.as_str(), sig),
+                DexMethod::Constructor { sig } => {
+                    let (params, ret) = split_method_sig(sig);
+                    methods.insert((
+                        descriptor(&class.super_class),
+                        "<init>".to_string(),
+                        (ret, params),
+                    ));
+                    (&class.name, "<init>", sig)
+                }
+            };
+            let (params, ret) = split_method_sig(sig);
+            for i in params.iter().chain(Some(&ret)) {
+                types.insert(i.as_str().into());
+            }
+            strings.insert(name.into());
+            methods.insert((descriptor(owner), name.to_string(), (ret, params)));
+        }
+    }
+    for (_, name, _) in methods.iter() {
+        strings.insert(name.as_str().into());
+    }
+    for (_, _, proto) in methods.iter() {
+        protos.insert(proto.clone());
+    }
+    let shorty_of = |(ret, params): &Proto| {
+        Some(ret)
+            .into_iter()
+            .chain(params.iter())
+            .map(|i| shorty(i))
+            .collect::<String>()
+    };
+    for p in protos.iter() {
+        strings.insert(shorty_of(p).as_str().into());
+    }
+    strings.extend(types.iter().cloned());
+
+    let string_index = strings
+        .iter()
+        .enumerate()
+        .map(|(i, s)| (s.1.clone(), i as u32))
+        .collect::<BTreeMap<_, _>>();
+    let type_index = types
+        .iter()
+        .enumerate()
+        .map(|(i, s)| (s.1.clone(), i as u16))
+        .collect::<BTreeMap<_, _>>();
+    // 原型按照返回值类型的索引排序，然后按参数列表排序。
+    let mut protos = protos
+        .into_iter()
+        .map(|p| {
+            let key = (
+                type_index[&p.0],
+                p.1.iter().map(|i| type_index[i]).collect::<Vec<_>>(),
+            );
+            (key, p)
+        })
+        .collect::<Vec<_>>();
+    protos.sort_by(|a, b| a.0.cmp(&b.0));
+    let proto_index = protos
+        .iter()
+        .enumerate()
+        .map(|(i, (_, p))| (p.clone(), i as u16))
+        .collect::<BTreeMap<_, _>>();
+    let mut fields = fields
+        .into_iter()
+        .map(|f| {
+            let key = (type_index[&f.0], string_index[&f.1], type_index[&f.2]);
+            (key, f)
+        })
+        .collect::<Vec<_>>();
+    fields.sort_by_key(|a| a.0);
+    let field_index = fields
+        .iter()
+        .enumerate()
+        .map(|(i, (_, f))| (f.clone(), i as u32))
+        .collect::<BTreeMap<_, _>>();
+    let mut methods = methods
+        .into_iter()
+        .map(|m| {
+            let key = (type_index[&m.0], string_index[&m.1], proto_index[&m.2]);
+            (key, m)
+        })
+        .collect::<Vec<_>>();
+    methods.sort_by_key(|a| a.0);
+    let method_index = methods
+        .iter()
+        .enumerate()
+        .map(|(i, (_, m))| (m.clone(), i as u32))
+        .collect::<BTreeMap<_, _>>();
+
+    let string_ids_off = HEADER_SIZE;
+    let type_ids_off = string_ids_off + strings.len() as u32 * 4;
+    let proto_ids_off = type_ids_off + types.len() as u32 * 4;
+    let field_ids_off = proto_ids_off + protos.len() as u32 * 12;
+    let method_ids_off = field_ids_off + fields.len() as u32 * 8;
+    let class_defs_off = method_ids_off + methods.len() as u32 * 8;
+    let data_off = class_defs_off + classes.len() as u32 * 32;
+
+    // 数据区，偏移量都从文件开头计算。
+    let mut data = vec![0u8; data_off as usize];
+
+    // 构造函数的代码：invoke-direct/range {v0 .. vN}, Super.<init>; return-void
+    let code_off = data.len() as u32;
+    let mut code_offsets = BTreeMap::new();
+    let mut code_count = 0;
+    for class in classes.iter() {
+        for m in class.methods.iter() {
+            let DexMethod::Constructor { sig } = m else {
+                continue;
+            };
+            let (params, ret) = split_method_sig(sig);
+            let registers = 1 + params.iter().map(|i| width(i)).sum::<u16>();
+            let super_init = method_index[&(
+                descriptor(&class.super_class),
+                "<init>".to_string(),
+                (ret, params),
+            )];
+            align(&mut data, 4);
+            code_offsets.insert((class.name.clone(), sig.clone()), data.len() as u32);
+            put_u16(&mut data, registers);
+            put_u16(&mut data, registers);
+            put_u16(&mut data, registers);
+            put_u16(&mut data, 0);
+            put_u32(&mut data, 0);
+            put_u32(&mut data, 4);
+            put_u16(&mut data, (registers << 8) | 0x76);
+            put_u16(&mut data, super_init as u16);
+            put_u16(&mut data, 0);
+            put_u16(&mut data, 0x0e);
+            code_count += 1;
+        }
+    }
+
+    // 类型列表：原型的参数和类的接口，相同的列表只写一次。
+    align(&mut data, 4);
+    let type_list_off = data.len() as u32;
+    let mut type_lists = BTreeMap::<Vec<u16>, u32>::new();
+    let lists = protos
+        .iter()
+        .map(|(_, p)| p.1.clone())
+        .chain(classes.iter().map(|c| {
+            c.interfaces
+                .iter()
+                .map(|i| descriptor(i))
+                .collect::<Vec<_>>()
+        }));
+    for list in lists {
+        if list.is_empty() {
+            continue;
+        }
+        let list = list.iter().map(|i| type_index[i]).collect::<Vec<_>>();
+        if type_lists.contains_key(&list) {
+            continue;
+        }
+        align(&mut data, 4);
+        type_lists.insert(list.clone(), data.len() as u32);
+        put_u32(&mut data, list.len() as u32);
+        for i in list.iter() {
+            put_u16(&mut data, *i);
+        }
+    }
+    let type_list_count = type_lists.len();
+
+    let string_data_off = data.len() as u32;
+    let mut string_offsets = vec![];
+    for s in strings.iter() {
+        string_offsets.push(data.len() as u32);
+        write_uleb128(&mut data, s.0.len() as u32);
+        write_mutf8(&mut data, &s.1);
+        data.push(0);
+    }
+
+    let class_data_off = data.len() as u32;
+    let mut class_data_offsets = vec![];
+    for class in classes.iter() {
+        let mut static_fields = vec![];
+        let mut instance_fields = vec![];
+        for f in class.fields.iter() {
+            let index = field_index[&(descriptor(&class.name), f.name.clone(), f.sig.clone())];
+            let flags = ACC_PRIVATE | if f.is_static { ACC_STATIC } else { 0 };
+            if f.is_static {
+                static_fields.push((index, flags, 0));
+            } else {
+                instance_fields.push((index, flags, 0));
+            }
+        }
+        let mut direct_methods = vec![];
+        let mut virtual_methods = vec![];
+        for m in class.methods.iter() {
+            let (name, sig) = match m {
+                DexMethod::Native { name, sig, .. } => (name.as_str(), sig),
+                DexMethod::Constructor { sig } => ("<init>", sig),
+            };
+            let (params, ret) = split_method_sig(sig);
+            let index = method_index[&(descriptor(&class.name), name.to_string(), (ret, params))];
+            match m {
+                DexMethod::Native { is_static, .. } => {
+                    if *is_static {
+                        direct_methods.push((index, ACC_PUBLIC | ACC_STATIC | ACC_NATIVE, 0));
+                    } else {
+                        virtual_methods.push((index, ACC_PUBLIC | ACC_NATIVE, 0));
+                    }
+                }
+                DexMethod::Constructor { sig } => direct_methods.push((
+                    index,
+                    ACC_PUBLIC | ACC_CONSTRUCTOR,
+                    code_offsets[&(class.name.clone(), sig.clone())],
+                )),
+            }
+        }
+
+        class_data_offsets.push(data.len() as u32);
+        let lists = [
+            &mut static_fields,
+            &mut instance_fields,
+            &mut direct_methods,
+            &mut virtual_methods,
+        ];
+        for list in lists.iter() {
+            write_uleb128(&mut data, list.len() as u32);
+        }
+        for (i, list) in lists.into_iter().enumerate() {
+            list.sort();
+            let mut prev = 0;
+            for (index, flags, code) in list.iter() {
+                write_uleb128(&mut data, index - prev);
+                write_uleb128(&mut data, *flags);
+                if i >= 2 {
+                    write_uleb128(&mut data, *code);
+                }
+                prev = *index;
+            }
+        }
+    }
+
+    align(&mut data, 4);
+    let map_off = data.len() as u32;
+    let mut map = vec![
+        (0x0000u16, 1u32, 0u32),
+        (0x0001, strings.len() as u32, string_ids_off),
+        (0x0002, types.len() as u32, type_ids_off),
+        (0x0003, protos.len() as u32, proto_ids_off),
+        (0x0004, fields.len() as u32, field_ids_off),
+        (0x0005, methods.len() as u32, method_ids_off),
+        (0x0006, classes.len() as u32, class_defs_off),
+        (0x2001, code_count, code_off),
+        (0x1001, type_list_count as u32, type_list_off),
+        (0x2002, strings.len() as u32, string_data_off),
+        (0x2000, classes.len() as u32, class_data_off),
+        (0x1000, 1, map_off),
+    ];
+    map.retain(|i| i.1 > 0);
+    put_u32(&mut data, map.len() as u32);
+    for (ty, size, offset) in map.iter() {
+        put_u16(&mut data, *ty);
+        put_u16(&mut data, 0);
+        put_u32(&mut data, *size);
+        put_u32(&mut data, *offset);
+    }
+
+    // 回填各个索引区。
+    let mut ids = Vec::new();
+    for i in string_offsets.iter() {
+        put_u32(&mut ids, *i);
+    }
+    for t in types.iter() {
+        put_u32(&mut ids, string_index[&t.1]);
+    }
+    for (_, p) in protos.iter() {
+        put_u32(&mut ids, string_index[&shorty_of(p)]);
+        put_u32(&mut ids, type_index[&p.0] as u32);
+        let list = p.1.iter().map(|i| type_index[i]).collect::<Vec<_>>();
+        put_u32(&mut ids, type_lists.get(&list).copied().unwrap_or(0));
+    }
+    for ((class, name, ty), _) in fields.iter() {
+        put_u16(&mut ids, *class);
+        put_u16(&mut ids, *ty);
+        put_u32(&mut ids, *name);
+    }
+    for ((class, name, proto), _) in methods.iter() {
+        put_u16(&mut ids, *class);
+        put_u16(&mut ids, *proto);
+        put_u32(&mut ids, *name);
+    }
+    for (class, class_data) in classes.iter().zip(class_data_offsets) {
+        let interfaces = class
+            .interfaces
+            .iter()
+            .map(|i| type_index[&descriptor(i)])
+            .collect::<Vec<_>>();
+        put_u32(&mut ids, type_index[&descriptor(&class.name)] as u32);
+        put_u32(&mut ids, ACC_PUBLIC);
+        put_u32(&mut ids, type_index[&descriptor(&class.super_class)] as u32);
+        put_u32(&mut ids, type_lists.get(&interfaces).copied().unwrap_or(0));
+        put_u32(&mut ids, NO_INDEX);
+        put_u32(&mut ids, 0);
+        put_u32(&mut ids, class_data);
+        put_u32(&mut ids, 0);
+    }
+    data[HEADER_SIZE as usize..data_off as usize].copy_from_slice(&ids);
+
+    // 文件头
+    let file_size = data.len() as u32;
+    let mut header = Vec::new();
+    header.extend_from_slice(b"dex\n035\0");
+    put_u32(&mut header, 0);
+    header.extend_from_slice(&[0; 20]);
+    put_u32(&mut header, file_size);
+    put_u32(&mut header, HEADER_SIZE);
+    put_u32(&mut header, 0x12345678);
+    put_u32(&mut header, 0);
+    put_u32(&mut header, 0);
+    put_u32(&mut header, map_off);
+    for (size, offset) in [
+        (strings.len(), string_ids_off),
+        (types.len(), type_ids_off),
+        (protos.len(), proto_ids_off),
+        (fields.len(), field_ids_off),
+        (methods.len(), method_ids_off),
+        (classes.len(), class_defs_off),
+    ] {
+        put_u32(&mut header, size as u32);
+        put_u32(&mut header, if size > 0 { offset } else { 0 });
+    }
+    put_u32(&mut header, file_size - data_off);
+    put_u32(&mut header, data_off);
+    data[..HEADER_SIZE as usize].copy_from_slice(&header);
+
+    let signature = sha1_smol::Sha1::from(&data[32..]).digest().bytes();
+    data[12..32].copy_from_slice(&signature);
+    let checksum = adler32(&data[12..]);
+    set_u32(&mut data, 8, checksum);
+
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INVOKE_SIG: &str =
+        "(Ljava/lang/Object;Ljava/lang/reflect/Method;[Ljava/lang/Object;)Ljava/lang/Object;";
+
+    /// 按照dex格式规范独立解析生成的文件，不依赖写入时使用的辅助函数。
+    struct DexReader<'a>(&'a [u8]);
+
+    /// 解析出的方法：名称、描述符、访问标志和代码偏移。
+    type ParsedMethod = (String, String, u32, u32);
+
+    /// 解析出的类：类型、父类、接口、字段（名称、类型、访问标志）和方法。
+    struct ParsedClass {
+        name: String,
+        super_class: String,
+        interfaces: Vec<String>,
+        fields: Vec<(String, String, u32)>,
+        methods: Vec<ParsedMethod>,
+    }
+
+    impl<'a> DexReader<'a> {
+        fn new(data: &'a [u8]) -> Self {
+            let reader = Self(data);
+            assert_eq!(b"dex\n035\0", &data[..8]);
+            assert_eq!(data.len() as u32, reader.u32(0x20), "file_size");
+            assert_eq!(0x70, reader.u32(0x24), "header_size");
+            assert_eq!(0x12345678, reader.u32(0x28), "endian_tag");
+            let expected = sha1_smol::Sha1::from(&data[32..]).digest().bytes();
+            assert_eq!(&expected, &data[12..32], "signature");
+            let (mut a, mut b) = (1u32, 0u32);
+            for i in data[12..].iter() {
+                a = (a + *i as u32) % 65521;
+                b = (b + a) % 65521;
+            }
+            assert_eq!((b << 16) | a, reader.u32(8), "checksum");
+            assert_eq!(
+                data.len() as u32,
+                reader.u32(0x68) + reader.u32(0x6c),
+                "data section"
+            );
+            reader
+        }
+
+        fn u16(&self, offset: usize) -> u16 {
+            u16::from_le_bytes(self.0[offset..offset + 2].try_into().unwrap())
+        }
+
+        fn u32(&self, offset: usize) -> u32 {
+            u32::from_le_bytes(self.0[offset..offset + 4].try_into().unwrap())
+        }
+
+        fn uleb128(&self, offset: &mut usize) -> u32 {
+            let mut value = 0;
+            let mut shift = 0;
+            loop {
+                let byte = self.0[*offset];
+                *offset += 1;
+                value |= ((byte & 0x7f) as u32) << shift;
+                if byte & 0x80 == 0 {
+                    return value;
+                }
+                shift += 7;
+            }
+        }
+
+        /// 头部中给定区段的数量和偏移。
+        fn section(&self, header_offset: usize) -> (usize, usize) {
+            (
+                self.u32(header_offset) as usize,
+                self.u32(header_offset + 4) as usize,
+            )
+        }
+
+        fn string(&self, index: u32) -> String {
+            let (size, offset) = self.section(0x38);
+            assert!((index as usize) < size);
+            let mut offset = self.u32(offset + index as usize * 4) as usize;
+            let len = self.uleb128(&mut offset) as usize;
+            let end = offset + self.0[offset..].iter().position(|i| *i == 0).unwrap();
+            let s = String::from_utf8(self.0[offset..end].to_vec()).unwrap();
+            assert_eq!(len, s.encode_utf16().count());
+            s
+        }
+
+        fn strings(&self) -> Vec<String> {
+            (0..self.section(0x38).0 as u32)
+                .map(|i| self.string(i))
+                .collect()
+        }
+
+        fn type_name(&self, index: u32) -> String {
+            let (size, offset) = self.section(0x40);
+            assert!((index as usize) < size);
+            self.string(self.u32(offset + index as usize * 4))
+        }
+
+        fn type_list(&self, offset: u32) -> Vec<String> {
+            if offset == 0 {
+                return vec![];
+            }
+            let offset = offset as usize;
+            assert_eq!(0, offset % 4);
+            (0..self.u32(offset) as usize)
+                .map(|i| self.type_name(self.u16(offset + 4 + i * 2) as u32))
+                .collect()
+        }
+
+        fn proto(&self, index: u16) -> String {
+            let offset = self.section(0x48).1 + index as usize * 12;
+            let ret = self.type_name(self.u32(offset + 4));
+            let params = self.type_list(self.u32(offset + 8));
+            let shorty = self.string(self.u32(offset));
+            let expected = Some(&ret)
+                .into_iter()
+                .chain(params.iter())
+                .map(|i| match i.as_bytes()[0] {
+                    b'[' => 'L',
+                    c => c as char,
+                })
+                .collect::<String>();
+            assert_eq!(expected, shorty);
+            format!("({}){}", params.concat(), ret)
+        }
+
+        fn field(&self, index: u32) -> (String, String, String) {
+            let offset = self.section(0x50).1 + index as usize * 8;
+            (
+                self.type_name(self.u16(offset) as u32),
+                self.string(self.u32(offset + 4)),
+                self.type_name(self.u16(offset + 2) as u32),
+            )
+        }
+
+        fn method(&self, index: u32) -> (String, String, String) {
+            let offset = self.section(0x58).1 + index as usize * 8;
+            (
+                self.type_name(self.u16(offset) as u32),
+                self.string(self.u32(offset + 4)),
+                self.proto(self.u16(offset + 2)),
+            )
+        }
+
+        fn classes(&self) -> Vec<ParsedClass> {
+            let (size, offset) = self.section(0x60);
+            (0..size)
+                .map(|i| {
+                    let offset = offset + i * 32;
+                    let name = self.type_name(self.u32(offset));
+                    let mut data = self.u32(offset + 24) as usize;
+                    let counts = [0; 4].map(|_| self.uleb128(&mut data));
+                    let mut fields = vec![];
+                    let mut methods = vec![];
+                    for (kind, count) in counts.into_iter().enumerate() {
+                        let mut index = 0;
+                        for _ in 0..count {
+                            index += self.uleb128(&mut data);
+                            let flags = self.uleb128(&mut data);
+                            if kind < 2 {
+                                let (owner, name, ty) = self.field(index);
+                                assert_eq!(self.type_name(self.u32(offset)), owner);
+                                fields.push((name, ty, flags));
+                            } else {
+                                let code = self.uleb128(&mut data);
+                                let (owner, name, sig) = self.method(index);
+                                assert_eq!(self.type_name(self.u32(offset)), owner);
+                                methods.push((name, sig, flags, code));
+                            }
+                        }
+                    }
+                    ParsedClass {
+                        name,
+                        super_class: self.type_name(self.u32(offset + 8)),
+                        interfaces: self.type_list(self.u32(offset + 12)),
+                        fields,
+                        methods,
+                    }
+                })
+                .collect()
+        }
+
+        /// 读取代码项，返回寄存器数量、参数寄存器数量和指令。
+        fn code(&self, offset: u32) -> (u16, u16, Vec<u16>) {
+            let offset = offset as usize;
+            assert_eq!(0, offset % 4);
+            let insns = (0..self.u32(offset + 12) as usize)
+                .map(|i| self.u16(offset + 16 + i * 2))
+                .collect();
+            (self.u16(offset), self.u16(offset + 2), insns)
+        }
+
+        /// map_list中的所有项：类型、数量和偏移。
+        fn map(&self) -> Vec<(u16, u32, u32)> {
+            let offset = self.u32(0x34) as usize;
+            (0..self.u32(offset) as usize)
+                .map(|i| {
+                    let item = offset + 4 + i * 12;
+                    (self.u16(item), self.u32(item + 4), self.u32(item + 8))
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_call_method_hook() {
+        let class = DexClass::new("rust/CallMethodHook", "java/lang/Object")
+            .interface("java/lang/reflect/InvocationHandler")
+            .constructor("()V")
+            .native_method("invoke", INVOKE_SIG, false);
+        let dex = write_dex(&[class]);
+        let reader = DexReader::new(&dex);
+
+        let strings = reader.strings();
+        let mut sorted = strings.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted, strings);
+
+        let classes = reader.classes();
+        assert_eq!(1, classes.len());
+        let class = &classes[0];
+        assert_eq!("Lrust/CallMethodHook;", class.name);
+        assert_eq!("Ljava/lang/Object;", class.super_class);
+        assert_eq!(
+            vec!["Ljava/lang/reflect/InvocationHandler;".to_string()],
+            class.interfaces
+        );
+        assert!(class.fields.is_empty());
+        assert_eq!(2, class.methods.len());
+
+        let (name, sig, flags, code) = &class.methods[0];
+        assert_eq!(("<init>", "()V"), (name.as_str(), sig.as_str()));
+        assert_eq!(ACC_PUBLIC | ACC_CONSTRUCTOR, *flags);
+        let (registers, ins, insns) = reader.code(*code);
+        assert_eq!((1, 1), (registers, ins));
+        // invoke-direct/range {v0}, Object.<init>; return-void
+        assert_eq!(4, insns.len());
+        assert_eq!((0x0176, 0, 0x0e), (insns[0], insns[2], insns[3]));
+        assert_eq!(
+            (
+                "Ljava/lang/Object;".to_string(),
+                "<init>".to_string(),
+                "()V".to_string()
+            ),
+            reader.method(insns[1] as u32)
+        );
+
+        let (name, sig, flags, code) = &class.methods[1];
+        assert_eq!(("invoke", INVOKE_SIG), (name.as_str(), sig.as_str()));
+        assert_eq!(ACC_PUBLIC | ACC_NATIVE, *flags);
+        assert_eq!(0, *code);
+
+        let map = reader.map();
+        assert_eq!(Some(&(0x0000, 1, 0)), map.first());
+        assert_eq!(Some(&(0x1000, 1, reader.u32(0x34))), map.last());
+        assert!(map.windows(2).all(|i| i[0].2 < i[1].2));
+    }
+
+    #[test]
+    fn test_fields_and_wide_constructor() {
+        let base =
+            DexClass::new("rust/Base", "java/lang/Thread").constructor("(Ljava/lang/String;)V");
+        let class = DexClass::new("rust/Worker", "rust/Base")
+            .interface("java/lang/Runnable")
+            .interface("java/lang/AutoCloseable")
+            .field("handle", "J", false)
+            .field("count", "I", true)
+            .constructor("(JLjava/lang/String;D)V")
+            .native_method("run", "()V", false)
+            .native_method("create", "([BZ)Lrust/Worker;", true);
+        let dex = write_dex(&[base, class]);
+        let reader = DexReader::new(&dex);
+        let classes = reader.classes();
+        assert_eq!(2, classes.len());
+        assert_eq!("Ljava/lang/Thread;", classes[0].super_class);
+
+        let class = &classes[1];
+        assert_eq!("Lrust/Worker;", class.name);
+        assert_eq!("Lrust/Base;", class.super_class);
+        assert_eq!(
+            vec![
+                "Ljava/lang/Runnable;".to_string(),
+                "Ljava/lang/AutoCloseable;".to_string()
+            ],
+            class.interfaces
+        );
+        assert_eq!(
+            vec![
+                (
+                    "count".to_string(),
+                    "I".to_string(),
+                    ACC_PRIVATE | ACC_STATIC
+                ),
+                ("handle".to_string(), "J".to_string(), ACC_PRIVATE),
+            ],
+            class.fields
+        );
+
+        let find = |name: &str| class.methods.iter().find(|i| i.0 == name).unwrap();
+        let (_, sig, flags, code) = find("<init>");
+        assert_eq!("(JLjava/lang/String;D)V", sig);
+        assert_eq!(ACC_PUBLIC | ACC_CONSTRUCTOR, *flags);
+        let (registers, ins, insns) = reader.code(*code);
+        // this + long(2) + String + double(2)
+        assert_eq!((6, 6), (registers, ins));
+        assert_eq!(0x0676, insns[0]);
+        assert_eq!(
+            (
+                "Lrust/Base;".to_string(),
+                "<init>".to_string(),
+                "(JLjava/lang/String;D)V".to_string()
+            ),
+            reader.method(insns[1] as u32)
+        );
+        let (_, sig, flags, _) = find("create");
+        assert_eq!("([BZ)Lrust/Worker;", sig);
+        assert_eq!(ACC_PUBLIC | ACC_STATIC | ACC_NATIVE, *flags);
+        let (_, _, flags, _) = find("run");
+        assert_eq!(ACC_PUBLIC | ACC_NATIVE, *flags);
+    }
+
+    #[test]
+    fn test_non_ascii_string() {
+        let class = DexClass::new("rust/Test", "java/lang/Object").field(
+            "名称",
+            "Ljava/lang/String;",
+            false,
+        );
+        let dex = write_dex(&[class]);
+        let reader = DexReader::new(&dex);
+        assert!(reader.strings().contains(&"名称".to_string()));
+        assert_eq!("名称", reader.classes()[0].fields[0].0);
+    }
+}
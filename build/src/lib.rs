@@ -0,0 +1,89 @@
+/*
+ * Copyright (c) 2025. The RigelA open source project team and
+ * its contributors reserve all rights.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software distributed under the
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and limitations under the License.
+ */
+
+//! 在build.rs中声明java胶水类并直接生成dex，不需要gradle工程、javac和d8。
+//! 生成的dex使用`droid_wrap_utils::include_dex!`嵌入到程序中，运行时通过`InMemoryDexClassLoader`加载。
+
+mod dex;
+
+pub use dex::*;
+use std::{
+    env::var_os,
+    fs::write,
+    io::{Error, Result},
+    path::{Path, PathBuf},
+};
+
+/// 收集需要生成的类并写入dex文件，一般在build.rs中使用。
+///
+/// # 示例
+///
+/// ```no_run
+/// // build.rs
+/// use droid_wrap_build::{DexBuilder, DexClass};
+///
+/// DexBuilder::new()
+///     .class(
+///         DexClass::new("com/example/Callback", "java/lang/Object")
+///             .interface("java/lang/Runnable")
+///             .constructor("()V")
+///             .native_method("run", "()V", false),
+///     )
+///     .build("glue")
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DexBuilder {
+    classes: Vec<DexClass>,
+}
+
+impl DexBuilder {
+    /// 创建空的构建器。
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加一个类，同一个dex中的父类需要先于子类添加。
+    pub fn class(mut self, class: DexClass) -> Self {
+        self.classes.push(class);
+        self
+    }
+
+    /// 生成dex文件的内容。
+    pub fn to_bytes(&self) -> Vec<u8> {
+        write_dex(&self.classes)
+    }
+
+    /// 把dex写入指定的路径。
+    pub fn write_to<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        write(path, self.to_bytes())
+    }
+
+    /// 把dex写入`$OUT_DIR/<name>.dex`，程序中可以使用`droid_wrap_utils::include_dex!("<name>")`嵌入。
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: dex文件的名称，不包含扩展名。
+    ///
+    /// returns: Result<PathBuf> 写入的文件路径
+    pub fn build(&self, name: &str) -> Result<PathBuf> {
+        let Some(out_dir) = var_os("OUT_DIR") else {
+            return Err(Error::other(
+                "OUT_DIR is not set, DexBuilder::build can only be used in build scripts.",
+            ));
+        };
+        let path = PathBuf::from(out_dir).join(format!("{}.dex", name));
+        self.write_to(&path)?;
+        Ok(path)
+    }
+}
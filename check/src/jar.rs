@@ -0,0 +1,362 @@
+/*
+ * Copyright (c) 2025. The RigelA open source project team and
+ * its contributors reserve all rights.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software distributed under the
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and limitations under the License.
+ */
+
+use noak::{AccessFlags, reader::Class};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    error::Error,
+    fs::File,
+    io::{Read, Seek},
+    path::Path,
+};
+use zip::ZipArchive;
+
+/// android.jar中的一个成员（方法或字段）。
+#[derive(Debug)]
+pub struct MemberInfo {
+    pub name: String,
+    pub descriptor: String,
+    pub is_static: bool,
+}
+
+/// android.jar中的一个类。
+#[derive(Debug, Default)]
+pub struct ClassInfo {
+    pub super_class: Option<String>,
+    pub interfaces: Vec<String>,
+    pub methods: Vec<MemberInfo>,
+    pub fields: Vec<MemberInfo>,
+}
+
+/// 成员的查找结果。
+#[derive(Debug)]
+pub enum Lookup {
+    /// 找到了成员，`is_static`表示成员是否为静态的。
+    Found { is_static: bool },
+    /// 类不存在。
+    ClassMissing,
+    /// 类存在但成员不存在，`candidates`是同名成员的描述符。
+    MemberMissing { candidates: Vec<String> },
+}
+
+/// android.jar中所有类的索引。
+pub struct AndroidJar {
+    classes: HashMap<String, ClassInfo>,
+}
+
+impl AndroidJar {
+    /// 读取android.jar并建立索引。
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn Error>> {
+        Self::read(File::open(path)?)
+    }
+
+    /// 从任意的jar数据中读取所有类并建立索引。
+    pub fn read<R: Read + Seek>(reader: R) -> Result<Self, Box<dyn Error>> {
+        let mut archive = ZipArchive::new(reader)?;
+        let mut classes = HashMap::new();
+        let mut buf = Vec::new();
+        for i in 0..archive.len() {
+            let mut entry = archive.by_index(i)?;
+            let Some(name) = entry.name().strip_suffix(".class").map(ToString::to_string) else {
+                continue;
+            };
+            buf.clear();
+            entry.read_to_end(&mut buf)?;
+            classes.insert(name, parse_class(&buf)?);
+        }
+
+        Ok(Self { classes })
+    }
+
+    /// 查找方法，包括从父类和接口继承的方法。构造器只在类本身中查找。
+    pub fn find_method(&self, class: &str, name: &str, descriptor: &str) -> Lookup {
+        let inherited = name != "<init>";
+        self.find(class, name, descriptor, inherited, |c| &c.methods)
+    }
+
+    /// 查找字段，包括从父类和接口继承的字段。
+    pub fn find_field(&self, class: &str, name: &str, descriptor: &str) -> Lookup {
+        self.find(class, name, descriptor, true, |c| &c.fields)
+    }
+
+    fn find(
+        &self,
+        class: &str,
+        name: &str,
+        descriptor: &str,
+        inherited: bool,
+        members: impl Fn(&ClassInfo) -> &Vec<MemberInfo>,
+    ) -> Lookup {
+        if !self.classes.contains_key(class) {
+            return Lookup::ClassMissing;
+        }
+
+        let mut candidates = Vec::new();
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::from([class.to_string()]);
+        while let Some(current) = queue.pop_front() {
+            if !visited.insert(current.clone()) {
+                continue;
+            }
+            let Some(info) = self.classes.get(&current) else {
+                continue;
+            };
+            for m in members(info).iter().filter(|m| m.name == name) {
+                if m.descriptor == descriptor {
+                    return Lookup::Found {
+                        is_static: m.is_static,
+                    };
+                }
+                candidates.push(m.descriptor.clone());
+            }
+            if !inherited {
+                break;
+            }
+            queue.extend(info.super_class.iter().cloned());
+            queue.extend(info.interfaces.iter().cloned());
+        }
+        candidates.sort();
+        candidates.dedup();
+
+        Lookup::MemberMissing { candidates }
+    }
+}
+
+fn parse_class(bytes: &[u8]) -> Result<ClassInfo, Box<dyn Error>> {
+    let class = Class::new(bytes)?;
+    let pool = class.pool();
+    let to_string = |s: &noak::MStr| s.to_str().unwrap_or_default().to_string();
+
+    let super_class = match class.super_class() {
+        Some(i) => Some(to_string(pool.retrieve(i)?.name)),
+        None => None,
+    };
+    let mut interfaces = Vec::new();
+    for i in class.interfaces() {
+        interfaces.push(to_string(pool.retrieve(i?)?.name));
+    }
+    let mut methods = Vec::new();
+    for m in class.methods() {
+        let m = m?;
+        methods.push(MemberInfo {
+            name: to_string(pool.retrieve(m.name())?),
+            descriptor: to_string(pool.retrieve(m.descriptor())?),
+            is_static: m.access_flags().contains(AccessFlags::STATIC),
+        });
+    }
+    let mut fields = Vec::new();
+    for f in class.fields() {
+        let f = f?;
+        fields.push(MemberInfo {
+            name: to_string(pool.retrieve(f.name())?),
+            descriptor: to_string(pool.retrieve(f.descriptor())?),
+            is_static: f.access_flags().contains(AccessFlags::STATIC),
+        });
+    }
+
+    Ok(ClassInfo {
+        super_class,
+        interfaces,
+        methods,
+        fields,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use noak::writer::ClassWriter;
+    use std::io::{Cursor, Write};
+    use zip::{ZipWriter, write::SimpleFileOptions};
+
+    type Members<'a> = &'a [(&'a str, &'a str, bool)];
+
+    /// 生成只包含声明（没有代码）的类文件。
+    fn class(
+        name: &str,
+        super_class: &str,
+        interfaces: &[&str],
+        methods: Members,
+        fields: Members,
+    ) -> Vec<u8> {
+        let flags = |is_static: bool| match is_static {
+            true => AccessFlags::PUBLIC | AccessFlags::STATIC,
+            false => AccessFlags::PUBLIC,
+        };
+        let mut bytes = Vec::new();
+        ClassWriter::new()
+            .version(noak::Version::V8)
+            .unwrap()
+            .access_flags(AccessFlags::PUBLIC | AccessFlags::SUPER)
+            .unwrap()
+            .this_class(name)
+            .unwrap()
+            .super_class(super_class)
+            .unwrap()
+            .interfaces(|w| {
+                for i in interfaces {
+                    w.begin(|w| w.interface(*i))?;
+                }
+                Ok(())
+            })
+            .unwrap()
+            .fields(|w| {
+                for (name, descriptor, is_static) in fields {
+                    w.begin(|w| {
+                        w.access_flags(flags(*is_static))?
+                            .name(*name)?
+                            .descriptor(*descriptor)?
+                            .attributes(|_| Ok(()))
+                    })?;
+                }
+                Ok(())
+            })
+            .unwrap()
+            .methods(|w| {
+                for (name, descriptor, is_static) in methods {
+                    w.begin(|w| {
+                        w.access_flags(flags(*is_static) | AccessFlags::ABSTRACT)?
+                            .name(*name)?
+                            .descriptor(*descriptor)?
+                            .attributes(|_| Ok(()))
+                    })?;
+                }
+                Ok(())
+            })
+            .unwrap()
+            .attributes(|_| Ok(()))
+            .unwrap()
+            .write_bytes_to(&mut bytes)
+            .unwrap();
+        bytes
+    }
+
+    fn fixture() -> AndroidJar {
+        let classes = [
+            (
+                "java/lang/Object",
+                class(
+                    "java/lang/Object",
+                    "java/lang/Object",
+                    &[],
+                    &[
+                        ("<init>", "()V", false),
+                        ("toString", "()Ljava/lang/String;", false),
+                    ],
+                    &[],
+                ),
+            ),
+            (
+                "java/lang/Runnable",
+                class(
+                    "java/lang/Runnable",
+                    "java/lang/Object",
+                    &[],
+                    &[("run", "()V", false)],
+                    &[],
+                ),
+            ),
+            (
+                "android/os/Handler",
+                class(
+                    "android/os/Handler",
+                    "java/lang/Object",
+                    &["java/lang/Runnable"],
+                    &[
+                        ("<init>", "()V", false),
+                        ("post", "(Ljava/lang/Runnable;)Z", false),
+                        ("post", "(Ljava/lang/Runnable;J)Z", false),
+                        ("getMain", "()Landroid/os/Handler;", true),
+                    ],
+                    &[("TAG", "Ljava/lang/String;", true), ("count", "I", false)],
+                ),
+            ),
+        ];
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, bytes) in classes {
+            zip.start_file(format!("{}.class", name), SimpleFileOptions::default())
+                .unwrap();
+            zip.write_all(&bytes).unwrap();
+        }
+        // 不是类文件的条目会被忽略。
+        zip.start_file("META-INF/MANIFEST.MF", SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(b"Manifest-Version: 1.0\n").unwrap();
+        let data = zip.finish().unwrap().into_inner();
+        AndroidJar::read(Cursor::new(data)).unwrap()
+    }
+
+    #[test]
+    fn test_find_method() {
+        let jar = fixture();
+        assert!(matches!(
+            jar.find_method("android/os/Handler", "post", "(Ljava/lang/Runnable;)Z"),
+            Lookup::Found { is_static: false }
+        ));
+        assert!(matches!(
+            jar.find_method("android/os/Handler", "getMain", "()Landroid/os/Handler;"),
+            Lookup::Found { is_static: true }
+        ));
+        // 从父类和接口继承的方法。
+        assert!(matches!(
+            jar.find_method("android/os/Handler", "toString", "()Ljava/lang/String;"),
+            Lookup::Found { is_static: false }
+        ));
+        assert!(matches!(
+            jar.find_method("android/os/Handler", "run", "()V"),
+            Lookup::Found { is_static: false }
+        ));
+        assert!(matches!(
+            jar.find_method("android/os/Looper", "prepare", "()V"),
+            Lookup::ClassMissing
+        ));
+        match jar.find_method("android/os/Handler", "post", "(Ljava/lang/Runnable;I)Z") {
+            Lookup::MemberMissing { candidates } => assert_eq!(
+                candidates,
+                ["(Ljava/lang/Runnable;)Z", "(Ljava/lang/Runnable;J)Z"]
+            ),
+            r => panic!("{:?}", r),
+        }
+    }
+
+    #[test]
+    fn test_find_constructor() {
+        let jar = fixture();
+        assert!(matches!(
+            jar.find_method("android/os/Handler", "<init>", "()V"),
+            Lookup::Found { is_static: false }
+        ));
+        // 构造器不会继承。
+        assert!(matches!(
+            jar.find_method("java/lang/Runnable", "<init>", "()V"),
+            Lookup::MemberMissing { .. }
+        ));
+    }
+
+    #[test]
+    fn test_find_field() {
+        let jar = fixture();
+        assert!(matches!(
+            jar.find_field("android/os/Handler", "TAG", "Ljava/lang/String;"),
+            Lookup::Found { is_static: true }
+        ));
+        assert!(matches!(
+            jar.find_field("android/os/Handler", "count", "I"),
+            Lookup::Found { is_static: false }
+        ));
+        match jar.find_field("android/os/Handler", "count", "J") {
+            Lookup::MemberMissing { candidates } => assert_eq!(candidates, ["I"]),
+            r => panic!("{:?}", r),
+        }
+    }
+}
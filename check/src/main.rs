@@ -0,0 +1,272 @@
+/*
+ * Copyright (c) 2025. The RigelA open source project team and
+ * its contributors reserve all rights.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software distributed under the
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and limitations under the License.
+ */
+
+#[cfg(test)]
+#[path = "../../macros/src/descriptor.rs"]
+mod descriptor;
+mod jar;
+mod scan;
+
+use android_build::{android_jar, android_sdk};
+use jar::{AndroidJar, Lookup};
+use scan::{Member, MemberKind, scan};
+use std::{
+    collections::HashMap,
+    env::args,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+    process::exit,
+};
+
+const USAGE: &str = "\
+Verifies the signatures of droid-wrap wrappers against android.jar.
+
+Usage: cargo droid-check [OPTIONS] [PATHS]...
+
+Arguments:
+  [PATHS]...  Source files or directories to scan [default: src]
+
+Options:
+  --api <LEVEL>            API level of the android.jar to check against
+                           [default: target_sdk_version, or the latest installed platform]
+  --min-sdk <LEVEL>        Report members that are newer than this API level
+                           [default: min_sdk_version from the manifest]
+  --manifest-path <PATH>   Path to Cargo.toml [default: Cargo.toml]
+  -h, --help               Print help";
+
+/// 命令行选项。
+#[derive(Default)]
+struct Options {
+    api: Option<u32>,
+    min_sdk: Option<u32>,
+    manifest_path: Option<PathBuf>,
+    paths: Vec<PathBuf>,
+}
+
+impl Options {
+    fn parse() -> Result<Self, String> {
+        let mut opts = Self::default();
+        let mut args = args().skip(1).peekable();
+        // 作为cargo扩展运行时，第一个参数是子命令的名称。
+        if args.peek().is_some_and(|i| i == "droid-check") {
+            args.next();
+        }
+        while let Some(arg) = args.next() {
+            let mut level = |name: &str| {
+                args.next()
+                    .and_then(|i| i.parse().ok())
+                    .ok_or_else(|| format!("`{}` requires an API level.", name))
+            };
+            match arg.as_str() {
+                "-h" | "--help" => {
+                    println!("{}", USAGE);
+                    exit(0);
+                }
+                "--api" => opts.api = Some(level("--api")?),
+                "--min-sdk" => opts.min_sdk = Some(level("--min-sdk")?),
+                "--manifest-path" => {
+                    opts.manifest_path = Some(
+                        args.next()
+                            .ok_or("`--manifest-path` requires a path.")?
+                            .into(),
+                    )
+                }
+                _ if arg.starts_with('-') => return Err(format!("Unknown option `{}`.", arg)),
+                _ => opts.paths.push(arg.into()),
+            }
+        }
+
+        Ok(opts)
+    }
+}
+
+/// 从`[package.metadata.android.sdk]`中读取`min_sdk_version`和`target_sdk_version`。
+fn read_sdk_versions(manifest_path: &Path) -> (Option<u32>, Option<u32>) {
+    let Ok(manifest) = read_to_string(manifest_path) else {
+        return (None, None);
+    };
+    let Ok(manifest) = manifest.parse::<toml::Table>() else {
+        return (None, None);
+    };
+    let sdk = manifest
+        .get("package")
+        .and_then(|i| i.get("metadata"))
+        .and_then(|i| i.get("android"))
+        .and_then(|i| i.get("sdk"));
+    let version = |key: &str| {
+        sdk.and_then(|i| i.get(key))
+            .and_then(|i| i.as_integer())
+            .map(|i| i as u32)
+    };
+
+    (version("min_sdk_version"), version("target_sdk_version"))
+}
+
+fn platform_jar(api: u32) -> Option<PathBuf> {
+    android_sdk()
+        .map(|i| {
+            i.join("platforms")
+                .join(format!("android-{}", api))
+                .join("android.jar")
+        })
+        .filter(|i| i.exists())
+}
+
+fn lookup(jar: &AndroidJar, member: &Member) -> Lookup {
+    match member.kind {
+        MemberKind::Field => jar.find_field(&member.class, &member.name, &member.descriptor),
+        _ => jar.find_method(&member.class, &member.name, &member.descriptor),
+    }
+}
+
+fn main() {
+    let opts = Options::parse().unwrap_or_else(|e| {
+        eprintln!("error: {}\n\n{}", e, USAGE);
+        exit(2);
+    });
+    let manifest_path = opts
+        .manifest_path
+        .clone()
+        .unwrap_or_else(|| "Cargo.toml".into());
+    let (min_sdk, target_sdk) = read_sdk_versions(&manifest_path);
+    let min_sdk = opts.min_sdk.or(min_sdk);
+    let api = opts.api.or(target_sdk);
+
+    let jar_path = match api {
+        Some(api) => platform_jar(api),
+        None => android_jar(None),
+    }
+    .unwrap_or_else(|| {
+        eprintln!(
+            "error: Can't find android.jar, please install the platform or set ANDROID_HOME."
+        );
+        exit(2);
+    });
+    let jar = AndroidJar::open(&jar_path).unwrap_or_else(|e| {
+        eprintln!("error: Can't read {}: {}", jar_path.display(), e);
+        exit(2);
+    });
+    let min_jar = min_sdk.and_then(|level| match platform_jar(level) {
+        Some(path) => Some((level, AndroidJar::open(path).ok()?)),
+        None => {
+            eprintln!(
+                "warning: android.jar for API {} is not installed, skipping the min_sdk_version check.",
+                level
+            );
+            None
+        }
+    });
+
+    let paths = if opts.paths.is_empty() {
+        vec![PathBuf::from("src")]
+    } else {
+        opts.paths
+    };
+    let result = scan(&paths).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        exit(2);
+    });
+
+    let mut errors = 0;
+    let mut warnings = 0;
+    // `since`声明的API级别对应的android.jar，没有安装时为None，此时信任声明的级别。
+    let mut since_jars = HashMap::new();
+    for i in result.unresolved.iter() {
+        warnings += 1;
+        println!(
+            "{}:{}: warning: `{}`: can't compute the descriptor of `{}`",
+            i.file.display(),
+            i.line,
+            i.rust_name,
+            i.ty
+        );
+    }
+    for m in result.members.iter() {
+        let location = format!("{}:{}", m.file.display(), m.line);
+        let java = format!("{}.{}{}", m.class, m.name, m.descriptor);
+        match lookup(&jar, m) {
+            Lookup::ClassMissing => {
+                warnings += 1;
+                println!(
+                    "{}: warning: `{}`: class `{}` is not in android.jar",
+                    location, m.rust_name, m.class
+                );
+                continue;
+            }
+            Lookup::MemberMissing { candidates } => {
+                errors += 1;
+                println!(
+                    "{}: error: `{}`: `{}` not found",
+                    location, m.rust_name, java
+                );
+                for c in candidates {
+                    println!("    candidate: {}.{}{}", m.class, m.name, c);
+                }
+                continue;
+            }
+            Lookup::Found { is_static } if is_static != m.is_static => {
+                errors += 1;
+                let (declared, actual) = if m.is_static {
+                    ("static", "an instance")
+                } else {
+                    ("an instance", "static")
+                };
+                println!(
+                    "{}: error: `{}`: declared as {} member but `{}` is {} member",
+                    location, m.rust_name, declared, java, actual
+                );
+                continue;
+            }
+            Lookup::Found { .. } => (),
+        }
+        if let Some((level, ref min_jar)) = min_jar
+            && !matches!(lookup(min_jar, m), Lookup::Found { .. })
+        {
+            match m.since {
+                // 生成的代码在低于`since`的系统中不会调用此成员，只需要确认成员在`since`中已经存在。
+                Some(since) if since > level => {
+                    let since_jar = since_jars
+                        .entry(since)
+                        .or_insert_with(|| AndroidJar::open(platform_jar(since)?).ok());
+                    if let Some(since_jar) = since_jar
+                        && !matches!(lookup(since_jar, m), Lookup::Found { .. })
+                    {
+                        warnings += 1;
+                        println!(
+                            "{}: warning: `{}`: `{}` is newer than `since = {}`",
+                            location, m.rust_name, java, since
+                        );
+                    }
+                }
+                _ => {
+                    warnings += 1;
+                    println!(
+                        "{}: warning: `{}`: `{}` is newer than min_sdk_version {}",
+                        location, m.rust_name, java, level
+                    );
+                }
+            }
+        }
+    }
+
+    println!(
+        "Checked {} members against {}: {} errors, {} warnings.",
+        result.members.len(),
+        jar_path.display(),
+        errors,
+        warnings
+    );
+    if errors > 0 {
+        exit(1);
+    }
+}
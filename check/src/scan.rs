@@ -0,0 +1,1116 @@
+/*
+ * Copyright (c) 2025. The RigelA open source project team and
+ * its contributors reserve all rights.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software distributed under the
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and limitations under the License.
+ */
+
+use heck::ToLowerCamelCase;
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fs::{read_dir, read_to_string},
+    path::{Path, PathBuf},
+};
+use syn::{
+    Attribute, Expr, FnArg, GenericArgument, Generics, Ident, ImplItem, ItemEnum, ItemImpl,
+    ItemStruct, ItemTrait, Lit, Meta, MetaNameValue, PatType, PathArguments, ReturnType, Signature,
+    Token, TraitItem, Type, TypeParamBound, bracketed, parenthesized,
+    parse::ParseStream,
+    punctuated::Punctuated,
+    token::{Bracket, Paren},
+    visit::Visit,
+};
+
+/// 成员的种类。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MemberKind {
+    Method,
+    Field,
+    Constructor,
+}
+
+/// 从源代码中找到的一个java成员的声明。
+#[derive(Debug)]
+pub struct Member {
+    pub file: PathBuf,
+    pub line: usize,
+    pub rust_name: String,
+    pub kind: MemberKind,
+    pub class: String,
+    pub name: String,
+    pub descriptor: String,
+    pub is_static: bool,
+    /// `java_method(since = N)`声明的最低API级别，低于此级别时生成的代码不会调用此成员。
+    pub since: Option<u32>,
+}
+
+/// 无法计算描述符的声明。
+#[derive(Debug)]
+pub struct Unresolved {
+    pub file: PathBuf,
+    pub line: usize,
+    pub rust_name: String,
+    pub ty: String,
+}
+
+/// 扫描的结果。
+#[derive(Debug, Default)]
+pub struct ScanResult {
+    pub members: Vec<Member>,
+    pub unresolved: Vec<Unresolved>,
+}
+
+/// 扫描给定路径（文件或目录）中的所有rust源代码。
+pub fn scan(paths: &[PathBuf]) -> Result<ScanResult, Box<dyn Error>> {
+    let mut files = Vec::new();
+    for path in paths {
+        collect_files(path, &mut files)?;
+    }
+    let mut parsed = Vec::with_capacity(files.len());
+    for file in files {
+        let content = read_to_string(&file)?;
+        let ast = syn::parse_file(&content).map_err(|e| format!("{}: {}", file.display(), e))?;
+        parsed.push((file, ast));
+    }
+
+    Ok(scan_files(&parsed))
+}
+
+/// 扫描已经解析的源代码。
+fn scan_files(parsed: &[(PathBuf, syn::File)]) -> ScanResult {
+    // 第一遍收集所有java类型，第二遍计算成员的描述符，这样类型的声明顺序就无关紧要了。
+    let mut types = TypeCollector::default();
+    for (_, ast) in parsed.iter() {
+        types.visit_file(ast);
+    }
+    let mut members = MemberCollector {
+        types: &types.types,
+        constants: &types.constants,
+        file: PathBuf::new(),
+        result: Default::default(),
+    };
+    for (file, ast) in parsed.iter() {
+        members.file = file.clone();
+        members.visit_file(ast);
+    }
+
+    members.result
+}
+
+fn collect_files(path: &Path, files: &mut Vec<PathBuf>) -> Result<(), Box<dyn Error>> {
+    if path.is_dir() {
+        let mut entries = read_dir(path)?
+            .map(|i| i.map(|i| i.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        entries.sort();
+        for entry in entries {
+            collect_files(&entry, files)?;
+        }
+    } else if path.extension().is_some_and(|i| i == "rs") {
+        files.push(path.to_path_buf());
+    }
+
+    Ok(())
+}
+
+fn find_attr<'a>(attrs: &'a [Attribute], name: &str) -> Option<&'a Attribute> {
+    attrs
+        .iter()
+        .find(|i| i.path().segments.last().is_some_and(|i| i.ident == name))
+}
+
+fn attr_args(attr: &Attribute) -> Vec<MetaNameValue> {
+    // 忽略`nonvirtual`这样的标记，它们不影响描述符。
+    attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+        .map(|i| {
+            i.into_iter()
+                .filter_map(|i| match i {
+                    Meta::NameValue(i) => Some(i),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 与宏一致地获取属性中`key = "..."`的值，跳过`implements = [...]`和`builder(...)`这样不能作为表达式解析的参数。
+fn attr_value(attr: &Attribute, key: &str) -> Option<String> {
+    attr.parse_args_with(|input: ParseStream| {
+        let mut value = None;
+        while !input.is_empty() {
+            let ident: Ident = input.parse()?;
+            if input.peek(Token![=]) {
+                input.parse::<Token![=]>()?;
+                if input.peek(Bracket) {
+                    let content;
+                    bracketed!(content in input);
+                    content.parse::<TokenStream>()?;
+                } else {
+                    let expr: Expr = input.parse()?;
+                    if ident == key {
+                        value = lit_str(&expr);
+                    }
+                }
+            } else if input.peek(Paren) {
+                let content;
+                parenthesized!(content in input);
+                content.parse::<TokenStream>()?;
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(value)
+    })
+    .ok()
+    .flatten()
+}
+
+fn lit_str(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(l) => match &l.lit {
+            Lit::Str(s) => Some(s.value()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn lit_int(expr: &Expr) -> Option<u32> {
+    match expr {
+        Expr::Lit(l) => match &l.lit {
+            Lit::Int(i) => i.base10_parse().ok(),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn tokens(t: impl ToTokens) -> String {
+    t.to_token_stream().to_string()
+}
+
+/// rust基本类型对应的java描述符，与宏中的映射（`macros/src/descriptor.rs`）保持一致，测试会比较两者。
+fn primitive_descriptor(ty: &str) -> Option<&'static str> {
+    match ty {
+        "i8" | "u8" => Some("B"),
+        "char" => Some("C"),
+        "i16" | "u16" => Some("S"),
+        "i32" | "u32" => Some("I"),
+        "i64" | "u64" => Some("J"),
+        "f32" => Some("F"),
+        "f64" => Some("D"),
+        "bool" => Some("Z"),
+        "()" => Some("V"),
+        _ => None,
+    }
+}
+
+#[derive(Default)]
+struct TypeCollector {
+    types: HashMap<String, String>,
+    // `java_constants`和`java_flags`生成的类型，按照int传递。
+    constants: HashSet<String>,
+}
+
+impl<'ast> Visit<'ast> for TypeCollector {
+    fn visit_item_enum(&mut self, i: &'ast ItemEnum) {
+        if find_attr(&i.attrs, "java_constants").is_some()
+            || find_attr(&i.attrs, "java_flags").is_some()
+        {
+            self.constants.insert(i.ident.to_string());
+        } else if let Some(name) =
+            find_attr(&i.attrs, "java_enum").and_then(|i| attr_value(i, "name"))
+        {
+            self.types.insert(i.ident.to_string(), name);
+        }
+    }
+
+    fn visit_item_struct(&mut self, i: &'ast ItemStruct) {
+        // `JavaBean`使用`#[java(class = "...")]`指定java类。
+        let name = match find_attr(&i.attrs, "java_class") {
+            Some(attr) => attr_value(attr, "name"),
+            None => find_attr(&i.attrs, "java").and_then(|i| attr_value(i, "class")),
+        };
+        if let Some(name) = name {
+            self.types.insert(i.ident.to_string(), name);
+        }
+    }
+
+    fn visit_item_trait(&mut self, i: &'ast ItemTrait) {
+        if let Some(attr) = find_attr(&i.attrs, "java_interface")
+            && let Some(name) = attr_value(attr, "name")
+        {
+            // `builder`和`wrapper`生成的`<Trait>Impl`也对应同一个java接口。
+            let generated = attr
+                .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .is_ok_and(|i| {
+                    i.iter()
+                        .any(|i| i.path().is_ident("builder") || i.path().is_ident("wrapper"))
+                });
+            if generated {
+                self.types.insert(format!("{}Impl", i.ident), name.clone());
+            }
+            self.types.insert(i.ident.to_string(), name);
+        }
+        syn::visit::visit_item_trait(self, i);
+    }
+}
+
+/// 需要转换后才能传递给java的参数类型，与宏中的`BorrowedArg`对应。
+enum BorrowedArg<'a> {
+    /// `&str`、`&String`或`impl AsRef<str>`，作为java字符串传递。
+    Str,
+    /// `impl Into<T>`或`Option<&T>`，按照`T`传递。
+    Type(&'a Type),
+    /// `impl Trait`、`&impl Trait`或`&dyn Trait`，按照接口传递。
+    Interface(&'a syn::Path),
+}
+
+fn single_type_arg(args: &PathArguments) -> Option<&Type> {
+    match args {
+        PathArguments::AngleBracketed(a) if a.args.len() == 1 => match a.args.first() {
+            Some(GenericArgument::Type(t)) => Some(t),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn borrowed_arg(ty: &Type) -> Option<BorrowedArg<'_>> {
+    if let Type::Path(p) = ty
+        && let Some(last) = p.path.segments.last()
+        && last.ident == "Option"
+    {
+        let inner = single_type_arg(&last.arguments)?;
+        return match (borrowed_arg_inner(inner, false), inner) {
+            (Some(kind), _) => Some(kind),
+            (None, Type::Reference(r)) => Some(BorrowedArg::Type(&r.elem)),
+            _ => None,
+        };
+    }
+    borrowed_arg_inner(ty, true)
+}
+
+fn borrowed_arg_inner(ty: &Type, owned: bool) -> Option<BorrowedArg<'_>> {
+    match ty {
+        Type::Reference(r) => match r.elem.as_ref() {
+            Type::Path(p) if p.path.is_ident("str") || p.path.is_ident("String") => {
+                Some(BorrowedArg::Str)
+            }
+            Type::TraitObject(t) => impl_arg(&t.bounds, false),
+            Type::ImplTrait(t) => impl_arg(&t.bounds, false),
+            _ => None,
+        },
+        Type::ImplTrait(t) => impl_arg(&t.bounds, owned),
+        _ => None,
+    }
+}
+
+fn impl_arg(
+    bounds: &Punctuated<TypeParamBound, Token![+]>,
+    owned: bool,
+) -> Option<BorrowedArg<'_>> {
+    let path = bounds.iter().find_map(|i| match i {
+        TypeParamBound::Trait(t) => Some(&t.path),
+        _ => None,
+    })?;
+    let last = path.segments.last()?;
+    match single_type_arg(&last.arguments) {
+        Some(Type::Path(p)) if last.ident == "AsRef" && p.path.is_ident("str") => {
+            Some(BorrowedArg::Str)
+        }
+        Some(ty) if last.ident == "Into" && owned => Some(BorrowedArg::Type(ty)),
+        _ => Some(BorrowedArg::Interface(path)),
+    }
+}
+
+/// 可变参数的元素类型，支持`&[T]`、`Vec<T>`和`impl IntoIterator<Item = T>`（`T`也可以是引用）。
+fn varargs_element_type(ty: &Type) -> Option<&Type> {
+    let elem = match ty {
+        Type::Reference(r) => match r.elem.as_ref() {
+            Type::Slice(s) => s.elem.as_ref(),
+            _ => return None,
+        },
+        Type::Path(p) => {
+            let last = p.path.segments.last()?;
+            if last.ident != "Vec" {
+                return None;
+            }
+            single_type_arg(&last.arguments)?
+        }
+        Type::ImplTrait(t) => t.bounds.iter().find_map(|b| {
+            let TypeParamBound::Trait(b) = b else {
+                return None;
+            };
+            let last = b.path.segments.last()?;
+            let PathArguments::AngleBracketed(args) = &last.arguments else {
+                return None;
+            };
+            if last.ident != "IntoIterator" {
+                return None;
+            }
+            args.args.iter().find_map(|a| match a {
+                GenericArgument::AssocType(a) if a.ident == "Item" => Some(&a.ty),
+                _ => None,
+            })
+        })?,
+        _ => return None,
+    };
+    match elem {
+        Type::Reference(r) => Some(r.elem.as_ref()),
+        _ => Some(elem),
+    }
+}
+
+/// 计算描述符时需要的上下文。
+struct Context<'a> {
+    self_class: Option<&'a str>,
+    generics: &'a Generics,
+    type_bounds: &'a [(String, String)],
+}
+
+struct MemberCollector<'a> {
+    types: &'a HashMap<String, String>,
+    constants: &'a HashSet<String>,
+    file: PathBuf,
+    result: ScanResult,
+}
+
+impl MemberCollector<'_> {
+    fn class_of_path(&self, ty: &str, ctx: &Context) -> Option<String> {
+        match ty {
+            "Self" => ctx.self_class.map(ToString::to_string),
+            "String" => Some("java/lang/String".to_string()),
+            _ => self.types.get(ty).cloned(),
+        }
+    }
+
+    /// 与宏中的`get_type_descriptor_token`一致地计算一个类型的描述符。
+    fn type_descriptor(&self, ty: &Type, ctx: &Context) -> Option<String> {
+        let ty_str = tokens(ty);
+        if let Some(primitive) = primitive_descriptor(&ty_str) {
+            return Some(primitive.to_string());
+        }
+
+        if let Some(gt) = ctx
+            .generics
+            .type_params()
+            .find(|i| i.ident == ty_str.as_str())
+        {
+            return match gt.bounds.first() {
+                Some(TypeParamBound::Trait(t)) => self
+                    .class_of_path(&t.path.segments.last()?.ident.to_string(), ctx)
+                    .map(|i| format!("L{};", i)),
+                _ => None,
+            };
+        }
+        // 约束到`JType`这类非java类型的trait时，描述符仍然由类型本身决定。
+        if let Some(class) = ctx
+            .type_bounds
+            .iter()
+            .find(|i| i.0 == ty_str)
+            .and_then(|(_, bound)| self.class_of_path(bound.rsplit(' ').next()?, ctx))
+        {
+            return Some(format!("L{};", class));
+        }
+
+        match ty {
+            Type::Reference(r) => self.type_descriptor(&r.elem, ctx),
+            Type::Paren(p) => self.type_descriptor(&p.elem, ctx),
+            Type::Slice(s) => Some(format!("[{}", self.type_descriptor(&s.elem, ctx)?)),
+            Type::Path(p) if p.qself.is_none() => {
+                let ident = p.path.segments.last()?.ident.to_string();
+                if self.constants.contains(&ident) {
+                    return Some("I".to_string());
+                }
+                self.class_of_path(&ident, ctx).map(|i| format!("L{};", i))
+            }
+            _ => None,
+        }
+    }
+
+    /// 与宏中的`unwrap_type`一致地去掉一层泛型或引用。
+    fn unwrap_type(ty: &Type) -> &Type {
+        match ty {
+            Type::Path(p) if p.qself.is_none() && p.path.segments.len() == 1 => {
+                match &p.path.segments[0].arguments {
+                    PathArguments::AngleBracketed(a) => match a.args.first() {
+                        Some(GenericArgument::Type(t)) => t,
+                        _ => ty,
+                    },
+                    _ => ty,
+                }
+            }
+            Type::Reference(r) => &r.elem,
+            _ => ty,
+        }
+    }
+
+    /// 与宏中的`get_borrowed_arg_token`和`varargs_element_type`一致地计算参数的描述符。
+    fn arg_descriptor(&self, arg: &PatType, ctx: &Context) -> Option<String> {
+        if find_attr(&arg.attrs, "varargs").is_some() {
+            let elem = varargs_element_type(&arg.ty)?;
+            return Some(format!("[{}", self.type_descriptor(elem, ctx)?));
+        }
+        match borrowed_arg(&arg.ty) {
+            Some(BorrowedArg::Str) => Some("Ljava/lang/String;".to_string()),
+            Some(BorrowedArg::Type(ty)) => self.type_descriptor(ty, ctx),
+            Some(BorrowedArg::Interface(path)) => self
+                .class_of_path(&path.segments.last()?.ident.to_string(), ctx)
+                .map(|i| format!("L{};", i)),
+            None => self.type_descriptor(Self::unwrap_type(&arg.ty), ctx),
+        }
+    }
+
+    fn signature(
+        &mut self,
+        sig: &Signature,
+        rust_name: &str,
+        ctx: &Context,
+    ) -> Option<(bool, Vec<String>, String)> {
+        let mut is_static = true;
+        let mut args = Vec::new();
+        for i in sig.inputs.iter() {
+            match i {
+                FnArg::Receiver(_) => is_static = false,
+                FnArg::Typed(t) => {
+                    let res = self.arg_descriptor(t, ctx);
+                    args.push(self.report(res, &t.ty, rust_name, sig)?);
+                }
+            }
+        }
+        let ret = match &sig.output {
+            ReturnType::Default => "V".to_string(),
+            ReturnType::Type(_, t) => self.resolve(t, rust_name, sig, ctx)?,
+        };
+
+        Some((is_static, args, ret))
+    }
+
+    fn resolve(
+        &mut self,
+        ty: &Type,
+        rust_name: &str,
+        sig: &Signature,
+        ctx: &Context,
+    ) -> Option<String> {
+        let res = self.type_descriptor(Self::unwrap_type(ty), ctx);
+        self.report(res, ty, rust_name, sig)
+    }
+
+    /// 记录无法计算描述符的类型。
+    fn report(
+        &mut self,
+        res: Option<String>,
+        ty: &Type,
+        rust_name: &str,
+        sig: &Signature,
+    ) -> Option<String> {
+        if res.is_none() {
+            self.result.unresolved.push(Unresolved {
+                file: self.file.clone(),
+                line: sig.ident.span().start().line,
+                rust_name: rust_name.to_string(),
+                ty: tokens(ty),
+            });
+        }
+        res
+    }
+
+    fn check_fn(
+        &mut self,
+        attrs: &[Attribute],
+        sig: &Signature,
+        owner: &str,
+        self_ty: &str,
+        self_class: Option<&str>,
+    ) {
+        let rust_name = format!("{}::{}", self_ty, sig.ident);
+        let line = sig.ident.span().start().line;
+
+        let is_static = sig.receiver().is_none();
+
+        if let Some(attr) = find_attr(attrs, "java_method") {
+            let mut type_bounds = Vec::new();
+            let mut name = sig.ident.to_string().to_lower_camel_case();
+            let mut java_sig = None;
+            let mut since = None;
+            for i in attr_args(attr) {
+                if i.path.is_ident("type_bound") {
+                    if let Expr::Tuple(t) = &i.value
+                        && let (Some(a), Some(b)) = (t.elems.first(), t.elems.iter().nth(1))
+                    {
+                        type_bounds.push((tokens(a), tokens(b)));
+                    }
+                } else if i.path.is_ident("overload") {
+                    name = tokens(&i.value).to_lower_camel_case();
+                } else if i.path.is_ident("name") {
+                    name = lit_str(&i.value).unwrap_or(name);
+                } else if i.path.is_ident("sig") {
+                    java_sig = lit_str(&i.value);
+                } else if i.path.is_ident("since") {
+                    since = lit_int(&i.value);
+                }
+            }
+            let descriptor = match java_sig {
+                Some(java_sig) => java_sig,
+                None => {
+                    let ctx = Context {
+                        self_class,
+                        generics: &sig.generics,
+                        type_bounds: &type_bounds,
+                    };
+                    let Some((_, args, ret)) = self.signature(sig, &rust_name, &ctx) else {
+                        return;
+                    };
+                    format!("({}){}", args.concat(), ret)
+                }
+            };
+            self.result.members.push(Member {
+                file: self.file.clone(),
+                line,
+                rust_name,
+                kind: MemberKind::Method,
+                class: owner.to_string(),
+                name,
+                descriptor,
+                is_static,
+                since,
+            });
+        } else if let Some(attr) = find_attr(attrs, "java_constructor") {
+            let java_sig = attr_args(attr)
+                .into_iter()
+                .find(|i| i.path.is_ident("sig"))
+                .and_then(|i| lit_str(&i.value));
+            let descriptor = match java_sig {
+                Some(java_sig) => java_sig,
+                None => {
+                    let ctx = Context {
+                        self_class,
+                        generics: &sig.generics,
+                        type_bounds: &[],
+                    };
+                    let Some((_, args, _)) = self.signature(sig, &rust_name, &ctx) else {
+                        return;
+                    };
+                    format!("({})V", args.concat())
+                }
+            };
+            self.result.members.push(Member {
+                file: self.file.clone(),
+                line,
+                rust_name,
+                kind: MemberKind::Constructor,
+                class: owner.to_string(),
+                name: "<init>".to_string(),
+                descriptor,
+                is_static: false,
+                since: None,
+            });
+        } else if let Some(attr) = find_attr(attrs, "java_field") {
+            let name = sig.ident.to_string().to_lower_camel_case();
+            let (is_set, mut name) = if let Some(name) = name.strip_prefix("get") {
+                (false, name.to_lower_camel_case())
+            } else if let Some(name) = name.strip_prefix("set") {
+                (true, name.to_lower_camel_case())
+            } else {
+                return;
+            };
+            let mut java_sig = None;
+            for i in attr_args(attr) {
+                if i.path.is_ident("name") {
+                    name = lit_str(&i.value).unwrap_or(name);
+                } else if i.path.is_ident("sig") {
+                    java_sig = lit_str(&i.value);
+                }
+            }
+            let descriptor = match java_sig {
+                Some(java_sig) => java_sig,
+                None => {
+                    let ctx = Context {
+                        self_class,
+                        generics: &sig.generics,
+                        type_bounds: &[],
+                    };
+                    let Some((_, args, ret)) = self.signature(sig, &rust_name, &ctx) else {
+                        return;
+                    };
+                    if is_set {
+                        args.into_iter().next().unwrap_or_default()
+                    } else {
+                        ret
+                    }
+                }
+            };
+            self.result.members.push(Member {
+                file: self.file.clone(),
+                line,
+                rust_name,
+                kind: MemberKind::Field,
+                class: owner.to_string(),
+                name,
+                descriptor,
+                is_static,
+                since: None,
+            });
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for MemberCollector<'_> {
+    fn visit_item_impl(&mut self, i: &'ast ItemImpl) {
+        let Type::Path(p) = i.self_ty.as_ref() else {
+            return;
+        };
+        let Some(self_ty) = p.path.segments.last().map(|i| i.ident.to_string()) else {
+            return;
+        };
+        let self_class = self.types.get(&self_ty).cloned();
+        // 实现java接口时，方法由接口声明，否则由类本身声明。
+        let owner = i
+            .trait_
+            .as_ref()
+            .and_then(|(_, p, _)| p.segments.last())
+            .and_then(|i| self.types.get(&i.ident.to_string()))
+            .or(self_class.as_ref())
+            .cloned();
+        let Some(owner) = owner else {
+            return;
+        };
+
+        for item in i.items.iter() {
+            if let ImplItem::Fn(f) = item {
+                self.check_fn(&f.attrs, &f.sig, &owner, &self_ty, self_class.as_deref());
+            }
+        }
+    }
+
+    fn visit_item_trait(&mut self, i: &'ast ItemTrait) {
+        let self_ty = i.ident.to_string();
+        let Some(owner) = self.types.get(&self_ty).cloned() else {
+            return;
+        };
+        for item in i.items.iter() {
+            if let TraitItem::Fn(f) = item {
+                self.check_fn(&f.attrs, &f.sig, &owner, &self_ty, Some(&owner));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scan_source(source: &str) -> ScanResult {
+        let ast = syn::parse_file(source).unwrap();
+        scan_files(&[(PathBuf::from("lib.rs"), ast)])
+    }
+
+    /// 按rust名称查找成员的描述符。
+    fn descriptor<'a>(result: &'a ScanResult, rust_name: &str) -> &'a str {
+        result
+            .members
+            .iter()
+            .find(|i| i.rust_name == rust_name)
+            .map(|i| i.descriptor.as_str())
+            .unwrap_or_else(|| panic!("`{}` not found", rust_name))
+    }
+
+    const TYPES: &str = r#"
+        #[java_class(name = "android/os/Handler")]
+        pub struct Handler;
+
+        #[java_class(name = "android/view/View", extends = Object)]
+        pub struct View;
+
+        #[java_interface(name = "java/lang/Runnable", builder)]
+        pub trait Runnable {
+            fn run(&self);
+        }
+
+        #[java_constants(prefix = "GRAVITY_")]
+        pub enum Gravity {
+            Center = 17,
+        }
+
+        #[java_flags]
+        pub enum ViewFlags {
+            Visible = 0,
+        }
+
+        #[java_class(name = "java/io/File", extends = Object, implements = [Comparable<File>])]
+        pub struct File;
+
+        #[java_enum(name = "java/lang/Thread$State")]
+        pub enum ThreadState {
+            New,
+        }
+
+        #[derive(JavaBean)]
+        #[java(class = "android/graphics/Rect")]
+        pub struct RectData {
+            pub left: i32,
+        }
+    "#;
+
+    #[test]
+    fn test_primitives_match_macros() {
+        let inputs = [
+            "i8", "u8", "char", "i16", "u16", "i32", "u32", "i64", "u64", "f32", "f64", "bool",
+            "()", "usize", "isize", "i128", "str", "String", "Self", "View", "& i32", "[u8]",
+        ];
+        for ty in inputs {
+            assert_eq!(
+                crate::descriptor::primitive_descriptor(ty),
+                primitive_descriptor(ty),
+                "`{}`",
+                ty
+            );
+        }
+    }
+
+    #[test]
+    fn test_primitives() {
+        let result = scan_source(&format!(
+            "{}{}",
+            TYPES,
+            r#"
+            impl Handler {
+                #[java_method]
+                pub fn signed(a: i8, b: char, c: i16, d: i32, e: i64, f: f32, g: f64, h: bool) {}
+                #[java_method]
+                pub fn unsigned(&self, a: u8, b: u16, c: u32) -> u64 {}
+                #[java_method]
+                pub fn checked(&self) -> Result<bool> {}
+                #[java_method]
+                pub fn maybe(&self) -> Option<i32> {}
+            }
+            "#
+        ));
+        assert_eq!("(BCSIJFDZ)V", descriptor(&result, "Handler::signed"));
+        assert_eq!("(BSI)J", descriptor(&result, "Handler::unsigned"));
+        assert_eq!("()Z", descriptor(&result, "Handler::checked"));
+        assert_eq!("()I", descriptor(&result, "Handler::maybe"));
+        let signed = result
+            .members
+            .iter()
+            .find(|i| i.rust_name == "Handler::signed")
+            .unwrap();
+        assert!(signed.is_static);
+        assert_eq!("signed", signed.name);
+        assert!(result.unresolved.is_empty());
+    }
+
+    #[test]
+    fn test_class_names() {
+        let result = scan_source(&format!(
+            "{}{}",
+            TYPES,
+            r#"
+            impl Handler {
+                #[java_method]
+                pub fn get_main_looper_view(&self, v: &View, name: String) -> Option<Self> {}
+                #[java_method(name = "obtain")]
+                pub fn create(&self) -> Result<View> {}
+                #[java_method(overload = post)]
+                pub fn post_runnable(&self, r: &RunnableImpl) -> bool {}
+                #[java_constructor]
+                pub fn new(v: &View) -> Result<Self> {}
+                #[java_field]
+                pub fn get_target(&self) -> Handler {}
+                #[java_field(name = "mView")]
+                pub fn set_view(&self, v: &View) {}
+            }
+            "#
+        ));
+        assert_eq!(
+            "(Landroid/view/View;Ljava/lang/String;)Landroid/os/Handler;",
+            descriptor(&result, "Handler::get_main_looper_view")
+        );
+        let create = result
+            .members
+            .iter()
+            .find(|i| i.rust_name == "Handler::create")
+            .unwrap();
+        assert_eq!("obtain", create.name);
+        assert_eq!("()Landroid/view/View;", create.descriptor);
+        let post = result
+            .members
+            .iter()
+            .find(|i| i.rust_name == "Handler::post_runnable")
+            .unwrap();
+        assert_eq!("post", post.name);
+        assert_eq!("(Ljava/lang/Runnable;)Z", post.descriptor);
+
+        let new = result
+            .members
+            .iter()
+            .find(|i| i.rust_name == "Handler::new")
+            .unwrap();
+        assert_eq!(MemberKind::Constructor, new.kind);
+        assert_eq!("<init>", new.name);
+        assert_eq!("(Landroid/view/View;)V", new.descriptor);
+
+        let target = result
+            .members
+            .iter()
+            .find(|i| i.rust_name == "Handler::get_target")
+            .unwrap();
+        assert_eq!(MemberKind::Field, target.kind);
+        assert_eq!(
+            ("target", "Landroid/os/Handler;"),
+            (target.name.as_str(), target.descriptor.as_str())
+        );
+        let view = result
+            .members
+            .iter()
+            .find(|i| i.rust_name == "Handler::set_view")
+            .unwrap();
+        assert_eq!(
+            ("mView", "Landroid/view/View;"),
+            (view.name.as_str(), view.descriptor.as_str())
+        );
+    }
+
+    #[test]
+    fn test_arrays() {
+        let result = scan_source(&format!(
+            "{}{}",
+            TYPES,
+            r#"
+            impl Handler {
+                #[java_method]
+                pub fn bytes(&self, data: &[u8]) {}
+                #[java_method]
+                pub fn views(&self, views: &[View]) {}
+            }
+            "#
+        ));
+        assert_eq!("([B)V", descriptor(&result, "Handler::bytes"));
+        assert_eq!(
+            "([Landroid/view/View;)V",
+            descriptor(&result, "Handler::views")
+        );
+    }
+
+    #[test]
+    fn test_generics() {
+        let result = scan_source(&format!(
+            "{}{}",
+            TYPES,
+            r#"
+            impl Handler {
+                #[java_method]
+                pub fn post<R: Runnable>(&self, r: &R) -> bool {}
+            }
+
+            #[java_interface(name = "android/view/ViewParent")]
+            pub trait ViewParent {
+                #[java_method(type_bound = (Self::V, View))]
+                fn get_child(&self, index: i32) -> Option<Self::V>;
+                #[java_method(type_bound = (Self::R, Runnable))]
+                fn post(&self, r: Self::R) -> bool;
+            }
+            "#
+        ));
+        assert_eq!(
+            "(Ljava/lang/Runnable;)Z",
+            descriptor(&result, "Handler::post")
+        );
+        assert_eq!(
+            "(I)Landroid/view/View;",
+            descriptor(&result, "ViewParent::get_child")
+        );
+        assert_eq!(
+            "(Ljava/lang/Runnable;)Z",
+            descriptor(&result, "ViewParent::post")
+        );
+        // 接口中声明的方法属于接口。
+        let post = result
+            .members
+            .iter()
+            .find(|i| i.rust_name == "ViewParent::post")
+            .unwrap();
+        assert_eq!("android/view/ViewParent", post.class);
+    }
+
+    #[test]
+    fn test_borrowed_args() {
+        let result = scan_source(&format!(
+            "{}{}",
+            TYPES,
+            r#"
+            impl Handler {
+                #[java_method]
+                pub fn set_name(&self, name: &str, tag: impl AsRef<str>, label: &String) {}
+                #[java_method]
+                pub fn set_title(&self, title: Option<&str>, view: Option<&View>) {}
+                #[java_method]
+                pub fn set_delay(&self, delay: impl Into<i64>, view: impl Into<View>) {}
+                #[java_method]
+                pub fn post(&self, a: impl Runnable, b: &impl Runnable, c: &dyn Runnable) -> bool {}
+                #[java_method]
+                pub fn remove(&self, r: Option<&dyn Runnable>) {}
+            }
+            "#
+        ));
+        assert_eq!(
+            "(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;)V",
+            descriptor(&result, "Handler::set_name")
+        );
+        assert_eq!(
+            "(Ljava/lang/String;Landroid/view/View;)V",
+            descriptor(&result, "Handler::set_title")
+        );
+        assert_eq!(
+            "(JLandroid/view/View;)V",
+            descriptor(&result, "Handler::set_delay")
+        );
+        assert_eq!(
+            "(Ljava/lang/Runnable;Ljava/lang/Runnable;Ljava/lang/Runnable;)Z",
+            descriptor(&result, "Handler::post")
+        );
+        assert_eq!(
+            "(Ljava/lang/Runnable;)V",
+            descriptor(&result, "Handler::remove")
+        );
+        assert!(result.unresolved.is_empty());
+    }
+
+    #[test]
+    fn test_varargs() {
+        let result = scan_source(&format!(
+            "{}{}",
+            TYPES,
+            r#"
+            impl Handler {
+                #[java_method]
+                pub fn concat(#[varargs] text: &[String]) -> String {}
+                #[java_method]
+                pub fn sum(#[varargs] values: Vec<i32>) -> i32 {}
+                #[java_method]
+                pub fn add(&self, first: &View, #[varargs] views: impl IntoIterator<Item = &View>) {}
+            }
+            "#
+        ));
+        assert_eq!(
+            "([Ljava/lang/String;)Ljava/lang/String;",
+            descriptor(&result, "Handler::concat")
+        );
+        assert_eq!("([I)I", descriptor(&result, "Handler::sum"));
+        assert_eq!(
+            "(Landroid/view/View;[Landroid/view/View;)V",
+            descriptor(&result, "Handler::add")
+        );
+        assert!(result.unresolved.is_empty());
+    }
+
+    #[test]
+    fn test_class_attrs() {
+        let result = scan_source(&format!(
+            "{}{}",
+            TYPES,
+            r#"
+            impl Handler {
+                #[java_method]
+                pub fn get_dir(&self, name: &str) -> Option<File> {}
+                #[java_method]
+                pub fn get_state(&self) -> Result<ThreadState> {}
+                #[java_method]
+                pub fn get_bounds(&self, bounds: &RectData) {}
+            }
+            "#
+        ));
+        assert_eq!(
+            "(Ljava/lang/String;)Ljava/io/File;",
+            descriptor(&result, "Handler::get_dir")
+        );
+        assert_eq!(
+            "()Ljava/lang/Thread$State;",
+            descriptor(&result, "Handler::get_state")
+        );
+        assert_eq!(
+            "(Landroid/graphics/Rect;)V",
+            descriptor(&result, "Handler::get_bounds")
+        );
+        assert!(result.unresolved.is_empty());
+    }
+
+    #[test]
+    fn test_api_level() {
+        let result = scan_source(&format!(
+            "{}{}",
+            TYPES,
+            r#"
+            impl Handler {
+                #[java_method(since = 31)]
+                pub fn has_callbacks(&self, r: &dyn Runnable) -> Result<bool> {}
+                #[java_method(until = 28)]
+                pub fn get_looper(&self) -> Option<Handler> {}
+            }
+            "#
+        ));
+        let since = |rust_name: &str| {
+            result
+                .members
+                .iter()
+                .find(|i| i.rust_name == rust_name)
+                .unwrap()
+                .since
+        };
+        assert_eq!(Some(31), since("Handler::has_callbacks"));
+        assert_eq!(None, since("Handler::get_looper"));
+    }
+
+    #[test]
+    fn test_constants() {
+        let result = scan_source(&format!(
+            "{}{}",
+            TYPES,
+            r#"
+            impl View {
+                #[java_method]
+                pub fn set_gravity(&self, gravity: Gravity) {}
+                #[java_method]
+                pub fn get_flags(&self) -> ViewFlags {}
+            }
+            "#
+        ));
+        assert_eq!("(I)V", descriptor(&result, "View::set_gravity"));
+        assert_eq!("()I", descriptor(&result, "View::get_flags"));
+    }
+
+    #[test]
+    fn test_unresolved() {
+        let result = scan_source(&format!(
+            "{}{}",
+            TYPES,
+            r#"
+            impl Handler {
+                #[java_method]
+                pub fn unknown(&self, a: Looper) {}
+                #[java_method(sig = "(Landroid/os/Looper;)V")]
+                pub fn known(&self, a: Looper) {}
+            }
+            "#
+        ));
+        assert_eq!(1, result.unresolved.len());
+        assert_eq!("Handler::unknown", result.unresolved[0].rust_name);
+        assert_eq!("Looper", result.unresolved[0].ty);
+        assert_eq!(
+            "(Landroid/os/Looper;)V",
+            descriptor(&result, "Handler::known")
+        );
+    }
+}
@@ -23,14 +23,14 @@ use droid_wrap::{
             TextView_OnEditorActionListenerImpl,
         },
     },
-    java::lang::{CharSequenceExt, CharSequenceImpl, RunnableImpl}, android_main
+    java::lang::{CharSequenceExt, RunnableImpl}, android_main
 };
 use std::sync::Arc;
 
 #[android_main]
 fn main() -> Result<()> {
     let act = Activity::fetch()?;
-    let cs = "hello".to_char_sequence::<CharSequenceImpl>()?;
+    let cs = "hello".to_char_sequence()?;
     dbg!(&cs);
     act.set_title(&cs);
     dbg!(&act);
@@ -38,47 +38,51 @@ fn main() -> Result<()> {
     let text_view = TextView::new(act.as_ref());
     text_view.set_text(
         "你好，这是一个用Rust构建的安卓示例。"
-            .to_char_sequence::<CharSequenceImpl>()
+            .to_char_sequence()
             .ok(),
     );
     let edit = EditText::new(act.as_ref());
 
-    let editor_listener = TextView_OnEditorActionListenerImpl::from_fn(|_, _, _| true)?;
+    let editor_listener = TextView_OnEditorActionListenerImpl::builder()
+        .on_editor_action(|_, _, _| true)
+        .build()?;
     edit.set_on_editor_action_listener(editor_listener.as_ref());
     // 请在合适的时机手动释放，因为rust无法感知java什么时候不再需要Listener。
     // editor_listener.release();
 
     let act2 = act.clone();
-    act.run_on_ui_thread(
-        RunnableImpl::from_fn(move || {
-            let params = LinearLayout_LayoutParams::new_with_weight(
-                ViewGroup_LayoutParams::MATCH_PARENT,
-                ViewGroup_LayoutParams::MATCH_PARENT,
-                1.0,
-            );
-            let layout = LinearLayout::new(act2.as_ref());
-            layout.set_orientation(LinearLayout::VERTICAL);
-            // layout.add_view(&text_view);
-            layout.set_content_description(Some("容器".to_char_sequence::<CharSequenceImpl>()?));
-            layout.set_layout_params(&params);
+    let setup = move || -> Result<()> {
+        let params = LinearLayout_LayoutParams::new_with_weight(
+            ViewGroup_LayoutParams::MATCH_PARENT,
+            ViewGroup_LayoutParams::MATCH_PARENT,
+            1.0,
+        );
+        let layout = LinearLayout::new(act2.as_ref());
+        layout.set_orientation(LinearLayout::VERTICAL);
+        // layout.add_view(&text_view);
+        layout.set_content_description(Some("容器".to_char_sequence()?));
+        layout.set_layout_params(&params);
 
-            act2.set_content_view(&layout);
+        act2.set_content_view(&layout);
 
-            let wm: WindowManagerImpl = act2.get_window_manager();
-            let params = WindowManager_LayoutParams::new();
-            let _ = wm.add_view(&text_view, &params);
-            wm.remove_view(&text_view);
-            let _ = wm.add_view(&edit, &params);
-            let runnable = RunnableImpl::from_fn(|| {
-                println!("post delayed");
-                Ok(())
-            })?;
-            edit.post_delayed(runnable.as_ref(), 100);
-            // 请在合适的时机手动释放，因为rust无法感知java什么时候不再需要Runnable。
-            // runnable.release();
-            Ok(())
-        })?
-        .as_ref(),
+        let wm: WindowManagerImpl = act2.get_window_manager();
+        let params = WindowManager_LayoutParams::new();
+        let _ = wm.add_view(&text_view, &params);
+        wm.remove_view(&text_view);
+        let _ = wm.add_view(&edit, &params);
+        let runnable = RunnableImpl::builder()
+            .run(|| println!("post delayed"))
+            .build()?;
+        edit.post_delayed(runnable.as_ref(), 100);
+        // 请在合适的时机手动释放，因为rust无法感知java什么时候不再需要Runnable。
+        // runnable.release();
+        Ok(())
+    };
+    act.run_on_ui_thread(
+        RunnableImpl::builder()
+            .run(move || setup().unwrap())
+            .build()?
+            .as_ref(),
     );
 
     Ok(())
@@ -0,0 +1,31 @@
+/*
+ * Copyright (c) 2024. The RigelA open source project team and
+ * its contributors reserve all rights.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software distributed under the
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and limitations under the License.
+ */
+
+//! rust基本类型到java描述符的映射。
+//! 此文件不依赖任何crate，`cargo-droid-check`的测试通过`#[path]`引用它，与检查工具自己的映射比较，确保两者不会分歧。
+
+/// 获取rust基本类型对应的java描述符，不是基本类型时返回`None`。
+pub(crate) fn primitive_descriptor(ty: &str) -> Option<&'static str> {
+    match ty {
+        "i8" | "u8" => Some("B"),
+        "char" => Some("C"),
+        "i16" | "u16" => Some("S"),
+        "i32" | "u32" => Some("I"),
+        "i64" | "u64" => Some("J"),
+        "f32" => Some("F"),
+        "f64" => Some("D"),
+        "bool" => Some("Z"),
+        "()" => Some("V"),
+        _ => None,
+    }
+}
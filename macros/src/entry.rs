@@ -11,35 +11,86 @@
  * See the License for the specific language governing permissions and limitations under the License.
  */
 
+use crate::utils::{MainBackend, MainMetadata};
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{ItemFn, parse2};
+use syn::{Error, ItemFn, parse2};
 
-pub(super) fn android_main(input: TokenStream) -> TokenStream {
-    let func: ItemFn = parse2(input).unwrap();
+pub(super) fn android_main(attrs: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
+    let attrs: MainMetadata = parse2(attrs)?;
+    let func: ItemFn = parse2(input)?;
     let name = &func.sig.ident;
 
-    quote! {
-        fn stop_unwind<F: FnOnce() -> T, T>(f: F) -> T {
-            match std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)) {
-                Ok(t) => t,
-                Err(err) => {
-                    eprintln!("attempt to unwind out of `rust` with err: {:?}", err);
-                    std::process::abort()
+    let level = &attrs.log_level;
+    let tag = match &attrs.log_tag {
+        Some(tag) => quote!(#tag),
+        None => quote!(env!("CARGO_PKG_NAME")),
+    };
+    let panic = &attrs.panic;
+    let show_error = attrs.show_error;
+    let options = quote! {
+        droid_wrap_utils::MainOptions {
+            tag: #tag,
+            level: droid_wrap_utils::LevelFilter::#level,
+            panic: droid_wrap_utils::PanicPolicy::#panic,
+            show_error: #show_error,
+        }
+    };
+
+    let start = match attrs.backend {
+        MainBackend::AndroidActivity => {
+            let call = match func.sig.inputs.len() {
+                0 => quote!(#name()),
+                1 => quote!(#name(app.clone())),
+                _ => {
+                    return Err(Error::new_spanned(
+                        &func.sig.inputs,
+                        "The main function can only receive the app handle.",
+                    ));
+                }
+            };
+            quote! {
+                #[cfg(target_os = "android")]
+                #[unsafe(no_mangle)]
+                fn android_main(app: droid_wrap_utils::AndroidApp) {
+                    #func
+                    let app = droid_wrap_utils::App::new(app);
+                    droid_wrap_utils::run_main(&#options, || #call);
+                    // main返回后继续处理事件，直到activity被销毁，否则activity会立即结束。
+                    app.wait_destroy();
+                }
+            }
+        }
+        MainBackend::NdkGlue => {
+            if !func.sig.inputs.is_empty() {
+                return Err(Error::new_spanned(
+                    &func.sig.inputs,
+                    "The main function can't receive the app handle with the `ndk_glue` backend.",
+                ));
+            }
+            quote! {
+                #[cfg_attr(target_os = "android", ndk_glue::main(ndk_glue = "ndk_glue"))]
+                fn _start_app() {
+                    #func
+                    droid_wrap_utils::run_main(&#options, #name);
                 }
             }
         }
+    };
 
-        #[cfg_attr(target_os = "android", ndk_glue::main(
-            backtrace = "on",
-            ndk_glue = "ndk_glue",
-        ))]
-        fn _start_app() {
+    // 应用句柄只存在于安卓平台，接收句柄的main不在其他平台上编译。
+    let host = if func.sig.inputs.is_empty() {
+        quote! {
+            #[cfg(not(target_os = "android"))]
+            #[allow(dead_code)]
             #func
-            let _ = dbg!(stop_unwind(#name));
         }
+    } else {
+        TokenStream::new()
+    };
 
-        #[cfg(not(target_os = "android"))]
-        #func
-    }
+    Ok(quote! {
+        #start
+        #host
+    })
 }
@@ -11,25 +11,34 @@
  * See the License for the specific language governing permissions and limitations under the License.
  */
 
-use heck::ToLowerCamelCase;
-use proc_macro2::{Ident, Span, TokenStream};
+use heck::{ToLowerCamelCase, ToShoutySnakeCase};
+use proc_macro2::{Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
 use quote::{ToTokens, quote};
 use syn::{
-    Field, FieldMutability, Fields, FieldsNamed, ImplItem, ItemFn, ItemImpl, ItemStruct, ItemTrait,
-    LitInt, Token, TraitItem, Type, TypeParamBound, Visibility, parse2, punctuated::Punctuated,
+    Error, Expr, Field, FieldMutability, Fields, FieldsNamed, FnArg, GenericArgument, ImplItem,
+    ItemEnum, ItemFn, ItemImpl, ItemStruct, ItemTrait, LitInt, Meta, Pat, Path, PathArguments,
+    ReturnType, Token, TraitItem, Type, TypeParamBound, Visibility, parse_quote, parse2,
+    punctuated::Punctuated,
+    visit_mut::{self, VisitMut},
 };
 
 use crate::utils::{
-    ClassMetadata, FieldMetadata, InterfaceMetadata, MethodMetadata, get_return_value_token,
-    get_type_form, parse_function_signature,
+    BeanFieldMetadata, BeanMetadata, ClassMetadata, ConstantsMetadata, ConstructorMetadata,
+    EnumMetadata, FailurePolicy, FieldMetadata, InterfaceMetadata, MethodMetadata, NativeMetadata,
+    SubclassMetadata, check_field_descriptor, check_method_descriptor, dyn_to_impl,
+    get_arg_value_token, get_class_loader_token, get_fallback_token, get_lit_str, get_ret_form,
+    get_return_value_token, get_strict_check, get_type_descriptor_token, get_type_form,
+    parse_function_signature, take_varargs, unwrap_type,
 };
 
 //noinspection SpellCheckingInspection
-pub(super) fn java_class(attrs: TokenStream, input: TokenStream) -> TokenStream {
-    let attrs: ClassMetadata = parse2(attrs).unwrap();
+pub(super) fn java_class(attrs: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
+    let attrs: ClassMetadata = parse2(attrs)?;
     let cls = attrs.class_name;
     let based = attrs.base_class;
-    let mut item: ItemStruct = parse2(input).unwrap();
+    let class_loader = get_class_loader_token(&attrs.loader);
+    let mut item: ItemStruct = parse2(input)?;
+    let implements = class_implements(&item, &attrs.implements)?;
     let name = item.ident.clone();
     let generics = item.generics.clone();
     let mut item2 = item.clone();
@@ -155,7 +164,85 @@ pub(super) fn java_class(attrs: TokenStream, input: TokenStream) -> TokenStream
         }
     };
 
-    quote! {
+    // 只有没有rust字段的类才能克隆，克隆后引用同一个java对象。
+    let impl_clone = if added_default.is_empty() {
+        quote! {
+            impl #generics Clone for #name #generics {
+                fn clone(&self) -> Self {
+                    let this = &self.#added_this;
+                    #build_self
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    // 向上转换：类可以转换为自身，并通过父类递归地转换为`extends`链上的所有祖先类。
+    let mut upcast_generics = generics.clone();
+    upcast_generics.params.push(parse_quote!(Ancestor));
+    let impl_upcast_based = if based.is_some() {
+        quote! {
+            impl #upcast_generics droid_wrap_utils::JUpcast<Ancestor> for #name #generics
+            where
+                #based: droid_wrap_utils::JUpcast<Ancestor>,
+            {
+                fn upcast(self) -> Ancestor {
+                    droid_wrap_utils::JUpcast::upcast(self.#added_super)
+                }
+
+                fn upcast_ref(&self) -> &Ancestor {
+                    droid_wrap_utils::JUpcast::upcast_ref(&self.#added_super)
+                }
+            }
+
+            impl #upcast_generics droid_wrap_utils::JExtends<Ancestor> for #name #generics
+            where
+                #based: droid_wrap_utils::JUpcast<Ancestor>,
+            {
+            }
+
+            impl #generics droid_wrap_utils::JSuper for #name #generics {
+                type Super = #based;
+            }
+        }
+    } else {
+        quote!()
+    };
+    let mut from_generics = generics.clone();
+    from_generics
+        .params
+        .push(parse_quote!(Derived: droid_wrap_utils::JExtends<#name #generics>));
+    let impl_upcast = quote! {
+        impl #generics droid_wrap_utils::JUpcast<Self> for #name #generics {
+            fn upcast(self) -> Self {
+                self
+            }
+
+            fn upcast_ref(&self) -> &Self {
+                self
+            }
+        }
+
+        impl #from_generics From<Derived> for #name #generics {
+            fn from(value: Derived) -> Self {
+                droid_wrap_utils::JUpcast::upcast(value)
+            }
+        }
+
+        impl #upcast_generics AsRef<Ancestor> for #name #generics
+        where
+            Self: droid_wrap_utils::JExtends<Ancestor>,
+        {
+            fn as_ref(&self) -> &Ancestor {
+                droid_wrap_utils::JUpcast::upcast_ref(self)
+            }
+        }
+
+        #impl_upcast_based
+    };
+
+    Ok(quote! {
         #item
         #item2_token
 
@@ -170,6 +257,7 @@ pub(super) fn java_class(attrs: TokenStream, input: TokenStream) -> TokenStream
         impl #generics JType for #name #generics {
             const CLASS: &'static str = #cls;
             const OBJECT_SIG: &'static str = concat!("L", #cls, ";");
+            #class_loader
         }
 
         impl #generics JObjRef for #name #generics {
@@ -187,6 +275,19 @@ pub(super) fn java_class(attrs: TokenStream, input: TokenStream) -> TokenStream
             }
     }
 
+        impl #generics Eq for #name #generics {}
+
+        impl #generics std::hash::Hash for #name #generics {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                let call_fn = || droid_wrap_utils::java_object_hash_code(self.java_ref()?);
+                call_fn()
+                    .unwrap_or_else(|e| panic!("Failed to call `hashCode`: {}", e))
+                    .hash(state)
+            }
+        }
+
+        #impl_clone
+
         impl #generics ToString for #name #generics {
             fn to_string(&self) -> String {
                 let call_fn = || droid_wrap_utils::java_object_to_string(self.java_ref()?);
@@ -213,103 +314,312 @@ pub(super) fn java_class(attrs: TokenStream, input: TokenStream) -> TokenStream
         }
 
         #impl_based_deref
+        #impl_upcast
+        #implements
+    })
+}
+
+/// 通过接口的隐藏宏为类生成`implements`声明的接口的实现，接口必须由java_interface定义。
+fn class_implements(item: &ItemStruct, implements: &[Path]) -> syn::Result<TokenStream> {
+    let name = &item.ident;
+    let generics = &item.generics;
+    let mut impls = TokenStream::new();
+    for path in implements {
+        let mut macro_path = path.clone();
+        let mut trait_path = path.clone();
+        let (Some(last), Some(trait_last)) = (
+            macro_path.segments.last_mut(),
+            trait_path.segments.last_mut(),
+        ) else {
+            return Err(Error::new_spanned(path, "Expected an interface."));
+        };
+        last.arguments = PathArguments::None;
+
+        // 泛型参数传递给宏，关联类型在实现中定义，不能出现在实现的接口路径中。
+        let mut types = Vec::new();
+        let mut assoc = Vec::new();
+        match &mut trait_last.arguments {
+            PathArguments::None => {}
+            PathArguments::AngleBracketed(args) => {
+                let mut trait_args = Punctuated::<GenericArgument, Token![,]>::new();
+                for arg in args.args.iter() {
+                    match arg {
+                        GenericArgument::Type(t) => {
+                            types.push(t.clone());
+                            trait_args.push(arg.clone());
+                        }
+                        GenericArgument::AssocType(t) if t.generics.is_none() => {
+                            let ident = &t.ident;
+                            let ty = &t.ty;
+                            assoc.push(quote! {#ident = #ty});
+                        }
+                        _ => {
+                            return Err(Error::new_spanned(
+                                arg,
+                                "Only type arguments and associated types are supported.",
+                            ));
+                        }
+                    }
+                }
+                if trait_args.is_empty() {
+                    trait_last.arguments = PathArguments::None;
+                } else {
+                    args.args = trait_args;
+                }
+            }
+            PathArguments::Parenthesized(_) => {
+                return Err(Error::new_spanned(path, "Expected an interface."));
+            }
+        }
+
+        impls.extend(quote! {
+            #macro_path!([#generics] #name #generics, [#trait_path], [#(#types),*], [#(#assoc),*]);
+        });
+
+        // 与自身比较的Comparable对应rust中的全序。
+        let is_self = |t: &Type| {
+            let t = t.to_token_stream().to_string();
+            t == "Self" || t == quote!(#name #generics).to_string()
+        };
+        if macro_path
+            .segments
+            .last()
+            .is_some_and(|i| i.ident == "Comparable")
+            && types.len() == 1
+            && is_self(&types[0])
+        {
+            impls.extend(quote! {
+                impl #generics PartialOrd for #name #generics {
+                    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                        <Self as #trait_path>::compare_to(self, other)
+                            .ok()
+                            .map(|i| i.cmp(&0))
+                    }
+                }
+
+                impl #generics Ord for #name #generics {
+                    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                        <Self as #trait_path>::compare_to(self, other)
+                            .unwrap_or_else(|e| panic!("Failed to call `compareTo`: {}", e))
+                            .cmp(&0)
+                    }
+                }
+            });
+        }
     }
+
+    Ok(impls)
 }
 
-pub(super) fn java_method(attrs: TokenStream, input: TokenStream) -> TokenStream {
-    let attrs: MethodMetadata = parse2(attrs).unwrap();
+pub(super) fn java_method(attrs: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
+    let attrs: MethodMetadata = parse2(attrs)?;
     let type_bounds = attrs.type_bounds;
     let overload = attrs.overload;
-    let item: ItemFn = parse2(input).unwrap();
+    let java_sig = attrs.sig;
+    let failure = attrs.failure;
+    let nonvirtual = attrs.nonvirtual;
+    // 方法要求的API级别，没有指定时不检查。
+    let check_api_level = match (attrs.since, attrs.until) {
+        (None, None) => quote! {},
+        (since, until) => {
+            let since = since.unwrap_or(1);
+            let until = match until {
+                Some(until) => quote! {Some(#until)},
+                None => quote! {None},
+            };
+            quote! {droid_wrap_utils::check_api_level(#since, #until)?;}
+        }
+    };
+    let item: ItemFn = parse2(input)?;
+    let name = match (attrs.name, overload) {
+        (Some(name), _) => name.value(),
+        (None, Some(overload)) => overload.to_token_stream().to_string().to_lower_camel_case(),
+        (None, None) => item.sig.ident.to_string().to_lower_camel_case(),
+    };
     let attrs = item.attrs.clone();
     let stmts = item.block.stmts.clone();
-    let name = if overload.is_none() {
-        item.sig.ident.to_string()
-    } else {
-        overload.unwrap().to_token_stream().to_string()
-    }
-    .to_lower_camel_case();
     let vis = item.vis.clone();
-    let sig = item.sig.clone();
+    let mut sig = item.sig.clone();
 
-    let (self_, _, arg_types_sig, fmt, arg_values, ret_type) =
+    let (self_, arg_types, arg_types_sig, fmt, arg_values, ret_type) =
         parse_function_signature(&sig, &type_bounds);
-    let (ret_value, ret_type_sig) = get_return_value_token(&ret_type, &sig.generics, &type_bounds);
+    take_varargs(&mut sig)?;
+    dyn_to_impl(&mut sig);
+    let (ret_value, ret_type_sig) = get_return_value_token(&ret_type, &sig.generics, &type_bounds)?;
+    // API级别不满足时返回的错误必须能交给调用者处理，否则仍然会按照失败策略panic或被忽略。
+    let ret_type_str = ret_type.to_string();
+    if !check_api_level.is_empty()
+        && !ret_type_str.starts_with("Option")
+        && !ret_type_str.starts_with("Result")
+    {
+        return Err(Error::new_spanned(
+            &sig.output,
+            format!(
+                "`{}` requires an API level, please return `Result<{}>` or `Option<{}>` so that the caller can handle unsupported devices.",
+                sig.ident, ret_type_str, ret_type_str
+            ),
+        ));
+    }
+    let java_sig = match java_sig {
+        Some(java_sig) => {
+            check_method_descriptor(&java_sig, arg_types.len())?;
+            quote! {#java_sig}
+        }
+        None => quote! {format!(#fmt, #arg_types_sig #ret_type_sig).as_str()},
+    };
 
     let class_token = if let Some(it) = type_bounds.iter().find(|i| i.0.to_string() == "Self") {
         let tt = it.1.clone();
-        quote! {droid_wrap_utils::load_class(<Self as #tt>::CLASS)?}
+        quote! {droid_wrap_utils::load_class_from(&<Self as JType>::class_loader()?, <Self as #tt>::CLASS)?}
     } else {
-        quote! {droid_wrap_utils::load_class(Self::CLASS)?}
+        quote! {<Self as JType>::java_class()?}
     };
 
-    let ret_form = get_type_form(&ret_type, &None);
     let opt = if self_.is_none() {
+        if nonvirtual {
+            return Err(Error::new_spanned(
+                &sig.ident,
+                format!(
+                    "Static method `{}` can't be called non-virtually.",
+                    sig.ident
+                ),
+            ));
+        }
         quote! {
-            env.call_static_method(#class_token,#name,format!(#fmt, #arg_types_sig #ret_type_sig).as_str(),&[#arg_values],)
+            env.call_static_method(#class_token,#name,#java_sig,&[#arg_values],)
+        }
+    } else if nonvirtual {
+        // 调用`extends`声明的父类中的实现，没有声明父类时由`JSuper`给出错误提示。
+        quote! {
+            droid_wrap_utils::call_nonvirtual_method(
+                &mut env,
+                #self_.java_ref()?,
+                &<<Self as droid_wrap_utils::JSuper>::Super as JType>::java_class()?,
+                #name,
+                #java_sig,
+                &[#arg_values],
+            )
         }
     } else {
         quote! {
-            env.call_method(#self_.java_ref()?,#name,format!(#fmt, #arg_types_sig #ret_type_sig).as_str(),&[#arg_values],)
+            env.call_method(#self_.java_ref()?,#name,#java_sig,&[#arg_values],)
         }
     };
 
-    quote! {
+    let ret_form = get_ret_form(&ret_type, failure, &sig.ident)?;
+    let strict_check = get_strict_check(&ret_type, failure, &sig.ident);
+
+    Ok(quote! {
         #(#attrs)*
         #vis #sig {
             #(#stmts)*
+            #strict_check
             let call_fn = || {
+                #check_api_level
                 let mut env = droid_wrap_utils::vm_attach()?;
                 let ret = #opt?;
                 Ok::<_, droid_wrap_utils::DroidWrapError>(#ret_value)
             };
             call_fn()#ret_form
         }
-    }
+    })
 }
 
-pub(super) fn java_constructor(input: TokenStream) -> TokenStream {
-    let item: ItemFn = parse2(input).unwrap();
+pub(super) fn java_constructor(attrs: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
+    let attrs: ConstructorMetadata = parse2(attrs)?;
+    let java_sig = attrs.sig;
+    let failure = attrs.failure;
+    let item: ItemFn = parse2(input)?;
     let attrs = item.attrs.clone();
     let vis = item.vis.clone();
-    let sig = item.sig.clone();
+    let mut sig = item.sig.clone();
     let stmts = item.block.stmts.clone();
-    let (self_, _, arg_types, fmt, arg_values, ret_type) = parse_function_signature(&sig, &vec![]);
+    let (self_, arg_types_vec, arg_types, fmt, arg_values, ret_type) =
+        parse_function_signature(&sig, &vec![]);
+    take_varargs(&mut sig)?;
+    dyn_to_impl(&mut sig);
 
-    if !self_.is_none() {
-        panic!(
-            "Incorrect constructor, please remove the '{}' in the arguments!",
-            self_.to_token_stream()
-        );
+    if let Some(self_) = self_ {
+        return Err(Error::new_spanned(
+            self_,
+            "Incorrect constructor, please remove the `self` in the arguments!",
+        ));
     }
 
     if !ret_type.to_string().contains("Self") {
-        panic!(
-            "Incorrect constructor, please modify the '{}' to 'Self', 'Option<Self>' or 'Result<Self>' in the return value!",
-            ret_type
-        );
+        return Err(Error::new_spanned(
+            &sig.output,
+            format!(
+                "Incorrect constructor, please modify the `{}` to `Self`, `Option<Self>` or `Result<Self>` in the return value!",
+                ret_type
+            ),
+        ));
     }
 
-    let ret_form = get_type_form(&ret_type, &None);
+    let java_sig = match java_sig {
+        Some(java_sig) => {
+            check_method_descriptor(&java_sig, arg_types_vec.len())?;
+            if !java_sig.value().ends_with(")V") {
+                return Err(Error::new_spanned(
+                    &java_sig,
+                    format!(
+                        "Incorrect constructor, the descriptor `{}` must return `V`.",
+                        java_sig.value()
+                    ),
+                ));
+            }
+            quote! {#java_sig}
+        }
+        None => quote! {format!(#fmt, #arg_types "V").as_str()},
+    };
+    let ret_form = get_ret_form(&ret_type, failure, &sig.ident)?;
+    let strict_check = get_strict_check(&ret_type, failure, &sig.ident);
 
-    quote! {
+    Ok(quote! {
         #(#attrs)*
         #vis #sig {
             #(#stmts)*
+            #strict_check
             let call_fn = || {
                 let mut env = droid_wrap_utils::vm_attach()?;
-                let obj = env.new_object(<Self as JType>::CLASS,format!(#fmt, #arg_types "V").as_str(),&[#arg_values],)?;
+                let obj = env.new_object(<Self as JType>::java_class()?,#java_sig,&[#arg_values],)?;
                 Self::_new(env.new_global_ref(obj)?.as_ref(), Default::default())
             };
             call_fn()#ret_form
         }
-    }
+    })
 }
 
-pub(super) fn java_interface(attrs: TokenStream, input: TokenStream) -> TokenStream {
-    let attrs: InterfaceMetadata = parse2(attrs).unwrap();
+pub(super) fn java_interface(attrs: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
+    let attrs: InterfaceMetadata = parse2(attrs)?;
     let cls = attrs.interface_name;
-    let mut item: ItemTrait = parse2(input).unwrap();
+    let mut item: ItemTrait = parse2(input)?;
+    // 需要实现的方法上的java_method属性只用于生成包装类型，不能保留在trait中。
+    let mut method_attrs = Vec::new();
+    for i in item.items.iter_mut() {
+        if let TraitItem::Fn(f) = i
+            && f.default.is_none()
+            && let Some(pos) = f
+                .attrs
+                .iter()
+                .position(|a| a.path().is_ident("java_method"))
+        {
+            let args = match f.attrs.remove(pos).meta {
+                Meta::List(l) => l.tokens,
+                _ => TokenStream::new(),
+            };
+            method_attrs.push((f.sig.ident.clone(), args));
+        }
+    }
+    let builder = match attrs.builder {
+        Some(assoc) => interface_builder(&item, &cls, &assoc)?,
+        None => quote!(),
+    };
+    let wrapper = match attrs.wrapper {
+        Some(assoc) => interface_wrapper(&item, &cls, &assoc, &method_attrs)?,
+        None => quote!(),
+    };
+    let implements = interface_implements(&item, &method_attrs)?;
     item.supertraits
         .push(TypeParamBound::Verbatim(quote! {JObjRef}));
     item.supertraits
@@ -332,14 +642,399 @@ pub(super) fn java_interface(attrs: TokenStream, input: TokenStream) -> TokenStr
         const DIM: u8 = 0;
     }));
 
-    quote! {
+    Ok(quote! {
         #item
+        #builder
+        #wrapper
+        #implements
+    })
+}
+
+/// 生成关联类型的定义，所有的关联类型都必须在`builder(...)`或`wrapper(...)`中指定。
+fn associated_types(
+    item: &ItemTrait,
+    assoc: &[(Ident, Type)],
+    attr: &str,
+) -> syn::Result<TokenStream> {
+    let mut assoc_types = TokenStream::new();
+    for i in item.items.iter() {
+        if let TraitItem::Type(t) = i {
+            let ident = &t.ident;
+            let Some((_, ty)) = assoc.iter().find(|(i, _)| i == ident) else {
+                return Err(Error::new_spanned(
+                    ident,
+                    format!(
+                        "The associated type `{}` of `{}` must be specified, for example `{}({} = ...)`.",
+                        ident, item.ident, attr, ident
+                    ),
+                ));
+            };
+            assoc_types.extend(quote! {type #ident = #ty;});
+        }
+    }
+
+    Ok(assoc_types)
+}
+
+/// 为接口中所有需要实现的方法生成通过java_method调用java层的实现。
+fn interface_methods(
+    item: &ItemTrait,
+    method_attrs: &[(Ident, TokenStream)],
+) -> syn::Result<TokenStream> {
+    let mut methods = TokenStream::new();
+    for i in item.items.iter() {
+        let TraitItem::Fn(f) = i else {
+            continue;
+        };
+        if f.default.is_some() {
+            continue;
+        }
+        let sig = &f.sig;
+        let args = method_attrs
+            .iter()
+            .find(|(i, _)| i == &sig.ident)
+            .map(|(_, args)| args.clone())
+            .unwrap_or_default();
+        methods.extend(java_method(args, quote! {#sig {}})?);
+    }
+
+    Ok(methods)
+}
+
+/// 将代码中的泛型参数替换为同名的`macro_rules`变量，例如`T`替换为`$T`。
+fn to_macro_vars(tokens: TokenStream, params: &[Ident]) -> TokenStream {
+    tokens
+        .into_iter()
+        .flat_map(|tt| match tt {
+            TokenTree::Ident(i) if params.contains(&i) => {
+                vec![
+                    TokenTree::Punct(Punct::new('$', Spacing::Alone)),
+                    TokenTree::Ident(i),
+                ]
+            }
+            TokenTree::Group(g) => {
+                let mut group = Group::new(g.delimiter(), to_macro_vars(g.stream(), params));
+                group.set_span(g.span());
+                vec![TokenTree::Group(group)]
+            }
+            tt => vec![tt],
+        })
+        .collect()
+}
+
+/**
+为java接口生成与接口同名的隐藏宏，`java_class`的`implements`参数通过它为类生成接口的实现，
+需要实现的方法都通过java_method调用java层（虚调用）。
+宏只在当前crate中可见，导入接口时会一起导入。
+*/
+fn interface_implements(
+    item: &ItemTrait,
+    method_attrs: &[(Ident, TokenStream)],
+) -> syn::Result<TokenStream> {
+    let trait_ = &item.ident;
+    let module = Ident::new(&format!("__{}_implements", trait_), Span::call_site());
+    let params = item
+        .generics
+        .type_params()
+        .map(|i| i.ident.clone())
+        .collect::<Vec<_>>();
+    let methods = to_macro_vars(interface_methods(item, method_attrs)?, &params);
+
+    Ok(quote! {
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        mod #module {
+            #[allow(unused_macros)]
+            macro_rules! #trait_ {
+                (
+                    [$($generics:tt)*] $self_ty:ty,
+                    [$($trait_:tt)*],
+                    [#($#params:ty),*],
+                    [$($assoc:ident = $assoc_ty:ty),*]
+                ) => {
+                    impl $($generics)* $($trait_)* for $self_ty {
+                        $(type $assoc = $assoc_ty;)*
+                        #methods
+                    }
+                };
+            }
+            #[allow(unused_imports)]
+            pub(crate) use #trait_;
+        }
+        #[allow(unused_imports)]
+        pub(crate) use #module::#trait_;
+    })
+}
+
+/// 为java接口生成包装类型`<Trait>Impl`，它可以持有任何实现了此接口的java对象，`CLASS`为接口本身的名称。
+/// 需要实现的方法通过java_method调用java层，父接口的方法需要在父接口中以带有java_method的默认方法提供。
+fn interface_wrapper(
+    item: &ItemTrait,
+    cls: &Expr,
+    assoc: &[(Ident, Type)],
+    method_attrs: &[(Ident, TokenStream)],
+) -> syn::Result<TokenStream> {
+    let trait_ = &item.ident;
+    let vis = &item.vis;
+    let name = Ident::new(&format!("{}Impl", trait_), Span::call_site());
+    let assoc_types = associated_types(item, assoc, "wrapper")?;
+
+    let methods = interface_methods(item, method_attrs)?;
+
+    let mut super_impls = TokenStream::new();
+    for i in item.supertraits.iter() {
+        let TypeParamBound::Trait(t) = i else {
+            continue;
+        };
+        let is_builtin = t.path.segments.last().is_some_and(|i| {
+            ["JType", "JObjRef", "JObjNew", "PartialEq", "Debug"]
+                .contains(&i.ident.to_string().as_str())
+        });
+        if !is_builtin {
+            let path = &t.path;
+            super_impls.extend(quote! {impl #path for #name {}});
+        }
+    }
+
+    let doc = format!(
+        "[`{}`]的包装类型，可以持有任何实现了此接口的java对象。",
+        trait_
+    );
+    let class = java_class(
+        quote! {name = #cls},
+        quote! {
+            #[doc = #doc]
+            #[allow(non_camel_case_types)]
+            #vis struct #name;
+        },
+    )?;
+
+    Ok(quote! {
+        #class
+        #super_impls
+
+        impl #trait_ for #name {
+            #assoc_types
+            #methods
+        }
+    })
+}
+
+/// 将类型中的`Self`替换为具体的类型，`Self::X`替换为`<T as Trait>::X`。
+struct ReplaceSelf<'a> {
+    ty: &'a Type,
+    trait_: &'a Ident,
+}
+
+impl VisitMut for ReplaceSelf<'_> {
+    fn visit_type_mut(&mut self, i: &mut Type) {
+        if let Type::Path(p) = i
+            && p.qself.is_none()
+            && p.path.segments.first().is_some_and(|s| s.ident == "Self")
+        {
+            let ty = self.ty;
+            let trait_ = self.trait_;
+            let rest = p.path.segments.iter().skip(1);
+            *i = if p.path.segments.len() == 1 {
+                ty.clone()
+            } else {
+                parse_quote! {<#ty as #trait_>::#(#rest)::*}
+            };
+            return;
+        }
+        visit_mut::visit_type_mut(self, i);
     }
 }
 
+/// 未设置闭包时方法的返回值，与java的默认值一致：数值为0，布尔值为false，`Option`为`None`，`Result`为`Ok`包装的默认值，
+/// 其他对象与失败策略一样使用`JObjNew::_fallback`（通常是null）。
+fn default_return_value(ret: &ReturnType, method: &Ident) -> TokenStream {
+    match ret {
+        ReturnType::Type(_, ty) => default_value(ty, method),
+        ReturnType::Default => quote!(()),
+    }
+}
+
+fn default_value(ty: &Type, method: &Ident) -> TokenStream {
+    let primitive = match ty {
+        Type::Tuple(t) => t.elems.is_empty(),
+        Type::Path(p) => p.path.get_ident().is_some_and(|i| {
+            [
+                "bool", "char", "i8", "u8", "i16", "u16", "i32", "u32", "i64", "u64", "f32", "f64",
+            ]
+            .contains(&i.to_string().as_str())
+        }),
+        _ => false,
+    };
+    if primitive {
+        return quote! {Default::default()};
+    }
+    let Type::Path(p) = ty else {
+        return get_fallback_token(&ty.to_token_stream(), method);
+    };
+    let Some(last) = p.path.segments.last() else {
+        return get_fallback_token(&ty.to_token_stream(), method);
+    };
+    if last.ident == "Option" {
+        return quote! {None};
+    }
+    if last.ident == "Result"
+        && let PathArguments::AngleBracketed(args) = &last.arguments
+        && let Some(GenericArgument::Type(inner)) = args.args.first()
+    {
+        let inner = default_value(inner, method);
+        return quote! {Ok(#inner)};
+    }
+    get_fallback_token(&ty.to_token_stream(), method)
+}
+
+/// 为java接口生成由闭包实现的类型`<Trait>Impl`，每个方法对应一个闭包，使用`<Trait>Impl::builder()`创建。
+fn interface_builder(
+    item: &ItemTrait,
+    cls: &Expr,
+    assoc: &[(Ident, Type)],
+) -> syn::Result<TokenStream> {
+    let trait_ = &item.ident;
+    let vis = &item.vis;
+    let name = Ident::new(&format!("{}Impl", trait_), Span::call_site());
+    let name2 = Ident::new(&format!("{}ImplDefault", trait_), Span::call_site());
+    let self_ty: Type = parse_quote! {#name};
+    let impl_self_ty: Type = parse_quote! {Self};
+
+    let assoc_types = associated_types(item, assoc, "builder")?;
+
+    let mut fields = TokenStream::new();
+    let mut fields_default = TokenStream::new();
+    let mut setters = TokenStream::new();
+    let mut methods = TokenStream::new();
+    for i in item.items.iter() {
+        let TraitItem::Fn(f) = i else {
+            continue;
+        };
+        // 只实现需要实现的实例方法，跳过下划线开头的方法，与java_implement的规则一致。
+        if f.default.is_some()
+            || f.sig.receiver().is_none()
+            || f.sig.ident.to_string().starts_with('_')
+        {
+            continue;
+        }
+        let method = &f.sig.ident;
+        let mut arg_names = Vec::new();
+        let mut arg_types = Vec::new();
+        let mut impl_arg_types = Vec::new();
+        for (index, arg) in f.sig.inputs.iter().enumerate() {
+            let FnArg::Typed(t) = arg else {
+                continue;
+            };
+            arg_names.push(match t.pat.as_ref() {
+                Pat::Ident(p) => p.ident.clone(),
+                _ => Ident::new(&format!("arg{}", index), Span::call_site()),
+            });
+            let mut ty = t.ty.as_ref().clone();
+            ReplaceSelf {
+                ty: &impl_self_ty,
+                trait_,
+            }
+            .visit_type_mut(&mut ty);
+            impl_arg_types.push(ty.clone());
+            ReplaceSelf {
+                ty: &self_ty,
+                trait_,
+            }
+            .visit_type_mut(&mut ty);
+            arg_types.push(ty);
+        }
+        let mut ret = f.sig.output.clone();
+        ReplaceSelf {
+            ty: &impl_self_ty,
+            trait_,
+        }
+        .visit_return_type_mut(&mut ret);
+        let impl_ret = ret.clone();
+        ReplaceSelf {
+            ty: &self_ty,
+            trait_,
+        }
+        .visit_return_type_mut(&mut ret);
+        let default_ret = default_return_value(&ret, method);
+        let doc = format!("设置`{}`方法的实现。", method);
+
+        fields.extend(quote! {
+            #method: Option<Box<dyn Fn(#(#arg_types),*) #ret + Send + Sync>>,
+        });
+        fields_default.extend(quote! {#method: None,});
+        setters.extend(quote! {
+            #[doc = #doc]
+            pub fn #method(mut self, func: impl Fn(#(#arg_types),*) #ret + Send + Sync + 'static) -> Self {
+                self.#method = Some(Box::new(func));
+                self
+            }
+        });
+        methods.extend(quote! {
+            fn #method(&self, #(#arg_names: #impl_arg_types),*) #impl_ret {
+                match self.#method {
+                    Some(ref func) => func(#(#arg_names),*),
+                    None => #default_ret,
+                }
+            }
+        });
+    }
+
+    let doc = format!(
+        "使用闭包实现的[`{}`]，通过`{}::builder()`为每个方法设置闭包，未设置的方法返回java的默认值（数值为0，布尔值为false，`Option`为`None`，对象为null）。",
+        trait_, name
+    );
+    let class = java_class(
+        quote! {name = #cls},
+        quote! {
+            #[doc = #doc]
+            #[allow(non_camel_case_types)]
+            #vis struct #name {
+                #fields
+            }
+        },
+    )?;
+    let implement = java_implement(
+        TokenStream::new(),
+        quote! {
+            impl #trait_ for #name {
+                #assoc_types
+                #methods
+            }
+        },
+    )?;
+
+    Ok(quote! {
+        #class
+        #implement
+
+        impl Default for #name2 {
+            fn default() -> Self {
+                Self { #fields_default }
+            }
+        }
+
+        impl #name2 {
+            #setters
+
+            /// 创建java代理对象。
+            pub fn build(self) -> droid_wrap_utils::Result<std::sync::Arc<#name>> {
+                <#name as JProxy>::new(self)
+            }
+        }
+
+        impl #name {
+            /// 创建构建器，未设置的方法返回java的默认值。
+            pub fn builder() -> #name2 {
+                Default::default()
+            }
+        }
+    })
+}
+
 //noinspection SpellCheckingInspection
-pub(super) fn java_implement(attrs: TokenStream, input: TokenStream) -> TokenStream {
-    let item = parse2::<ItemImpl>(input).unwrap();
+pub(super) fn java_implement(attrs: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
+    let item = parse2::<ItemImpl>(input)?;
 
     let mut methods = TokenStream::new();
     for item in item.items.iter() {
@@ -403,7 +1098,7 @@ pub(super) fn java_implement(attrs: TokenStream, input: TokenStream) -> TokenStr
 
     let name = item.self_ty.clone();
     let class_token = match item.trait_ {
-        None => quote! {Self::CLASS},
+        None => quote! {<Self as JType>::CLASS},
         Some((_, ref p, _)) => quote! {<Self as #p>::CLASS},
     };
 
@@ -426,7 +1121,7 @@ pub(super) fn java_implement(attrs: TokenStream, input: TokenStream) -> TokenStr
         }
     };
 
-    quote! {
+    Ok(quote! {
         #attrs
         #item
 
@@ -439,16 +1134,19 @@ pub(super) fn java_implement(attrs: TokenStream, input: TokenStream) -> TokenStr
                 let _ = self.release();
             }
         }
-    }
+    })
 }
 
-pub(super) fn java_field(attrs: TokenStream, input: TokenStream) -> TokenStream {
-    let attrs: FieldMetadata = parse2(attrs).unwrap();
+pub(super) fn java_field(attrs: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
+    let attrs: FieldMetadata = parse2(attrs)?;
     let default_value = attrs.default_value.clone();
-    let item: ItemFn = parse2(input).unwrap();
+    let item: ItemFn = parse2(input)?;
+    let field_name = attrs.name;
+    let java_sig = attrs.sig;
+    let failure = attrs.failure;
+    let name = item.sig.ident.to_string().to_lower_camel_case();
     let attrs = item.attrs.clone();
     let stmts = item.block.stmts.clone();
-    let name = item.sig.ident.to_string().to_lower_camel_case();
     let vis = item.vis.clone();
     let sig = item.sig.clone();
 
@@ -457,30 +1155,48 @@ pub(super) fn java_field(attrs: TokenStream, input: TokenStream) -> TokenStream
     } else if name.starts_with("set") {
         (true, name.trim_start_matches("set").to_lower_camel_case())
     } else {
-        panic!("Field name `{}` must start with get or set.", name);
+        return Err(Error::new_spanned(
+            &item.sig.ident,
+            format!(
+                "Field name `{}` must start with get or set.",
+                item.sig.ident
+            ),
+        ));
     };
+    let name = field_name.map_or(name, |i| i.value());
 
     let (self_, arg_types, arg_types_sig, _, arg_values, ret_type) =
         parse_function_signature(&sig, &vec![]);
     if is_set {
         if arg_types.len() != 1 {
-            panic!(
-                "The number of setter arguments for the field `{}` must be one.",
-                name
-            )
-        }
-    } else {
-        if !arg_types.is_empty() {
-            panic!("The getter field `{}` cannot provide any arguments.", name)
+            return Err(Error::new_spanned(
+                &sig.inputs,
+                format!(
+                    "The number of setter arguments for the field `{}` must be one.",
+                    name
+                ),
+            ));
         }
+    } else if !arg_types.is_empty() {
+        return Err(Error::new_spanned(
+            &sig.inputs,
+            format!("The getter field `{}` cannot provide any arguments.", name),
+        ));
     }
 
-    let (ret_value, ret_type_sig) = get_return_value_token(&ret_type, &sig.generics, &vec![]);
+    let (ret_value, ret_type_sig) = get_return_value_token(&ret_type, &sig.generics, &vec![])?;
+    let (arg_types_sig, ret_type_sig) = match java_sig {
+        Some(java_sig) => {
+            check_field_descriptor(&java_sig)?;
+            (quote! {#java_sig,}, quote! {#java_sig})
+        }
+        None => (arg_types_sig, ret_type_sig),
+    };
 
     let opt = if is_set {
         if self_.is_none() {
             quote! {
-                Ok::<_, droid_wrap_utils::DroidWrapError>(env.set_static_field(Self::CLASS, #name, #arg_types_sig #arg_values)?)
+                Ok::<_, droid_wrap_utils::DroidWrapError>(env.set_static_field(<Self as JType>::java_class()?, #name, #arg_types_sig #arg_values)?)
             }
         } else {
             quote! {
@@ -490,7 +1206,7 @@ pub(super) fn java_field(attrs: TokenStream, input: TokenStream) -> TokenStream
     } else {
         if self_.is_none() {
             quote! {
-                let ret = env.get_static_field(Self::CLASS, #name, #ret_type_sig)?;
+                let ret = env.get_static_field(<Self as JType>::java_class()?, #name, #ret_type_sig)?;
                 Ok::<_, droid_wrap_utils::DroidWrapError>(#ret_value)
             }
         } else {
@@ -501,12 +1217,19 @@ pub(super) fn java_field(attrs: TokenStream, input: TokenStream) -> TokenStream
         }
     };
 
-    let ret_form = get_type_form(&ret_type, &default_value);
+    let (ret_form, strict_check) = match default_value {
+        Some(_) => (get_type_form(&ret_type, &default_value), TokenStream::new()),
+        None => (
+            get_ret_form(&ret_type, failure, &sig.ident)?,
+            get_strict_check(&ret_type, failure, &sig.ident),
+        ),
+    };
 
-    quote! {
+    Ok(quote! {
         #(#attrs)*
         #vis #sig {
             #(#stmts)*
+            #strict_check
             let call_fn = || {
                 let mut env = droid_wrap_utils::vm_attach()?;
                 #opt
@@ -514,5 +1237,1103 @@ pub(super) fn java_field(attrs: TokenStream, input: TokenStream) -> TokenStream
 
             call_fn()#ret_form
         }
+    })
+}
+
+/// rust基本类型对应的JNI类型，对象类型返回None。
+fn jni_primitive_type(ty: &str) -> Option<TokenStream> {
+    Some(match ty {
+        "bool" => quote! {droid_wrap_utils::jboolean},
+        "char" => quote! {droid_wrap_utils::jchar},
+        "i8" | "u8" => quote! {droid_wrap_utils::jbyte},
+        "i16" | "u16" => quote! {droid_wrap_utils::jshort},
+        "i32" | "u32" => quote! {droid_wrap_utils::jint},
+        "i64" | "u64" => quote! {droid_wrap_utils::jlong},
+        "f32" => quote! {droid_wrap_utils::jfloat},
+        "f64" => quote! {droid_wrap_utils::jdouble},
+        _ => return None,
+    })
+}
+
+/// 把本地方法收到的JNI参数转换为rust参数。
+fn native_arg_value(
+    arg: &Ident,
+    unwrapped_ty: &TokenStream,
+    origin_ty: &TokenStream,
+) -> TokenStream {
+    let ty_str = unwrapped_ty.to_string();
+    match ty_str.as_str() {
+        "bool" => return quote! {#arg != 0},
+        "char" => return quote! {char::from_u32(#arg as u32).unwrap_or_default()},
+        "u8" | "u16" | "u32" | "u64" => return quote! {#arg as #unwrapped_ty},
+        _ if jni_primitive_type(&ty_str).is_some() => return quote! {#arg},
+        _ => {}
+    }
+    let origin_str = origin_ty.to_string();
+    let value = quote! {
+        <#unwrapped_ty as JObjNew>::_new(&env.new_global_ref(&#arg)?, Default::default())?
+    };
+    if origin_str.starts_with("Option") {
+        quote! {
+            if #arg.is_null() { None } else { Some(#value) }
+        }
+    } else if origin_str.starts_with('&') {
+        quote! {&#value}
+    } else {
+        value
+    }
+}
+
+/// 把rust方法的返回值转换为本地方法的JNI返回值。
+fn native_return_value(ret_type: &TokenStream) -> (TokenStream, TokenStream) {
+    let unwrapped_ty = unwrap_type(ret_type);
+    let ty_str = unwrapped_ty.to_string();
+    let ret_str = ret_type.to_string();
+    if ty_str == "()" {
+        return (quote!(), quote! {ret});
+    }
+    if let Some(jni_ty) = jni_primitive_type(&ty_str) {
+        return (quote! {-> #jni_ty}, quote! {ret as #jni_ty});
+    }
+    let value = quote! {env.new_local_ref(ret.java_ref()?.as_obj())?};
+    let value = if ret_str.starts_with("Option") {
+        quote! {
+            match ret {
+                Some(ret) => #value,
+                None => droid_wrap_utils::JObject::null(),
+            }
+        }
+    } else {
+        value
+    };
+    (quote! {-> droid_wrap_utils::JObject<'local>}, value)
+}
+
+//noinspection SpellCheckingInspection
+pub(super) fn java_subclass(attrs: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
+    let attrs: SubclassMetadata = parse2(attrs)?;
+    let mut item: ItemImpl = parse2(input)?;
+    if let Some((_, path, _)) = &item.trait_ {
+        return Err(Error::new_spanned(
+            path,
+            "`java_subclass` must be used on an inherent impl block.",
+        ));
+    }
+    let self_ty = item.self_ty.clone();
+    let super_class = match attrs.super_class {
+        Some(s) => quote! {#s},
+        None => quote! {<<Self as std::ops::Deref>::Target as JType>::CLASS},
+    };
+
+    let mut natives = TokenStream::new();
+    let mut register = TokenStream::new();
+    let mut constructors = Vec::new();
+    for i in item.items.iter_mut() {
+        let ImplItem::Fn(f) = i else {
+            continue;
+        };
+        if let Some(pos) = f
+            .attrs
+            .iter()
+            .position(|a| a.path().is_ident("java_constructor"))
+        {
+            f.attrs.remove(pos);
+            constructors.push(f.clone());
+            continue;
+        }
+        let name = f.sig.ident.clone();
+        if name.to_string().starts_with('_') {
+            // 跳过下划线开头的函数
+            continue;
+        }
+        let (self_, arg_types, arg_types_sig, fmt, _, ret_type) =
+            parse_function_signature(&f.sig, &vec![]);
+        if self_.is_none() {
+            continue;
+        }
+        let name_camel = name.to_string().to_lower_camel_case();
+        let ret_sig = get_type_descriptor_token(&unwrap_type(&ret_type), &f.sig.generics, &vec![]);
+
+        let mut params = TokenStream::new();
+        let mut values = TokenStream::new();
+        for (index, (unwrapped_ty, origin_ty)) in arg_types.iter().enumerate() {
+            let arg = Ident::new(&format!("arg{}", index), Span::call_site());
+            let jni_ty = jni_primitive_type(&unwrapped_ty.to_string())
+                .unwrap_or(quote! {droid_wrap_utils::JObject<'local>});
+            params.extend(quote! {#arg: #jni_ty,});
+            let value = native_arg_value(&arg, unwrapped_ty, origin_ty);
+            values.extend(quote! {#value,});
+        }
+        let try_ret = if ret_type.to_string().starts_with("Result") {
+            quote! {?}
+        } else {
+            quote!()
+        };
+        let (jni_ret, ret_value) = native_return_value(&ret_type);
+
+        natives.extend(quote! {
+            unsafe extern "system" fn #name<'local>(
+                mut env: droid_wrap_utils::JNIEnv<'local>,
+                this: droid_wrap_utils::JObject<'local>,
+                #params
+            ) #jni_ret {
+                droid_wrap_utils::subclass_call(&mut env, &this, |env, self_: std::sync::Arc<#self_ty>| {
+                    let ret = self_.#name(#values)#try_ret;
+                    Ok(#ret_value)
+                })
+            }
+        });
+        register.extend(quote! {
+            let sig = format!(#fmt, #arg_types_sig #ret_sig);
+            let class = class.native_method(#name_camel, &sig, false);
+            natives.push(droid_wrap_utils::NativeMethod {
+                name: #name_camel.into(),
+                sig: sig.into(),
+                fn_ptr: #name as *mut _,
+            });
+        });
+    }
+
+    // 没有声明构造函数时，使用父类的无参构造函数。
+    if constructors.is_empty() {
+        let f: syn::ImplItemFn = parse_quote! {
+            /// 创建java子类的对象，并把`fields`作为rust状态绑定到对象上。
+            pub fn new(
+                fields: <Self as JObjNew>::Fields,
+            ) -> droid_wrap_utils::Result<std::sync::Arc<Self>> {}
+        };
+        item.items.push(ImplItem::Fn(f.clone()));
+        constructors.push(f);
+    }
+    for c in constructors.iter() {
+        let Some(FnArg::Typed(fields)) = c.sig.inputs.last() else {
+            return Err(Error::new_spanned(
+                &c.sig,
+                "The last argument of a subclass constructor must be the rust fields, for example `fields: <Self as JObjNew>::Fields`.",
+            ));
+        };
+        let fields = fields.pat.clone();
+        let mut sig = c.sig.clone();
+        sig.inputs.pop();
+        let (self_, _, arg_types_sig, fmt, arg_values, _) = parse_function_signature(&sig, &vec![]);
+        if let Some(self_) = self_ {
+            return Err(Error::new_spanned(
+                self_,
+                "Incorrect constructor, please remove the `self` in the arguments!",
+            ));
+        }
+        let java_sig = quote! {format!(#fmt, #arg_types_sig "V")};
+        // 只根据最后一段判断返回值的形式，从而支持`droid_wrap_utils::Result<...>`这样的路径。
+        let ret_type = match &c.sig.output {
+            ReturnType::Type(_, t) => match t.as_ref() {
+                Type::Path(p) => p.path.segments.last().to_token_stream(),
+                t => t.to_token_stream(),
+            },
+            ReturnType::Default => quote! {()},
+        };
+        let ret_form = get_ret_form(&ret_type, None, &c.sig.ident)?;
+        let strict_check = get_strict_check(&ret_type, None, &c.sig.ident);
+        let attrs = &c.attrs;
+        let vis = &c.vis;
+        let c_sig = &c.sig;
+        register.extend(quote! {
+            let class = class.constructor(&#java_sig);
+        });
+        let body: syn::ImplItemFn = parse_quote! {
+            #(#attrs)*
+            #vis #c_sig {
+                #strict_check
+                let call_fn = || {
+                    let class = Self::_class()?;
+                    let mut env = droid_wrap_utils::vm_attach()?;
+                    let obj = env.new_object(class, #java_sig, &[#arg_values])?;
+                    let obj = env.new_global_ref(&obj)?;
+                    let ret = std::sync::Arc::new(Self::_new(&obj, #fields)?);
+                    let state = std::sync::Arc::downgrade(&ret);
+                    let state: std::sync::Weak<dyn std::any::Any + Send + Sync> = state;
+                    droid_wrap_utils::bind_subclass_state(&mut env, obj.as_obj(), state)?;
+                    Ok::<_, droid_wrap_utils::DroidWrapError>(ret)
+                };
+                call_fn()#ret_form
+            }
+        };
+        for i in item.items.iter_mut() {
+            if let ImplItem::Fn(f) = i
+                && f.sig.ident == c.sig.ident
+            {
+                *f = body.clone();
+            }
+        }
+    }
+
+    Ok(quote! {
+        #item
+
+        impl #self_ty {
+            /// 获取java子类，首次调用时生成并加载此类，然后注册本地方法。
+            fn _class() -> droid_wrap_utils::Result<&'static droid_wrap_utils::GlobalRef> {
+                static CLASS: std::sync::OnceLock<droid_wrap_utils::GlobalRef> = std::sync::OnceLock::new();
+                #natives
+
+                if let Some(class) = CLASS.get() {
+                    return Ok(class);
+                }
+                // 只缓存成功加载的类，失败（例如类加载器还没有准备好）时下次调用会重试。
+                let class = droid_wrap_utils::DexClass::new(<Self as JType>::CLASS, #super_class);
+                let mut natives = Vec::new();
+                #register
+                let class = droid_wrap_utils::load_subclass_in(&<Self as JType>::class_loader()?, class, &natives)?;
+                Ok(CLASS.get_or_init(|| class))
+            }
+        }
+    })
+}
+
+//noinspection SpellCheckingInspection
+pub(super) fn java_native(attrs: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
+    let attrs: NativeMetadata = parse2(attrs)?;
+    let item: ItemFn = parse2(input)?;
+    let name = item.sig.ident.clone();
+    if !item.sig.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &item.sig.generics,
+            "A native method can't have generic parameters.",
+        ));
+    }
+    let class_name = attrs.class_name;
+    let java_name = match attrs.name {
+        Some(name) => name.value(),
+        None => name.to_string().to_lower_camel_case(),
+    };
+
+    // 实例方法的第一个参数接收java对象本身，不属于描述符的一部分。
+    let mut sig = item.sig.clone();
+    let this = if attrs.instance {
+        let this = sig.inputs.iter().next().cloned();
+        let Some(FnArg::Typed(this)) = this else {
+            return Err(Error::new_spanned(
+                &item.sig,
+                "The first argument of an instance native method must be the java object, for example `this: Object`.",
+            ));
+        };
+        sig.inputs = sig.inputs.into_iter().skip(1).collect();
+        Some(this)
+    } else {
+        None
+    };
+    let (self_, arg_types, arg_types_sig, fmt, _, ret_type) =
+        parse_function_signature(&sig, &vec![]);
+    if let Some(self_) = self_ {
+        return Err(Error::new_spanned(
+            self_,
+            "A native method must be a free function, please remove the `self` in the arguments!",
+        ));
+    }
+    let unwrapped_ret = unwrap_type(&ret_type);
+    let java_sig = match attrs.sig {
+        Some(java_sig) => {
+            check_method_descriptor(&java_sig, arg_types.len())?;
+            quote! {#java_sig.to_string()}
+        }
+        None => {
+            let ret_sig = get_type_descriptor_token(&unwrapped_ret, &sig.generics, &vec![]);
+            quote! {format!(#fmt, #arg_types_sig #ret_sig)}
+        }
+    };
+
+    let mut params = TokenStream::new();
+    let mut values = TokenStream::new();
+    let this_param = match this {
+        Some(this) => {
+            let origin_ty = this.ty.to_token_stream();
+            let arg = Ident::new("this", Span::call_site());
+            let value = native_arg_value(&arg, &unwrap_type(&origin_ty), &origin_ty);
+            values.extend(quote! {#value,});
+            quote! {this: droid_wrap_utils::JObject<'local>}
+        }
+        None => quote! {_class: droid_wrap_utils::JObject<'local>},
+    };
+    for (index, (unwrapped_ty, origin_ty)) in arg_types.iter().enumerate() {
+        let arg = Ident::new(&format!("arg{}", index), Span::call_site());
+        let jni_ty = jni_primitive_type(&unwrapped_ty.to_string())
+            .unwrap_or(quote! {droid_wrap_utils::JObject<'local>});
+        params.extend(quote! {#arg: #jni_ty,});
+        let value = native_arg_value(&arg, unwrapped_ty, origin_ty);
+        values.extend(quote! {#value,});
+    }
+    let try_ret = if ret_type.to_string().starts_with("Result") {
+        quote! {?}
+    } else {
+        quote!()
+    };
+    let (jni_ret, ret_value) = native_return_value(&ret_type);
+    let (loader, loader_value) = match attrs.loader {
+        Some(loader) => (
+            quote! {
+                fn loader() -> droid_wrap_utils::Result<droid_wrap_utils::GlobalRef> {
+                    droid_wrap_utils::ClassLoaderSource::class_loader(&#loader)
+                }
+            },
+            quote! {Some(loader)},
+        ),
+        None => (quote!(), quote! {None}),
+    };
+
+    Ok(quote! {
+        #item
+
+        const _: () = {
+            unsafe extern "system" fn native<'local>(
+                mut env: droid_wrap_utils::JNIEnv<'local>,
+                #this_param,
+                #params
+            ) #jni_ret {
+                droid_wrap_utils::native_call(&mut env, |env| {
+                    let ret = #name(#values)#try_ret;
+                    Ok(#ret_value)
+                })
+            }
+
+            fn method() -> droid_wrap_utils::NativeMethod {
+                droid_wrap_utils::NativeMethod {
+                    name: #java_name.into(),
+                    sig: #java_sig.into(),
+                    fn_ptr: native as *mut _,
+                }
+            }
+
+            #loader
+
+            // 在库加载时提交本地方法，随后由`droid_wrap_utils::register_natives`统一注册。
+            extern "C" fn submit() {
+                droid_wrap_utils::submit_native(#class_name, #loader_value, method);
+            }
+
+            #[used]
+            #[cfg_attr(
+                any(target_os = "android", target_os = "linux"),
+                unsafe(link_section = ".init_array")
+            )]
+            static SUBMIT: extern "C" fn() = submit;
+        };
+    })
+}
+
+pub(super) fn java_constants(attrs: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
+    let attrs: ConstantsMetadata = parse2(attrs)?;
+    let mut item: ItemEnum = parse2(input)?;
+    if !item.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &item.generics,
+            "A constant group can't have generic parameters.",
+        ));
+    }
+    let name = item.ident.clone();
+    let prefix = attrs.prefix.map(|i| i.value()).unwrap_or_default();
+
+    let mut variants = Vec::new();
+    let mut values = Vec::new();
+    let mut java_names = Vec::new();
+    for v in item.variants.iter_mut() {
+        if !matches!(v.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                &v.fields,
+                "The variants of a constant group can't have fields.",
+            ));
+        }
+        if v.ident == "Unknown" {
+            return Err(Error::new_spanned(
+                &v.ident,
+                "The variant `Unknown` is reserved for values outside of the constant group, please rename it and specify the java name with `#[java_name = \"...\"]`.",
+            ));
+        }
+        let Some((_, value)) = v.discriminant.take() else {
+            return Err(Error::new_spanned(
+                &v.ident,
+                format!(
+                    "The variant `{}` must specify the value of the java constant, for example `{} = 0`.",
+                    v.ident, v.ident
+                ),
+            ));
+        };
+        // 默认使用前缀加上变体名的大写蛇形形式作为java中的名称，可以使用`#[java_name = "..."]`指定。
+        let java_name = match v.attrs.iter().position(|a| a.path().is_ident("java_name")) {
+            Some(pos) => {
+                let attr = v.attrs.remove(pos);
+                get_lit_str(&attr.meta.require_name_value()?.value)?.value()
+            }
+            None => format!("{}{}", prefix, v.ident.to_string().to_shouty_snake_case()),
+        };
+        variants.push(v.ident.clone());
+        values.push(value);
+        java_names.push(java_name);
+    }
+    item.variants.push(parse_quote! {
+        /// 不属于此常量组的值。
+        Unknown(i32)
+    });
+    let name_str = name.to_string();
+
+    Ok(quote! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #item
+
+        impl #name {
+            /// 获取常量在java中的名称，未知的值返回None。
+            pub fn java_name(&self) -> Option<&'static str> {
+                match self {
+                    #(Self::#variants => Some(#java_names),)*
+                    Self::Unknown(_) => None,
+                }
+            }
+
+            fn _from_i32(value: i32) -> Self {
+                #(if value == (#values) {
+                    return Self::#variants;
+                })*
+                Self::Unknown(value)
+            }
+        }
+
+        impl TryFrom<i32> for #name {
+            type Error = droid_wrap_utils::DroidWrapError;
+
+            fn try_from(value: i32) -> std::result::Result<Self, droid_wrap_utils::DroidWrapError> {
+                match Self::_from_i32(value) {
+                    Self::Unknown(value) => Err(droid_wrap_utils::DroidWrapError::UnknownConstant(#name_str, value)),
+                    v => Ok(v),
+                }
+            }
+        }
+
+        impl From<#name> for i32 {
+            fn from(value: #name) -> Self {
+                match value {
+                    #(#name::#variants => #values,)*
+                    #name::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl std::fmt::Display for #name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    Self::Unknown(value) => std::fmt::Display::fmt(value, f),
+                    v => f.write_str(v.java_name().unwrap_or_default()),
+                }
+            }
+        }
+
+        impl JObjRef for #name {
+            fn java_ref(&self) -> droid_wrap_utils::Result<droid_wrap_utils::GlobalRef> {
+                let mut env = droid_wrap_utils::vm_attach()?;
+                let obj = env
+                    .call_static_method(
+                        "java/lang/Integer",
+                        "valueOf",
+                        "(I)Ljava/lang/Integer;",
+                        &[droid_wrap_utils::JValue::Int((*self).into())],
+                    )?
+                    .l()?;
+                Ok(env.new_global_ref(&obj)?)
+            }
+
+            fn java_value(&self) -> droid_wrap_utils::Result<droid_wrap_utils::JArgValue> {
+                Ok(droid_wrap_utils::JArgValue::Int((*self).into()))
+            }
+        }
+
+        impl JObjNew for #name {
+            type Fields = ();
+
+            fn _new(this: &droid_wrap_utils::GlobalRef, _: Self::Fields) -> droid_wrap_utils::Result<Self> {
+                // 装箱的null不是任何常量，不能当作0处理。
+                if this.is_null() {
+                    return Err(droid_wrap_utils::JniError::NullPtr(#name_str).into());
+                }
+                let mut env = droid_wrap_utils::vm_attach()?;
+                let value = env.call_method(this, "intValue", "()I", &[])?.i()?;
+                Ok(Self::_from_i32(value))
+            }
+
+            fn _from_value(
+                _: &mut droid_wrap_utils::JNIEnv,
+                value: droid_wrap_utils::JValueOwned,
+            ) -> droid_wrap_utils::Result<Self> {
+                Ok(Self::_from_i32(value.i()?))
+            }
+
+            fn _fallback() -> Self {
+                Self::_from_i32(0)
+            }
+        }
+
+        // 转换为java对象（例如放入集合）时使用装箱的`Integer`，因此类名是真实的`java/lang/Integer`；
+        // 作为方法参数、返回值和字段时按照int传递，描述符明确使用基本类型`I`，而不是根据类名生成的`Ljava/lang/Integer;`。
+        impl JType for #name {
+            const CLASS: &'static str = "java/lang/Integer";
+            const OBJECT_SIG: &'static str = "I";
+        }
+    })
+}
+
+pub(super) fn java_enum(attrs: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
+    let attrs: EnumMetadata = parse2(attrs)?;
+    let cls = attrs.enum_name;
+    let class_loader = get_class_loader_token(&attrs.loader);
+    let mut item: ItemEnum = parse2(input)?;
+    if !item.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &item.generics,
+            "A java enum can't have generic parameters.",
+        ));
+    }
+    let name = item.ident.clone();
+
+    let mut variants = Vec::new();
+    let mut java_names = Vec::new();
+    for v in item.variants.iter_mut() {
+        if !matches!(v.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                &v.fields,
+                "The variants of a java enum can't have fields.",
+            ));
+        }
+        if let Some((_, value)) = &v.discriminant {
+            return Err(Error::new_spanned(
+                value,
+                "The variants of a java enum can't specify values, the ordinal is read from java.",
+            ));
+        }
+        // 默认使用变体名的大写蛇形形式作为java中的名称，可以使用`#[java_name = "..."]`指定。
+        let java_name = match v.attrs.iter().position(|a| a.path().is_ident("java_name")) {
+            Some(pos) => {
+                let attr = v.attrs.remove(pos);
+                get_lit_str(&attr.meta.require_name_value()?.value)?.value()
+            }
+            None => v.ident.to_string().to_shouty_snake_case(),
+        };
+        variants.push(v.ident.clone());
+        java_names.push(java_name);
+    }
+    let name_str = name.to_string();
+
+    Ok(quote! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #item
+
+        impl #name {
+            /// 获取枚举常量在java中的名称（`name()`的返回值）。
+            pub fn java_name(&self) -> &'static str {
+                match self {
+                    #(Self::#variants => #java_names,)*
+                }
+            }
+
+            /// 获取枚举常量在java中的序号（`ordinal()`的返回值）。
+            pub fn ordinal(&self) -> droid_wrap_utils::Result<i32> {
+                let mut env = droid_wrap_utils::vm_attach()?;
+                Ok(env.call_method(self.java_ref()?, "ordinal", "()I", &[])?.i()?)
+            }
+        }
+
+        impl std::fmt::Display for #name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.java_name())
+            }
+        }
+
+        impl JObjRef for #name {
+            fn java_ref(&self) -> droid_wrap_utils::Result<droid_wrap_utils::GlobalRef> {
+                let mut env = droid_wrap_utils::vm_attach()?;
+                let obj = env
+                    .get_static_field(
+                        <Self as JType>::java_class()?,
+                        self.java_name(),
+                        <Self as JType>::OBJECT_SIG,
+                    )?
+                    .l()?;
+                Ok(env.new_global_ref(&obj)?)
+            }
+        }
+
+        impl JObjNew for #name {
+            type Fields = ();
+            const HAS_FALLBACK: bool = false;
+
+            fn _new(this: &droid_wrap_utils::GlobalRef, _: Self::Fields) -> droid_wrap_utils::Result<Self> {
+                if this.is_null() {
+                    return Err(droid_wrap_utils::DroidWrapError::UnknownEnumConstant(#name_str, "null".to_string()));
+                }
+                let mut env = droid_wrap_utils::vm_attach()?;
+                let java_name = env
+                    .call_method(this, "name", "()Ljava/lang/String;", &[])?
+                    .l()?;
+                let java_name = env.new_global_ref(&java_name)?;
+                let java_name = String::_new(&java_name, ())?;
+                match java_name.as_str() {
+                    #(#java_names => Ok(Self::#variants),)*
+                    _ => Err(droid_wrap_utils::DroidWrapError::UnknownEnumConstant(#name_str, java_name)),
+                }
+            }
+        }
+
+        impl JType for #name {
+            const CLASS: &'static str = #cls;
+            const OBJECT_SIG: &'static str = concat!("L", #cls, ";");
+            #class_loader
+        }
+    })
+}
+
+//noinspection SpellCheckingInspection
+pub(super) fn java_bean(input: TokenStream) -> syn::Result<TokenStream> {
+    let item: ItemStruct = parse2(input)?;
+    if !item.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &item.generics,
+            "A java bean can't have generic parameters.",
+        ));
+    }
+    let Some(attr) = item.attrs.iter().find(|a| a.path().is_ident("java")) else {
+        return Err(Error::new_spanned(
+            &item.ident,
+            "The java class name must be specified, for example `#[java(class = \"android/graphics/Rect\")]`.",
+        ));
+    };
+    let attrs: BeanMetadata = attr.parse_args()?;
+    let cls = attrs.class_name;
+    let class_loader = get_class_loader_token(&attrs.loader);
+    let Fields::Named(fields) = &item.fields else {
+        return Err(Error::new_spanned(
+            &item.fields,
+            "JavaBean can only be derived for structs with named fields.",
+        ));
+    };
+    let name = &item.ident;
+
+    let mut reads = Vec::new();
+    let mut writes = Vec::new();
+    for f in fields.named.iter() {
+        let ident = f.ident.as_ref().unwrap();
+        let attrs: BeanFieldMetadata = match f.attrs.iter().find(|a| a.path().is_ident("java")) {
+            Some(attr) => attr.parse_args()?,
+            None => Default::default(),
+        };
+        if attrs.skip {
+            reads.push(quote! {#ident: Default::default()});
+            continue;
+        }
+
+        let ty = f.ty.to_token_stream();
+        let unwrapped_ty = unwrap_type(&ty);
+        let unwrapped_ty_str = unwrapped_ty.to_string();
+        let (ret_value, sig) = get_return_value_token(&ty, &Default::default(), &vec![])?;
+        // 对象类型使用字段本身的类型转换，这样`Option<T>`字段可以接收java中的null。
+        let ret_value = if sig.to_string().contains("OBJECT_SIG") {
+            quote! {<#ty as JObjNew>::_from_value(&mut env, ret)?}
+        } else {
+            ret_value
+        };
+        let arg_value = get_arg_value_token(&quote! {self.#ident}, &unwrapped_ty_str);
+
+        // 默认使用字段名的小驼峰形式作为java中的字段名。
+        let java_name = attrs.name.map_or_else(
+            || {
+                ident
+                    .to_string()
+                    .trim_start_matches("r#")
+                    .to_lower_camel_case()
+            },
+            |i| i.value(),
+        );
+        let read = match &attrs.getter {
+            Some(getter) => quote! {
+                env.call_method(this, #getter, format!("(){}", #sig), &[])?
+            },
+            None => quote! {
+                env.get_field(this, #java_name, #sig)?
+            },
+        };
+        reads.push(quote! {
+            #ident: {
+                let ret = #read;
+                #ret_value
+            }
+        });
+        match (&attrs.getter, &attrs.setter) {
+            // 只有getter的属性是只读的。
+            (Some(_), None) => (),
+            (_, Some(setter)) => writes.push(quote! {
+                env.call_method(this, #setter, format!("({})V", #sig), &[#arg_value])?;
+            }),
+            (None, None) => writes.push(quote! {
+                env.set_field(this, #java_name, #sig, #arg_value)?;
+            }),
+        }
+    }
+
+    Ok(quote! {
+        impl #name {
+            /// 从java对象中复制所有字段。
+            pub fn from_java(obj: &impl JObjRef) -> droid_wrap_utils::Result<Self> {
+                <Self as JObjNew>::_new(&obj.java_ref()?, ())
+            }
+
+            /// 把所有可写的字段写入已有的java对象。
+            pub fn write_to(&self, obj: &impl JObjRef) -> droid_wrap_utils::Result<()> {
+                self._write_java(&obj.java_ref()?)
+            }
+
+            /// 使用无参数的构造函数创建新的java对象，并写入所有可写的字段。
+            pub fn to_java<T: JObjNew>(&self) -> droid_wrap_utils::Result<T>
+            where
+                T::Fields: Default,
+            {
+                T::_new(&self.java_ref()?, Default::default())
+            }
+
+            fn _write_java(&self, this: &droid_wrap_utils::GlobalRef) -> droid_wrap_utils::Result<()> {
+                let mut env = droid_wrap_utils::vm_attach()?;
+                #(#writes)*
+                Ok(())
+            }
+        }
+
+        impl JObjRef for #name {
+            fn java_ref(&self) -> droid_wrap_utils::Result<droid_wrap_utils::GlobalRef> {
+                let mut env = droid_wrap_utils::vm_attach()?;
+                let obj = env.new_object(<Self as JType>::java_class()?, "()V", &[])?;
+                let obj = env.new_global_ref(&obj)?;
+                self._write_java(&obj)?;
+                Ok(obj)
+            }
+        }
+
+        impl JObjNew for #name {
+            type Fields = ();
+            const HAS_FALLBACK: bool = false;
+
+            fn _new(this: &droid_wrap_utils::GlobalRef, _: Self::Fields) -> droid_wrap_utils::Result<Self> {
+                if this.is_null() {
+                    return Err(droid_wrap_utils::JniError::NullPtr(<Self as JType>::CLASS).into());
+                }
+                let mut env = droid_wrap_utils::vm_attach()?;
+                Ok(Self {
+                    #(#reads,)*
+                })
+            }
+        }
+
+        impl JType for #name {
+            const CLASS: &'static str = #cls;
+            const OBJECT_SIG: &'static str = concat!("L", #cls, ";");
+            #class_loader
+        }
+    })
+}
+
+pub(super) fn java_flags(attrs: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
+    if !attrs.is_empty() {
+        return Err(Error::new_spanned(attrs, "Unsupported attribute."));
+    }
+    let item: ItemEnum = parse2(input)?;
+    if !item.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &item.generics,
+            "A flag set can't have generic parameters.",
+        ));
+    }
+    let name = &item.ident;
+    let name_str = name.to_string();
+    let vis = &item.vis;
+    let attrs = &item.attrs;
+
+    let mut consts = TokenStream::new();
+    let mut flags = Vec::new();
+    for v in item.variants.iter() {
+        if !matches!(v.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                &v.fields,
+                "The flags of a flag set can't have fields.",
+            ));
+        }
+        let Some((_, value)) = &v.discriminant else {
+            return Err(Error::new_spanned(
+                &v.ident,
+                format!(
+                    "The flag `{}` must specify the value of the java constant, for example `{} = 0x1`.",
+                    v.ident, v.ident
+                ),
+            ));
+        };
+        let ident = &v.ident;
+        let doc = &v.attrs;
+        consts.extend(quote! {
+            #(#doc)*
+            pub const #ident: Self = Self(#value);
+        });
+        flags.push(ident.clone());
     }
+    let flag_names = flags.iter().map(|i| i.to_string());
+
+    Ok(quote! {
+        #(#attrs)*
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+        #vis struct #name(u32);
+
+        #[allow(non_upper_case_globals)]
+        impl #name {
+            #consts
+
+            /// 所有具有名称的标志，以及它们在java中的名称。
+            const _FLAGS: &'static [(&'static str, Self)] = &[#((#flag_names, Self::#flags)),*];
+
+            /// 没有设置任何标志。
+            pub const fn empty() -> Self {
+                Self(0)
+            }
+
+            /// 从整数值创建，保留所有的位，包括没有名称的位。
+            pub const fn from_bits_retain(bits: u32) -> Self {
+                Self(bits)
+            }
+
+            /// 获取整数值。
+            pub const fn bits(&self) -> u32 {
+                self.0
+            }
+
+            /// 是否没有设置任何标志。
+            pub const fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+
+            /// 是否设置了`other`中的所有标志。
+            pub const fn contains(&self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// 是否设置了`other`中的任意一个标志。
+            pub const fn intersects(&self, other: Self) -> bool {
+                self.0 & other.0 != 0
+            }
+
+            /// 合并`other`中的标志，与`|`相同，但可以在常量中使用。
+            pub const fn union(self, other: Self) -> Self {
+                Self(self.0 | other.0)
+            }
+
+            /// 设置`other`中的标志。
+            pub fn insert(&mut self, other: Self) {
+                self.0 |= other.0;
+            }
+
+            /// 清除`other`中的标志。
+            pub fn remove(&mut self, other: Self) {
+                self.0 &= !other.0;
+            }
+
+            /// 根据`value`设置或清除`other`中的标志。
+            pub fn set(&mut self, other: Self, value: bool) {
+                if value {
+                    self.insert(other)
+                } else {
+                    self.remove(other)
+                }
+            }
+        }
+
+        impl std::fmt::Debug for #name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}(", stringify!(#name))?;
+                // 按照声明的顺序输出包含的标志，剩余没有名称的位以十六进制输出。
+                let mut remaining = self.0;
+                let mut first = true;
+                for (name, flag) in Self::_FLAGS {
+                    if flag.0 == 0 || flag.0 & self.0 != flag.0 || flag.0 & remaining == 0 {
+                        continue;
+                    }
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    f.write_str(name)?;
+                    remaining &= !flag.0;
+                    first = false;
+                }
+                if remaining != 0 || first {
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    write!(f, "{:#x}", remaining)?;
+                }
+                f.write_str(")")
+            }
+        }
+
+        impl std::ops::BitOr for #name {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self {
+                Self(self.0 | rhs.0)
+            }
+        }
+
+        impl std::ops::BitOrAssign for #name {
+            fn bitor_assign(&mut self, rhs: Self) {
+                self.0 |= rhs.0;
+            }
+        }
+
+        impl std::ops::BitAnd for #name {
+            type Output = Self;
+
+            fn bitand(self, rhs: Self) -> Self {
+                Self(self.0 & rhs.0)
+            }
+        }
+
+        impl std::ops::BitAndAssign for #name {
+            fn bitand_assign(&mut self, rhs: Self) {
+                self.0 &= rhs.0;
+            }
+        }
+
+        impl std::ops::BitXor for #name {
+            type Output = Self;
+
+            fn bitxor(self, rhs: Self) -> Self {
+                Self(self.0 ^ rhs.0)
+            }
+        }
+
+        impl std::ops::BitXorAssign for #name {
+            fn bitxor_assign(&mut self, rhs: Self) {
+                self.0 ^= rhs.0;
+            }
+        }
+
+        impl std::ops::Sub for #name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 & !rhs.0)
+            }
+        }
+
+        impl std::ops::SubAssign for #name {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 &= !rhs.0;
+            }
+        }
+
+        impl std::ops::Not for #name {
+            type Output = Self;
+
+            fn not(self) -> Self {
+                Self(!self.0)
+            }
+        }
+
+        impl From<u32> for #name {
+            fn from(value: u32) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<#name> for u32 {
+            fn from(value: #name) -> Self {
+                value.0
+            }
+        }
+
+        impl JObjRef for #name {
+            fn java_ref(&self) -> droid_wrap_utils::Result<droid_wrap_utils::GlobalRef> {
+                let mut env = droid_wrap_utils::vm_attach()?;
+                let obj = env
+                    .call_static_method(
+                        "java/lang/Integer",
+                        "valueOf",
+                        "(I)Ljava/lang/Integer;",
+                        &[droid_wrap_utils::JValue::Int(self.0 as i32)],
+                    )?
+                    .l()?;
+                Ok(env.new_global_ref(&obj)?)
+            }
+
+            fn java_value(&self) -> droid_wrap_utils::Result<droid_wrap_utils::JArgValue> {
+                Ok(droid_wrap_utils::JArgValue::Int(self.0 as i32))
+            }
+        }
+
+        impl JObjNew for #name {
+            type Fields = ();
+
+            fn _new(this: &droid_wrap_utils::GlobalRef, _: Self::Fields) -> droid_wrap_utils::Result<Self> {
+                // 与`java_constants`一致，装箱的null不是任何标志组合，不能当作空集合处理。
+                if this.is_null() {
+                    return Err(droid_wrap_utils::JniError::NullPtr(#name_str).into());
+                }
+                let mut env = droid_wrap_utils::vm_attach()?;
+                let value = env.call_method(this, "intValue", "()I", &[])?.i()?;
+                Ok(Self(value as u32))
+            }
+
+            fn _from_value(
+                _: &mut droid_wrap_utils::JNIEnv,
+                value: droid_wrap_utils::JValueOwned,
+            ) -> droid_wrap_utils::Result<Self> {
+                Ok(Self(value.i()? as u32))
+            }
+
+            fn _fallback() -> Self {
+                Self(0)
+            }
+        }
+
+        // 转换为java对象（例如放入集合）时使用装箱的`Integer`，因此类名是真实的`java/lang/Integer`；
+        // 作为方法参数、返回值和字段时按照int传递，描述符明确使用基本类型`I`，而不是根据类名生成的`Ljava/lang/Integer;`。
+        impl JType for #name {
+            const CLASS: &'static str = "java/lang/Integer";
+            const OBJECT_SIG: &'static str = "I";
+        }
+    })
+}
+
+pub(super) fn failure_policy(attrs: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
+    let policy: Expr = parse2(attrs)?;
+    FailurePolicy::parse_value(&policy)?;
+    let mut item: ItemImpl = parse2(input)?;
+    for i in item.items.iter_mut() {
+        let ImplItem::Fn(f) = i else {
+            continue;
+        };
+        for attr in f.attrs.iter_mut() {
+            let Some(last) = attr.path().segments.last() else {
+                continue;
+            };
+            if !["java_method", "java_constructor", "java_field"]
+                .contains(&last.ident.to_string().as_str())
+            {
+                continue;
+            }
+            // 方法上明确指定的策略优先。
+            match &attr.meta {
+                Meta::Path(p) => attr.meta = parse_quote!(#p(failure = #policy)),
+                Meta::List(l) => {
+                    let mut args =
+                        l.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+                    if !args.iter().any(|i| i.path().is_ident("failure")) {
+                        // 参数可能为空或以逗号结尾，使用push自动处理分隔符。
+                        args.push(parse_quote!(failure = #policy));
+                        let p = &l.path;
+                        attr.meta = parse_quote!(#p(#args));
+                    }
+                }
+                Meta::NameValue(_) => {}
+            }
+        }
+    }
+
+    Ok(item.to_token_stream())
 }
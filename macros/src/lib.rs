@@ -11,6 +11,7 @@
  * See the License for the specific language governing permissions and limitations under the License.
  */
 
+mod descriptor;
 mod entry;
 mod java;
 mod utils;
@@ -20,6 +21,25 @@ use proc_macro::TokenStream;
 /// 安卓平台的入口
 ///
 /// 将此宏标记在`fn main()`函数上可以自动实现安卓应用的入口函数。
+/// 默认使用`android-activity`作为后端，需要启用`droid-wrap`的`native_activity`或`game_activity`特性，分别支持NativeActivity和GameActivity；
+/// `main`可以接收一个`App`参数，用于获取生命周期事件（恢复、暂停、窗口创建和销毁、配置改变、内存不足等）和输入事件。
+/// `main`返回后会继续处理事件直到activity被销毁，`Activity::fetch`和`android_context`在两种后端中都可以使用。
+/// 也可以使用`backend = "ndk_glue"`切换到已经弃用的`ndk-glue`后端，此时`main`不能接收参数。
+/// 生成的代码会用到`droid_wrap_utils`，使用此宏的crate需要依赖`droid-wrap-utils`（使用`ndk_glue`后端时还需要依赖`ndk-glue`）。
+/// 进入`main`之前会初始化日志（安卓平台上输出到logcat），panic的信息和调用栈也会写入日志。
+/// `main`可以返回`()`或者错误类型实现了`Debug`的`Result`，返回的错误会写入日志。
+///
+/// # Arguments
+///
+/// * `attrs`: 属性，支持以下选项：
+///   - `backend`: 后端，可选值为`android_activity`（默认）和`ndk_glue`。
+///   - `log_level`: 日志级别，可选值为`off`、`error`、`warn`、`info`（默认）、`debug`和`trace`。
+///   - `log_tag`: 日志的标签，默认为crate的名称。
+///   - `panic`: panic时的处理方式，`abort`（默认）结束进程，`finish`结束activity，`toast`使用Toast显示错误信息后结束activity。
+///   - `show_error`: 标记后，`main`返回错误时使用Toast向用户显示错误信息，然后结束activity。
+/// * `input`: 函数输入。
+///
+/// returns: TokenStream
 ///
 /// # 示例
 ///
@@ -29,16 +49,45 @@ use proc_macro::TokenStream;
 /// #[android_main]
 /// fn main() {}
 /// ```
+///
+/// ```ignore
+/// use droid_wrap::{App, AppEvent, android_main};
+///
+/// #[android_main(log_level = "debug", log_tag = "MyApp", panic = "toast", show_error)]
+/// fn main(app: App) {
+///     for event in app.events() {
+///         match event {
+///             AppEvent::Resume => println!("resumed"),
+///             AppEvent::LowMemory => println!("low memory"),
+///             _ => {}
+///         }
+///     }
+/// }
+/// ```
 #[proc_macro_attribute]
-pub fn android_main(_args: TokenStream, input: TokenStream) -> TokenStream {
-    entry::android_main(input.into()).into()
+pub fn android_main(attrs: TokenStream, input: TokenStream) -> TokenStream {
+    entry::android_main(attrs.into(), input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
 /// 定义java class，将此属性标记在struct上，可以自动实现操作java对象的必要功能。
+/// 使用`extends = Base`声明父类时，会为struct实现到父类的`Deref`和`DerefMut`，父类（以及父类的父类）的方法可以直接在子类上调用，无需手动编写转发的方法。
+/// 同时会为`extends`链上的每个祖先类实现`From<子类>`，并为子类实现`AsRef<祖先类>`，例如按值把`Button`传递给需要`View`的参数，或者把不同的视图放入`Vec<View>`。
+/// 生成的类型通过java的`equals`和`hashCode`实现`PartialEq`、`Eq`和`Hash`，`equals`调用失败时视为不相等，`hashCode`调用失败时会panic，以免相等的对象得到不同的哈希值；
+/// 需要判断是否为同一个java对象时使用`JObjRef::same_object`。
+/// 只有没有rust字段的类才会实现`Clone`（复制全局引用），带有rust字段的类无法确定字段应如何复制，需要时请自行实现。
+/// 使用`implements = [Trait, ...]`声明类实现的接口时，会为struct实现这些`java_interface`定义的trait，接口中需要实现的方法都通过虚调用转发到java对象；
+/// 接口的泛型参数和关联类型直接在路径中指定，例如`implements = [Comparable<File>, ViewParent<VP = Self>]`。
+/// 接口必须在当前crate中定义，并且与trait一样需要导入或使用完整的路径。实现了与自身比较的`Comparable<Self>`时，还会通过`compareTo`实现`PartialOrd`和`Ord`，
+/// `compareTo`调用失败时`partial_cmp`返回`None`，而`cmp`会panic。
+/// 生成的构造函数、静态方法和静态字段都通过`JType::java_class`查找类，默认使用应用的类加载器；
+/// 类来自插件（`DexClassLoader`、`InMemoryDexClassLoader`）时，使用`loader = ...`指定任何实现了`droid_wrap_utils::ClassLoaderSource`的值，
+/// 例如`EmbeddedDex`的静态变量、类加载器的`GlobalRef`，或者返回`Result<GlobalRef>`的函数。
 ///
 /// # Arguments
 ///
-/// * `attrs`: 属性输入。
+/// * `attrs`: 属性输入，支持`name`、`extends`、`implements`和`loader`。
 /// * `input`: struct输入。
 ///
 /// returns: TokenStream
@@ -50,16 +99,35 @@ pub fn android_main(_args: TokenStream, input: TokenStream) -> TokenStream {
 /// #[java_class(name = "java/lang/System")]
 /// struct System;
 /// ```
+///
+/// ```ignore
+/// static PLUGIN: EmbeddedDex = include_dex!("plugin");
+///
+/// #[java_class(name = "com/example/plugin/Greeter", loader = PLUGIN)]
+/// pub struct Greeter;
+/// ```
 #[proc_macro_attribute]
 pub fn java_class(attrs: TokenStream, input: TokenStream) -> TokenStream {
-    java::java_class(attrs.into(), input.into()).into()
+    java::java_class(attrs.into(), input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
 /// 实现java类的方法，将此属性标记在fn函数上，可以自动实现调用java方法，可以自动识别静态方法（如果参数中没有“self”）。
+/// 默认使用函数名的小驼峰形式作为java方法名，并根据参数和返回值类型计算描述符；
+/// 如果自动推导的结果不正确（例如`is64BitAbi`），可以使用`name = "..."`指定方法名，使用`sig = "(...)..."`指定完整的描述符，描述符的参数个数会在编译时检查。
+/// 标记`nonvirtual`时，将使用`CallNonvirtual<Type>Method`调用`extends`声明的父类中的实现（相当于java中的`super.method()`），只能用于实例方法。
+/// 使用`since = N`和`until = M`声明方法支持的API级别范围（都包含在内），调用前会与缓存的`Build.VERSION.SDK_INT`比较，
+/// 不在范围内时返回`DroidWrapError::UnsupportedApiLevel`错误（返回`Option`时为`None`），因此返回值必须是`Result`或`Option`，否则编译失败；需要在运行时选择兼容实现时可以使用`is_api_level`。
+/// 参数除了基本类型和java对象之外，还可以使用`&str`、`impl AsRef<str>`（作为java字符串传递）、`Option<&T>`（`None`作为null传递）、`impl Into<T>`（转换为`T`后传递），
+/// 以及`impl Trait`、`&impl Trait`和`&dyn Trait`形式的接口参数，其中`&dyn Trait`会被替换为`&impl Trait`（接口trait包含关联常量，不能作为trait对象使用）。
+/// 返回值不是`Option`或`Result`时，调用失败默认会panic，可以使用`failure = "default"`或`failure = "last_error"`改为返回默认值，详见`failure_policy`。
+/// java的可变参数方法（例如`TextUtils.concat(CharSequence...)`）可以在最后一个参数上标记`#[varargs]`，参数类型为`&[T]`、`Vec<T>`或`impl IntoIterator<Item = T>`，
+/// 调用时会创建元素类型为`T`的java数组（`T`为基本类型时创建基本类型数组）作为最后一个参数传递，`java_constructor`同样支持。
 ///
 /// # Arguments
 ///
-/// * `attrs`: 属性输入。
+/// * `attrs`: 属性输入，支持`type_bound`、`overload`、`name`、`sig`、`nonvirtual`、`since`、`until`和`failure`，其中`name`和`overload`不能同时使用。
 /// * `input`: 函数输入。
 ///
 /// returns: TokenStream
@@ -72,18 +140,28 @@ pub fn java_class(attrs: TokenStream, input: TokenStream) -> TokenStream {
 /// impl System {
 /// #[java_method]
 /// fn current_time_millis() -> i64 {}
+/// #[java_method(name = "getProperty", sig = "(Ljava/lang/String;)Ljava/lang/String;")]
+/// fn property(key: String) -> Option<String> {}
+/// #[java_method(since = 31)]
+/// fn new_api() -> Result<bool, droid_wrap_utils::DroidWrapError> {}
+/// #[java_method(name = "format", sig = "(Ljava/lang/String;[Ljava/lang/Object;)Ljava/lang/String;")]
+/// fn format(format: &str, #[varargs] args: Vec<String>) -> String {}
 /// }
 /// ```
 #[proc_macro_attribute]
 pub fn java_method(attrs: TokenStream, input: TokenStream) -> TokenStream {
-    java::java_method(attrs.into(), input.into()).into()
+    java::java_method(attrs.into(), input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
 /// 实现java类的构造器，将此属性标记在fn函数上，可以自动实现调用java类的构造器。
+/// 构造器的名称固定为`<init>`，可以使用`sig = "(...)V"`指定完整的描述符。
+/// 最后一个参数可以像`java_method`一样标记`#[varargs]`，作为java的可变参数传递。
 ///
 /// # Arguments
 ///
-/// * `_`: 未使用。
+/// * `attrs`: 属性输入，支持`sig`和`failure`。
 /// * `input`: 函数输入。
 ///
 /// returns: TokenStream
@@ -96,18 +174,28 @@ pub fn java_method(attrs: TokenStream, input: TokenStream) -> TokenStream {
 /// impl Integer {
 /// #[java_constructor]
 /// fn new(value: i32) -> Self {}
+/// #[java_constructor(sig = "(Ljava/lang/String;)V")]
+/// fn parse(value: String) -> Self {}
 /// }
 /// ```
 #[proc_macro_attribute]
-pub fn java_constructor(_: TokenStream, input: TokenStream) -> TokenStream {
-    java::java_constructor(input.into()).into()
+pub fn java_constructor(attrs: TokenStream, input: TokenStream) -> TokenStream {
+    java::java_constructor(attrs.into(), input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
 /// 定义java interface，将此属性标记在trait上，可以自动实现提供java对象与rust对象的互操作的功能。
+/// 指定`builder`时，还会生成由闭包实现此接口的类型`<Trait>Impl`，每个方法对应一个闭包，通过`<Trait>Impl::builder()`设置，
+/// 未设置的方法返回java的默认值（数值为0，布尔值为false，`Option`为`None`，对象为null），与`failure_policy`的默认值一致，没有默认值的类型（例如`java_enum`）在编译时报错。如果trait中有关联类型，需要使用`builder(Type = ...)`指定具体的类型。
+/// 指定`wrapper`时，会生成包装java端任意实现对象的类型`<Trait>Impl`，trait中没有默认实现的方法需要标记`#[java_method]`，
+/// 这些方法在`<Trait>Impl`中通过jni调用java对象，方法上的`#[java_method]`参数会原样传递。关联类型使用`wrapper(Type = ...)`指定。
+/// `builder`和`wrapper`不能同时使用。
+/// 此外还会生成一个与trait同名的隐藏宏，供`java_class`的`implements`为类生成此接口的实现，同样会使用方法上的`#[java_method]`参数。
 ///
 /// # Arguments
 ///
-/// * `attrs`: 属性。
+/// * `attrs`: 属性，支持`name`、`builder`和`wrapper`。
 /// * `input`: 特征输入。
 ///
 /// returns: TokenStream
@@ -120,9 +208,32 @@ pub fn java_constructor(_: TokenStream, input: TokenStream) -> TokenStream {
 /// fn run(&self);
 /// }
 /// ```
+///
+/// ```ignore
+/// #[java_interface(name = "android/view/View$OnClickListener", builder)]
+/// pub trait View_OnClickListener {
+///     fn on_click(&self, v: View);
+/// }
+///
+/// let listener = View_OnClickListenerImpl::builder()
+///     .on_click(|v| println!("{:?} is clicked.", v))
+///     .build()?;
+/// view.set_on_click_listener(listener.as_ref());
+///
+/// #[java_interface(name = "java/lang/CharSequence", wrapper)]
+/// pub trait CharSequence {
+///     #[java_method]
+///     fn length(&self) -> i32;
+/// }
+///
+/// let cs: CharSequenceImpl = text_view.get_text().unwrap();
+/// println!("{}", cs.length());
+/// ```
 #[proc_macro_attribute]
 pub fn java_interface(attrs: TokenStream, input: TokenStream) -> TokenStream {
-    java::java_interface(attrs.into(), input.into()).into()
+    java::java_interface(attrs.into(), input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
 /// 实现java interface，将此属性标记在impl上，可以自动实现java接口的动态代理，从而实现java层回调rust层。
@@ -164,14 +275,227 @@ pub fn java_interface(attrs: TokenStream, input: TokenStream) -> TokenStream {
 /// ```
 #[proc_macro_attribute]
 pub fn java_implement(attrs: TokenStream, input: TokenStream) -> TokenStream {
-    java::java_implement(attrs.into(), input.into()).into()
+    java::java_implement(attrs.into(), input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// 继承java类，将此属性标记在`java_class`类型的impl上，运行时会生成一个真正的java子类（类名为`java_class`中的`name`），
+/// 其中的每一个实例方法（下划线“_”开头的函数除外）都成为java中的本地方法，重写父类中具有相同名称和描述符的方法，调用时转发到rust的实现。
+/// 父类默认为`java_class`中`extends`声明的类型，也可以使用`extends = "..."`指定。
+/// 每一个java对象都绑定了自己的rust状态，即`java_class`中声明的字段，java层调用时通过对象找到对应的rust值。
+/// 使用`#[java_constructor]`标记的函数声明构造函数，最后一个参数是rust状态，其余参数传递给父类的构造函数；如果没有声明构造函数，会生成使用父类无参构造函数的`new(fields)`。
+/// 构造函数返回`Arc<Self>`，java对象默认只持有rust状态的弱引用，rust端释放`Arc`后，java的调用只会记录警告并返回默认值。
+/// 交给java长期持有的对象（例如注册的`BroadcastReceiver`或者投递到`Handler`的`Runnable`）应该调用`droid_wrap_utils::retain_subclass_state`，
+/// 让java对象持有rust状态的强引用，不再需要时调用`droid_wrap_utils::release_subclass_state`释放，否则两者都不会被回收。
+///
+/// # Arguments
+///
+/// * `attrs`: 属性，支持`extends`。
+/// * `input`: impl输入。
+///
+/// returns: TokenStream
+///
+/// # Examples
+///
+/// ```ignore
+/// #[java_class(name = "rust/droid/CountingReceiver", extends = BroadcastReceiver)]
+/// pub struct CountingReceiver {
+///     count: AtomicI32,
+/// }
+///
+/// #[java_subclass]
+/// impl CountingReceiver {
+///     fn on_receive(&self, context: Context, intent: Intent) {
+///         self.count.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+///
+/// let receiver = CountingReceiver::new(CountingReceiverDefault { count: AtomicI32::new(0) })?;
+/// // 注册后由java持有，rust端不再保存receiver时也能继续接收广播
+/// droid_wrap_utils::retain_subclass_state(&receiver.java_ref()?)?;
+/// ```
+#[proc_macro_attribute]
+pub fn java_subclass(attrs: TokenStream, input: TokenStream) -> TokenStream {
+    java::java_subclass(attrs.into(), input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// 实现java类中声明的本地方法，将此属性标记在fn函数上，java层调用`class`中的本地方法时会转发到此函数。
+/// 默认使用函数名的小驼峰形式作为java方法名，并根据参数和返回值类型计算描述符，规则与`java_method`一致；也可以使用`name`和`sig`指定。
+/// 默认为静态方法，标记`instance`时为实例方法，此时第一个参数接收java对象本身。
+/// 本地方法在库加载时提交，并在`android_main`进入`main`之前注册；使用`droid_wrap_utils::jni_on_load!()`导出`JNI_OnLoad`时，会在`System.loadLibrary`加载库时注册，
+/// 这样在`android_main`运行之前或者没有`android_main`的库中也可以调用。
+/// 类默认通过应用的类加载器加载，可以使用`loader = ...`指定与`java_class`相同的类加载器，例如插件的类加载器（`Plugin`加载时会自动注册），其他类加载器在可用之后需要再次调用`droid_wrap_utils::register_natives()`。
+/// 函数返回错误或者发生panic时会在java层抛出`RuntimeException`（已经有待处理的java异常时除外），然后返回默认值。
+///
+/// # Arguments
+///
+/// * `attrs`: 属性，支持`class`、`name`、`sig`、`instance`和`loader`，其中`class`是必须的。
+/// * `input`: 函数输入。
+///
+/// returns: TokenStream
+///
+/// # Examples
+///
+/// ```ignore
+/// // package com.example; class NativeLib { static native int add(int a, int b); native String greet(String name); }
+/// #[java_native(class = "com/example/NativeLib")]
+/// fn add(a: i32, b: i32) -> i32 {
+///     a + b
+/// }
+///
+/// #[java_native(class = "com/example/NativeLib", instance)]
+/// fn greet(this: Object, name: String) -> Result<String> {
+///     Ok(format!("Hello, {}!", name))
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn java_native(attrs: TokenStream, input: TokenStream) -> TokenStream {
+    java::java_native(attrs.into(), input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// 定义java中的枚举类，将此属性标记在enum上，每个变体对应一个枚举常量。
+/// java中常量的名称默认为变体名的大写蛇形形式，可以在变体上使用`#[java_name = "..."]`指定完整的名称。
+/// 传递给java时读取枚举类中同名的静态字段，从java返回时根据`name()`转换为对应的变体（不属于此enum的常量会返回`DroidWrapError::UnknownEnumConstant`错误），
+/// 还会实现`java_name`、调用java中`ordinal()`的`ordinal`方法以及使用java中的名称的`Display`。
+/// 生成的类型实现了`JType`、`JObjRef`和`JObjNew`，可以直接作为`java_method`、`java_field`等的参数和返回值。
+///
+/// # Arguments
+///
+/// * `attrs`: 属性，支持`name`和`loader`（与`java_class`相同）。
+/// * `input`: 枚举输入。
+///
+/// returns: TokenStream
+///
+/// # Examples
+///
+/// ```ignore
+/// #[java_enum(name = "java/lang/Thread$State")]
+/// pub enum ThreadState {
+///     New,
+///     Runnable,
+///     Blocked,
+///     Waiting,
+///     TimedWaiting,
+///     Terminated,
+/// }
+///
+/// assert_eq!("TIMED_WAITING", ThreadState::TimedWaiting.to_string());
+/// ```
+#[proc_macro_attribute]
+pub fn java_enum(attrs: TokenStream, input: TokenStream) -> TokenStream {
+    java::java_enum(attrs.into(), input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// 把java中的对象（bean）映射为普通的rust结构体，在结构体上使用`#[java(class = "...")]`指定java类，还可以使用`loader = ...`指定类加载器（与`java_class`相同）。
+/// 每个字段默认读写java中同名（小驼峰形式）的字段，可以使用`#[java(name = "...")]`指定字段名，或者使用`#[java(getter = "...", setter = "...")]`通过方法读写，
+/// 只指定`getter`的字段是只读的，不会写回java对象；标记`#[java(skip)]`的字段不与java交互，读取时使用`Default::default()`。
+/// 会生成一次复制整个对象的`from_java`，写入已有对象的`write_to`，以及使用无参数构造函数创建新对象的`to_java`。
+/// 生成的类型同样实现了`JType`、`JObjRef`和`JObjNew`，可以直接作为`java_method`等的参数和返回值，作为参数时每次都会创建新的java对象。
+///
+/// # Arguments
+///
+/// * `input`: 结构体输入，只支持具名字段。
+///
+/// returns: TokenStream
+///
+/// # Examples
+///
+/// ```ignore
+/// #[derive(Debug, Default, JavaBean)]
+/// #[java(class = "android/graphics/Rect")]
+/// pub struct RectData {
+///     pub left: i32,
+///     pub top: i32,
+///     pub right: i32,
+///     pub bottom: i32,
+/// }
+///
+/// let data = RectData::from_java(&rect)?;
+/// let copied: Rect = data.to_java()?;
+/// ```
+#[proc_macro_derive(JavaBean, attributes(java))]
+pub fn java_bean(input: TokenStream) -> TokenStream {
+    java::java_bean(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// 定义java中的一组int常量，将此属性标记在enum上，每个变体对应一个常量，变体的值即为常量的值。
+/// 会为enum添加`Unknown(i32)`变体用于表示不属于此组的值，并实现`TryFrom<i32>`（未知的值返回错误）、`Into<i32>`以及使用java中的名称的`Display`。
+/// java中的名称默认为前缀加上变体名的大写蛇形形式，可以使用`prefix = "..."`指定前缀，也可以在变体上使用`#[java_name = "..."]`指定完整的名称。
+/// 生成的类型可以直接作为`java_method`、`java_field`等的参数和返回值，调用时按照int传递，从java返回的未知值会保存为`Unknown`，
+/// 从装箱的`Integer`对象创建时，null会返回`NullPtr`错误。
+///
+/// # Arguments
+///
+/// * `attrs`: 属性，支持`prefix`。
+/// * `input`: 枚举输入。
+///
+/// returns: TokenStream
+///
+/// # Examples
+///
+/// ```ignore
+/// #[java_constants(prefix = "EFFECT_")]
+/// pub enum Effect {
+///     Click = 0,
+///     DoubleClick = 1,
+/// }
+///
+/// assert_eq!("EFFECT_DOUBLE_CLICK", Effect::DoubleClick.to_string());
+/// assert_eq!(Effect::Click, Effect::try_from(0)?);
+/// ```
+#[proc_macro_attribute]
+pub fn java_constants(attrs: TokenStream, input: TokenStream) -> TokenStream {
+    java::java_constants(attrs.into(), input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// 定义java中的一组int标志，将此属性标记在enum上，会生成同名的标志集合类型（类似`bitflags`），每个变体成为一个同名的常量，变体的值即为标志的值。
+/// 标志集合可以使用`|`、`&`、`^`、`-`和`!`组合，提供`contains`、`insert`、`remove`等方法，`Debug`输出包含的标志名称，没有名称的位以十六进制输出。
+/// 与`java_constants`一样，生成的类型可以直接作为`java_method`、`java_field`等的参数和返回值，调用时按照int传递，从装箱的`Integer`对象创建时，null会返回`NullPtr`错误。
+///
+/// # Arguments
+///
+/// * `attrs`: 属性，不支持任何属性。
+/// * `input`: 枚举输入。
+///
+/// returns: TokenStream
+///
+/// # Examples
+///
+/// ```ignore
+/// #[java_flags]
+/// pub enum IntentFlags {
+///     FLAG_GRANT_READ_URI_PERMISSION = 0x00000001,
+///     FLAG_ACTIVITY_NEW_TASK = 0x10000000,
+/// }
+///
+/// let flags = IntentFlags::FLAG_ACTIVITY_NEW_TASK | IntentFlags::FLAG_GRANT_READ_URI_PERMISSION;
+/// assert!(flags.contains(IntentFlags::FLAG_ACTIVITY_NEW_TASK));
+/// assert_eq!("IntentFlags(FLAG_GRANT_READ_URI_PERMISSION | FLAG_ACTIVITY_NEW_TASK)", format!("{:?}", flags));
+/// ```
+#[proc_macro_attribute]
+pub fn java_flags(attrs: TokenStream, input: TokenStream) -> TokenStream {
+    java::java_flags(attrs.into(), input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
 /// 实现java类的字段，将此属性标记在带有get或set的fn函数上，可以自动实现访问java字段的能力，可以自动识别静态字段（如果参数中没有“self”）。
+/// 默认去掉函数名的get或set前缀后作为字段名，可以使用`name = "..."`指定字段名，使用`sig = "..."`指定字段的描述符。
 ///
 /// # Arguments
 ///
-/// * `_`: 未使用。
+/// * `attrs`: 属性输入，支持`default_value`、`name`、`sig`和`failure`。
 /// * `input`: 函数输入。
 ///
 /// returns: TokenStream
@@ -189,9 +513,55 @@ pub fn java_implement(attrs: TokenStream, input: TokenStream) -> TokenStream {
 ///
 ///     #[java_field]
 ///     pub fn set_width(&self, value: i32) {}
+///
+///     #[java_field(name = "mFlags", sig = "I")]
+///     pub fn get_flags(&self) -> i32 {}
 /// }
 /// ```
 #[proc_macro_attribute]
 pub fn java_field(attrs: TokenStream, input: TokenStream) -> TokenStream {
-    java::java_field(attrs.into(), input.into()).into()
+    java::java_field(attrs.into(), input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// 为impl块中所有的`java_method`、`java_constructor`和`java_field`指定失败策略，方法上使用`failure = "..."`明确指定的策略优先。
+/// 返回值不是`Option`或`Result`的方法遇到错误（例如JNI调用抛出异常）时，默认会panic，可以选择以下策略：
+///
+/// * `"panic"`: 直接panic。
+/// * `"default"`: 记录错误日志并返回默认值（基本类型的默认值，java对象为null）。
+/// * `"last_error"`: 把错误保存到当前线程中并返回默认值，之后可以使用`droid_wrap_utils::take_last_error`取出。
+///
+/// 没有指定策略时，使用`droid-wrap`的`failure_default`或`failure_last_error` feature选择的策略；
+/// crate可以在构建脚本中输出`cargo::rustc-cfg=droid_wrap_failure_strict`启用严格模式，此时该crate中没有明确指定策略的方法只能返回`Option`或`Result`，
+/// 这个cfg只影响设置了它的crate，不会影响`droid-wrap`等依赖。
+/// 使用`"default"`或`"last_error"`策略时，返回的类型必须有默认值（java对象为null，`java_constants`生成的类型为0对应的值），`java_enum`和`JavaBean`生成的类型会在编译时报错。
+/// 注意java对象的null需要java虚拟机创建，如果当前进程从未成功附加过java虚拟机（此时任何java调用都不可能成功），这两个策略仍然会panic。
+///
+/// # Arguments
+///
+/// * `attrs`: 策略名称。
+/// * `input`: impl块输入。
+///
+/// returns: TokenStream
+///
+/// # Examples
+///
+/// ```ignore
+/// use droid_wrap::{failure_policy, java_class, java_method};
+///
+/// #[java_class(name = "android/view/View")]
+/// pub struct View;
+///
+/// #[failure_policy("last_error")]
+/// impl View {
+///     #[java_method]
+///     pub fn get_width(&self) -> i32 {}
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn failure_policy(attrs: TokenStream, input: TokenStream) -> TokenStream {
+    java::failure_policy(attrs.into(), input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
@@ -11,115 +11,726 @@
  * See the License for the specific language governing permissions and limitations under the License.
  */
 
-use proc_macro2::TokenStream;
-use quote::{ToTokens, quote};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{ToTokens, quote, quote_spanned};
 use syn::{
-    Expr, FnArg, Generics, MetaNameValue, PathArguments, PathSegment, ReturnType, Signature, Token,
-    Type, TypeReference,
+    Error, Expr, ExprLit, FnArg, GenericArgument, Generics, Lit, LitStr, Meta, MetaNameValue, Pat,
+    Path, PathArguments, PathSegment, ReturnType, Signature, Token, Type, TypeParamBound,
+    TypeReference, bracketed,
     parse::{Parse, ParseStream},
-    parse2,
+    parse_quote, parse2,
     punctuated::Punctuated,
     token::SelfValue,
+    visit_mut::{self, VisitMut},
 };
 
+use crate::descriptor::primitive_descriptor;
+
 pub(super) struct ClassMetadata {
     pub(crate) class_name: Expr,
     pub(crate) base_class: Option<Expr>,
+    pub(crate) implements: Vec<Path>,
+    pub(crate) loader: Option<Expr>,
 }
 
 impl Parse for ClassMetadata {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let attrs = Punctuated::<MetaNameValue, Token![,]>::parse_terminated(input)?;
-        let cls = attrs
-            .iter()
-            .find(|i| i.path.is_ident("name"))
-            .unwrap()
-            .value
-            .clone();
-        let based = match attrs.iter().find(|i| i.path.is_ident("extends")) {
-            Some(o) => Some(o.value.clone()),
-            None => None,
+        let mut cls = None;
+        let mut based = None;
+        let mut implements = Vec::new();
+        let mut loader = None;
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            if key == "implements" {
+                // 接口可以带有泛型参数和关联类型，例如`Comparable<File>`，不能作为表达式解析。
+                let content;
+                bracketed!(content in input);
+                implements.extend(Punctuated::<Path, Token![,]>::parse_terminated(&content)?);
+            } else {
+                let value: Expr = input.parse()?;
+                if key == "name" {
+                    cls = Some(value);
+                } else if key == "extends" {
+                    based = Some(value);
+                } else if key == "loader" {
+                    loader = Some(value);
+                } else {
+                    return Err(Error::new_spanned(key, "Unsupported attribute."));
+                }
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        let Some(cls) = cls else {
+            return Err(Error::new(
+                Span::call_site(),
+                "The java class name must be specified, for example `name = \"java/lang/Object\"`.",
+            ));
         };
         Ok(Self {
             class_name: cls,
             base_class: based,
+            implements,
+            loader,
         })
     }
 }
 
+/// 生成`JType::class_loader`的实现，没有指定`loader`时使用默认的应用类加载器。
+pub(super) fn get_class_loader_token(loader: &Option<Expr>) -> TokenStream {
+    match loader {
+        Some(loader) => quote! {
+            fn class_loader() -> droid_wrap_utils::Result<droid_wrap_utils::GlobalRef> {
+                droid_wrap_utils::ClassLoaderSource::class_loader(&#loader)
+            }
+        },
+        None => TokenStream::new(),
+    }
+}
+
+pub(super) fn get_lit_str(value: &Expr) -> syn::Result<LitStr> {
+    match value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(s), ..
+        }) => Ok(s.clone()),
+        _ => Err(Error::new_spanned(value, "Expected a string literal.")),
+    }
+}
+
+/// 获取API级别，必须是正整数字面量。
+fn get_api_level(value: &Expr) -> syn::Result<i32> {
+    match value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(i), ..
+        }) => match i.base10_parse::<i32>() {
+            Ok(level) if level > 0 => Ok(level),
+            _ => Err(Error::new_spanned(
+                value,
+                "The API level must be a positive integer.",
+            )),
+        },
+        _ => Err(Error::new_spanned(value, "Expected an integer literal.")),
+    }
+}
+
+/// 返回值不是`Option`或`Result`的方法遇到错误时的处理方式。
+#[derive(Clone, Copy, PartialEq)]
+pub(super) enum FailurePolicy {
+    /// 直接panic，默认的策略。
+    Panic,
+    /// 记录日志并返回默认值（基本类型的默认值或java的null）。
+    Default,
+    /// 保存到当前线程的最近错误中并返回默认值，可以使用`take_last_error`取出。
+    LastError,
+}
+
+impl FailurePolicy {
+    /// 由crate的feature选择的策略，没有启用任何feature时为`Panic`。
+    pub(super) fn from_features() -> Self {
+        if cfg!(feature = "failure_last_error") {
+            Self::LastError
+        } else if cfg!(feature = "failure_default") {
+            Self::Default
+        } else {
+            Self::Panic
+        }
+    }
+
+    pub(super) fn parse_value(value: &Expr) -> syn::Result<Self> {
+        let value = get_lit_str(value)?;
+        match value.value().as_str() {
+            "panic" => Ok(Self::Panic),
+            "default" => Ok(Self::Default),
+            "last_error" => Ok(Self::LastError),
+            _ => Err(Error::new_spanned(
+                value,
+                "Expected `\"panic\"`, `\"default\"` or `\"last_error\"`.",
+            )),
+        }
+    }
+}
+
 pub(super) struct FieldMetadata {
     pub(crate) default_value: Option<Expr>,
+    pub(crate) name: Option<LitStr>,
+    pub(crate) sig: Option<LitStr>,
+    pub(crate) failure: Option<FailurePolicy>,
 }
 
 impl Parse for FieldMetadata {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let attrs = Punctuated::<MetaNameValue, Token![,]>::parse_terminated(input)?;
-        let default_value = match attrs.iter().find(|i| i.path.is_ident("default_value")) {
-            None => None,
-            Some(v) => Some(v.value.clone()),
-        };
+        let mut default_value = None;
+        let mut name = None;
+        let mut sig = None;
+        let mut failure = None;
+        for item in attrs.iter() {
+            if item.path.is_ident("default_value") {
+                default_value = Some(item.value.clone());
+            } else if item.path.is_ident("name") {
+                name = Some(get_lit_str(&item.value)?);
+            } else if item.path.is_ident("sig") {
+                sig = Some(get_lit_str(&item.value)?);
+            } else if item.path.is_ident("failure") {
+                failure = Some(FailurePolicy::parse_value(&item.value)?);
+            } else {
+                return Err(Error::new_spanned(item, "Unsupported attribute."));
+            }
+        }
+
+        Ok(Self {
+            default_value,
+            name,
+            sig,
+            failure,
+        })
+    }
+}
+
+pub(super) struct ConstructorMetadata {
+    pub(crate) sig: Option<LitStr>,
+    pub(crate) failure: Option<FailurePolicy>,
+}
+
+impl Parse for ConstructorMetadata {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = Punctuated::<MetaNameValue, Token![,]>::parse_terminated(input)?;
+        let mut sig = None;
+        let mut failure = None;
+        for item in attrs.iter() {
+            if item.path.is_ident("sig") {
+                sig = Some(get_lit_str(&item.value)?);
+            } else if item.path.is_ident("failure") {
+                failure = Some(FailurePolicy::parse_value(&item.value)?);
+            } else if item.path.is_ident("name") {
+                return Err(Error::new_spanned(
+                    item,
+                    "The name of a constructor is always `<init>`, only `sig` can be specified.",
+                ));
+            } else {
+                return Err(Error::new_spanned(item, "Unsupported attribute."));
+            }
+        }
 
-        Ok(Self { default_value })
+        Ok(Self { sig, failure })
     }
 }
 
 pub(super) struct InterfaceMetadata {
     pub(crate) interface_name: Expr,
+    /// 是否生成由闭包实现的构建器，以及构建器中关联类型的具体类型。
+    pub(crate) builder: Option<Vec<(Ident, Type)>>,
+    /// 是否生成持有任意java对象的包装类型，以及包装类型中关联类型的具体类型。
+    pub(crate) wrapper: Option<Vec<(Ident, Type)>>,
+}
+
+/// 解析`builder`或`wrapper`，可以使用`builder(Type = ...)`指定关联类型的具体类型。
+fn parse_associated_types(meta: &Meta) -> syn::Result<Vec<(Ident, Type)>> {
+    let Meta::List(l) = meta else {
+        return Ok(vec![]);
+    };
+    let types = l.parse_args_with(Punctuated::<MetaNameValue, Token![,]>::parse_terminated)?;
+    let mut assoc = vec![];
+    for i in types.iter() {
+        let Some(ident) = i.path.get_ident() else {
+            return Err(Error::new_spanned(&i.path, "Expected an associated type."));
+        };
+        assoc.push((ident.clone(), parse2(i.value.to_token_stream())?));
+    }
+
+    Ok(assoc)
 }
 
 impl Parse for InterfaceMetadata {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let attrs = Punctuated::<MetaNameValue, Token![,]>::parse_terminated(input)?;
-        let cls = attrs
-            .iter()
-            .find(|i| i.path.is_ident("name"))
-            .unwrap()
-            .value
-            .clone();
+        let attrs = Punctuated::<Meta, Token![,]>::parse_terminated(input)?;
+        let mut cls = None;
+        let mut builder = None;
+        let mut wrapper = None;
+        for item in attrs.iter() {
+            match item {
+                Meta::NameValue(i) if i.path.is_ident("name") => cls = Some(i.value.clone()),
+                i if i.path().is_ident("builder") => builder = Some(parse_associated_types(i)?),
+                i if i.path().is_ident("wrapper") => wrapper = Some(parse_associated_types(i)?),
+                _ => return Err(Error::new_spanned(item, "Unsupported attribute.")),
+            }
+        }
+        if builder.is_some()
+            && let Some(wrapper) = attrs.iter().find(|i| i.path().is_ident("wrapper"))
+        {
+            return Err(Error::new_spanned(
+                wrapper,
+                "`builder` and `wrapper` both generate `<Trait>Impl` and cannot be used together.",
+            ));
+        }
+        let Some(cls) = cls else {
+            return Err(Error::new(
+                Span::call_site(),
+                "The java interface name must be specified, for example `name = \"java/lang/Runnable\"`.",
+            ));
+        };
         Ok(Self {
             interface_name: cls,
+            builder,
+            wrapper,
         })
     }
 }
 
+pub(super) struct SubclassMetadata {
+    pub(crate) super_class: Option<LitStr>,
+}
+
+impl Parse for SubclassMetadata {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = Punctuated::<MetaNameValue, Token![,]>::parse_terminated(input)?;
+        let mut super_class = None;
+        for item in attrs.iter() {
+            if item.path.is_ident("extends") {
+                super_class = Some(get_lit_str(&item.value)?);
+            } else {
+                return Err(Error::new_spanned(item, "Unsupported attribute."));
+            }
+        }
+
+        Ok(Self { super_class })
+    }
+}
+
+pub(super) struct NativeMetadata {
+    pub(crate) class_name: LitStr,
+    pub(crate) name: Option<LitStr>,
+    pub(crate) sig: Option<LitStr>,
+    pub(crate) instance: bool,
+    pub(crate) loader: Option<Expr>,
+}
+
+impl Parse for NativeMetadata {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = Punctuated::<Meta, Token![,]>::parse_terminated(input)?;
+        let mut class_name = None;
+        let mut name = None;
+        let mut sig = None;
+        let mut instance = false;
+        let mut loader = None;
+        for item in attrs.iter() {
+            let item = match item {
+                Meta::Path(p) if p.is_ident("instance") => {
+                    instance = true;
+                    continue;
+                }
+                Meta::NameValue(item) => item,
+                _ => return Err(Error::new_spanned(item, "Unsupported attribute.")),
+            };
+            if item.path.is_ident("class") {
+                class_name = Some(get_lit_str(&item.value)?);
+            } else if item.path.is_ident("name") {
+                name = Some(get_lit_str(&item.value)?);
+            } else if item.path.is_ident("sig") {
+                sig = Some(get_lit_str(&item.value)?);
+            } else if item.path.is_ident("loader") {
+                loader = Some(item.value.clone());
+            } else {
+                return Err(Error::new_spanned(item, "Unsupported attribute."));
+            }
+        }
+        let Some(class_name) = class_name else {
+            return Err(Error::new(
+                Span::call_site(),
+                "The java class declaring the native method must be specified, for example `class = \"com/example/Native\"`.",
+            ));
+        };
+
+        Ok(Self {
+            class_name,
+            name,
+            sig,
+            instance,
+            loader,
+        })
+    }
+}
+
+/// `android_main`使用的后端。
+pub(super) enum MainBackend {
+    /// 基于`android-activity`，支持NativeActivity和GameActivity。
+    AndroidActivity,
+    /// 基于已经弃用的`ndk-glue`，只支持NativeActivity。
+    NdkGlue,
+}
+
+pub(super) struct MainMetadata {
+    pub(crate) backend: MainBackend,
+    pub(crate) log_level: Ident,
+    pub(crate) log_tag: Option<LitStr>,
+    pub(crate) panic: Ident,
+    pub(crate) show_error: bool,
+}
+
+impl Parse for MainMetadata {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = Punctuated::<Meta, Token![,]>::parse_terminated(input)?;
+        let mut backend = MainBackend::AndroidActivity;
+        let mut log_level = Ident::new("Info", Span::call_site());
+        let mut log_tag = None;
+        let mut panic = Ident::new("Abort", Span::call_site());
+        let mut show_error = false;
+        for item in attrs.iter() {
+            let item = match item {
+                Meta::Path(p) if p.is_ident("show_error") => {
+                    show_error = true;
+                    continue;
+                }
+                Meta::NameValue(i) => i,
+                _ => return Err(Error::new_spanned(item, "Unsupported attribute.")),
+            };
+            if item.path.is_ident("backend") {
+                let value = get_lit_str(&item.value)?;
+                backend = match value.value().as_str() {
+                    "android_activity" => MainBackend::AndroidActivity,
+                    "ndk_glue" => MainBackend::NdkGlue,
+                    _ => {
+                        return Err(Error::new_spanned(
+                            value,
+                            "The backend must be `android_activity` or `ndk_glue`.",
+                        ));
+                    }
+                };
+            } else if item.path.is_ident("log_level") {
+                let value = get_lit_str(&item.value)?;
+                let level = match value.value().as_str() {
+                    "off" => "Off",
+                    "error" => "Error",
+                    "warn" => "Warn",
+                    "info" => "Info",
+                    "debug" => "Debug",
+                    "trace" => "Trace",
+                    _ => {
+                        return Err(Error::new_spanned(
+                            value,
+                            "The log level must be one of `off`, `error`, `warn`, `info`, `debug` and `trace`.",
+                        ));
+                    }
+                };
+                log_level = Ident::new(level, value.span());
+            } else if item.path.is_ident("log_tag") {
+                log_tag = Some(get_lit_str(&item.value)?);
+            } else if item.path.is_ident("panic") {
+                let value = get_lit_str(&item.value)?;
+                let policy = match value.value().as_str() {
+                    "abort" => "Abort",
+                    "finish" => "Finish",
+                    "toast" => "Toast",
+                    _ => {
+                        return Err(Error::new_spanned(
+                            value,
+                            "The panic policy must be one of `abort`, `finish` and `toast`.",
+                        ));
+                    }
+                };
+                panic = Ident::new(policy, value.span());
+            } else {
+                return Err(Error::new_spanned(item, "Unsupported attribute."));
+            }
+        }
+
+        Ok(Self {
+            backend,
+            log_level,
+            log_tag,
+            panic,
+            show_error,
+        })
+    }
+}
+
+pub(super) struct BeanMetadata {
+    pub(crate) class_name: Expr,
+    pub(crate) loader: Option<Expr>,
+}
+
+impl Parse for BeanMetadata {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = Punctuated::<MetaNameValue, Token![,]>::parse_terminated(input)?;
+        let mut cls = None;
+        let mut loader = None;
+        for item in attrs.iter() {
+            if item.path.is_ident("class") {
+                cls = Some(item.value.clone());
+            } else if item.path.is_ident("loader") {
+                loader = Some(item.value.clone());
+            } else {
+                return Err(Error::new_spanned(item, "Unsupported attribute."));
+            }
+        }
+        let Some(cls) = cls else {
+            return Err(Error::new(
+                Span::call_site(),
+                "The java class name must be specified, for example `#[java(class = \"android/graphics/Rect\")]`.",
+            ));
+        };
+
+        Ok(Self {
+            class_name: cls,
+            loader,
+        })
+    }
+}
+
+/// `JavaBean`中字段的属性，默认读写java中同名（小驼峰形式）的字段。
+#[derive(Default)]
+pub(super) struct BeanFieldMetadata {
+    pub(crate) name: Option<LitStr>,
+    pub(crate) getter: Option<LitStr>,
+    pub(crate) setter: Option<LitStr>,
+    pub(crate) skip: bool,
+}
+
+impl Parse for BeanFieldMetadata {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = Punctuated::<Meta, Token![,]>::parse_terminated(input)?;
+        let mut res = Self::default();
+        for item in attrs.iter() {
+            let item = match item {
+                Meta::Path(p) if p.is_ident("skip") => {
+                    res.skip = true;
+                    continue;
+                }
+                Meta::NameValue(item) => item,
+                _ => return Err(Error::new_spanned(item, "Unsupported attribute.")),
+            };
+            if item.path.is_ident("name") {
+                res.name = Some(get_lit_str(&item.value)?);
+            } else if item.path.is_ident("getter") {
+                res.getter = Some(get_lit_str(&item.value)?);
+            } else if item.path.is_ident("setter") {
+                res.setter = Some(get_lit_str(&item.value)?);
+            } else {
+                return Err(Error::new_spanned(item, "Unsupported attribute."));
+            }
+        }
+        if let Some(name) = &res.name
+            && (res.getter.is_some() || res.setter.is_some())
+        {
+            return Err(Error::new_spanned(
+                name,
+                "`name` and `getter`/`setter` cannot be used together.",
+            ));
+        }
+
+        Ok(res)
+    }
+}
+
+pub(super) struct EnumMetadata {
+    pub(crate) enum_name: Expr,
+    pub(crate) loader: Option<Expr>,
+}
+
+impl Parse for EnumMetadata {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = Punctuated::<MetaNameValue, Token![,]>::parse_terminated(input)?;
+        let mut enum_name = None;
+        let mut loader = None;
+        for item in attrs.iter() {
+            if item.path.is_ident("name") {
+                enum_name = Some(item.value.clone());
+            } else if item.path.is_ident("loader") {
+                loader = Some(item.value.clone());
+            } else {
+                return Err(Error::new_spanned(item, "Unsupported attribute."));
+            }
+        }
+        let Some(enum_name) = enum_name else {
+            return Err(Error::new(
+                Span::call_site(),
+                "The java enum name must be specified, for example `name = \"java/lang/Thread$State\"`.",
+            ));
+        };
+
+        Ok(Self { enum_name, loader })
+    }
+}
+
+pub(super) struct ConstantsMetadata {
+    pub(crate) prefix: Option<LitStr>,
+}
+
+impl Parse for ConstantsMetadata {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = Punctuated::<MetaNameValue, Token![,]>::parse_terminated(input)?;
+        let mut prefix = None;
+        for item in attrs.iter() {
+            if item.path.is_ident("prefix") {
+                prefix = Some(get_lit_str(&item.value)?);
+            } else {
+                return Err(Error::new_spanned(item, "Unsupported attribute."));
+            }
+        }
+
+        Ok(Self { prefix })
+    }
+}
+
 pub(super) struct MethodMetadata {
     pub(crate) type_bounds: Vec<(TokenStream, TokenStream)>,
     pub(crate) overload: Option<Expr>,
+    pub(crate) name: Option<LitStr>,
+    pub(crate) sig: Option<LitStr>,
+    pub(crate) nonvirtual: bool,
+    pub(crate) since: Option<i32>,
+    pub(crate) until: Option<i32>,
+    pub(crate) failure: Option<FailurePolicy>,
 }
 
 impl Parse for MethodMetadata {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let attrs = Punctuated::<MetaNameValue, Token![,]>::parse_terminated(input)?;
+        let attrs = Punctuated::<Meta, Token![,]>::parse_terminated(input)?;
         let mut type_bounds = Vec::new();
         let mut overload = None;
+        let mut name = None;
+        let mut sig = None;
+        let mut nonvirtual = false;
+        let mut since = None;
+        let mut until = None;
+        let mut failure = None;
         for item in attrs.iter() {
+            let item = match item {
+                Meta::Path(p) if p.is_ident("nonvirtual") => {
+                    nonvirtual = true;
+                    continue;
+                }
+                Meta::NameValue(item) => item,
+                _ => return Err(Error::new_spanned(item, "Unsupported attribute.")),
+            };
             if item.path.is_ident("type_bound") {
                 match item.value {
-                    Expr::Tuple(ref t) => {
+                    Expr::Tuple(ref t) if t.elems.len() == 2 => {
                         type_bounds.push((
                             t.elems.first().to_token_stream(),
                             t.elems.iter().nth(1).to_token_stream(),
                         ));
                     }
-                    _ => {}
+                    _ => {
+                        return Err(Error::new_spanned(
+                            &item.value,
+                            "Expected a type bound like `(T, Trait)`.",
+                        ));
+                    }
                 }
             } else if item.path.is_ident("overload") {
                 overload = Some(item.value.clone());
+            } else if item.path.is_ident("name") {
+                name = Some(get_lit_str(&item.value)?);
+            } else if item.path.is_ident("sig") {
+                sig = Some(get_lit_str(&item.value)?);
+            } else if item.path.is_ident("since") {
+                since = Some(get_api_level(&item.value)?);
+            } else if item.path.is_ident("until") {
+                until = Some((get_api_level(&item.value)?, &item.value));
+            } else if item.path.is_ident("failure") {
+                failure = Some(FailurePolicy::parse_value(&item.value)?);
+            } else {
+                return Err(Error::new_spanned(item, "Unsupported attribute."));
             }
         }
+        if let (Some(_), Some(name)) = (&overload, &name) {
+            return Err(Error::new_spanned(
+                name,
+                "`name` and `overload` cannot be used together.",
+            ));
+        }
+        if let (Some(since), Some((until, value))) = (since, until)
+            && since > until
+        {
+            return Err(Error::new_spanned(
+                value,
+                "`until` must not be less than `since`.",
+            ));
+        }
         Ok(Self {
             type_bounds,
             overload,
+            name,
+            sig,
+            nonvirtual,
+            since,
+            until: until.map(|(until, _)| until),
+            failure,
         })
     }
 }
 
-fn unwrap_type(ty: &TokenStream) -> TokenStream {
+/// 跳过描述符中的一个字段类型，返回剩余的部分，格式错误时返回None。
+fn skip_field_descriptor(sig: &str) -> Option<&str> {
+    let sig = sig.trim_start_matches('[');
+    match sig.chars().next()? {
+        'B' | 'C' | 'D' | 'F' | 'I' | 'J' | 'S' | 'Z' => Some(&sig[1..]),
+        'L' => {
+            let end = sig.find(';')?;
+            if end < 2 {
+                return None;
+            }
+            Some(&sig[end + 1..])
+        }
+        _ => None,
+    }
+}
+
+/// 检查字段描述符的格式。
+pub(super) fn check_field_descriptor(sig: &LitStr) -> syn::Result<()> {
+    match skip_field_descriptor(&sig.value()) {
+        Some("") => Ok(()),
+        _ => Err(Error::new_spanned(
+            sig,
+            format!("`{}` is not a valid field descriptor.", sig.value()),
+        )),
+    }
+}
+
+/// 检查方法描述符的格式，并确保参数数量与rust函数的参数数量一致。
+pub(super) fn check_method_descriptor(sig: &LitStr, args: usize) -> syn::Result<()> {
+    let value = sig.value();
+    let invalid = || {
+        Error::new_spanned(
+            sig,
+            format!("`{}` is not a valid method descriptor.", value),
+        )
+    };
+    let mut rest = value.strip_prefix('(').ok_or_else(invalid)?;
+    let mut count = 0;
+    while !rest.starts_with(')') {
+        rest = skip_field_descriptor(rest).ok_or_else(invalid)?;
+        count += 1;
+    }
+    let ret = &rest[1..];
+    if ret != "V" && skip_field_descriptor(ret) != Some("") {
+        return Err(invalid());
+    }
+    if count != args {
+        return Err(Error::new_spanned(
+            sig,
+            format!(
+                "The descriptor `{}` has {} argument(s), but the function has {}.",
+                value, count, args
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+pub(super) fn unwrap_type(ty: &TokenStream) -> TokenStream {
     let res = parse2::<PathSegment>(ty.clone());
 
     match res {
         Ok(item) => match item.arguments {
-            PathArguments::AngleBracketed(arg) => arg.args.first().unwrap().to_token_stream(),
+            PathArguments::AngleBracketed(arg) => match arg.args.first() {
+                Some(arg) => arg.to_token_stream(),
+                None => ty.clone(),
+            },
             _ => item.to_token_stream(),
         },
         _ => match parse2::<Type>(ty.clone()) {
@@ -129,30 +740,14 @@ fn unwrap_type(ty: &TokenStream) -> TokenStream {
     }
 }
 
-fn get_type_descriptor_token(
+pub(super) fn get_type_descriptor_token(
     ty: &TokenStream,
     generics: &Generics,
     type_bounds: &Vec<(TokenStream, TokenStream)>,
 ) -> TokenStream {
     let ty_str = ty.to_string();
-    if ty_str == "i8" || ty_str == "u8" {
-        quote! {"B"}
-    } else if ty_str == "char" {
-        quote! {"C"}
-    } else if ty_str == "i16" || ty_str == "u16" {
-        quote! {"S"}
-    } else if ty_str == "i32" || ty_str == "u32" {
-        quote! {"I"}
-    } else if ty_str == "i64" || ty_str == "u64" {
-        quote! {"J"}
-    } else if ty_str == "f32" {
-        quote! {"F"}
-    } else if ty_str == "f64" {
-        quote! {"D"}
-    } else if ty_str == "bool" {
-        quote! {"Z"}
-    } else if ty_str == "()" {
-        quote! {"V"}
+    if let Some(sig) = primitive_descriptor(&ty_str) {
+        quote! {#sig}
     } else {
         let ty = if let Some(gt) = generics
             .type_params()
@@ -169,6 +764,9 @@ fn get_type_descriptor_token(
         } else if !ty_str.starts_with(|c: char| c.is_alphabetic() || c == '&') {
             // 如果不是有效标识符开头的类型（也不是引用类型）则需要使用`<...>`
             quote! {<&#ty>}
+        } else if ty_str == "Self" || parse2::<Ident>(ty.clone()).is_ok() {
+            // 类（或接口的包装类型）同时实现了JType和接口trait时，两者都定义了OBJECT_SIG，需要明确使用JType中的定义。
+            quote! {<#ty as JType>}
         } else {
             ty.clone()
         };
@@ -202,28 +800,54 @@ pub(super) fn parse_function_signature(
             }
             FnArg::Typed(t) => {
                 let origin_ty = t.ty.to_token_stream();
+                let v = t.pat.clone();
+                if let Some(elem_ty) = t
+                    .attrs
+                    .iter()
+                    .any(|i| i.path().is_ident("varargs"))
+                    .then(|| varargs_element_type(&t.ty))
+                    .flatten()
+                {
+                    // 可变参数需要先创建java数组，再作为最后一个参数传递。
+                    let elem_ty = elem_ty.to_token_stream();
+                    let elem_sig = get_type_descriptor_token(&elem_ty, &sig.generics, type_bounds);
+                    let v = match get_primitive_jni_type(&elem_ty.to_string()) {
+                        Some(jni_ty) => quote! {
+                            droid_wrap_utils::to_java_primitive_varargs(
+                                &#v
+                                    .into_iter()
+                                    .map(|i| *std::borrow::Borrow::<#elem_ty>::borrow(&i) as #jni_ty)
+                                    .collect::<Vec<_>>(),
+                            )?
+                            .as_jvalue()
+                        },
+                        None => quote! {
+                            droid_wrap_utils::to_java_object_varargs(
+                                &#v
+                                    .into_iter()
+                                    .map(|i| i.java_ref())
+                                    .collect::<droid_wrap_utils::Result<Vec<_>>>()?,
+                                #elem_sig,
+                            )?
+                            .as_jvalue()
+                        },
+                    };
+                    arg_types.push((quote! {[#elem_ty]}, origin_ty));
+                    arg_types_sig.extend(quote!(&format!("[{}", #elem_sig),));
+                    arg_values.push(Expr::Verbatim(v));
+                    continue;
+                }
+                if let Some((java_ty, v, arg_sig)) =
+                    get_borrowed_arg_token(&t.ty, &v, &sig.generics, type_bounds)
+                {
+                    arg_types.push((java_ty, origin_ty));
+                    arg_types_sig.extend(quote!(#arg_sig,));
+                    arg_values.push(Expr::Verbatim(v));
+                    continue;
+                }
                 let unwrapped_ty = unwrap_type(&origin_ty);
                 let ty_str = unwrapped_ty.to_string();
-                let v = t.pat.clone();
-                let v = if ty_str == "i8" || ty_str == "u8" {
-                    quote! {(#v as droid_wrap_utils::jbyte).into()}
-                } else if ty_str == "char" {
-                    quote! {(#v as droid_wrap_utils::jchar).into()}
-                } else if ty_str == "i16" || ty_str == "u16" {
-                    quote! {(#v as droid_wrap_utils::jshort).into()}
-                } else if ty_str == "i32" || ty_str == "u32" {
-                    quote! {(#v as droid_wrap_utils::jint).into()}
-                } else if ty_str == "i64" || ty_str == "u64" {
-                    quote! {(#v as droid_wrap_utils::jlong).into()}
-                } else if ty_str == "f32" {
-                    quote! {(#v as droid_wrap_utils::jfloat).into()}
-                } else if ty_str == "f64" {
-                    quote! {(#v as droid_wrap_utils::jdouble).into()}
-                } else if ty_str == "bool" {
-                    quote! {(#v as droid_wrap_utils::jboolean).into()}
-                } else {
-                    quote! {#v.java_ref()?.as_obj().into()}
-                };
+                let v = get_arg_value_token(&v.to_token_stream(), &ty_str);
 
                 let arg_sig = get_type_descriptor_token(&unwrapped_ty, &sig.generics, &type_bounds);
                 arg_types.push((unwrapped_ty, origin_ty));
@@ -250,20 +874,299 @@ pub(super) fn parse_function_signature(
     )
 }
 
+/// 需要转换后才能传递给java的参数类型。
+enum BorrowedArg<'a> {
+    /// `&str`、`&String`或`impl AsRef<str>`，作为java字符串传递。
+    Str,
+    /// `impl Into<T>`，转换为`T`后传递。
+    Into(&'a Type),
+    /// `impl Trait`、`&impl Trait`或`&dyn Trait`，其中`Trait`是`java_interface`定义的接口。
+    Interface(&'a Path),
+    /// `Option<&T>`中的`&T`。
+    Ref(&'a Type),
+}
+
+fn get_borrowed_arg(ty: &Type, owned: bool) -> Option<BorrowedArg<'_>> {
+    match ty {
+        Type::Reference(TypeReference { elem, .. }) => match elem.as_ref() {
+            Type::Path(p) if p.path.is_ident("str") || p.path.is_ident("String") => {
+                Some(BorrowedArg::Str)
+            }
+            Type::TraitObject(t) => get_impl_arg(&t.bounds, false),
+            Type::ImplTrait(t) => get_impl_arg(&t.bounds, false),
+            _ => None,
+        },
+        Type::ImplTrait(t) => get_impl_arg(&t.bounds, owned),
+        _ => None,
+    }
+}
+
+fn get_impl_arg(
+    bounds: &Punctuated<TypeParamBound, Token![+]>,
+    owned: bool,
+) -> Option<BorrowedArg<'_>> {
+    let path = bounds.iter().find_map(|i| match i {
+        TypeParamBound::Trait(t) => Some(&t.path),
+        _ => None,
+    })?;
+    let last = path.segments.last()?;
+    let arg = match &last.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match args.args.first() {
+            Some(GenericArgument::Type(ty)) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    };
+    match arg {
+        Some(Type::Path(p)) if last.ident == "AsRef" && p.path.is_ident("str") => {
+            Some(BorrowedArg::Str)
+        }
+        // `Into`需要获取参数的所有权，不能用于引用。
+        Some(ty) if last.ident == "Into" && owned => Some(BorrowedArg::Into(ty)),
+        _ => Some(BorrowedArg::Interface(path)),
+    }
+}
+
+/// 生成借用或泛型参数（`&str`、`impl AsRef<str>`、`Option<&T>`、`&dyn Trait`、`impl Into<T>`等）的java类型、参数值和类型描述符，
+/// 其他参数返回`None`。
+fn get_borrowed_arg_token(
+    ty: &Type,
+    v: &Pat,
+    generics: &Generics,
+    type_bounds: &Vec<(TokenStream, TokenStream)>,
+) -> Option<(TokenStream, TokenStream, TokenStream)> {
+    let option_inner = match ty {
+        Type::Path(p) => match p.path.segments.last() {
+            Some(last) if last.ident == "Option" => match &last.arguments {
+                PathArguments::AngleBracketed(args) => match args.args.first() {
+                    Some(GenericArgument::Type(ty)) => Some(ty),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    };
+    let (kind, optional) = match option_inner {
+        Some(inner) => {
+            let kind = match (get_borrowed_arg(inner, false), inner) {
+                (Some(kind), _) => kind,
+                (None, Type::Reference(r)) => BorrowedArg::Ref(&r.elem),
+                _ => return None,
+            };
+            (kind, true)
+        }
+        None => (get_borrowed_arg(ty, true)?, false),
+    };
+
+    let (java_ty, arg_sig) = match kind {
+        BorrowedArg::Str => {
+            let java_ty = quote! {String};
+            let arg_sig = get_type_descriptor_token(&java_ty, generics, type_bounds);
+            (java_ty, arg_sig)
+        }
+        BorrowedArg::Into(ty) | BorrowedArg::Ref(ty) => {
+            let java_ty = ty.to_token_stream();
+            let arg_sig = get_type_descriptor_token(&java_ty, generics, type_bounds);
+            (java_ty, arg_sig)
+        }
+        BorrowedArg::Interface(path) => {
+            // 匿名的参数类型无法直接访问，通过泛型函数推导出具体的类型后获取接口的描述符。
+            let mut param_ty = ty.clone();
+            InferTypeVisitor.visit_type_mut(&mut param_ty);
+            let arg_sig = quote! {
+                &{
+                    fn object_sig<T: #path>(_: &#param_ty) -> String {
+                        "[".repeat(<T as #path>::DIM as _) + <T as #path>::OBJECT_SIG
+                    }
+                    object_sig(&#v)
+                }
+            };
+            (path.to_token_stream(), arg_sig)
+        }
+    };
+
+    let value = if optional {
+        let some = match kind {
+            BorrowedArg::Str => {
+                quote! {droid_wrap_utils::JArgValue::string(AsRef::<str>::as_ref(i))?}
+            }
+            _ => quote! {i.java_value()?},
+        };
+        quote! {
+            match &#v {
+                Some(i) => #some,
+                None => droid_wrap_utils::JArgValue::null()?,
+            }
+            .as_jvalue()
+        }
+    } else {
+        match kind {
+            BorrowedArg::Str => quote! {
+                droid_wrap_utils::JArgValue::string(AsRef::<str>::as_ref(&#v))?.as_jvalue()
+            },
+            BorrowedArg::Into(ty) => {
+                match get_primitive_jni_type(&ty.to_token_stream().to_string()) {
+                    Some(jni_ty) => quote! {(Into::<#ty>::into(#v) as #jni_ty).into()},
+                    None => quote! {Into::<#ty>::into(#v).java_value()?.as_jvalue()},
+                }
+            }
+            _ => quote! {#v.java_value()?.as_jvalue()},
+        }
+    };
+
+    Some((java_ty, value, arg_sig))
+}
+
+/// 把参数类型中的`impl Trait`和`dyn Trait`替换为泛型参数`T`，并移除引用的生命周期，用于推导参数的具体类型。
+struct InferTypeVisitor;
+
+impl VisitMut for InferTypeVisitor {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Type::ImplTrait(_) | Type::TraitObject(_) = ty {
+            *ty = parse_quote!(T);
+            return;
+        }
+        visit_mut::visit_type_mut(self, ty);
+    }
+
+    fn visit_type_reference_mut(&mut self, r: &mut TypeReference) {
+        r.lifetime = None;
+        visit_mut::visit_type_reference_mut(self, r);
+    }
+}
+
+/// 把参数中的`&dyn Trait`替换为`&impl Trait`，`java_interface`定义的接口包含关联常量，不能作为trait对象使用。
+pub(super) fn dyn_to_impl(sig: &mut Signature) {
+    struct DynVisitor;
+
+    impl VisitMut for DynVisitor {
+        fn visit_type_mut(&mut self, ty: &mut Type) {
+            if let Type::TraitObject(t) = ty {
+                let bounds = &t.bounds;
+                *ty = parse_quote!(impl #bounds);
+                return;
+            }
+            visit_mut::visit_type_mut(self, ty);
+        }
+    }
+
+    for arg in sig.inputs.iter_mut() {
+        if let FnArg::Typed(t) = arg {
+            DynVisitor.visit_type_mut(&mut t.ty);
+        }
+    }
+}
+
+/// 生成传递给java的值，基本类型转换为对应的jni类型，其他类型使用`java_value`。
+pub(super) fn get_arg_value_token(v: &TokenStream, ty_str: &str) -> TokenStream {
+    match get_primitive_jni_type(ty_str) {
+        Some(jni_ty) => quote! {(#v as #jni_ty).into()},
+        None => quote! {#v.java_value()?.as_jvalue()},
+    }
+}
+
+/// 获取rust基本类型对应的jni类型，不是基本类型时返回`None`。
+fn get_primitive_jni_type(ty_str: &str) -> Option<TokenStream> {
+    Some(match ty_str {
+        "i8" | "u8" => quote! {droid_wrap_utils::jbyte},
+        "char" => quote! {droid_wrap_utils::jchar},
+        "i16" | "u16" => quote! {droid_wrap_utils::jshort},
+        "i32" | "u32" => quote! {droid_wrap_utils::jint},
+        "i64" | "u64" => quote! {droid_wrap_utils::jlong},
+        "f32" => quote! {droid_wrap_utils::jfloat},
+        "f64" => quote! {droid_wrap_utils::jdouble},
+        "bool" => quote! {droid_wrap_utils::jboolean},
+        _ => return None,
+    })
+}
+
+/// 获取可变参数的元素类型，支持`&[T]`、`Vec<T>`和`impl IntoIterator<Item = T>`（`T`也可以是引用）。
+fn varargs_element_type(ty: &Type) -> Option<&Type> {
+    let elem = match ty {
+        Type::Reference(TypeReference { elem, .. }) => match elem.as_ref() {
+            Type::Slice(slice) => slice.elem.as_ref(),
+            _ => return None,
+        },
+        Type::Path(path) => {
+            let last = path.path.segments.last()?;
+            if last.ident != "Vec" {
+                return None;
+            }
+            match &last.arguments {
+                PathArguments::AngleBracketed(args) => match args.args.first()? {
+                    GenericArgument::Type(ty) => ty,
+                    _ => return None,
+                },
+                _ => return None,
+            }
+        }
+        Type::ImplTrait(it) => it.bounds.iter().find_map(|b| {
+            let TypeParamBound::Trait(b) = b else {
+                return None;
+            };
+            let last = b.path.segments.last()?;
+            if last.ident != "IntoIterator" {
+                return None;
+            }
+            let PathArguments::AngleBracketed(args) = &last.arguments else {
+                return None;
+            };
+            args.args.iter().find_map(|a| match a {
+                GenericArgument::AssocType(a) if a.ident == "Item" => Some(&a.ty),
+                _ => None,
+            })
+        })?,
+        _ => return None,
+    };
+    match elem {
+        Type::Reference(TypeReference { elem, .. }) => Some(elem.as_ref()),
+        _ => Some(elem),
+    }
+}
+
+/// 检查并移除函数参数上的`#[varargs]`属性，只有最后一个参数可以标记为可变参数。
+pub(super) fn take_varargs(sig: &mut Signature) -> syn::Result<()> {
+    let len = sig.inputs.len();
+    for (index, arg) in sig.inputs.iter_mut().enumerate() {
+        let FnArg::Typed(t) = arg else {
+            continue;
+        };
+        let Some(pos) = t.attrs.iter().position(|i| i.path().is_ident("varargs")) else {
+            continue;
+        };
+        let attr = t.attrs.remove(pos);
+        if index + 1 != len {
+            return Err(Error::new_spanned(
+                attr,
+                "`#[varargs]` can only be used on the last argument.",
+            ));
+        }
+        if varargs_element_type(&t.ty).is_none() {
+            return Err(Error::new_spanned(
+                &t.ty,
+                "`#[varargs]` requires a `&[T]`, `Vec<T>` or `impl IntoIterator<Item = T>` argument.",
+            ));
+        }
+    }
+    Ok(())
+}
+
 pub(super) fn get_return_value_token(
     ret_type: &TokenStream,
     generics: &Generics,
     type_bounds: &Vec<(TokenStream, TokenStream)>,
-) -> (TokenStream, TokenStream) {
+) -> syn::Result<(TokenStream, TokenStream)> {
     let unwrapped_ty = unwrap_type(ret_type);
     let ret_type_sig = get_type_descriptor_token(&unwrapped_ty, generics, &type_bounds);
     if ret_type_sig.to_string().contains("OBJECT_SIG") {
-        return (
+        return Ok((
             quote! {
-                #unwrapped_ty::_new(env.new_global_ref(ret.l()?)?.as_ref(), Default::default())?
+                #unwrapped_ty::_from_value(&mut env, ret)?
             },
             ret_type_sig,
-        );
+        ));
     }
 
     let unwrapped_ty_str = unwrapped_ty.to_string();
@@ -286,7 +1189,10 @@ pub(super) fn get_return_value_token(
             } else if unwrapped_ty_str.ends_with("64") {
                 quote! { i64 }
             } else {
-                panic!("Unsupported return value type {unwrapped_ty_str}.");
+                return Err(Error::new_spanned(
+                    ret_type,
+                    format!("Unsupported return value type `{unwrapped_ty_str}`."),
+                ));
             };
 
             quote! {
@@ -298,7 +1204,77 @@ pub(super) fn get_return_value_token(
         }
     };
 
-    (opt, ret_type_sig)
+    Ok((opt, ret_type_sig))
+}
+
+/// 获取java对象类型`ty`的默认值（通常是java的null），即`JObjNew::_fallback`，没有默认值的类型（例如`java_enum`）在编译时报错。
+pub(super) fn get_fallback_token(ty: &TokenStream, ident: &Ident) -> TokenStream {
+    let ty_str = ty.to_string();
+    let msg = format!(
+        "`{ident}` returns a type without a default value, please return `Result<{ty_str}>` or `Option<{ty_str}>`, or specify `failure = \"panic\"`."
+    );
+    quote! {{
+        const { assert!(<#ty as JObjNew>::HAS_FALLBACK, #msg) };
+        <#ty as JObjNew>::_fallback()
+    }}
+}
+
+/// 获取严格模式的检查语句。使用宏的crate设置了`droid_wrap_failure_strict` cfg时（通常由构建脚本输出`cargo::rustc-cfg=droid_wrap_failure_strict`），
+/// 返回值不是`Option`或`Result`的方法必须明确指定失败策略。cfg在使用宏的crate中求值，因此只影响设置了它的crate，
+/// 没有设置时也不会产生`unexpected_cfgs`警告。
+pub(super) fn get_strict_check(
+    ty: &TokenStream,
+    failure: Option<FailurePolicy>,
+    ident: &Ident,
+) -> TokenStream {
+    let ty_str = ty.to_string();
+    if failure.is_some() || ty_str.starts_with("Option") || ty_str.starts_with("Result") {
+        return TokenStream::new();
+    }
+    let msg = format!(
+        "`{ident}` can't report failures in strict builds, please return `Result<{ty_str}>` or `Option<{ty_str}>`, or specify `failure = \"...\"`."
+    );
+    quote_spanned! {ident.span()=>
+        #[allow(unexpected_cfgs)]
+        const _: () = {
+            #[cfg(droid_wrap_failure_strict)]
+            compile_error!(#msg);
+        };
+    }
+}
+
+/// 获取返回值的处理形式，与`get_type_form`相同，但返回值不是`Option`或`Result`时使用失败策略处理错误。
+/// `failure`是属性中明确指定的策略，没有指定时使用crate的feature选择的策略，严格模式的检查见`get_strict_check`。
+pub(super) fn get_ret_form(
+    ty: &TokenStream,
+    failure: Option<FailurePolicy>,
+    ident: &Ident,
+) -> syn::Result<TokenStream> {
+    let ty_str = ty.to_string();
+    if ty_str.starts_with("Option") || ty_str.starts_with("Result") {
+        return Ok(get_type_form(ty, &None));
+    }
+    let fallback = if get_primitive_jni_type(&ty_str).is_some() || ty_str == "()" {
+        quote! {Default::default()}
+    } else {
+        get_fallback_token(ty, ident)
+    };
+    let name = ident.to_string();
+    Ok(match failure.unwrap_or_else(FailurePolicy::from_features) {
+        FailurePolicy::Panic => quote! {.unwrap()},
+        FailurePolicy::Default => quote! {
+            .unwrap_or_else(|e| {
+                droid_wrap_utils::log_failure(#name, e);
+                #fallback
+            })
+        },
+        FailurePolicy::LastError => quote! {
+            .unwrap_or_else(|e| {
+                droid_wrap_utils::set_last_error(e);
+                #fallback
+            })
+        },
+    })
 }
 
 pub(super) fn get_type_form(ty: &TokenStream, default_value: &Option<Expr>) -> TokenStream {
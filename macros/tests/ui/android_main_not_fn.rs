@@ -0,0 +1,6 @@
+use droid_wrap::*;
+
+#[android_main]
+struct App;
+
+fn main() {}
@@ -0,0 +1,19 @@
+use droid_wrap::*;
+
+#[derive(JavaBean)]
+#[java(class = "android/graphics/Point")]
+pub struct PointTuple(i32, i32);
+
+#[derive(JavaBean)]
+pub struct MissingClass {
+    x: i32,
+}
+
+#[derive(JavaBean)]
+#[java(class = "android/graphics/Rect")]
+pub struct Conflict {
+    #[java(name = "left", getter = "getLeft")]
+    left: i32,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use droid_wrap::*;
+
+#[java_class(name = "java/lang/Thread", implements = [Comparable<'static>])]
+pub struct Thread;
+
+fn main() {}
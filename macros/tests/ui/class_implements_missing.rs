@@ -0,0 +1,10 @@
+use droid_wrap::*;
+
+pub trait Plain {
+    fn f(&self);
+}
+
+#[java_class(name = "java/lang/Thread", implements = [Plain])]
+pub struct Thread;
+
+fn main() {}
@@ -0,0 +1,6 @@
+use droid_wrap::*;
+
+#[java_class(extends = Object)]
+pub struct System;
+
+fn main() {}
@@ -0,0 +1,15 @@
+use droid_wrap::*;
+
+#[java_constants(prefix = "EFFECT_")]
+pub enum Effect {
+    Click = 0,
+    DoubleClick,
+}
+
+#[java_constants]
+pub enum Status {
+    Success = 0,
+    Unknown = 1,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use droid_wrap::*;
+
+#[java_class(name = "java/lang/Object")]
+pub struct Object;
+
+impl Object {
+    #[java_constructor(name = "create")]
+    pub fn new() -> Self {}
+}
+
+fn main() {}
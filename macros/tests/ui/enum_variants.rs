@@ -0,0 +1,20 @@
+use droid_wrap::*;
+
+#[java_enum(name = "java/lang/Thread$State")]
+pub enum ThreadState {
+    New = 0,
+    Runnable,
+}
+
+#[java_enum(name = "android/graphics/Paint$Style")]
+pub enum PaintStyle {
+    Fill(i32),
+    Stroke,
+}
+
+#[java_enum]
+pub enum TimeUnit {
+    Seconds,
+}
+
+fn main() {}
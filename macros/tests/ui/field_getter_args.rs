@@ -0,0 +1,11 @@
+use droid_wrap::*;
+
+#[java_class(name = "android/graphics/Point")]
+pub struct Point;
+
+impl Point {
+    #[java_field]
+    pub fn get_x(&self, y: i32) -> i32 {}
+}
+
+fn main() {}
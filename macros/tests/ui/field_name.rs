@@ -0,0 +1,11 @@
+use droid_wrap::*;
+
+#[java_class(name = "android/graphics/Point")]
+pub struct Point;
+
+impl Point {
+    #[java_field]
+    pub fn x(&self) -> i32 {}
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use droid_wrap::*;
+
+#[java_class(name = "android/graphics/Point")]
+pub struct Point;
+
+impl Point {
+    #[java_field]
+    pub fn set_x(&self, x: i32, y: i32) {}
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use droid_wrap::*;
+
+#[java_flags]
+pub enum Flags {
+    FLAG_A = 0x1,
+    FLAG_B,
+}
+
+#[java_flags(prefix = "FLAG_")]
+pub enum Options {
+    FLAG_C = 0x2,
+}
+
+fn main() {}
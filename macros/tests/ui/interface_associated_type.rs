@@ -0,0 +1,10 @@
+use droid_wrap::*;
+
+#[java_interface(name = "java/util/function/Supplier", builder)]
+pub trait Supplier {
+    type T: JObjNew;
+
+    fn get(&self) -> Self::T;
+}
+
+fn main() {}
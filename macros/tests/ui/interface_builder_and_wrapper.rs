@@ -0,0 +1,8 @@
+use droid_wrap::*;
+
+#[java_interface(name = "java/lang/Runnable", builder, wrapper)]
+pub trait Runnable {
+    fn run(&self);
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use droid_wrap::*;
+
+#[java_interface(name = "java/util/function/Supplier", builder)]
+pub trait Supplier {
+    fn get(&self) -> Option<String>;
+}
+
+#[java_interface(name = "java/util/concurrent/Callable", builder)]
+pub trait Callable {
+    fn call(&self) -> Result<String>;
+}
+
+fn main() {
+    let _: () = SupplierImpl::builder();
+    let _: () = CallableImpl::builder();
+}
@@ -0,0 +1,8 @@
+use droid_wrap::*;
+
+#[java_interface(builder)]
+pub trait Runnable {
+    fn run(&self);
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+mod unknown {
+    use droid_wrap::*;
+
+    #[android_main(backend = "glutin")]
+    fn main() {}
+}
+
+mod too_many_args {
+    use droid_wrap::*;
+
+    #[android_main]
+    fn main(_a: i32, _b: i32) {}
+}
+
+mod ndk_glue_with_app {
+    use droid_wrap::*;
+
+    #[android_main(backend = "ndk_glue")]
+    fn main(_a: i32) {}
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+mod level {
+    use droid_wrap::*;
+
+    #[android_main(log_level = "verbose")]
+    fn main() {}
+}
+
+mod panic {
+    use droid_wrap::*;
+
+    #[android_main(panic = "ignore")]
+    fn main() {}
+}
+
+mod unknown {
+    use droid_wrap::*;
+
+    #[android_main(exit)]
+    fn main() {}
+}
+
+fn main() {}
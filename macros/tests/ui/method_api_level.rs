@@ -0,0 +1,20 @@
+use droid_wrap::*;
+
+#[java_class(name = "java/lang/System")]
+pub struct System;
+
+impl System {
+    #[java_method(until = 30, since = 31)]
+    pub fn nano_time() -> i64 {}
+
+    #[java_method(since = 0)]
+    pub fn current_time_millis() -> i64 {}
+
+    #[java_method(since = 31)]
+    pub fn line_separator() -> Result<String> {}
+
+    #[java_method(since = 31)]
+    pub fn identity_hash_code(x: &Self) -> i32 {}
+}
+
+fn main() {}
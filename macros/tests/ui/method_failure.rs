@@ -0,0 +1,32 @@
+use droid_wrap::*;
+
+#[java_class(name = "java/lang/System")]
+pub struct System;
+
+impl System {
+    #[java_method(failure = "ignore")]
+    pub fn current_time_millis() -> i64 {}
+}
+
+#[failure_policy(default)]
+impl System {
+    #[java_method]
+    pub fn nano_time() -> i64 {}
+}
+
+#[failure_policy("default")]
+impl System {
+    #[java_method]
+    pub fn gc() {}
+
+    #[java_method(failure = "panic")]
+    pub fn identity_hash_code(x: &Object) -> i32 {}
+
+    #[java_field(failure = "last_error")]
+    pub fn get_out() -> Object {}
+
+    #[java_method()]
+    pub fn line_separator() -> String {}
+}
+
+fn main() {}
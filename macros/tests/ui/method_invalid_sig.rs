@@ -0,0 +1,11 @@
+use droid_wrap::*;
+
+#[java_class(name = "java/lang/System")]
+pub struct System;
+
+impl System {
+    #[java_method(sig = "(I")]
+    pub fn identity_hash_code(x: i32) -> i32 {}
+}
+
+fn main() {}
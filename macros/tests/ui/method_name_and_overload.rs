@@ -0,0 +1,11 @@
+use droid_wrap::*;
+
+#[java_class(name = "java/lang/System")]
+pub struct System;
+
+impl System {
+    #[java_method(overload = nanoTime, name = "nanoTime")]
+    pub fn nano_time2() -> i64 {}
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+use droid_wrap::*;
+
+#[java_class(name = "java/lang/Object")]
+pub struct Object;
+
+#[java_class(name = "java/lang/Thread", extends = Object)]
+pub struct Thread;
+
+impl Thread {
+    #[java_method(nonvirtual)]
+    pub fn hash_code(&self) -> Result<i32> {}
+}
+
+#[java_class(name = "java/lang/Runtime")]
+pub struct Runtime;
+
+impl Runtime {
+    #[java_method(nonvirtual)]
+    pub fn hash_code(&self) -> Result<i32> {}
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use droid_wrap::*;
+
+#[java_class(name = "java/lang/System")]
+pub struct System;
+
+impl System {
+    #[java_method(nonvirtual)]
+    pub fn current_time_millis() -> i64 {}
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use droid_wrap::*;
+
+#[java_class(name = "java/lang/System")]
+pub struct System;
+
+impl System {
+    #[java_method(sig = "()J")]
+    pub fn current_time_millis(x: i32) -> i64 {}
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use droid_wrap::*;
+
+#[java_class(name = "java/lang/System")]
+pub struct System;
+
+impl System {
+    #[java_method]
+    pub fn nano_time() -> u8 {}
+}
+
+fn main() {}
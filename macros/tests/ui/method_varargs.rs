@@ -0,0 +1,14 @@
+use droid_wrap::*;
+
+#[java_class(name = "android/text/TextUtils")]
+pub struct TextUtils;
+
+impl TextUtils {
+    #[java_method]
+    pub fn concat(#[varargs] text: &[String], count: i32) -> String {}
+
+    #[java_method]
+    pub fn join(delimiter: String, #[varargs] tokens: String) -> String {}
+}
+
+fn main() {}
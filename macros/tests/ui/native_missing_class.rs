@@ -0,0 +1,23 @@
+use droid_wrap::*;
+
+#[java_native]
+fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[java_native(class = "com/example/NativeLib", instance)]
+fn length() -> i32 {
+    0
+}
+
+#[java_native(class = "com/example/Plugin", loader = droid_wrap_utils::app_class_loader, unknown)]
+fn version() -> i32 {
+    1
+}
+
+#[java_native(class = "com/example/Plugin", loader = droid_wrap_utils::app_class_loader)]
+fn name() -> String {
+    String::new()
+}
+
+fn main() {}
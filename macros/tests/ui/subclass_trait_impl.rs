@@ -0,0 +1,16 @@
+use droid_wrap::*;
+
+#[java_class(name = "rust/droid/Task", extends = Object)]
+pub struct Task;
+
+#[java_class(name = "java/lang/Object")]
+pub struct Object;
+
+#[java_subclass]
+impl Default for Task {
+    fn default() -> Self {
+        unimplemented!()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+use droid_wrap::*;
+
+#[java_class(name = "java/lang/Runtime", extend = Object)]
+pub struct Runtime;
+
+#[java_class(name = "java/lang/System")]
+pub struct System;
+
+impl System {
+    #[java_method(overlaod = current_time)]
+    pub fn current_time_millis() -> i64 {}
+
+    #[java_field(default = 0)]
+    pub fn get_out() -> i64 {}
+
+    #[java_constructor(signature = "()V")]
+    pub fn new() -> Self {}
+}
+
+#[java_interface(name = "java/lang/Runnable", builde)]
+pub trait Runnable {
+    fn run(&self);
+}
+
+fn main() {}
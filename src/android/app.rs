@@ -13,8 +13,8 @@
 
 use crate::{
     JObjNew, JObjRef, JType, Result,
-    android::view::{ContextThemeWrapper, View, Window, WindowManager},
-    java::lang::{CharSequence, Runnable},
+    android::view::{ContextThemeWrapper, View, Window, WindowManagerImpl},
+    java::lang::{CharSequence, CharSequenceImpl, Runnable},
     java_class, java_method, vm_attach,
 };
 use droid_wrap_utils::android_context;
@@ -167,7 +167,7 @@ impl Activity {
     获取与此活动关联的标题。
     */
     #[java_method]
-    pub fn get_title<CS: CharSequence>(&self) -> CS {}
+    pub fn get_title(&self) -> CharSequenceImpl {}
 
     /**
     将活动内容设置为显式视图。此视图直接放入活动的视图层次结构中。它本身可以是一个复杂的视图层次结构。调用此方法时，将忽略指定视图的布局参数。视图的宽度和高度默认设置为 ViewGroup.LayoutParams.MATCH_PARENT。要使用您自己的布局参数，请改为调用 setContentView(View, ViewGroup.LayoutParams)。
@@ -185,7 +185,7 @@ impl Activity {
 
     /// 查询用于显示自定义窗口的窗口管理器。
     #[java_method]
-    pub fn get_window_manager<WM: WindowManager>(&self) -> WM {}
+    pub fn get_window_manager(&self) -> WindowManagerImpl {}
 
     /**
     获取实例。
@@ -245,28 +245,28 @@ impl Activity {
     `permission` 您的应用想要请求的权限。
     */
     #[java_method]
-    pub fn should_show_request_permission_rationale(&self, permission: String) -> bool {}
+    pub fn should_show_request_permission_rationale(&self, permission: &str) -> bool {}
 }
 
 /// 测试android.app
 #[cfg(feature = "test_android_app")]
 pub fn test() {
     use crate::{
-        android::{view::WindowManagerImpl, widget::EditText},
-        java::lang::{CharSequenceExt, CharSequenceImpl, RunnableImpl},
+        android::widget::EditText,
+        java::lang::{CharSequenceExt, RunnableImpl},
     };
     let act = std::sync::Arc::new(Activity::fetch().unwrap());
     assert!(act.to_string().starts_with("android.app.NativeActivity"));
-    let cs = "我的应用".to_char_sequence::<CharSequenceImpl>().unwrap();
+    let cs = "我的应用".to_char_sequence().unwrap();
     act.set_title(&cs);
     assert_eq!(cs, act.get_title());
     assert_eq!(false, act.is_finishing());
     let edit = EditText::new(&act);
     let act2 = act.clone();
-    let runnable = RunnableImpl::from_fn(move || {
-        act2.set_content_view(&edit);
-        Ok(())
-    }).unwrap();
+    let runnable = RunnableImpl::builder()
+        .run(move || act2.set_content_view(&edit))
+        .build()
+        .unwrap();
     act.run_on_ui_thread(runnable.as_ref());
     let wm: WindowManagerImpl = act.get_window_manager();
     assert!(wm.to_string().starts_with("android.view.WindowManagerImpl"));
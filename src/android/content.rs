@@ -22,7 +22,7 @@ use crate::{
         io::{File, Serializable},
         lang::{CharSequence, ClassLoader, Comparable, Object},
     },
-    java_class, java_constructor, java_interface, java_method,
+    java_class, java_constructor, java_flags, java_interface, java_method,
 };
 
 /**
@@ -853,9 +853,9 @@ impl Context {
 
     /**
     归因可用于复杂的应用中，以在逻辑上区分应用的各个部分。例如，博客应用可能还内置有即时通讯应用。在这种情况下，每个子功能可以使用两个单独的标签。
-    返回：此上下文所针对的归因标签，如果这是默认标签，则返回 null。
+    返回：此上下文所针对的归因标签，如果这是默认标签或者API级别低于30，则返回 null。
     */
-    #[java_method]
+    #[java_method(since = 30)]
     pub fn get_attribution_tag(&self) -> Option<String> {}
 
     /**
@@ -935,13 +935,13 @@ impl Context {
     `name` 所需服务的名称。
     */
     #[java_method]
-    pub fn get_system_service(&self, name: String) -> Option<Object> {}
+    pub fn get_system_service(&self, name: &str) -> Option<Object> {}
 
     //noinspection SpellCheckingInspection
     #[doc(hidden)]
     #[deprecated(note = "使用 getSharedPreferencesPath(String)")]
     #[java_method]
-    pub fn get_shared_prefs_file(&self, name: String) -> File {}
+    pub fn get_shared_prefs_file(&self, name: &str) -> File {}
 
     /**
     将现有的共享首选项文件从给定的源存储上下文移动到此上下文。这通常用于升级后在存储位置之间迁移数据，例如移至设备保护的存储。
@@ -950,7 +950,7 @@ impl Context {
     `name` 共享首选项文件的名称。
     */
     #[java_method]
-    pub fn move_shared_preferences_from(&self, source_context: &Self, name: String) -> bool {}
+    pub fn move_shared_preferences_from(&self, source_context: &Self, name: &str) -> bool {}
 
     /**
     删除现有的共享首选项文件。
@@ -958,7 +958,7 @@ impl Context {
     `name` 共享首选项文件的名称（在应用程序包中是唯一的）。
     */
     #[java_method]
-    pub fn delete_shared_preferences(&self, name: String) -> bool {}
+    pub fn delete_shared_preferences(&self, name: &str) -> bool {}
 
     /**
     删除与此 Context 的应用程序包关联的给定私有文件。
@@ -966,7 +966,7 @@ impl Context {
     `name` 要删除的文件的名称；不能包含路径分隔符。
     */
     #[java_method]
-    pub fn delete_file(&self, name: String) -> bool {}
+    pub fn delete_file(&self, name: &str) -> bool {}
 
     /**
     返回文件系统中存储使用 openFileOutput 创建的文件的绝对路径。如果调用应用程序移动到采用的存储设备，则返回的路径可能会随时间而变化，因此只应保留相对路径。
@@ -974,7 +974,7 @@ impl Context {
     `name` 您想要获取其路径的文件的名称。
     */
     #[java_method]
-    pub fn get_file_stream_path(&self, name: String) -> File {}
+    pub fn get_file_stream_path(&self, name: &str) -> File {}
 
     /**
     返回文件系统中存储使用 getSharedPreferences(String, int) 创建的文件的绝对路径。如果调用应用程序移动到采用的存储设备，则返回的路径可能会随时间而变化，因此只应保留相对路径。
@@ -982,7 +982,7 @@ impl Context {
     `name` 您想要获取其路径的共享首选项的名称。
     */
     #[java_method]
-    pub fn get_shared_preferences_path(&self, name: String) -> File {}
+    pub fn get_shared_preferences_path(&self, name: &str) -> File {}
 
     /**
     返回文件系统中存储此应用所有私有文件的目录的绝对路径。应用不应直接使用此路径；而应使用 getFilesDir()、getCacheDir()、getDir(String, int) 或此类上的其他存储 API。
@@ -1005,7 +1005,7 @@ impl Context {
     `crate_id` getDataDir()/ crates 下的经过验证的相对文件名
     */
     #[java_method]
-    pub fn get_crate_dir(&self, crate_id: String) -> File {}
+    pub fn get_crate_dir(&self, crate_id: &str) -> File {}
 
     /**
     返回文件系统上目录的绝对路径，类似于 getFilesDir()。不同之处在于，放置在此目录下的文件将被排除在自动备份到远程存储之外。
@@ -1035,7 +1035,7 @@ impl Context {
     Environment.DIRECTORY_MUSIC、Environment.DIRECTORY_PODCASTS、Environment.DIRECTORY_RINGTONES、Environment.DIRECTORY_ALARMS、Environment.DIRECTORY_NOTIFICATIONS、Environment.DIRECTORY_PICTURES 或 Environment.DIRECTORY_MOVIES。
     */
     #[java_method]
-    pub fn get_external_files_dir(&self, r#type: Option<String>) -> Option<File> {}
+    pub fn get_external_files_dir(&self, r#type: Option<&str>) -> Option<File> {}
 
     /**
     返回可找到此应用程序的 OBB 文件（如果有）的主要共享/外部存储目录。请注意，如果应用程序没有任何 OBB 文件，则此目录可能不存在。
@@ -1113,7 +1113,7 @@ impl Context {
     `mode` 操作模式。
     */
     #[java_method]
-    pub fn get_dir(&self, name: String, mode: i32) -> File {}
+    pub fn get_dir(&self, name: &str, mode: i32) -> File {}
 
     /**
     将现有数据库文件从给定的源存储上下文移动到此上下文。这通常用于在升级后在存储位置之间迁移数据，例如迁移到受设备保护的存储。移动数据库之前必须关闭数据库。
@@ -1122,7 +1122,7 @@ impl Context {
     `name` 数据库文件的名称。
     */
     #[java_method]
-    pub fn move_database_from(&self, source_context: &Self, name: String) -> bool {}
+    pub fn move_database_from(&self, source_context: &Self, name: &str) -> bool {}
 
     /**
     返回文件系统上存储使用 openOrCreateDatabase 创建的数据库的绝对路径。如果调用应用程序移动到采用的存储设备，则返回的路径可能会随时间而变化，因此只应保留相对路径。
@@ -1130,7 +1130,7 @@ impl Context {
     `name` 您想要获取其路径的数据库的名称。
     */
     #[java_method]
-    pub fn get_database_path(&self, name: String) -> File {}
+    pub fn get_database_path(&self, name: &str) -> File {}
 
     /**
     请求启动给定的应用服务。Intent 应包含要启动的特定服务实现的完整类名，或要定位的特定包名。
@@ -1161,7 +1161,7 @@ impl Context {
     `permission` 正在检查的权限的名称。
     */
     #[java_method]
-    pub fn check_self_permission(&self, permission: String) -> i32 {}
+    pub fn check_self_permission(&self, permission: &str) -> i32 {}
 
     /**
     确定您正在处理的 IPC 的调用进程是否已被授予特定权限。这与使用 android.os.Binder.getCallingPid 和 android.os.Binder.getCallingUid 返回的 pid 和 uid 调用 checkPermission(String, int, int) 基本相同。
@@ -1170,7 +1170,7 @@ impl Context {
     `permission` 正在检查的权限的名称。
     */
     #[java_method]
-    pub fn check_calling_permission(&self, permission: String) -> i32 {}
+    pub fn check_calling_permission(&self, permission: &str) -> i32 {}
 }
 
 /**
@@ -1179,39 +1179,197 @@ Context 的代理实现，它只是将其所有调用委托给另一个 Context
 #[java_class(name = "android/content/ContextWrapper", extends = Context)]
 pub struct ContextWrapper;
 
-impl ContextWrapper {
+//noinspection SpellCheckingInspection
+/// 可以通过 Intent 的 set_flags 和 add_flags 使用的标志。
+#[java_flags]
+pub enum IntentFlags {
+    /// 如果设置，则此 Intent 的接收者将被授予对 Intent 数据中的 URI 以及其 ClipData 中指定的任何 URI 执行读取操作的权限。当应用于 Intent 的 ClipData 时，将授予所有 URI 以及对 Intent 项目中的数据或其他 ClipData 的递归遍历；仅使用顶级 Intent 的授权标志。
+    FLAG_GRANT_READ_URI_PERMISSION = 0x00000001,
+
+    /// 如果设置，则此 Intent 的接收者将被授予对 Intent 数据中的 URI 以及其 ClipData 中指定的任何 URI 执行写入操作的权限。当应用于 Intent 的 ClipData 时，将授予所有 URI 以及对 Intent 项目中的数据或其他 ClipData 的递归遍历；仅使用顶级 Intent 的授予标志。
+    FLAG_GRANT_WRITE_URI_PERMISSION = 0x00000002,
+
+    /// 可以由调用者设置，以表明此 Intent 来自后台操作，而不是来自直接用户交互。
+    FLAG_FROM_BACKGROUND = 0x00000004,
+
+    /// 您可以启用一个标志来进行调试：设置后，在解决此意图期间将打印日志消息，以向您显示已找到的内容以创建最终地解析列表。
+    FLAG_DEBUG_LOG_RESOLUTION = 0x00000008,
+
+    /// 如果设置，此意图将不会匹配当前已停止的软件包中的任何组件。如果未设置，则默认行为是将此类应用程序包含在结果中。
+    FLAG_EXCLUDE_STOPPED_PACKAGES = 0x00000010,
+
+    /// 如果设置了该 Intent，则该 Intent 将始终匹配当前已停止的软件包中的任何组件。这是未设置 FLAG_EXCLUDE_STOPPED_PACKAGES 时的默认行为。如果同时设置了这两个标志，则该标志优先（它允许在框架可能自动设置排除标志的地方覆盖排除）。
+    FLAG_INCLUDE_STOPPED_PACKAGES = 0x00000020,
+
+    /// 与 FLAG_GRANT_READ_URI_PERMISSION 和/或 FLAG_GRANT_WRITE_URI_PERMISSION 结合使用时，URI 权限授予可在设备重启后继续保留，直到使用 Context#revokeUriPermission(Uri, int) 明确撤销。此标志仅提供可能保留的授予；接收应用程序必须调用 ContentResolver#takePersistableUriPermission(Uri, int) 才能真正保留。
+    FLAG_GRANT_PERSISTABLE_URI_PERMISSION = 0x00000040,
+
+    /// 当与 FLAG_GRANT_READ_URI_PERMISSION 和/或 FLAG_GRANT_WRITE_URI_PERMISSION 结合使用时，URI 权限授予适用于任何与原始授予 URI 前缀匹配的 URI。（如果没有此标记，URI 必须完全匹配才能授予访问权限。）仅当方案、权限和前缀定义的所有路径段完全匹配时，另一个 URI 才被视为前缀匹配。
+    FLAG_GRANT_PREFIX_URI_PERMISSION = 0x00000080,
+
+    /// 用于根据直接启动感知和当前用户状态自动匹配意图的标志。由于默认行为是自动应用当前用户状态，因此这实际上是一个哨兵值，不会根据其存在与否更改任何查询的输出。相反，此值可以与 android.os.StrictMode.VmPolicy.Builder#detectImplicitDirectBoot() 结合使用，以检测调用者何时依赖隐式自动匹配，而不是确认他们想要的显式行为。
+    FLAG_DIRECT_BOOT_AUTO = 0x00000100,
+
+    #[doc(hidden)]
+    FLAG_DEBUG_TRIAGED_MISSING = 0x00000100,
+
+    /// 在解析意图时，不应考虑用于指示短暂应用程序的内部标志。
+    FLAG_IGNORE_EPHEMERAL = 0x80000000,
+
     /**
-    返回可用于查询此包中的类的类加载器。
+    如果设置，新活动将不会保留在历史堆栈中。一旦用户离开，活动就会结束。也可以使用 android.R.styleable#AndroidManifestActivity_noHistory noHistory 属性进行设置。
+    如果设置，则当当前活动启动一个设置结果并完成的新活动时，永远不会调用 android.app.Activity#onActivityResult onActivityResult()。
     */
-    pub fn get_class_loader(&self) -> ClassLoader {
-        self._based.get_class_loader()
-    }
+    FLAG_ACTIVITY_NO_HISTORY = 0x40000000,
+
+    /// 如果设置，则如果活动已在历史堆栈顶部运行，则不会启动该活动。有关更多信息，请参阅任务和返回堆栈。
+    FLAG_ACTIVITY_SINGLE_TOP = 0x20000000,
 
     /**
-    将给定的意图广播给所有感兴趣的 BroadcastReceiver。此调用是异步的；它会立即返回，您将在接收器运行时继续执行。接收器不会传播任何结果，接收器也无法中止广播。如果您想允许接收器传播结果或中止广播，则必须使用 sendOrderedBroadcast(Intent, String) 发送有序广播。有关 Intent 广播的更多信息，请参阅 BroadcastReceiver。
-    `intent` 要广播的 Intent；与此 Intent 匹配的所有接收器都将收到广播。
+    如果设置，此活动将成为此历史堆栈上新任务的开始。任务（从启动它的活动到下一个任务活动）定义了用户可以移动到的原子活动组。任务可以移动到前台和后台；特定任务内的所有活动始终保持相同的顺序。有关任务的更多信息，请参阅任务和返回堆栈。
+    此标志通常由想要呈现“启动器”样式行为的活动使用：它们为用户提供可以执行的单独操作的列表，这些操作否则将完全独立于启动它们的活动运行。
+    使用此标志时，如果一个任务已经为您现在启动的活动运行，则不会启动新的活动。取而代之的是，当前的任务将简单地带到屏幕的前面。
+    当调用者正在请求正在启动的活动的结果时不能使用此标志。
     */
-    pub fn send_broadcast(&self, intent: &Intent) {
-        self._based.send_broadcast(intent)
-    }
+    FLAG_ACTIVITY_NEW_TASK = 0x10000000,
 
-    #[doc(hidden)]
-    pub fn start_service(&self, service: &Intent) -> Result<ComponentName> {
-        self._based.start_service(service)
-    }
+    /**
+    此标志用于创建新任务并在其中启动活动。此标志始终与 FLAG_ACTIVITY_NEW_DOCUMENT 或 FLAG_ACTIVITY_NEW_TASK 配对使用。在这两种情况下，仅使用这些标志即可在现有任务中搜索与此 Intent 匹配的任务。只有未找到此类任务时才会创建新任务。当与 FLAG_ACTIVITY_MULTIPLE_TASK 配对使用时，这两种行为都会被修改为跳过搜索匹配任务并无条件启动新任务。
+    与 FLAG_ACTIVITY_NEW_TASK 一起使用时，除非您正在实现自己的顶级应用程序启动器，否则请勿使用此标志。与 FLAG_ACTIVITY_NEW_TASK 一起使用可禁用将现有任务带到前台的行为。设置后，无论是否已有现有任务正在运行相同的任务，都会启动一个新任务来托管 Intent 的 Activity。
+    由于默认系统不包含图形任务管理，因此您不应使用此标志，除非您为用户提供某种方式返回到您已启动的任务。
+    有关此标志用于创建新文档任务的详细信息，请参阅 FLAG_ACTIVITY_NEW_DOCUMENT。
+    如果 FLAG_ACTIVITY_NEW_TASK 或 FLAG_ACTIVITY_NEW_DOCUMENT 之一未设置，则忽略此标志。
+    有关任务的更多信息，请参阅任务和返回堆栈。
+    */
+    FLAG_ACTIVITY_MULTIPLE_TASK = 0x08000000,
 
-    #[doc(hidden)]
-    pub fn check_self_permission(&self, permission: String) -> i32 {
-        self._based.check_self_permission(permission)
-    }
+    /**
+    如果设置了，并且正在启动的活动已经在当前任务中运行，那么将不会启动该活动的新实例，而是关闭其上的所有其他活动，并且此 Intent 将作为新 Intent 传递给（现在位于顶部的）旧活动。
+    例如，考虑一个由活动 A、B、C、D 组成的任务。如果 D 调用 startActivity() 并把 Intent 解析为活动 B 的组件，那么 C 和 D 将完成，并且 B 会收到给定的 Intent，导致堆栈现在为：A、B。
+    上例中，当前正在运行的 Activity B 实例要么在其 onNewIntent() 方法中接收您在此处启动的新 Intent，要么自行完成并重新启动新 Intent。如果它已将其启动模式声明为“多个”（默认），并且您未在同一 Intent 中设置 FLAG_ACTIVITY_SINGLE_TOP，则它将完成并重新创建；对于所有其他启动模式，或者如果设置了 FLAG_ACTIVITY_SINGLE_TOP，则此 Intent 将传递到当前实例的 onNewIntent()。
+    此启动模式还可与 FLAG_ACTIVITY_NEW_TASK 配合使用，效果良好：如果用于启动任务的根 Activity，它会将该任务的任何当前正在运行的实例带到前台，然后将其清除为其根状态。这尤其有用，例如，当从通知管理器启动 Activity 时。
+    有关任务的更多信息，请参阅任务和返回堆栈。
+    */
+    FLAG_ACTIVITY_CLEAR_TOP = 0x04000000,
+
+    /// 如果已设置，并且此意图用于从现有活动启动新活动，则现有活动的回复目标将转移到新活动。这样，新活动可以调用 android.app.Activity#setResult 并将该结果发送回原始活动的回复目标。
+    FLAG_ACTIVITY_FORWARD_RESULT = 0x02000000,
+
+    /// 如果已设置，并且此意图用于从现有活动启动新活动，则当前活动将不被视为顶部活动，用于决定是否应将新意图传递到顶部活动而不是启动新活动。前一个活动将用作顶部活动，假设当前活动将立即完成。
+    FLAG_ACTIVITY_PREVIOUS_IS_TOP = 0x01000000,
+
+    /// 如果设置，新活动将不会保存在最近启动的活动列表中。
+    FLAG_ACTIVITY_EXCLUDE_FROM_RECENTS = 0x00800000,
+
+    /// 此标志通常不是按应用程序代码设置的，而是按系统为您设置的，如android.R.styleable#AndroidManifestActivity_launchMode启动模式文档。
+    FLAG_ACTIVITY_BROUGHT_TO_FRONT = 0x00400000,
+
+    /// 如果已设置，并且此活动要么在新任务中启动，要么置于现有任务的顶部，则它将作为任务的前门启动。这将导致应用使该任务处于正确状态所需的任何亲和性（将活动移入或移出），或者在需要时简单地将该任务重置为其初始状态。
+    FLAG_ACTIVITY_RESET_TASK_IF_NEEDED = 0x00200000,
+
+    /// 此标志通常不由应用程序代码设置，但如果此活动是从历史记录中启动的，则由系统为您设置。
+    FLAG_ACTIVITY_LAUNCHED_FROM_HISTORY = 0x00100000,
 
     #[doc(hidden)]
-    pub fn check_calling_permission(&self, permission: String) -> i32 {
-        self._based.check_calling_permission(permission)
-    }
+    FLAG_ACTIVITY_CLEAR_WHEN_TASK_RESET = 0x00080000,
+
+    /**
+    此标志用于将文档打开到以此 Intent 启动的活动为基础的新任务中。通过使用此标志或其等效属性，android.R.attr#documentLaunchMode 包含不同文档的同一活动的多个实例将出现在最近任务列表中。
+    与此处描述的 Intent 标志相比，最好使用活动属性形式 android.R.attr#documentLaunchMode。属性形式允许 Activity 为 Activity 的所有启动器指定多个文档行为，而使用此标志则要求启动 Activity 的每个 Intent 都指定它。
+    请注意，此标志的默认语义（即活动结束后是否保留其最近条目）与 FLAG_ACTIVITY_NEW_TASK 和 android.R.attr#documentLaunchMode 的使用不同 - 如果使用此标志创建新的最近条目，则默认情况下，活动结束后将删除该条目。您可以使用 FLAG_ACTIVITY_RETAIN_IN_RECENTS 修改此行为。
+    FLAG_ACTIVITY_NEW_DOCUMENT 可与 FLAG_ACTIVITY_MULTIPLE_TASK 结合使用。单独使用时，它相当于 Activity 清单指定 android.R.attr#documentLaunchMode="intoExisting"。与 FLAG_ACTIVITY_MULTIPLE_TASK 结合使用时，它相当于 Activity 清单指定 android.R.attr#documentLaunchMode="always"。当 Activity 清单指定 android.R.attr#documentLaunchMode="never" 时，即使与 FLAG_ACTIVITY_MULTIPLE_TASK 结合使用，也会忽略该标志。
+    有关更多信息，请参阅 android.R.attr#documentLaunchMode。
+    */
+    FLAG_ACTIVITY_NEW_DOCUMENT = 0x00080000,
+
+    /**
+    如果设置了此标志，则在新启动的活动被置于最前面时，将阻止正常的 android.app.Activity#onUserLeaveHint 回调在当前最前面的活动暂停之前发生在该活动上。
+    通常，Activity 可以依赖该回调来指示明确的用户操作已导致其 Activity 移出前台。回调标记了 Activity 生命周期中的适当点，以便其关闭打算显示的任何通知“直到用户看到它们”，例如闪烁的 LED。
+    如果活动是通过任何非用户驱动的事件（例如电话接听或警报处理程序）启动的，则应将此标志传递给 Context#startActivity Context.startActivity，确保暂停活动不会认为用户已确认其通知。
+    */
+    FLAG_ACTIVITY_NO_USER_ACTION = 0x00040000,
+
+    /**
+    如果在传递给 Context#startActivity Context.startActivity() 的 Intent 中设置，此标志将导致启动的活动被带到其任务历史堆栈的前面（如果它已经在运行）。
+    例如，考虑一个由四个活动组成的任务：A、B、C、D。如果 D 使用解析为活动 B 的组件的 Intent 调用 startActivity()，则 B 将被带到历史堆栈的前面，结果顺序为：A、C、D、B。
+    如果还指定了 FLAG_ACTIVITY_CLEAR_TOP，则此标志将被忽略。
+    */
+    FLAG_ACTIVITY_REORDER_TO_FRONT = 0x00020000,
+
+    /// 如果在传递给 Context#startActivity Context.startActivity() 的 Intent 中设置了此标志，则系统不会应用活动 过渡动画 来进入下一个活动状态。这并不意味着动画永远不会运行 - 如果在显示此处启动的活动之前发生了另一个未指定此标志的活动更改，则将使用该过渡。当您要执行一系列活动操作但用户看到的动画不应由第一个活动更改驱动而应由后续活动更改驱动时，可以很好地使用此标志。
+    FLAG_ACTIVITY_NO_ANIMATION = 0x00010000,
+
+    /// 如果在传递给 Context#startActivity Context.startActivity() 的 Intent 中设置此标志，则此标志将导致在启动活动之前清除与活动相关联的任何现有任务。也就是说，活动将成为原本为空的任务的新根，并且所有旧活动都将完成。这只能与 FLAG_ACTIVITY_NEW_TASK 结合使用。
+    FLAG_ACTIVITY_CLEAR_TASK = 0x00008000,
+
+    /// 如果在传递给 Context#startActivity Context.startActivity() 的 Intent 中设置此标志，则会导致将新启动的任务置于当前主页活动任务（如果有）之上。也就是说，从任务中按“返回”按钮将始终让用户返回主页，即使这不是他们看到的最后一个活动。这只能与 FLAG_ACTIVITY_NEW_TASK 结合使用。
+    FLAG_ACTIVITY_TASK_ON_HOME = 0x00004000,
+
+    /// 默认情况下，当用户关闭由 FLAG_ACTIVITY_NEW_DOCUMENT 创建的文档时（使用 back 或其他任何方法 finish()），其在最近任务中的条目将被删除。如果您希望允许文档保留在最近任务中以便重新启动，则可以使用此标志。设置后，任务的活动已完成，最近任务条目将保留在界面中，以便用户重新启动它，就像顶级应用程序的最近任务条目一样。接收活动可以使用 android.R.attr#autoRemoveFromRecents 或通过明确调用 android.app.Activity#finishAndRemoveTask() Activity.finishAndRemoveTask() 来覆盖此请求。
+    FLAG_ACTIVITY_RETAIN_IN_RECENTS = 0x00002000,
+
+    /// 此标志仅用于分屏多窗口模式。新活动将显示在启动它的活动旁边。这只能与 FLAG_ACTIVITY_NEW_TASK 结合使用。此外，如果您想要创建现有活动的新实例，则需要设置 FLAG_ACTIVITY_MULTIPLE_TASK。
+    FLAG_ACTIVITY_LAUNCH_ADJACENT = 0x00001000,
+
+    /// 如果在传递给 Context#startActivity Context.startActivity() 的 Intent 中设置了此标志，则如果设备上没有完整的应用可以处理此意图，则此标志将尝试启动免安装应用。尝试从外部解析免安装应用时，支持以下 Intent 属性：Intent#setAction(String) Intent#addCategory(String) Intent#setData(Uri) Intent#setType(String) Intent#setPackage(String) Intent#addFlags(int) 如果找不到免安装应用，则将启动安装程序以通知用户无法解析该意图。在不支持免安装应用的设备上，将忽略此标志。
+    FLAG_ACTIVITY_MATCH_EXTERNAL = 0x00000800,
+
+    /// 如果在传递给 Context#startActivity Context.startActivity() 的 Intent 中设置此标志，则仅当该 Intent 解析为非浏览器结果时，才会启动该 Intent。如果不存在这样的结果，则会抛出 ActivityNotFoundException。
+    FLAG_ACTIVITY_REQUIRE_NON_BROWSER = 0x00000400,
+
+    /// 如果在传递给 Context#startActivity Context.startActivity() 的 Intent 中设置此标志，则仅当该 Intent 解析为单个结果时才会启动该 Intent。如果不存在这样的结果或系统选择器会以其他方式显示，则会抛出 ActivityNotFoundException。
+    FLAG_ACTIVITY_REQUIRE_DEFAULT = 0x00000200,
+
+    /// 如果设置，则发送广播时只会调用已注册的接收器——不会启动任何 BroadcastReceiver 组件。
+    FLAG_RECEIVER_REGISTERED_ONLY = 0x40000000,
+
+    /**
+    如果设置，则在发送广播时，新广播将替换与其匹配的任何现有待处理广播。匹配由 Intent#filterEquals(Intent) 定义，Intent.filterEquals 为两个广播的意图返回 true。找到匹配项后，新广播（及其关联的接收器）将替换待处理广播列表中的现有广播，并保持在列表中的相同位置。
+    此标志通常用于粘性广播，粘性广播仅关心将广播的最新值传递给接收者。
+    */
+    FLAG_RECEIVER_REPLACE_PENDING = 0x20000000,
+
+    /// 如果设置，则在发送广播时，允许接收者以前台优先级运行，超时间隔更短。在正常广播期间，接收者不会自动脱离后台优先级类别。
+    FLAG_RECEIVER_FOREGROUND = 0x10000000,
+
+    /// 如果设置，则在发送广播时，接收者将在卸载队列上运行。
+    FLAG_RECEIVER_OFFLOAD = 0x80000000,
+
+    /// 如果设置，当发送广播时，接收者将在系统专用队列上运行。
+    FLAG_RECEIVER_OFFLOAD_FOREGROUND = 0x00000800,
+
+    /// 如果这是有序广播，则不允许接收者中止广播。它们仍然可以将结果传播给后面的接收者，但不能阻止后面的接收者看到广播。
+    FLAG_RECEIVER_NO_ABORT = 0x08000000,
+
+    /**
+    如果设置，则在系统完全启动之前发送广播时（甚至在发送 ACTION_LOCKED_BOOT_COMPLETED 之前）只会调用已注册的接收器 - 不会启动任何 BroadcastReceiver 组件。即使没有接收器被调用，粘性意图状态也会被正确记录。如果在广播意图中指定了 FLAG_RECEIVER_REGISTERED_ONLY，则此标志是不必要的。
+    此标志仅供系统服务（甚至主线模块的服务）使用，以避免必须在检测启动完成方面实现更复杂的机制。
+    这对系统服务器主线模块很有用
+    */
+    FLAG_RECEIVER_REGISTERED_ONLY_BEFORE_BOOT = 0x04000000,
+
+    /// 当此广播用于启动升级时设置，这是一种特殊模式，允许在系统准备就绪之前发送广播并启动没有运行任何提供程序的应用程序进程。
+    FLAG_RECEIVER_BOOT_UPGRADE = 0x02000000,
+
+    /**
+    如果设置，广播将始终发送到后台（缓存或未运行）应用中的清单接收器，无论默认情况下是否这样做。默认情况下，它们仅在广播指定了显式组件或软件包名称时才会接收广播。
+    注意：dumpstate 以数字方式使用此标志，因此当其值改变时，那里的广播代码也必须改变以匹配。
+    */
+    FLAG_RECEIVER_INCLUDE_BACKGROUND = 0x01000000,
+
+    /// 如果设置，则无论是否默认执行此操作，广播都不会发送到后台（缓存或未运行）应用中的清单接收器。默认情况下，如果广播指定了明确的组件或软件包名称，它们将接收广播。
+    FLAG_RECEIVER_EXCLUDE_BACKGROUND = 0x00800000,
+
+    /// 如果设置，则此广播将从 shell 发送。
+    FLAG_RECEIVER_FROM_SHELL = 0x00400000,
+
+    /**
+    如果设置，广播将对 Instant Apps 中的接收者可见。默认情况下，Instant Apps 不会接收广播。
+    当 Instant App 使用时，此标志无效。
+    */
+    FLAG_RECEIVER_VISIBLE_TO_INSTANT_APPS = 0x00200000,
 }
 
-//noinspection SpellCheckingInspection
 /**
 意图是要执行的操作的抽象描述。它可以与 startActivity 一起使用来启动活动，与 broadcastIntent 一起使用来将其发送到任何感兴趣的 BroadcastReceiver 组件，以及与 Context.startService 或 Context.bindService 一起使用来与后台 android.app.Service 通信。
 意图提供了一种在不同应用程序的代码之间执行后期运行时绑定的功能。它最重要的用途是在启动活动时，可以将其视为活动之间的粘合剂。它基本上是一个被动数据结构，包含要执行的操作的抽象描述。
@@ -3947,49 +4105,55 @@ impl Intent {
         "android.intent.extra.VISIBILITY_ALLOW_LIST";
 
     /// 如果设置，则此 Intent 的接收者将被授予对 Intent 数据中的 URI 以及其 ClipData 中指定的任何 URI 执行读取操作的权限。当应用于 Intent 的 ClipData 时，将授予所有 URI 以及对 Intent 项目中的数据或其他 ClipData 的递归遍历；仅使用顶级 Intent 的授权标志。
-    pub const FLAG_GRANT_READ_URI_PERMISSION: u32 = 0x00000001;
+    pub const FLAG_GRANT_READ_URI_PERMISSION: IntentFlags =
+        IntentFlags::FLAG_GRANT_READ_URI_PERMISSION;
 
     /// 如果设置，则此 Intent 的接收者将被授予对 Intent 数据中的 URI 以及其 ClipData 中指定的任何 URI 执行写入操作的权限。当应用于 Intent 的 ClipData 时，将授予所有 URI 以及对 Intent 项目中的数据或其他 ClipData 的递归遍历；仅使用顶级 Intent 的授予标志。
-    pub const FLAG_GRANT_WRITE_URI_PERMISSION: u32 = 0x00000002;
+    pub const FLAG_GRANT_WRITE_URI_PERMISSION: IntentFlags =
+        IntentFlags::FLAG_GRANT_WRITE_URI_PERMISSION;
 
     /// 可以由调用者设置，以表明此 Intent 来自后台操作，而不是来自直接用户交互。
-    pub const FLAG_FROM_BACKGROUND: u32 = 0x00000004;
+    pub const FLAG_FROM_BACKGROUND: IntentFlags = IntentFlags::FLAG_FROM_BACKGROUND;
 
     /// 您可以启用一个标志来进行调试：设置后，在解决此意图期间将打印日志消息，以向您显示已找到的内容以创建最终地解析列表。
-    pub const FLAG_DEBUG_LOG_RESOLUTION: u32 = 0x00000008;
+    pub const FLAG_DEBUG_LOG_RESOLUTION: IntentFlags = IntentFlags::FLAG_DEBUG_LOG_RESOLUTION;
 
     /// 如果设置，此意图将不会匹配当前已停止的软件包中的任何组件。如果未设置，则默认行为是将此类应用程序包含在结果中。
-    pub const FLAG_EXCLUDE_STOPPED_PACKAGES: u32 = 0x00000010;
+    pub const FLAG_EXCLUDE_STOPPED_PACKAGES: IntentFlags =
+        IntentFlags::FLAG_EXCLUDE_STOPPED_PACKAGES;
 
     /// 如果设置了该 Intent，则该 Intent 将始终匹配当前已停止的软件包中的任何组件。这是未设置 FLAG_EXCLUDE_STOPPED_PACKAGES 时的默认行为。如果同时设置了这两个标志，则该标志优先（它允许在框架可能自动设置排除标志的地方覆盖排除）。
-    pub const FLAG_INCLUDE_STOPPED_PACKAGES: u32 = 0x00000020;
+    pub const FLAG_INCLUDE_STOPPED_PACKAGES: IntentFlags =
+        IntentFlags::FLAG_INCLUDE_STOPPED_PACKAGES;
 
     //noinspection SpellCheckingInspection
     /// 与 FLAG_GRANT_READ_URI_PERMISSION 和/或 FLAG_GRANT_WRITE_URI_PERMISSION 结合使用时，URI 权限授予可在设备重启后继续保留，直到使用 Context#revokeUriPermission(Uri, int) 明确撤销。此标志仅提供可能保留的授予；接收应用程序必须调用 ContentResolver#takePersistableUriPermission(Uri, int) 才能真正保留。
-    pub const FLAG_GRANT_PERSISTABLE_URI_PERMISSION: u32 = 0x00000040;
+    pub const FLAG_GRANT_PERSISTABLE_URI_PERMISSION: IntentFlags =
+        IntentFlags::FLAG_GRANT_PERSISTABLE_URI_PERMISSION;
 
     /// 当与 FLAG_GRANT_READ_URI_PERMISSION 和/或 FLAG_GRANT_WRITE_URI_PERMISSION 结合使用时，URI 权限授予适用于任何与原始授予 URI 前缀匹配的 URI。（如果没有此标记，URI 必须完全匹配才能授予访问权限。）仅当方案、权限和前缀定义的所有路径段完全匹配时，另一个 URI 才被视为前缀匹配。
-    pub const FLAG_GRANT_PREFIX_URI_PERMISSION: u32 = 0x00000080;
+    pub const FLAG_GRANT_PREFIX_URI_PERMISSION: IntentFlags =
+        IntentFlags::FLAG_GRANT_PREFIX_URI_PERMISSION;
 
     /// 用于根据直接启动感知和当前用户状态自动匹配意图的标志。由于默认行为是自动应用当前用户状态，因此这实际上是一个哨兵值，不会根据其存在与否更改任何查询的输出。相反，此值可以与 android.os.StrictMode.VmPolicy.Builder#detectImplicitDirectBoot() 结合使用，以检测调用者何时依赖隐式自动匹配，而不是确认他们想要的显式行为。
-    pub const FLAG_DIRECT_BOOT_AUTO: u32 = 0x00000100;
+    pub const FLAG_DIRECT_BOOT_AUTO: IntentFlags = IntentFlags::FLAG_DIRECT_BOOT_AUTO;
 
     #[doc(hidden)]
     #[deprecated]
-    pub const FLAG_DEBUG_TRIAGED_MISSING: u32 = Self::FLAG_DIRECT_BOOT_AUTO;
+    pub const FLAG_DEBUG_TRIAGED_MISSING: IntentFlags = IntentFlags::FLAG_DEBUG_TRIAGED_MISSING;
 
     /// 在解析意图时，不应考虑用于指示短暂应用程序的内部标志。
-    pub const FLAG_IGNORE_EPHEMERAL: u32 = 0x80000000;
+    pub const FLAG_IGNORE_EPHEMERAL: IntentFlags = IntentFlags::FLAG_IGNORE_EPHEMERAL;
 
     //noinspection SpellCheckingInspection
     /**
     如果设置，新活动将不会保留在历史堆栈中。一旦用户离开，活动就会结束。也可以使用 android.R.styleable#AndroidManifestActivity_noHistory noHistory 属性进行设置。
     如果设置，则当当前活动启动一个设置结果并完成的新活动时，永远不会调用 android.app.Activity#onActivityResult onActivityResult()。
     */
-    pub const FLAG_ACTIVITY_NO_HISTORY: u32 = 0x40000000;
+    pub const FLAG_ACTIVITY_NO_HISTORY: IntentFlags = IntentFlags::FLAG_ACTIVITY_NO_HISTORY;
 
     /// 如果设置，则如果活动已在历史堆栈顶部运行，则不会启动该活动。有关更多信息，请参阅任务和返回堆栈。
-    pub const FLAG_ACTIVITY_SINGLE_TOP: u32 = 0x20000000;
+    pub const FLAG_ACTIVITY_SINGLE_TOP: IntentFlags = IntentFlags::FLAG_ACTIVITY_SINGLE_TOP;
 
     /**
     如果设置，此活动将成为此历史堆栈上新任务的开始。任务（从启动它的活动到下一个任务活动）定义了用户可以移动到的原子活动组。任务可以移动到前台和后台；特定任务内的所有活动始终保持相同的顺序。有关任务的更多信息，请参阅任务和返回堆栈。
@@ -3997,7 +4161,7 @@ impl Intent {
     使用此标志时，如果一个任务已经为您现在启动的活动运行，则不会启动新的活动。取而代之的是，当前的任务将简单地带到屏幕的前面。
     当调用者正在请求正在启动的活动的结果时不能使用此标志。
     */
-    pub const FLAG_ACTIVITY_NEW_TASK: u32 = 0x10000000;
+    pub const FLAG_ACTIVITY_NEW_TASK: IntentFlags = IntentFlags::FLAG_ACTIVITY_NEW_TASK;
 
     /**
     此标志用于创建新任务并在其中启动活动。此标志始终与 FLAG_ACTIVITY_NEW_DOCUMENT 或 FLAG_ACTIVITY_NEW_TASK 配对使用。在这两种情况下，仅使用这些标志即可在现有任务中搜索与此 Intent 匹配的任务。只有未找到此类任务时才会创建新任务。当与 FLAG_ACTIVITY_MULTIPLE_TASK 配对使用时，这两种行为都会被修改为跳过搜索匹配任务并无条件启动新任务。
@@ -4007,7 +4171,7 @@ impl Intent {
     如果 FLAG_ACTIVITY_NEW_TASK 或 FLAG_ACTIVITY_NEW_DOCUMENT 之一未设置，则忽略此标志。
     有关任务的更多信息，请参阅任务和返回堆栈。
     */
-    pub const FLAG_ACTIVITY_MULTIPLE_TASK: u32 = 0x08000000;
+    pub const FLAG_ACTIVITY_MULTIPLE_TASK: IntentFlags = IntentFlags::FLAG_ACTIVITY_MULTIPLE_TASK;
 
     /**
     如果设置了，并且正在启动的活动已经在当前任务中运行，那么将不会启动该活动的新实例，而是关闭其上的所有其他活动，并且此 Intent 将作为新 Intent 传递给（现在位于顶部的）旧活动。
@@ -4016,33 +4180,39 @@ impl Intent {
     此启动模式还可与 FLAG_ACTIVITY_NEW_TASK 配合使用，效果良好：如果用于启动任务的根 Activity，它会将该任务的任何当前正在运行的实例带到前台，然后将其清除为其根状态。这尤其有用，例如，当从通知管理器启动 Activity 时。
     有关任务的更多信息，请参阅任务和返回堆栈。
     */
-    pub const FLAG_ACTIVITY_CLEAR_TOP: u32 = 0x04000000;
+    pub const FLAG_ACTIVITY_CLEAR_TOP: IntentFlags = IntentFlags::FLAG_ACTIVITY_CLEAR_TOP;
 
     /// 如果已设置，并且此意图用于从现有活动启动新活动，则现有活动的回复目标将转移到新活动。这样，新活动可以调用 android.app.Activity#setResult 并将该结果发送回原始活动的回复目标。
-    pub const FLAG_ACTIVITY_FORWARD_RESULT: u32 = 0x02000000;
+    pub const FLAG_ACTIVITY_FORWARD_RESULT: IntentFlags = IntentFlags::FLAG_ACTIVITY_FORWARD_RESULT;
 
     /// 如果已设置，并且此意图用于从现有活动启动新活动，则当前活动将不被视为顶部活动，用于决定是否应将新意图传递到顶部活动而不是启动新活动。前一个活动将用作顶部活动，假设当前活动将立即完成。
-    pub const FLAG_ACTIVITY_PREVIOUS_IS_TOP: u32 = 0x01000000;
+    pub const FLAG_ACTIVITY_PREVIOUS_IS_TOP: IntentFlags =
+        IntentFlags::FLAG_ACTIVITY_PREVIOUS_IS_TOP;
 
     //noinspection SpellCheckingInspection
     /// 如果设置，新活动将不会保存在最近启动的活动列表中。
-    pub const FLAG_ACTIVITY_EXCLUDE_FROM_RECENTS: u32 = 0x00800000;
+    pub const FLAG_ACTIVITY_EXCLUDE_FROM_RECENTS: IntentFlags =
+        IntentFlags::FLAG_ACTIVITY_EXCLUDE_FROM_RECENTS;
 
     //noinspection SpellCheckingInspection
     /// 此标志通常不是按应用程序代码设置的，而是按系统为您设置的，如android.R.styleable#AndroidManifestActivity_launchMode启动模式文档。
-    pub const FLAG_ACTIVITY_BROUGHT_TO_FRONT: u32 = 0x00400000;
+    pub const FLAG_ACTIVITY_BROUGHT_TO_FRONT: IntentFlags =
+        IntentFlags::FLAG_ACTIVITY_BROUGHT_TO_FRONT;
 
     /// 如果已设置，并且此活动要么在新任务中启动，要么置于现有任务的顶部，则它将作为任务的前门启动。这将导致应用使该任务处于正确状态所需的任何亲和性（将活动移入或移出），或者在需要时简单地将该任务重置为其初始状态。
-    pub const FLAG_ACTIVITY_RESET_TASK_IF_NEEDED: u32 = 0x00200000;
+    pub const FLAG_ACTIVITY_RESET_TASK_IF_NEEDED: IntentFlags =
+        IntentFlags::FLAG_ACTIVITY_RESET_TASK_IF_NEEDED;
 
     /// 此标志通常不由应用程序代码设置，但如果此活动是从历史记录中启动的，则由系统为您设置。
-    pub const FLAG_ACTIVITY_LAUNCHED_FROM_HISTORY: u32 = 0x00100000;
+    pub const FLAG_ACTIVITY_LAUNCHED_FROM_HISTORY: IntentFlags =
+        IntentFlags::FLAG_ACTIVITY_LAUNCHED_FROM_HISTORY;
 
     #[doc(hidden)]
     #[deprecated(
         note = "从 API 21 开始，它的执行方式与 FLAG_ACTIVITY_NEW_DOCUMENT 相同，应使用 FLAG_ACTIVITY_NEW_DOCUMENT 来代替它。"
     )]
-    pub const FLAG_ACTIVITY_CLEAR_WHEN_TASK_RESET: u32 = 0x00080000;
+    pub const FLAG_ACTIVITY_CLEAR_WHEN_TASK_RESET: IntentFlags =
+        IntentFlags::FLAG_ACTIVITY_CLEAR_WHEN_TASK_RESET;
 
     //noinspection SpellCheckingInspection
     /**
@@ -4053,104 +4223,116 @@ impl Intent {
     有关更多信息，请参阅 android.R.attr#documentLaunchMode。
     */
     #[allow(deprecated)]
-    pub const FLAG_ACTIVITY_NEW_DOCUMENT: u32 = Self::FLAG_ACTIVITY_CLEAR_WHEN_TASK_RESET;
+    pub const FLAG_ACTIVITY_NEW_DOCUMENT: IntentFlags = IntentFlags::FLAG_ACTIVITY_NEW_DOCUMENT;
 
     /**
     如果设置了此标志，则在新启动的活动被置于最前面时，将阻止正常的 android.app.Activity#onUserLeaveHint 回调在当前最前面的活动暂停之前发生在该活动上。
     通常，Activity 可以依赖该回调来指示明确的用户操作已导致其 Activity 移出前台。回调标记了 Activity 生命周期中的适当点，以便其关闭打算显示的任何通知“直到用户看到它们”，例如闪烁的 LED。
     如果活动是通过任何非用户驱动的事件（例如电话接听或警报处理程序）启动的，则应将此标志传递给 Context#startActivity Context.startActivity，确保暂停活动不会认为用户已确认其通知。
     */
-    pub const FLAG_ACTIVITY_NO_USER_ACTION: u32 = 0x00040000;
+    pub const FLAG_ACTIVITY_NO_USER_ACTION: IntentFlags = IntentFlags::FLAG_ACTIVITY_NO_USER_ACTION;
 
     /**
     如果在传递给 Context#startActivity Context.startActivity() 的 Intent 中设置，此标志将导致启动的活动被带到其任务历史堆栈的前面（如果它已经在运行）。
     例如，考虑一个由四个活动组成的任务：A、B、C、D。如果 D 使用解析为活动 B 的组件的 Intent 调用 startActivity()，则 B 将被带到历史堆栈的前面，结果顺序为：A、C、D、B。
     如果还指定了 FLAG_ACTIVITY_CLEAR_TOP，则此标志将被忽略。
     */
-    pub const FLAG_ACTIVITY_REORDER_TO_FRONT: u32 = 0x00020000;
+    pub const FLAG_ACTIVITY_REORDER_TO_FRONT: IntentFlags =
+        IntentFlags::FLAG_ACTIVITY_REORDER_TO_FRONT;
 
     /// 如果在传递给 Context#startActivity Context.startActivity() 的 Intent 中设置了此标志，则系统不会应用活动 过渡动画 来进入下一个活动状态。这并不意味着动画永远不会运行 - 如果在显示此处启动的活动之前发生了另一个未指定此标志的活动更改，则将使用该过渡。当您要执行一系列活动操作但用户看到的动画不应由第一个活动更改驱动而应由后续活动更改驱动时，可以很好地使用此标志。
-    pub const FLAG_ACTIVITY_NO_ANIMATION: u32 = 0x00010000;
+    pub const FLAG_ACTIVITY_NO_ANIMATION: IntentFlags = IntentFlags::FLAG_ACTIVITY_NO_ANIMATION;
 
     /// 如果在传递给 Context#startActivity Context.startActivity() 的 Intent 中设置此标志，则此标志将导致在启动活动之前清除与活动相关联的任何现有任务。也就是说，活动将成为原本为空的任务的新根，并且所有旧活动都将完成。这只能与 FLAG_ACTIVITY_NEW_TASK 结合使用。
-    pub const FLAG_ACTIVITY_CLEAR_TASK: u32 = 0x00008000;
+    pub const FLAG_ACTIVITY_CLEAR_TASK: IntentFlags = IntentFlags::FLAG_ACTIVITY_CLEAR_TASK;
 
     /// 如果在传递给 Context#startActivity Context.startActivity() 的 Intent 中设置此标志，则会导致将新启动的任务置于当前主页活动任务（如果有）之上。也就是说，从任务中按“返回”按钮将始终让用户返回主页，即使这不是他们看到的最后一个活动。这只能与 FLAG_ACTIVITY_NEW_TASK 结合使用。
-    pub const FLAG_ACTIVITY_TASK_ON_HOME: u32 = 0x00004000;
+    pub const FLAG_ACTIVITY_TASK_ON_HOME: IntentFlags = IntentFlags::FLAG_ACTIVITY_TASK_ON_HOME;
 
     //noinspection SpellCheckingInspection
     /// 默认情况下，当用户关闭由 FLAG_ACTIVITY_NEW_DOCUMENT 创建的文档时（使用 back 或其他任何方法 finish()），其在最近任务中的条目将被删除。如果您希望允许文档保留在最近任务中以便重新启动，则可以使用此标志。设置后，任务的活动已完成，最近任务条目将保留在界面中，以便用户重新启动它，就像顶级应用程序的最近任务条目一样。接收活动可以使用 android.R.attr#autoRemoveFromRecents 或通过明确调用 android.app.Activity#finishAndRemoveTask() Activity.finishAndRemoveTask() 来覆盖此请求。
-    pub const FLAG_ACTIVITY_RETAIN_IN_RECENTS: u32 = 0x00002000;
+    pub const FLAG_ACTIVITY_RETAIN_IN_RECENTS: IntentFlags =
+        IntentFlags::FLAG_ACTIVITY_RETAIN_IN_RECENTS;
 
     /// 此标志仅用于分屏多窗口模式。新活动将显示在启动它的活动旁边。这只能与 FLAG_ACTIVITY_NEW_TASK 结合使用。此外，如果您想要创建现有活动的新实例，则需要设置 FLAG_ACTIVITY_MULTIPLE_TASK。
-    pub const FLAG_ACTIVITY_LAUNCH_ADJACENT: u32 = 0x00001000;
+    pub const FLAG_ACTIVITY_LAUNCH_ADJACENT: IntentFlags =
+        IntentFlags::FLAG_ACTIVITY_LAUNCH_ADJACENT;
 
     /// 如果在传递给 Context#startActivity Context.startActivity() 的 Intent 中设置了此标志，则如果设备上没有完整的应用可以处理此意图，则此标志将尝试启动免安装应用。尝试从外部解析免安装应用时，支持以下 Intent 属性：Intent#setAction(String) Intent#addCategory(String) Intent#setData(Uri) Intent#setType(String) Intent#setPackage(String) Intent#addFlags(int) 如果找不到免安装应用，则将启动安装程序以通知用户无法解析该意图。在不支持免安装应用的设备上，将忽略此标志。
-    pub const FLAG_ACTIVITY_MATCH_EXTERNAL: u32 = 0x00000800;
+    pub const FLAG_ACTIVITY_MATCH_EXTERNAL: IntentFlags = IntentFlags::FLAG_ACTIVITY_MATCH_EXTERNAL;
 
     /// 如果在传递给 Context#startActivity Context.startActivity() 的 Intent 中设置此标志，则仅当该 Intent 解析为非浏览器结果时，才会启动该 Intent。如果不存在这样的结果，则会抛出 ActivityNotFoundException。
-    pub const FLAG_ACTIVITY_REQUIRE_NON_BROWSER: u32 = 0x00000400;
+    pub const FLAG_ACTIVITY_REQUIRE_NON_BROWSER: IntentFlags =
+        IntentFlags::FLAG_ACTIVITY_REQUIRE_NON_BROWSER;
 
     /// 如果在传递给 Context#startActivity Context.startActivity() 的 Intent 中设置此标志，则仅当该 Intent 解析为单个结果时才会启动该 Intent。如果不存在这样的结果或系统选择器会以其他方式显示，则会抛出 ActivityNotFoundException。
-    pub const FLAG_ACTIVITY_REQUIRE_DEFAULT: u32 = 0x00000200;
+    pub const FLAG_ACTIVITY_REQUIRE_DEFAULT: IntentFlags =
+        IntentFlags::FLAG_ACTIVITY_REQUIRE_DEFAULT;
 
     /// 如果设置，则发送广播时只会调用已注册的接收器——不会启动任何 BroadcastReceiver 组件。
-    pub const FLAG_RECEIVER_REGISTERED_ONLY: u32 = 0x40000000;
+    pub const FLAG_RECEIVER_REGISTERED_ONLY: IntentFlags =
+        IntentFlags::FLAG_RECEIVER_REGISTERED_ONLY;
 
     /**
     如果设置，则在发送广播时，新广播将替换与其匹配的任何现有待处理广播。匹配由 Intent#filterEquals(Intent) 定义，Intent.filterEquals 为两个广播的意图返回 true。找到匹配项后，新广播（及其关联的接收器）将替换待处理广播列表中的现有广播，并保持在列表中的相同位置。
     此标志通常用于粘性广播，粘性广播仅关心将广播的最新值传递给接收者。
     */
-    pub const FLAG_RECEIVER_REPLACE_PENDING: u32 = 0x20000000;
+    pub const FLAG_RECEIVER_REPLACE_PENDING: IntentFlags =
+        IntentFlags::FLAG_RECEIVER_REPLACE_PENDING;
 
     /// 如果设置，则在发送广播时，允许接收者以前台优先级运行，超时间隔更短。在正常广播期间，接收者不会自动脱离后台优先级类别。
-    pub const FLAG_RECEIVER_FOREGROUND: u32 = 0x10000000;
+    pub const FLAG_RECEIVER_FOREGROUND: IntentFlags = IntentFlags::FLAG_RECEIVER_FOREGROUND;
 
     /// 如果设置，则在发送广播时，接收者将在卸载队列上运行。
-    pub const FLAG_RECEIVER_OFFLOAD: u32 = 0x80000000;
+    pub const FLAG_RECEIVER_OFFLOAD: IntentFlags = IntentFlags::FLAG_RECEIVER_OFFLOAD;
 
     /// 如果设置，当发送广播时，接收者将在系统专用队列上运行。
-    pub const FLAG_RECEIVER_OFFLOAD_FOREGROUND: u32 = 0x00000800;
+    pub const FLAG_RECEIVER_OFFLOAD_FOREGROUND: IntentFlags =
+        IntentFlags::FLAG_RECEIVER_OFFLOAD_FOREGROUND;
 
     /// 如果这是有序广播，则不允许接收者中止广播。它们仍然可以将结果传播给后面的接收者，但不能阻止后面的接收者看到广播。
-    pub const FLAG_RECEIVER_NO_ABORT: u32 = 0x08000000;
+    pub const FLAG_RECEIVER_NO_ABORT: IntentFlags = IntentFlags::FLAG_RECEIVER_NO_ABORT;
 
     /**
     如果设置，则在系统完全启动之前发送广播时（甚至在发送 ACTION_LOCKED_BOOT_COMPLETED 之前）只会调用已注册的接收器 - 不会启动任何 BroadcastReceiver 组件。即使没有接收器被调用，粘性意图状态也会被正确记录。如果在广播意图中指定了 FLAG_RECEIVER_REGISTERED_ONLY，则此标志是不必要的。
     此标志仅供系统服务（甚至主线模块的服务）使用，以避免必须在检测启动完成方面实现更复杂的机制。
     这对系统服务器主线模块很有用
     */
-    pub const FLAG_RECEIVER_REGISTERED_ONLY_BEFORE_BOOT: u32 = 0x04000000;
+    pub const FLAG_RECEIVER_REGISTERED_ONLY_BEFORE_BOOT: IntentFlags =
+        IntentFlags::FLAG_RECEIVER_REGISTERED_ONLY_BEFORE_BOOT;
 
     /// 当此广播用于启动升级时设置，这是一种特殊模式，允许在系统准备就绪之前发送广播并启动没有运行任何提供程序的应用程序进程。
-    pub const FLAG_RECEIVER_BOOT_UPGRADE: u32 = 0x02000000;
+    pub const FLAG_RECEIVER_BOOT_UPGRADE: IntentFlags = IntentFlags::FLAG_RECEIVER_BOOT_UPGRADE;
 
     //noinspection SpellCheckingInspection
     /**
     如果设置，广播将始终发送到后台（缓存或未运行）应用中的清单接收器，无论默认情况下是否这样做。默认情况下，它们仅在广播指定了显式组件或软件包名称时才会接收广播。
     注意：dumpstate 以数字方式使用此标志，因此当其值改变时，那里的广播代码也必须改变以匹配。
     */
-    pub const FLAG_RECEIVER_INCLUDE_BACKGROUND: u32 = 0x01000000;
+    pub const FLAG_RECEIVER_INCLUDE_BACKGROUND: IntentFlags =
+        IntentFlags::FLAG_RECEIVER_INCLUDE_BACKGROUND;
 
     /// 如果设置，则无论是否默认执行此操作，广播都不会发送到后台（缓存或未运行）应用中的清单接收器。默认情况下，如果广播指定了明确的组件或软件包名称，它们将接收广播。
-    pub const FLAG_RECEIVER_EXCLUDE_BACKGROUND: u32 = 0x00800000;
+    pub const FLAG_RECEIVER_EXCLUDE_BACKGROUND: IntentFlags =
+        IntentFlags::FLAG_RECEIVER_EXCLUDE_BACKGROUND;
 
     /// 如果设置，则此广播将从 shell 发送。
-    pub const FLAG_RECEIVER_FROM_SHELL: u32 = 0x00400000;
+    pub const FLAG_RECEIVER_FROM_SHELL: IntentFlags = IntentFlags::FLAG_RECEIVER_FROM_SHELL;
 
     /**
     如果设置，广播将对 Instant Apps 中的接收者可见。默认情况下，Instant Apps 不会接收广播。
     当 Instant App 使用时，此标志无效。
     */
-    pub const FLAG_RECEIVER_VISIBLE_TO_INSTANT_APPS: u32 = 0x00200000;
+    pub const FLAG_RECEIVER_VISIBLE_TO_INSTANT_APPS: IntentFlags =
+        IntentFlags::FLAG_RECEIVER_VISIBLE_TO_INSTANT_APPS;
 
     /**
     无法使用 PendingIntent 更改的标志。
     */
-    pub const IMMUTABLE_FLAGS: u32 = Self::FLAG_GRANT_READ_URI_PERMISSION
-        | Self::FLAG_GRANT_WRITE_URI_PERMISSION
-        | Self::FLAG_GRANT_PERSISTABLE_URI_PERMISSION
-        | Self::FLAG_GRANT_PREFIX_URI_PERMISSION;
+    pub const IMMUTABLE_FLAGS: IntentFlags = Self::FLAG_GRANT_READ_URI_PERMISSION
+        .union(Self::FLAG_GRANT_WRITE_URI_PERMISSION)
+        .union(Self::FLAG_GRANT_PERSISTABLE_URI_PERMISSION)
+        .union(Self::FLAG_GRANT_PREFIX_URI_PERMISSION);
 
     /// 本地标志表明该实例是由系统创建的。
     pub const LOCAL_FLAG_FROM_SYSTEM: u32 = 1 << 5;
@@ -4222,7 +4404,7 @@ impl Intent {
     `action` Intent 操作，例如 ACTION_VIEW。
     */
     #[java_constructor]
-    pub fn from_action(action: String) -> Self {}
+    pub fn from_action(action: &str) -> Self {}
 
     /**
     向意图添加新类别。类别提供有关意图执行的操作的更多详细信息。解析意图时，仅使用提供所有请求类别的活动。
@@ -4230,14 +4412,14 @@ impl Intent {
     `category` 所需类别。这可以是预定义的意图类别之一，也可以是您自己的命名空间中的自定义类别。
     */
     #[java_method]
-    pub fn add_category(&self, category: String) -> Self {}
+    pub fn add_category(&self, category: &str) -> Self {}
 
     /**
     从意图中删除类别。
     `category` 要删除的类别。
     */
     #[java_method]
-    pub fn remove_category(&self, category: String) {}
+    pub fn remove_category(&self, category: &str) {}
 
     /**
     检查意图中是否存在类别。
@@ -4245,7 +4427,7 @@ impl Intent {
     `category` 要检查的类别。
     */
     #[java_method]
-    pub fn has_category(&self, category: String) -> bool {}
+    pub fn has_category(&self, category: &str) -> bool {}
 
     /**
     查询要执行的一般操作，例如 ACTION_VIEW。操作描述了意图中其余信息的一般解释方式——最重要的是，如何处理 getData 返回的数据。
@@ -4260,7 +4442,7 @@ impl Intent {
     `action` 操作名称，例如 ACTION_VIEW。特定于应用程序的操作应以供应商的软件包名称作为前缀。
     */
     #[java_method]
-    pub fn set_action(&self, action: String) -> Self {}
+    pub fn set_action(&self, action: &str) -> Self {}
 
     /**
     向意图添加其他标志（或使用现有标志值）。
@@ -4268,21 +4450,21 @@ impl Intent {
     `flags` 要设置的新标志。
     */
     #[java_method]
-    pub fn add_flags(&self, flags: u32) -> Self {}
+    pub fn add_flags(&self, flags: IntentFlags) -> Self {}
 
     /**
     查询与此意图相关的任何特殊标志。通常只需使用 setFlags 设置它们，然后让系统对它们采取适当的操作。
     返回：当前设置的标志。
     */
     #[java_method]
-    pub fn get_flags(&self) -> u32 {}
+    pub fn get_flags(&self) -> IntentFlags {}
 
     /**
     从意图中删除这些标志。
     `flags` 要删除的标志。
     */
     #[java_method]
-    pub fn remove_flags(&self, flags: u32) {}
+    pub fn remove_flags(&self, flags: IntentFlags) {}
 
     /**
     与 getData() 相同，但以编码字符串的形式返回 URI。
@@ -4297,7 +4479,7 @@ impl Intent {
     `value` 可序列化的数据值。
     */
     #[java_method]
-    pub fn put_extra<S: Serializable>(&self, name: String, value: Option<S>) -> Self {}
+    pub fn put_extra<S: Serializable>(&self, name: &str, value: Option<S>) -> Self {}
 
     /**
     向 Intent 添加扩展数据。名称必须包含包前缀，例如，应用程序 com.android.contacts 将使用“com.android.contacts.ShowAll”之类的名称。
@@ -4306,11 +4488,7 @@ impl Intent {
     `value` CharSequence 数据值。
     */
     #[java_method(overload = putExtra)]
-    pub fn put_extra_char_sequence<CS: CharSequence>(
-        &self,
-        name: String,
-        value: Option<CS>,
-    ) -> Self {
+    pub fn put_extra_char_sequence<CS: CharSequence>(&self, name: &str, value: Option<CS>) -> Self {
     }
 
     /**
@@ -4320,7 +4498,7 @@ impl Intent {
     `value` 字符串数据值。
     */
     #[java_method(overload = putExtra)]
-    pub fn put_extra_string(&self, name: String, value: Option<String>) -> Self {}
+    pub fn put_extra_string(&self, name: &str, value: Option<&str>) -> Self {}
 
     /**
     向 Intent 添加扩展数据。名称必须包含包前缀，例如，应用程序 com.android.contacts 将使用“com.android.contacts.ShowAll”之类的名称。
@@ -4329,7 +4507,7 @@ impl Intent {
     `value` 双精度数据值。
     */
     #[java_method(overload = putExtra)]
-    pub fn put_extra_double(&self, name: String, value: f64) -> Self {}
+    pub fn put_extra_double(&self, name: &str, value: f64) -> Self {}
 
     /**
     向 Intent 添加扩展数据。名称必须包含包前缀，例如，应用程序 com.android.contacts 将使用“com.android.contacts.ShowAll”之类的名称。
@@ -4338,7 +4516,7 @@ impl Intent {
     `value` 浮点数据值。
     */
     #[java_method(overload = putExtra)]
-    pub fn put_extra_float(&self, name: String, value: f32) -> Self {}
+    pub fn put_extra_float(&self, name: &str, value: f32) -> Self {}
 
     /**
     向 Intent 添加扩展数据。名称必须包含包前缀，例如，应用程序 com.android.contacts 将使用“com.android.contacts.ShowAll”之类的名称。
@@ -4347,7 +4525,7 @@ impl Intent {
     `value` 长数据值。
     */
     #[java_method(overload = putExtra)]
-    pub fn put_extra_long(&self, name: String, value: i64) -> Self {}
+    pub fn put_extra_long(&self, name: &str, value: i64) -> Self {}
 
     /**
     向 Intent 添加扩展数据。名称必须包含包前缀，例如，应用程序 com.android.contacts 将使用“com.android.contacts.ShowAll”之类的名称。
@@ -4356,7 +4534,7 @@ impl Intent {
     `value` 整数数据值。
     */
     #[java_method(overload = putExtra)]
-    pub fn put_extra_int(&self, name: String, value: i32) -> Self {}
+    pub fn put_extra_int(&self, name: &str, value: i32) -> Self {}
 
     /**
     向 Intent 添加扩展数据。名称必须包含包前缀，例如，应用程序 com.android.contacts 将使用“com.android.contacts.ShowAll”之类的名称。
@@ -4365,7 +4543,7 @@ impl Intent {
     `value` 短数据值。
     */
     #[java_method(overload = putExtra)]
-    pub fn put_extra_short(&self, name: String, value: i16) -> Self {}
+    pub fn put_extra_short(&self, name: &str, value: i16) -> Self {}
 
     /**
     向 Intent 添加扩展数据。名称必须包含包前缀，例如，应用程序 com.android.contacts 将使用“com.android.contacts.ShowAll”之类的名称。
@@ -4374,7 +4552,7 @@ impl Intent {
     `value` char 数据值。
     */
     #[java_method(overload = putExtra)]
-    pub fn put_extra_char(&self, name: String, value: char) -> Self {}
+    pub fn put_extra_char(&self, name: &str, value: char) -> Self {}
 
     /**
     向 Intent 添加扩展数据。名称必须包含包前缀，例如，应用程序 com.android.contacts 将使用“com.android.contacts.ShowAll”之类的名称。
@@ -4383,7 +4561,7 @@ impl Intent {
     `value` 字节数据值。
     */
     #[java_method(overload = putExtra)]
-    pub fn put_extra_byte(&self, name: String, value: u8) -> Self {}
+    pub fn put_extra_byte(&self, name: &str, value: u8) -> Self {}
 
     /**
     向 Intent 添加扩展数据。名称必须包含包前缀，例如，应用程序 com.android.contacts 将使用“com.android.contacts.ShowAll”之类的名称。
@@ -4392,7 +4570,7 @@ impl Intent {
     `value` 布尔数据值。
     */
     #[java_method(overload = putExtra)]
-    pub fn put_extra_boolean(&self, name: String, value: bool) -> Self {}
+    pub fn put_extra_boolean(&self, name: &str, value: bool) -> Self {}
 
     /**
     向 Intent 添加扩展数据。名称必须包含包前缀，例如，应用程序 com.android.contacts 将使用“com.android.contacts.ShowAll”之类的名称。
@@ -4401,7 +4579,7 @@ impl Intent {
     `value` Bundle 数据值。
     */
     #[java_method(overload = putExtra)]
-    pub fn put_extra_bundle(&self, name: String, value: Option<Bundle>) -> Self {}
+    pub fn put_extra_bundle(&self, name: &str, value: Option<&Bundle>) -> Self {}
 
     /**
     将“src”中的所有额外内容复制到此意图中。
@@ -4429,13 +4607,13 @@ impl Intent {
     `extras` Intent 中的新额外内容集，或为 null 以删除所有额外内容。
     */
     #[java_method(overload = replaceExtras)]
-    pub fn replace_extras_bundle(&self, extras: Option<Bundle>) -> Self {}
+    pub fn replace_extras_bundle(&self, extras: Option<&Bundle>) -> Self {}
 
     /**
     从意图中删除扩展数据。
     */
     #[java_method]
-    pub fn remove_extra(&self, name: String) {}
+    pub fn remove_extra(&self, name: &str) {}
 
     /**
     （通常为可选）设置一个明确的应用程序包名称，以限制此 Intent 将解析到的组件。如果保留默认值 null，则将考虑所有应用程序中的所有组件。
@@ -4444,7 +4622,7 @@ impl Intent {
     `package_name` 用于处理 Intent 的应用程序包的名称，或 null 以允许任何应用程序包。
     */
     #[java_method]
-    pub fn set_package(&self, package_name: Option<String>) -> Self {}
+    pub fn set_package(&self, package_name: Option<&str>) -> Self {}
 
     /**
     （通常可选）明确设置处理意图的组件。如果保留默认值 null，系统将根据意图中的其他字段（操作、数据、类型、类别）确定要使用的适当类。
@@ -4453,7 +4631,7 @@ impl Intent {
     `component` 处理意图的应用程序组件的名称，或 null 让系统为您找到一个。
     */
     #[java_method]
-    pub fn set_component(&self, component: Option<ComponentName>) -> Self {}
+    pub fn set_component(&self, component: Option<&ComponentName>) -> Self {}
 
     /**
     使用显式类名调用 setComponent 非常方便。
@@ -4462,11 +4640,7 @@ impl Intent {
     `class_name` 应用程序包内将用作此 Intent 组件的类的名称。
     */
     #[java_method(overload = setClassName)]
-    pub fn set_class_name_from_context(
-        &self,
-        package_context: &Context,
-        class_name: String,
-    ) -> Self {
+    pub fn set_class_name_from_context(&self, package_context: &Context, class_name: &str) -> Self {
     }
 
     /**
@@ -4476,7 +4650,7 @@ impl Intent {
     `class_name` 应用程序包内将用作此 Intent 组件的类的名称。
     */
     #[java_method]
-    pub fn set_class_name(&self, package_name: String, class_name: String) -> Self {}
+    pub fn set_class_name(&self, package_name: &str, class_name: &str) -> Self {}
 
     /**
     查询此 Intent 所限的应用程序包名称。解析 Intent 时，如果非空，则将解析限制为仅给定应用程序包中的组件。
@@ -4497,7 +4671,10 @@ impl Intent {
 可用的特定应用组件（android.app.Activity、android.app.Service、BroadcastReceiver 或 ContentProvider）的标识符。
 需要在此处封装两条信息来识别组件：组件所在的包（字符串）以及该包内的类名（字符串）。
 */
-#[java_class(name = "android/content/ComponentName")]
+#[java_class(
+    name = "android/content/ComponentName",
+    implements = [Comparable<ComponentName>]
+)]
 pub struct ComponentName;
 
 impl ComponentName {
@@ -4510,7 +4687,7 @@ impl ComponentName {
     `cls` pkg 中实现组件的类的名称
     */
     #[java_method]
-    pub fn create_relative(pkg: String, cls: String) -> Self {}
+    pub fn create_relative(pkg: &str, cls: &str) -> Self {}
 
     /**
     创建一个新的组件标识符，其中类名可以指定为绝对名称或相对于包含包的名称。相对包名以“.”字符开头。
@@ -4521,7 +4698,7 @@ impl ComponentName {
     `cls` 实现组件的 pkg 内类的名称
     */
     #[java_method(overload = createRelative)]
-    pub fn create_relative_context(pkg: &Context, cls: String) -> Self {}
+    pub fn create_relative_context(pkg: &Context, cls: &str) -> Self {}
 
     /**
     创建一个新的组件标识符。
@@ -4529,7 +4706,7 @@ impl ComponentName {
     `cls` pkg 中实现组件的类的名称。不能为空。
     */
     #[java_constructor]
-    pub fn new(pkg: String, cls: String) -> Self {}
+    pub fn new(pkg: &str, cls: &str) -> Self {}
 
     /**
     根据上下文和类名创建新的组件标识符。
@@ -4537,7 +4714,7 @@ impl ComponentName {
     `cls` pkg 中实现组件的类的名称。
     */
     #[java_constructor]
-    pub fn new_context(pkg: &Context, cls: String) -> Self {}
+    pub fn new_context(pkg: &Context, cls: &str) -> Self {}
 
     #[doc(hidden)]
     #[java_method]
@@ -4559,7 +4736,7 @@ impl ComponentName {
     助手在可以为空的 ComponentName 引用中获取 flattenToShortString()。
     */
     #[java_method(overload = flattenToShortString)]
-    pub fn flatten_to_short_string_static(component_name: Option<Self>) -> String {}
+    pub fn flatten_to_short_string_static(component_name: Option<&Self>) -> String {}
 
     //noinspection SpellCheckingInspection
     /**
@@ -4585,7 +4762,7 @@ impl ComponentName {
     `str` flattenToString() 返回的字符串。
     */
     #[java_method]
-    pub fn unflatten_from_string(r#str: String) -> Option<Self> {}
+    pub fn unflatten_from_string(r#str: &str) -> Option<Self> {}
 
     /**
     返回此类的字符串表示形式，不带类名作为前缀。
@@ -4598,11 +4775,6 @@ impl ComponentName {
     pub fn describe_contents(&self) -> i32 {}
 }
 
-impl Comparable<ComponentName> for ComponentName {
-    #[java_method]
-    fn compare_to(&self, o: &ComponentName) -> Result<i32> {}
-}
-
 /// 与组件名称关联的类的接口。
 #[allow(non_camel_case_types)]
 #[java_interface(name = "android/content/ComponentName$WithComponentName")]
@@ -4644,23 +4816,23 @@ pub fn test() {
     assert!(intent.to_string().starts_with("Intent"));
     assert_eq!(
         intent,
-        intent.add_category(Intent::CATEGORY_ACCESSIBILITY_SHORTCUT_TARGET.to_string())
+        intent.add_category(Intent::CATEGORY_ACCESSIBILITY_SHORTCUT_TARGET)
     );
-    intent.remove_category(Intent::CATEGORY_ACCESSIBILITY_SHORTCUT_TARGET.to_string());
+    intent.remove_category(Intent::CATEGORY_ACCESSIBILITY_SHORTCUT_TARGET);
     assert_eq!(
         false,
-        intent.has_category(Intent::CATEGORY_ACCESSIBILITY_SHORTCUT_TARGET.to_string())
+        intent.has_category(Intent::CATEGORY_ACCESSIBILITY_SHORTCUT_TARGET)
     );
-    assert_eq!(intent, intent.set_action(Intent::ACTION_VIEW.to_string()));
+    assert_eq!(intent, intent.set_action(Intent::ACTION_VIEW));
     assert_eq!(Intent::ACTION_VIEW, intent.get_action());
     assert_eq!(intent, intent.add_flags(Intent::FLAG_ACTIVITY_NEW_TASK));
     assert_eq!(Intent::FLAG_ACTIVITY_NEW_TASK, intent.get_flags());
     intent.remove_flags(Intent::FLAG_ACTIVITY_NEW_TASK);
-    assert_eq!(0, intent.get_flags());
+    assert!(intent.get_flags().is_empty());
     assert!(intent.get_data_string().is_some());
 
     context.send_broadcast(&intent);
     context.start_activity(&intent);
-    let am = context.get_system_service(Context::AUDIO_SERVICE.to_string());
+    let am = context.get_system_service(Context::AUDIO_SERVICE);
     assert!(am.is_some());
 }
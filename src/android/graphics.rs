@@ -11,7 +11,7 @@
  * See the License for the specific language governing permissions and limitations under the License.
  */
 
-use crate::{JObjNew, JObjRef, JType, java_class};
+use crate::{JObjNew, JObjRef, JType, JavaBean, java_class};
 
 /**
 Canvas 类包含“绘制”调用。要绘制某些内容，您需要 4 个基本组件：用于保存像素的 Bitmap、用于托管绘制调用（写入位图）的 Canvas、绘图基元（例如 Rect、Path、文本、Bitmap）和 Paint（用于描述绘图的颜色和样式）。
@@ -30,6 +30,20 @@ Rect 保存矩形的四个整数坐标。矩形由其 4 条边（左、上、右
 #[java_class(name = "android/graphics/Rect")]
 pub struct Rect;
 
+/// Rect的四个坐标，可以一次性从Rect对象中复制或者写回。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, JavaBean)]
+#[java(class = "android/graphics/Rect")]
+pub struct RectData {
+    /// 左边的坐标。
+    pub left: i32,
+    /// 上边的坐标。
+    pub top: i32,
+    /// 右边的坐标。
+    pub right: i32,
+    /// 下边的坐标。
+    pub bottom: i32,
+}
+
 //noinspection SpellCheckingInspection
 /**
 将图像流中的帧捕获为 OpenGL ES 纹理。图像流可能来自相机预览或视频解码。从 SurfaceTexture 创建的 Surface 可用作 android.hardware.camera2、android.media.MediaCodec、android.media.MediaPlayer 和 android.renderscript.Allocation API 的输出目标。
@@ -50,3 +64,39 @@ pub struct SurfaceTexture;
 */
 #[java_class(name = "android/graphics/Point")]
 pub struct Point;
+
+/// Point的两个坐标，可以一次性从Point对象中复制或者写回。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, JavaBean)]
+#[java(class = "android/graphics/Point")]
+pub struct PointData {
+    /// x坐标。
+    pub x: i32,
+    /// y坐标。
+    pub y: i32,
+}
+
+/// 测试android.graphics
+#[cfg(feature = "test_android_graphics")]
+pub fn test() {
+    let data = RectData {
+        left: 1,
+        top: 2,
+        right: 30,
+        bottom: 40,
+    };
+    let rect: Rect = data.to_java().unwrap();
+    assert_eq!(data, RectData::from_java(&rect).unwrap());
+    let moved = RectData {
+        left: 10,
+        right: 300,
+        ..data
+    };
+    moved.write_to(&rect).unwrap();
+    assert_eq!(moved, RectData::from_java(&rect).unwrap());
+
+    let point: Point = PointData { x: 3, y: 4 }.to_java().unwrap();
+    assert_eq!(
+        PointData { x: 3, y: 4 },
+        PointData::from_java(&point).unwrap()
+    );
+}
@@ -1057,11 +1057,11 @@ impl Camera_Parameters {
     `flattened` 以分号分隔的参数字符串（键值对）
     */
     #[java_method]
-    pub fn unflatten(&self, flattened: String) {}
+    pub fn unflatten(&self, flattened: &str) {}
 
     #[doc(hidden)]
     #[java_method]
-    pub fn remove(&self, key: String) {}
+    pub fn remove(&self, key: &str) {}
 
     /**
     设置字符串参数。
@@ -1069,7 +1069,7 @@ impl Camera_Parameters {
     `value` 参数的字符串值
     */
     #[java_method]
-    pub fn set(&self, key: String, value: String) {}
+    pub fn set(&self, key: &str, value: &str) {}
 
     /**
     设置一个整数参数。
@@ -1077,7 +1077,7 @@ impl Camera_Parameters {
     `value` 参数的 int 值
     */
     #[java_method(overload = set)]
-    pub fn set_int(&self, key: String, value: i32) {}
+    pub fn set_int(&self, key: &str, value: i32) {}
 
     /**
     返回字符串参数的值。
@@ -1085,7 +1085,7 @@ impl Camera_Parameters {
     `key` 参数的键名
     */
     #[java_method]
-    pub fn get(&self, key: String) -> Result<String> {}
+    pub fn get(&self, key: &str) -> Result<String> {}
 
     /**
     返回整数参数的值。
@@ -1093,7 +1093,7 @@ impl Camera_Parameters {
     `key` 参数的键名称
     */
     #[java_method]
-    pub fn get_int(&self, key: String) -> Result<i32> {}
+    pub fn get_int(&self, key: &str) -> Result<i32> {}
 
     //noinspection SpellCheckingInspection
     /**
@@ -1269,7 +1269,7 @@ pub fn test() {
     let camera_permission = crate::android::Manifest_permission::CAMERA.to_string();
     let permissions = vec![camera_permission.clone()];
     act.request_permissions(&permissions, 100).unwrap();
-    if act.check_self_permission(camera_permission)
+    if act.check_self_permission(&camera_permission)
         == crate::android::content::pm::PackageManager::PERMISSION_GRANTED
     {
         let info = Camera_CameraInfo::new();
@@ -11,93 +11,102 @@
  * See the License for the specific language governing permissions and limitations under the License.
  */
 
+use crate::{JObjNew, JObjRef, JType, java_constants};
+
 /// 振动器效果
-pub trait Effect {
+#[java_constants]
+pub enum Effect {
     #[doc(hidden)]
-    const CLICK: i32 = 0;
+    Click = 0,
 
     #[doc(hidden)]
-    const DOUBLE_CLICK: i32 = 1;
+    DoubleClick = 1,
 
     #[doc(hidden)]
-    const TICK: i32 = 2;
+    Tick = 2,
 
     #[doc(hidden)]
-    const THUD: i32 = 3;
+    Thud = 3,
 
     #[doc(hidden)]
-    const POP: i32 = 4;
+    Pop = 4,
 
     #[doc(hidden)]
-    const HEAVY_CLICK: i32 = 5;
+    HeavyClick = 5,
 
     #[doc(hidden)]
-    const RINGTONE_1: i32 = 6;
+    #[java_name = "RINGTONE_1"]
+    Ringtone1 = 6,
 
     #[doc(hidden)]
-    const RINGTONE_2: i32 = 7;
+    #[java_name = "RINGTONE_2"]
+    Ringtone2 = 7,
 
     #[doc(hidden)]
-    const RINGTONE_3: i32 = 8;
+    #[java_name = "RINGTONE_3"]
+    Ringtone3 = 8,
 
     #[doc(hidden)]
-    const RINGTONE_4: i32 = 9;
+    #[java_name = "RINGTONE_4"]
+    Ringtone4 = 9,
 
     #[doc(hidden)]
-    const RINGTONE_5: i32 = 10;
+    #[java_name = "RINGTONE_5"]
+    Ringtone5 = 10,
 
     #[doc(hidden)]
-    const RINGTONE_6: i32 = 11;
+    #[java_name = "RINGTONE_6"]
+    Ringtone6 = 11,
 
     #[doc(hidden)]
-    const RINGTONE_7: i32 = 12;
+    #[java_name = "RINGTONE_7"]
+    Ringtone7 = 12,
 
     #[doc(hidden)]
-    const RINGTONE_8: i32 = 13;
+    #[java_name = "RINGTONE_8"]
+    Ringtone8 = 13,
 
     #[doc(hidden)]
-    const RINGTONE_9: i32 = 14;
+    #[java_name = "RINGTONE_9"]
+    Ringtone9 = 14,
 
     #[doc(hidden)]
-    const RINGTONE_10: i32 = 15;
+    #[java_name = "RINGTONE_10"]
+    Ringtone10 = 15,
 
     #[doc(hidden)]
-    const RINGTONE_11: i32 = 16;
+    #[java_name = "RINGTONE_11"]
+    Ringtone11 = 16,
 
     #[doc(hidden)]
-    const RINGTONE_12: i32 = 17;
+    #[java_name = "RINGTONE_12"]
+    Ringtone12 = 17,
 
     #[doc(hidden)]
-    const RINGTONE_13: i32 = 18;
+    #[java_name = "RINGTONE_13"]
+    Ringtone13 = 18,
 
     #[doc(hidden)]
-    const RINGTONE_14: i32 = 19;
+    #[java_name = "RINGTONE_14"]
+    Ringtone14 = 19,
 
     #[doc(hidden)]
-    const RINGTONE_15: i32 = 20;
+    #[java_name = "RINGTONE_15"]
+    Ringtone15 = 20,
 
     #[doc(hidden)]
-    const TEXTURE_TICK: i32 = 21;
+    TextureTick = 21,
 }
 
-#[doc(hidden)]
-pub struct EffectImpl;
-
-impl Effect for EffectImpl {}
-
-#[doc(hidden)]
-pub trait EffectStrength {
+/// 振动器效果的强度
+#[java_constants]
+pub enum EffectStrength {
     #[doc(hidden)]
-    const LIGHT: u8 = 0;
+    Light = 0,
 
     #[doc(hidden)]
-    const MEDIUM: u8 = 1;
+    Medium = 1,
 
     #[doc(hidden)]
-    const STRONG: u8 = 2;
+    Strong = 2,
 }
-
-#[doc(hidden)]
-pub struct EffectStrengthImpl;
-
-impl EffectStrength for EffectStrengthImpl {}
@@ -13,7 +13,7 @@
 
 use crate::{
     JObjNew, JObjRef, JType, Result,
-    android::hardware::vibrator::{Effect, EffectImpl, EffectStrength, EffectStrengthImpl},
+    android::hardware::vibrator::{Effect, EffectStrength},
     java::lang::Runnable,
     java_class, java_constructor, java_method,
 };
@@ -136,9 +136,10 @@ impl Vibrator {
     /**
     检查振动器是否具有独立的频率控制。
     返回：如果硬件可以独立于振动幅度控制振动频率，则返回 True，否则返回 false。
+    需要API 31及以上，否则返回`crate::DroidWrapError::UnsupportedApiLevel`。
     */
-    #[java_method]
-    pub fn has_frequency_control(&self) -> bool {}
+    #[java_method(since = 31)]
+    pub fn has_frequency_control(&self) -> Result<bool> {}
 
     /**
     检查振动器是否支持给定 VibrationEffect 的所有组件（即振动器是否能按预期播放给定效果）。如果此方法返回 true，则 VibrationEffect 应按预期播放。 如果为 false，播放 VibrationEffect 仍可能产生振动，但振动效果可能与预期大不相同。此方法汇总功能检查方法的结果，例如 hasAmplitudeControl、areAllPrimitivesSupported(int...) 等，具体取决于 VibrationEffect 实际使用的功能。
@@ -223,35 +224,35 @@ impl VibrationEffect {
     pub const MAX_AMPLITUDE: i32 = 255;
 
     /// 点击效果。使用此效果作为基准，因为它是最常见的点击效果类型。
-    pub const EFFECT_CLICK: i32 = <EffectImpl as Effect>::CLICK;
+    pub const EFFECT_CLICK: Effect = Effect::Click;
 
     /// 双击效果。
-    pub const EFFECT_DOUBLE_CLICK: i32 = <EffectImpl as Effect>::DOUBLE_CLICK;
+    pub const EFFECT_DOUBLE_CLICK: Effect = Effect::DoubleClick;
 
     /// 勾选效果。与 EFFECT_CLICK 相比，此效果强度较低。
-    pub const EFFECT_TICK: i32 = <EffectImpl as Effect>::TICK;
+    pub const EFFECT_TICK: Effect = Effect::Tick;
 
     /// 一个轰动的效果。
-    pub const EFFECT_THUD: i32 = <EffectImpl as Effect>::THUD;
+    pub const EFFECT_THUD: Effect = Effect::Thud;
 
     /// 具有流行效果。
-    pub const EFFECT_POP: i32 = <EffectImpl as Effect>::POP;
+    pub const EFFECT_POP: Effect = Effect::Pop;
 
     /// 重击效果。此效果比 EFFECT_CLICK 更强。
-    pub const EFFECT_HEAVY_CLICK: i32 = <EffectImpl as Effect>::HEAVY_CLICK;
+    pub const EFFECT_HEAVY_CLICK: Effect = Effect::HeavyClick;
 
     /// 一种纹理效果，用于复制柔软的滴答声。
     /// 与普通效果不同，纹理效果需要反复调用，通常是为了响应某些动作，以复制用户手指下方的某些纹理的感觉。
-    pub const EFFECT_TEXTURE_TICK: i32 = <EffectImpl as Effect>::TEXTURE_TICK;
+    pub const EFFECT_TEXTURE_TICK: Effect = Effect::TextureTick;
 
     #[doc(hidden)]
-    pub const EFFECT_STRENGTH_LIGHT: i32 = <EffectStrengthImpl as EffectStrength>::LIGHT as i32;
+    pub const EFFECT_STRENGTH_LIGHT: EffectStrength = EffectStrength::Light;
 
     #[doc(hidden)]
-    pub const EFFECT_STRENGTH_MEDIUM: i32 = <EffectStrengthImpl as EffectStrength>::MEDIUM as i32;
+    pub const EFFECT_STRENGTH_MEDIUM: EffectStrength = EffectStrength::Medium;
 
     #[doc(hidden)]
-    pub const EFFECT_STRENGTH_STRONG: i32 = <EffectStrengthImpl as EffectStrength>::STRONG as i32;
+    pub const EFFECT_STRENGTH_STRONG: EffectStrength = EffectStrength::Strong;
 
     /**
     防止从框架外部进行子类化
@@ -274,7 +275,7 @@ impl VibrationEffect {
     `effect_id` 要执行的效果的 ID：EFFECT_CLICK、EFFECT_DOUBLE_CLICK、EFFECT_TICK
     */
     #[java_method]
-    pub fn create_predefined(effect_id: i32) -> Self {}
+    pub fn create_predefined(effect_id: Effect) -> Self {}
 
     /**
     获取预定义的振动效果。预定义效果是一组常见的振动效果，无论它们来自哪个应用，它们都应该相同，以便为整个设备上的用户提供一致的体验。它们还可以根据设备硬件进行定制，以提供比使用通用构建块构建得更好的体验。如果存在通用模式，并且不存在特定于硬件的效果实现，则将回退到通用模式。
@@ -282,7 +283,7 @@ impl VibrationEffect {
     `effect_id` 要执行的效果的 ID：EFFECT_CLICK、EFFECT_DOUBLE_CLICK、EFFECT_TICK
     */
     #[java_method]
-    pub fn get(effect_id: i32) -> Self {}
+    pub fn get(effect_id: Effect) -> Self {}
 
     /**
     获取预定义的振动效果。预定义效果是一组常见的振动效果，无论它们来自哪个应用，它们都应该相同，以便为整个设备上的用户提供一致的体验。它们还可以根据设备硬件进行定制，以提供比使用通用构建块构建得更好的体验。您可能只想在有特定于硬件的实现时播放某些效果，因为它们可能会在不进行调整的情况下对用户造成太大的干扰。fallback 参数允许您决定是要回退到通用实现，还是仅在有经过调整的特定于硬件的实现时才播放。
@@ -291,7 +292,7 @@ impl VibrationEffect {
     `fallback` 如果不存在特定于硬件的实现，是否回退到通用模式。
     */
     #[java_method(overload=get)]
-    pub fn get_with_fallback(effect_id: i32, fallback: bool) -> Self {}
+    pub fn get_with_fallback(effect_id: Effect, fallback: bool) -> Self {}
 
     #[doc(hidden)]
     #[java_method]
@@ -335,7 +336,7 @@ impl VibrationEffect {
     `effect_strength` 要应用的新效果强度，VibrationEffect.EFFECT_STRENGTH_* 之一。
     */
     #[java_method]
-    pub fn apply_effect_strength(&self, effect_strength: i32) -> Self {}
+    pub fn apply_effect_strength(&self, effect_strength: EffectStrength) -> Self {}
 
     /**
     根据给定的因子缩放给定的振动强度。
@@ -347,11 +348,11 @@ impl VibrationEffect {
 
     #[doc(hidden)]
     #[java_method]
-    pub fn effect_id_to_string(effect_id: i32) -> String {}
+    pub fn effect_id_to_string(effect_id: Effect) -> String {}
 
     #[doc(hidden)]
     #[java_method]
-    pub fn effect_strength_to_string(effect_strength: i32) -> String {}
+    pub fn effect_strength_to_string(effect_strength: EffectStrength) -> String {}
 }
 
 /// 从系统属性中提取的有关当前构建的信息。
@@ -363,7 +364,7 @@ impl Build {
     pub const UNKNOWN: &'static str = "unknown";
 
     fn get_string(property: &str) -> String {
-        SystemProperties::get_with_default(property.to_string(), Self::UNKNOWN.to_string())
+        SystemProperties::get_with_default(property, Self::UNKNOWN)
     }
 
     /// 可以是变更列表编号，也可以是像“M4-rc20”这样的标签。
@@ -473,7 +474,7 @@ impl Build {
     pub fn get_serial() -> Result<String> {}
 
     fn get_string_list(property: &str, separator: &str) -> Vec<String> {
-        let value = SystemProperties::get(property.to_string());
+        let value = SystemProperties::get(property);
         if value.is_empty() {
             return vec![];
         }
@@ -505,8 +506,8 @@ impl Build {
         || Self::get_string_list("ro.product.cpu.abilist64", ",");
 
     #[doc(hidden)]
-    #[java_method]
-    pub fn is64bit_abi(abi: String) {}
+    #[java_method(name = "is64BitAbi")]
+    pub fn is64bit_abi(abi: &str) -> bool {}
 
     /// 构建类型，如“user”或“eng”。
     pub const TYPE: ConstFn = || Self::get_string("ro.build.type");
@@ -519,7 +520,7 @@ impl Build {
 
     /// 有些设备将指纹组件分成多个分区，因此我们可能会在运行时获取指纹。
     fn derive_fingerprint() -> String {
-        let mut finger = SystemProperties::get("ro.build.fingerprint".to_string());
+        let mut finger = SystemProperties::get("ro.build.fingerprint");
         if finger.is_empty() {
             finger = format!(
                 "{}/{}/{}:{}/{}/{}:{}/{}",
@@ -545,11 +546,11 @@ impl Build {
     目的是针对比真实硬件慢几个数量级的软件模拟器的产品可以将其设置为较大的数字。在真实设备和硬件加速的虚拟化设备上不应设置此数字。
     */
     pub const HW_TIMEOUT_MULTIPLIER: ConstFn<i32> =
-        || SystemProperties::get_int("ro.hw_timeout_multiplier".to_string(), 1);
+        || SystemProperties::get_int("ro.hw_timeout_multiplier", 1);
 
     /// 如果此设备启用了 Treble 并且需要该功能，则为 True。
     pub const IS_TREBLE_ENABLED: ConstFn<bool> =
-        || SystemProperties::get_boolean("ro.treble.enabled".to_string(), false);
+        || SystemProperties::get_boolean("ro.treble.enabled", false);
 
     //noinspection SpellCheckingInspection
     /**
@@ -560,7 +561,7 @@ impl Build {
     pub fn is_build_consistent() -> bool {}
 
     fn get_long(property: &str) -> i64 {
-        if let Ok(num) = i64::from_str(&SystemProperties::get(property.to_string())) {
+        if let Ok(num) = i64::from_str(&SystemProperties::get(property)) {
             return num;
         }
         -1
@@ -580,8 +581,7 @@ impl Build {
     如果设备正在运行可调试版本（例如“userdebug”或“eng”），则返回 true。
     可调试版本允许用户通过本地 shell 获得 root 访问权限、将调试器附加到任何应用程序（无论它们是否设置了“可调试”属性），或者将 selinux 降级为“宽容”模式。
     */
-    pub const IS_DEBUGGABLE: ConstFn<bool> =
-        || SystemProperties::get_int("ro.debuggable".to_string(), 0) == 1;
+    pub const IS_DEBUGGABLE: ConstFn<bool> = || SystemProperties::get_int("ro.debuggable", 0) == 1;
 
     //noinspection SpellCheckingInspection
     /**
@@ -607,8 +607,7 @@ impl Build {
     此版本是否在 ARC（适用于 Chrome 的 Android 运行时）上运行<https://chromium.googlesource.com/chromiumos/docs/+/master/containers_and_vms.md>。在 R 之前，它被实现为容器，但从 R 开始，它将是 VM。属性的名称仍为 ro.boot.conntainer，因为它在其他项目中被引用。
     如果可能的话，我们应尽量避免检查此标记，以尽量减少 与 非容器 Android 行为的不必要差异。当低级资源不同时，检查此标记是可以接受的，例如某些功能的可用性、对系统资源的访问受到限制以及主机操作系统可能会为我们处理某些功能。对于更高级别的行为差异，应优先进行其他检查。
     */
-    pub const IS_ARC: ConstFn<bool> =
-        || SystemProperties::get_boolean("ro.boot.container".to_string(), false);
+    pub const IS_ARC: ConstFn<bool> = || SystemProperties::get_boolean("ro.boot.container", false);
 
     /**
     指定是否应在旧版应用的任何组件运行之前审核其所需的权限。旧版应用是指 targetSdkVersion < 23 的应用，即使用旧权限模型的应用。如果无需审核，则会在安装应用之前审核权限。
@@ -639,29 +638,23 @@ impl Build_VERSION {
         || Build::get_string("ro.build.version.release_or_preview_display");
 
     /// 产品所基于的基本操作系统构建。
-    pub const BASE_OS: ConstFn = || {
-        SystemProperties::get_with_default("ro.build.version.base_os".to_string(), String::new())
-    };
+    pub const BASE_OS: ConstFn =
+        || SystemProperties::get_with_default("ro.build.version.base_os", "");
 
     /// 用户可见的安全补丁级别。此值表示设备最近应用安全补丁的日期。
-    pub const SECURITY_PATCH: ConstFn = || {
-        SystemProperties::get_with_default(
-            "ro.build.version.security_patch".to_string(),
-            String::new(),
-        )
-    };
+    pub const SECURITY_PATCH: ConstFn =
+        || SystemProperties::get_with_default("ro.build.version.security_patch", "");
 
     /// 框架的原始字符串表示形式中用户可见的 SDK 版本；请改用 SDK_INT。
     #[deprecated(note = "使用 SDK_INT 可轻松将其作为整数获取。")]
     pub const SDK: ConstFn = || Build::get_string("ro.build.version.sdk");
 
     /// 此硬件设备上当前运行的软件的 SDK 版本。设备启动时此值不会改变，但硬件制造商提供 OTA 更新时此值可能会增加。可能的值在 Build.VERSION_CODES 中定义。
-    pub const SDK_INT: ConstFn<i32> =
-        || SystemProperties::get_int("ro.build.version.sdk".to_string(), 0);
+    pub const SDK_INT: ConstFn<i32> = || SystemProperties::get_int("ro.build.version.sdk", 0);
 
     /// 此硬件设备上最初搭载的软件的 SDK 版本。该版本在设备生命周期内永远不会改变，即使 SDK_INT 因 OTA 更新而增加也是如此。可能的值在 Build.VERSION_CODES 中定义。
     pub const DEVICE_INITIAL_SDK_INT: ConstFn<i32> =
-        || SystemProperties::get_int("ro.product.first_api_level".to_string(), 0);
+        || SystemProperties::get_int("ro.product.first_api_level", 0);
 
     /**
     预发布 SDK 的开发者预览修订版本。在生产平台版本/设备上，此值始终为 0。
@@ -669,19 +662,15 @@ impl Build_VERSION {
     针对预览 API 的实验性应用在使用任何预发布平台 API 之前，应检查此值是否与它们所针对的预览 SDK 修订版本相等 (==)。如果应用检测到的预览 SDK 修订版本不是它们所期望的特定修订版本，则应回退到仅使用之前发布的 API 级别的 API，以避免不必要的运行时异常。
     */
     pub const PREVIEW_SDK_INT: ConstFn<i32> =
-        || SystemProperties::get_int("ro.build.version.preview_sdk".to_string(), 0);
+        || SystemProperties::get_int("ro.build.version.preview_sdk", 0);
 
     /**
     给定预发布 SDK 的 SDK 指纹。此值在生产平台版本/设备上始终为“ ”。
     当该值不是''时。
     此属性旨在供安装程序用于更细粒度地定位软件包。针对预览 API 的应用程序不应使用此字段，而应使用 ` ` 或使用反射或其他运行时检查来检测 API 的存在或防止意外的运行时行为。
     */
-    pub const PREVIEW_SDK_FINGERPRINT: ConstFn = || {
-        SystemProperties::get_with_default(
-            "ro.build.version.preview_sdk_fingerprint".to_string(),
-            "REL".to_string(),
-        )
-    };
+    pub const PREVIEW_SDK_FINGERPRINT: ConstFn =
+        || SystemProperties::get_with_default("ro.build.version.preview_sdk_fingerprint", "REL");
 
     /// 当前开发代号，如果这是发布版本，则为字符串“REL”。
     pub const CODENAME: ConstFn = || Build::get_string("ro.build.version.codename");
@@ -699,7 +688,7 @@ impl Build_VERSION {
 
     /// 应用目标 SDK 当前支持的最低值。以较低值为目标的应用可能无法在运行此 SDK 版本的设备上运行。其可能的值在 Build.VERSION_CODES 中定义。
     pub const MIN_SUPPORTED_TARGET_SDK_INT: ConstFn<i32> =
-        || SystemProperties::get_int("ro.build.version.min_supported_target_sdk".to_string(), 0);
+        || SystemProperties::get_int("ro.build.version.min_supported_target_sdk", 0);
 }
 
 /// 当前已知的 SDK 版本代码的枚举。这些值可以在 VERSION#SDK 中找到。版本号会随着每个官方平台版本的发布而单调递增。
@@ -1036,7 +1025,7 @@ impl SystemProperties {
     `key` 查找的钥匙如果找不到键，则一个空字符串
     */
     #[java_method]
-    pub fn get(key: String) -> String {}
+    pub fn get(key: &str) -> String {}
 
     /**
     获取给定键的字符串值。
@@ -1045,7 +1034,7 @@ impl SystemProperties {
     `def` 如果属性未设置或为空，则返回默认值
     */
     #[java_method(overload=get)]
-    pub fn get_with_default(key: String, def: String) -> String {}
+    pub fn get_with_default(key: &str, def: &str) -> String {}
 
     /**
     获取给定键的值，并以整数形式返回。
@@ -1054,7 +1043,7 @@ impl SystemProperties {
     `def` 要返回的默认值
     */
     #[java_method]
-    pub fn get_int(key: String, def: i32) -> i32 {}
+    pub fn get_int(key: &str, def: i32) -> i32 {}
 
     /**
     获取给定键的值，并以长整型返回。
@@ -1063,7 +1052,7 @@ impl SystemProperties {
     `def` 要返回的默认值
     */
     #[java_method]
-    pub fn get_long(key: String, def: i64) -> i64 {}
+    pub fn get_long(key: &str, def: i64) -> i64 {}
 
     /**
     获取给定键的值，以布尔值形式返回。值“n”、“no”、“0”、“false”或“off”被视为假。值“y”、“yes”、“1”、“true”或“on”被视为真。（区分大小写）。如果键不存在或具有任何其他值，则返回默认结果。
@@ -1072,7 +1061,7 @@ impl SystemProperties {
     `def` 要返回的默认值
     */
     #[java_method]
-    pub fn get_boolean(key: String, def: bool) -> bool {}
+    pub fn get_boolean(key: &str, def: bool) -> bool {}
 
     /**
     将给定键的值设置为 val。
@@ -1083,7 +1072,7 @@ impl SystemProperties {
     `val` 要设置的值。
     */
     #[java_method]
-    pub fn set(key: String, val: Option<String>) {}
+    pub fn set(key: &str, val: Option<&str>) {}
 
     /**
     添加一个回调，该回调将在系统属性发生任何变化时运行。
@@ -1112,7 +1101,7 @@ impl SystemProperties {
     `name` 属性的名称
     */
     #[java_method]
-    pub fn find(name: String) -> Option<SystemProperties_Handle> {}
+    pub fn find(name: &str) -> Option<SystemProperties_Handle> {}
 }
 
 /// 预定位属性的句柄。提前查找属性句柄可以实现对单个属性的最佳重复查找。
@@ -1158,12 +1147,15 @@ pub fn test() {
     assert!(bundle.to_string().starts_with("Bundle"));
     let context = Activity::fetch().unwrap();
     let vm: VibratorManager = context
-        .get_system_service(Context::VIBRATOR_MANAGER_SERVICE.to_string())
+        .get_system_service(Context::VIBRATOR_MANAGER_SERVICE)
         .unwrap()
         .cast()
         .unwrap();
     let effect = VibrationEffect::create_one_shot(500, VibrationEffect::DEFAULT_AMPLITUDE);
     vm.get_default_vibrator().vibrate_effect(&effect);
+    let effect = VibrationEffect::create_predefined(VibrationEffect::EFFECT_CLICK);
+    vm.get_default_vibrator().vibrate_effect(&effect);
+    assert_eq!("CLICK", VibrationEffect::EFFECT_CLICK.to_string());
     let _ = dbg!(
         Build::PRODUCT_FOR_ATTESTATION(),
         Build::DISPLAY(),
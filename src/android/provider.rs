@@ -1609,7 +1609,7 @@ impl Settings {
     pub fn is_calling_package_allowed_to_write_settings(
         context: &Context,
         uid: i32,
-        calling_package: String,
+        calling_package: &str,
         throw_exception: bool,
     ) -> bool {
     }
@@ -1622,7 +1622,7 @@ impl Settings {
     pub fn check_and_note_write_settings_operation_convenience(
         context: &Context,
         uid: i32,
-        calling_package: String,
+        calling_package: &str,
         throw_exception: bool,
     ) -> bool {
     }
@@ -1636,8 +1636,8 @@ impl Settings {
     pub fn check_and_note_write_settings_operation(
         context: &Context,
         uid: i32,
-        calling_package: String,
-        calling_attribution_tag: Option<String>,
+        calling_package: &str,
+        calling_attribution_tag: Option<&str>,
         throw_exception: bool,
     ) -> bool {
     }
@@ -1649,7 +1649,7 @@ impl Settings {
     pub fn is_calling_package_allowed_to_draw_overlays(
         context: &Context,
         uid: i32,
-        calling_package: String,
+        calling_package: &str,
         throw_exception: bool,
     ) -> bool {
     }
@@ -1663,8 +1663,8 @@ impl Settings {
     pub fn check_and_note_draw_overlays_operation(
         context: &Context,
         uid: i32,
-        calling_package: String,
-        calling_attribution_tag: String,
+        calling_package: &str,
+        calling_attribution_tag: &str,
         throw_exception: bool,
     ) -> bool {
     }
@@ -1677,7 +1677,7 @@ impl Settings {
     pub fn is_calling_package_allowed_to_perform_app_ops_protected_operation_convenience(
         context: &Context,
         uid: i32,
-        calling_package: String,
+        calling_package: &str,
         throw_exception: bool,
         app_ops_op_code: i32,
         permissions: &[String],
@@ -1693,8 +1693,8 @@ impl Settings {
     pub fn is_calling_package_allowed_to_perform_app_ops_protected_operation(
         context: &Context,
         uid: i32,
-        calling_package: String,
-        calling_attribution_tag: String,
+        calling_package: &str,
+        calling_attribution_tag: &str,
         throw_exception: bool,
         app_ops_op_code: i32,
         permissions: &[String],
@@ -12,12 +12,11 @@
  */
 
 use crate::{
-    JObjNew, JObjRef, JProxy, JType, Result,
+    JObjNew, JObjRef, JProxy, JType,
     android::{content::Context, os::Bundle},
     java::lang::CharSequence,
-    java_class, java_constructor, java_implement, java_interface, java_method,
+    java_class, java_constants, java_constructor, java_interface, java_method,
 };
-use std::sync::Arc;
 
 /**
 从文本合成语音以立即播放或创建声音文件。 TextToSpeech 实例只有在完成初始化后才可用于合成文本。实现 TextToSpeech.OnInitListener 以接收初始化完成的通知。使用完 TextToSpeech 实例后，调用 shutdown() 方法释放 TextToSpeech 引擎使用的原生资源。针对 Android 11 且使用文本转语音的应用应在其清单的查询元素中声明 TextToSpeech.Engine.INTENT_ACTION_TTS_SERVICE：
@@ -38,25 +37,25 @@ impl TextToSpeech {
         "android.speech.tts.TTS_QUEUE_PROCESSING_COMPLETED";
 
     /// 表示一般操作失败。
-    pub const ERROR: i32 = -1;
+    pub const ERROR: TextToSpeechStatus = TextToSpeechStatus::Error;
 
     /// 表示由无效请求导致的失败。
-    pub const ERROR_INVALID_REQUEST: i32 = -8;
+    pub const ERROR_INVALID_REQUEST: TextToSpeechStatus = TextToSpeechStatus::ErrorInvalidRequest;
 
     /// 表示由网络连接问题导致的故障。
-    pub const ERROR_NETWORK: i32 = -6;
+    pub const ERROR_NETWORK: TextToSpeechStatus = TextToSpeechStatus::ErrorNetwork;
 
     /// 表示由于网络超时导致的失败。
-    pub const ERROR_NETWORK_TIMEOUT: i32 = -7;
+    pub const ERROR_NETWORK_TIMEOUT: TextToSpeechStatus = TextToSpeechStatus::ErrorNetworkTimeout;
 
     /// 表示由于语音数据未下载完成而导致的失败。
-    pub const ERROR_NOT_INSTALLED_YET: i32 = -9;
+    pub const ERROR_NOT_INSTALLED_YET: TextToSpeechStatus = TextToSpeechStatus::ErrorNotInstalledYet;
 
     /// 表示与输出（音频设备或文件）相关的故障。
-    pub const ERROR_OUTPUT: i32 = -5;
+    pub const ERROR_OUTPUT: TextToSpeechStatus = TextToSpeechStatus::ErrorOutput;
 
     /// 表示 TTS 引擎无法合成给定的输入。
-    pub const ERROR_SYNTHESIS: i32 = -3;
+    pub const ERROR_SYNTHESIS: TextToSpeechStatus = TextToSpeechStatus::ErrorSynthesis;
 
     /// 表示该语言适用于该区域设置的语言，但不适用于该国家/地区和变体。
     pub const LANG_AVAILABLE: i32 = 0;
@@ -83,13 +82,13 @@ impl TextToSpeech {
     pub const QUEUE_FLUSH: i32 = 0;
 
     /// 表示客户端请求停止。它仅在 API 的服务端使用，客户端不应该期望看到此结果代码。
-    pub const STOPPED: i32 = -2;
+    pub const STOPPED: TextToSpeechStatus = TextToSpeechStatus::Stopped;
 
     /// 表示操作成功。
-    pub const SUCCESS: i32 = 0;
+    pub const SUCCESS: TextToSpeechStatus = TextToSpeechStatus::Success;
 
     /// 表示 TTS 服务失败。
-    pub const ERROR_SERVICE: i32 = -4;
+    pub const ERROR_SERVICE: TextToSpeechStatus = TextToSpeechStatus::ErrorService;
 
     /**
     TextToSpeech 类的构造函数，使用默认的 TTS 引擎。如果尚未运行，这还将初始化关联的 TextToSpeech 引擎。
@@ -104,7 +103,7 @@ impl TextToSpeech {
     返回：错误或成功。
     */
     #[java_method]
-    pub fn stop(&self) -> i32 {}
+    pub fn stop(&self) -> TextToSpeechStatus {}
 
     /**
     检查 TTS 引擎是否正在讲话。请注意，一旦语音项目的音频数据被发送到音频混音器或写入文件，该语音项目即被视为完成。此时与音频硬件完成播放之间可能会有有限地滞后。如果 TTS 引擎正在讲话，则返回 true。
@@ -124,7 +123,7 @@ impl TextToSpeech {
     `pitch` 语音音调。1.0 为正常音调，值越低，合成语音的音调越低，值越高，合成语音的音调越高。
     */
     #[java_method]
-    pub fn set_pitch(&self, pitch: f32) -> i32 {}
+    pub fn set_pitch(&self, pitch: f32) -> TextToSpeechStatus {}
 
     /**
     设置语速。这对任何预先录制的语音没有影响。
@@ -132,7 +131,7 @@ impl TextToSpeech {
     `speech_rate` 语速。1.0 是正常语速，较低的值会减慢语速（0.5 是正常语速的一半），较高的值会加快语速（2.0 是正常语速的两倍）。
     */
     #[java_method]
-    pub fn set_speech_rate(&self, speech_rate: f32) -> i32 {}
+    pub fn set_speech_rate(&self, speech_rate: f32) -> TextToSpeechStatus {}
 
     //noinspection SpellCheckingInspection
     /**
@@ -144,7 +143,7 @@ impl TextToSpeech {
     #[deprecated(
         note = "这不会在 TTS 引擎初始化时通知调用者。TextToSpeech(Context, TextToSpeech.OnInitListener, String) 可以与适当的引擎名称一起使用。此外，不能保证指定的引擎将被加载。如果未安装或禁用，则将应用用户/系统范围的默认值。"
     )]
-    pub fn set_engine_by_package_name(&self, engine_package_name: String) -> i32 {}
+    pub fn set_engine_by_package_name(&self, engine_package_name: &str) -> TextToSpeechStatus {}
 
     /**
     传递给 Speaking 和 SynthesizeToFile 的输入字符串的长度限制。
@@ -187,47 +186,57 @@ impl TextToSpeech {
         &self,
         text: &CS,
         queue_mode: i32,
-        params: Option<Bundle>,
-        utterance_id: String,
-    ) -> i32 {
+        params: Option<&Bundle>,
+        utterance_id: &str,
+    ) -> TextToSpeechStatus {
     }
 }
 
+/// TextToSpeech 操作的结果，即 TextToSpeech 中的 SUCCESS、ERROR 等常量。
+#[java_constants]
+pub enum TextToSpeechStatus {
+    /// 表示操作成功。
+    Success = 0,
+
+    /// 表示一般操作失败。
+    Error = -1,
+
+    /// 表示客户端请求停止。它仅在 API 的服务端使用，客户端不应该期望看到此结果代码。
+    Stopped = -2,
+
+    /// 表示 TTS 引擎无法合成给定的输入。
+    ErrorSynthesis = -3,
+
+    /// 表示 TTS 服务失败。
+    ErrorService = -4,
+
+    /// 表示与输出（音频设备或文件）相关的故障。
+    ErrorOutput = -5,
+
+    /// 表示由网络连接问题导致的故障。
+    ErrorNetwork = -6,
+
+    /// 表示由于网络超时导致的失败。
+    ErrorNetworkTimeout = -7,
+
+    /// 表示由无效请求导致的失败。
+    ErrorInvalidRequest = -8,
+
+    /// 表示由于语音数据未下载完成而导致的失败。
+    ErrorNotInstalledYet = -9,
+}
+
 /**
 调用回调接口定义，指示 TextToSpeech 引擎初始化完成。
 */
 #[allow(non_camel_case_types)]
-#[java_interface(name = "android/speech/tts/TextToSpeech$OnInitListener")]
+#[java_interface(name = "android/speech/tts/TextToSpeech$OnInitListener", builder)]
 pub trait TextToSpeech_OnInitListener {
     /**
     调用以表示 TextToSpeech 引擎初始化完成。
     `status` 成功或错误。
     */
-    fn on_init(&self, status: i32);
-}
-
-#[doc(hidden)]
-#[allow(non_camel_case_types)]
-#[java_class(name = "android/speech/tts/TextToSpeech$OnInitListenerImpl")]
-pub struct TextToSpeech_OnInitListenerImpl(Box<dyn Fn(i32) + Send + Sync>);
-
-#[java_implement]
-impl TextToSpeech_OnInitListener for TextToSpeech_OnInitListenerImpl {
-    fn on_init(&self, status: i32) {
-        self.0(status)
-    }
-}
-
-impl Default for TextToSpeech_OnInitListenerImplDefault {
-    fn default() -> Self {
-        Self(Box::new(|_| ()))
-    }
-}
-
-impl TextToSpeech_OnInitListenerImpl {
-    pub fn from_fn(func: impl Fn(/* status */ i32) + Send + Sync + 'static) -> Result<Arc<Self>> {
-        Self::new(TextToSpeech_OnInitListenerImplDefault(Box::new(func)))
-    }
+    fn on_init(&self, status: TextToSpeechStatus);
 }
 
 //noinspection SpellCheckingInspection
@@ -236,13 +245,13 @@ impl TextToSpeech_OnInitListenerImpl {
 pub fn test() {
     use crate::{
         android::app::Activity,
-        java::lang::{CharSequenceExt, CharSequenceImpl},
+        java::lang::CharSequenceExt,
     };
     let context = Activity::fetch().unwrap();
-    let init_listener = TextToSpeech_OnInitListenerImpl::from_fn(|status| {
-        println!("Tts is initialized status: {}.", status)
-    })
-    .unwrap();
+    let init_listener = TextToSpeech_OnInitListenerImpl::builder()
+        .on_init(|status| println!("Tts is initialized status: {}.", status))
+        .build()
+        .unwrap();
     let tts = TextToSpeech::new(&context, init_listener.as_ref());
     assert!(
         tts.to_string()
@@ -257,10 +266,10 @@ pub fn test() {
     assert!(!tts.get_current_engine().is_empty());
     // dbg!(tts.are_defaults_enforced());
     tts.speak(
-        &"你好".to_char_sequence::<CharSequenceImpl>().unwrap(),
+        &"你好".to_char_sequence().unwrap(),
         TextToSpeech::QUEUE_ADD,
         None,
-        "test".to_string(),
+        "test",
     );
     tts.shutdown();
     assert!(TextToSpeech::get_max_speech_input_length() > 0);
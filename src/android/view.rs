@@ -25,10 +25,10 @@ use crate::{
         graphics::{Canvas, Rect},
         os::Bundle,
     },
-    java::lang::{CharSequence, Integer, Runnable},
-    java_class, java_constructor, java_field, java_implement, java_interface, java_method,
+    java::lang::{CharSequence, CharSequenceImpl, Integer, Runnable},
+    java_class, java_constants, java_constructor, java_field, java_flags, java_interface,
+    java_method,
 };
-use std::sync::Arc;
 
 /**
 此类代表用户界面组件的基本构建块。View 占据屏幕上的矩形区域，负责绘制和事件处理。View 是小部件的基类，用于创建交互式 UI 组件（按钮、文本字段等）。ViewGroup 子类是布局的基类，布局是不可见的容器，用于容纳其他 View（或其他 ViewGroup）并定义其布局属性。
@@ -168,7 +168,7 @@ impl View {
     返回：内容描述
     */
     #[java_method]
-    pub fn get_content_description<CS: CharSequence>(&self) -> Option<CS> {}
+    pub fn get_content_description(&self) -> Option<CharSequenceImpl> {}
 
     /**
     设置视图的内容描述。内容描述简要描述视图，主要用于辅助功能支持，以确定应如何向用户呈现视图。对于没有文本表示的视图（如 android.widget.ImageButton），有用的内容描述会解释视图的作用。例如，用于拨打电话的带有电话图标的图像按钮可以使用“呼叫”作为其内容描述。用于保存文件的软盘图像可以使用“保存”。这应该省略角色或状态。角色是指视图的用户界面元素类型，例如按钮或复选框。状态是指视图经常变化的属性，例如按钮的开/关状态或音量滑块的音频级别。内容描述更新并不频繁，并且在元素的语义内容（而不是状态）发生变化时使用。例如，在音乐播放期间，播放按钮可能会更改为暂停按钮。
@@ -343,7 +343,7 @@ impl View {
 
     /// 二进制兼容性存根。当我们最终完成 O API 时将被删除。
     #[java_method]
-    pub fn get_tooltip<CS: CharSequence>(&self) -> Option<CS> {}
+    pub fn get_tooltip(&self) -> Option<CharSequenceImpl> {}
 
     /**
     返回视图的工具提示文本。
@@ -351,7 +351,7 @@ impl View {
     返回：工具提示文本
     */
     #[java_method]
-    pub fn get_tooltip_text<CS: CharSequence>(&self) -> Option<CS> {}
+    pub fn get_tooltip_text(&self) -> Option<CharSequenceImpl> {}
 
     /// 二进制兼容性存根。当我们最终完成 O API 时将被删除。
     #[java_method]
@@ -449,6 +449,7 @@ pub trait ViewParent {
     如果父级存在则返回该父级，否则返回 null。
     返回：ViewParent，如果此 ViewParent 没有父级则返回 null。
     */
+    #[java_method(type_bound=(Self::VP, ViewParent))]
     fn get_parent(&self) -> Option<Self::VP>;
 
     /**
@@ -519,6 +520,7 @@ pub trait ViewParent {
     获取给定 View 的父级，以实现可访问性。由于某些 View 未暴露给可访问性层，因此可访问性的父级不一定是 View 的直接父级，而是前任。
     返回：父级，如果未找到，则返回 null。
     */
+    #[java_method(type_bound=(Self::VP, ViewParent))]
     fn get_parent_for_accessibility(&self) -> Option<Self::VP>;
 
     /**
@@ -673,7 +675,7 @@ pub trait ViewParent {
 
 /// 当视图被点击时调用的回调的接口定义。
 #[allow(non_camel_case_types)]
-#[java_interface(name = "android/view/View$OnClickListener")]
+#[java_interface(name = "android/view/View$OnClickListener", builder)]
 pub trait View_OnClickListener {
     /**
     当单击某个视图时调用。
@@ -682,30 +684,6 @@ pub trait View_OnClickListener {
     fn on_click(&self, v: View);
 }
 
-#[doc(hidden)]
-#[allow(non_camel_case_types)]
-#[java_class(name = "android/view/View$OnClickListenerImpl")]
-pub struct View_OnClickListenerImpl(Box<dyn Fn(View) + Send + Sync>);
-
-impl View_OnClickListenerImpl {
-    pub fn from_fn(func: impl Fn(/* v */ View) + Send + Sync + 'static) -> Result<Arc<Self>> {
-        Self::new(View_OnClickListenerImplDefault(Box::new(func)))
-    }
-}
-
-impl Default for View_OnClickListenerImplDefault {
-    fn default() -> Self {
-        Self(Box::new(|v| unimplemented!("{:?}", v)))
-    }
-}
-
-#[java_implement]
-impl View_OnClickListener for View_OnClickListenerImpl {
-    fn on_click(&self, v: View) {
-        self.0(v)
-    }
-}
-
 /**
 接口允许您向 Activity 添加和删除子视图。要获取此类的实例，请调用 Context.getSystemService()。
 */
@@ -717,20 +695,23 @@ pub trait ViewManager: JType {
     `view` 要添加到此窗口的视图。
     `params` 要分配给视图的 LayoutParams。
     */
-    fn add_view(&self, view: &View, params: &ViewGroup_LayoutParams) -> Result<()>;
+    #[java_method]
+    fn add_view(&self, view: &View, params: &ViewGroup_LayoutParams) -> Result<()> {}
 
     #[doc(hidden)]
-    fn update_view_layout(&self, view: &View, params: &ViewGroup_LayoutParams);
+    #[java_method]
+    fn update_view_layout(&self, view: &View, params: &ViewGroup_LayoutParams) {}
 
     #[doc(hidden)]
-    fn remove_view(&self, view: &View);
+    #[java_method]
+    fn remove_view(&self, view: &View) {}
 }
 
 /**
 应用用于与窗口管理器通信的接口。每个窗口管理器实例都绑定到一个 Display。要获取与显示器关联的 WindowManager，请调用 Context.createWindowContext(Display, int, Bundle) 以获取显示器的 UI 上下文，然后在 UI 上下文上调用 Context.getSystemService(String) 或 Context.getSystemService(Class)。
 在特定显示器上显示窗口的最简单方法是创建一个 Presentation，它会自动获取显示器的 WindowManager 和上下文。
 */
-#[java_interface(name = "android/view/WindowManager")]
+#[java_interface(name = "android/view/WindowManager", wrapper)]
 pub trait WindowManager: ViewManager {
     #[doc(hidden)]
     const PARCEL_KEY_SHORTCUTS_ARRAY: &'static str = "shortcuts_array";
@@ -751,26 +732,6 @@ pub trait WindowManager: ViewManager {
     fn get_default_display(&self) -> Display {}
 }
 
-#[doc(hidden)]
-#[java_class(name = "android/view/WindowManagerImpl")]
-pub struct WindowManagerImpl;
-
-impl ViewManager for WindowManagerImpl {
-    #[java_method]
-    fn add_view(&self, view: &View, params: &ViewGroup_LayoutParams) -> Result<()> {}
-
-    #[java_method]
-    fn update_view_layout(&self, view: &View, params: &ViewGroup_LayoutParams) {}
-
-    #[java_method]
-    fn remove_view(&self, view: &View) {}
-}
-
-impl WindowManager for WindowManagerImpl {
-    #[java_method]
-    fn remove_view_immediate(&self, view: &View) {}
-}
-
 /**
 ViewGroup 是一种特殊视图，可以包含其他视图（称为子视图）。视图组是布局和视图容器的基类。
 此类还定义了 ViewGroup.LayoutParams 类，该类用作布局参数的基类。另请参阅 ViewGroup.LayoutParams 以了解布局属性。
@@ -783,7 +744,11 @@ ViewGroup 是一种特殊视图，可以包含其他视图（称为子视图）
 最后，布局管理器可以在 XML 布局中使用，如下所示：
 @sample development/samples/ApiDemos/res/layout/custom_layout.xml 完整版
 */
-#[java_class(name = "android/view/ViewGroup", extends=View)]
+#[java_class(
+    name = "android/view/ViewGroup",
+    extends = View,
+    implements = [ViewManager, ViewParent<VP = Self>]
+)]
 pub struct ViewGroup;
 
 impl ViewGroup {
@@ -809,142 +774,6 @@ impl ViewGroup {
     pub fn remove_all_views_in_layout(&self) {}
 }
 
-impl ViewManager for ViewGroup {
-    #[java_method]
-    fn add_view(&self, view: &View, params: &ViewGroup_LayoutParams) -> Result<()> {}
-
-    #[java_method]
-    fn update_view_layout(&self, view: &View, params: &ViewGroup_LayoutParams) {}
-
-    #[java_method]
-    fn remove_view(&self, view: &View) {}
-}
-
-impl ViewParent for ViewGroup {
-    type VP = Self;
-
-    #[java_method]
-    fn request_layout(&self) {}
-
-    #[java_method]
-    fn is_layout_requested(&self) -> bool {}
-
-    #[java_method]
-    fn request_transparent_region(&self, child: &View) {}
-
-    #[java_method(type_bound=(Self::VP, ViewParent))]
-    fn get_parent(&self) -> Option<Self::VP> {}
-
-    #[java_method]
-    fn request_child_focus(&self, child: &View, focused: &View) {}
-
-    #[java_method]
-    fn recompute_view_attributes(&self, child: &View) {}
-
-    #[java_method]
-    fn clear_child_focus(&self, child: &View) {}
-
-    #[java_method]
-    fn focus_search(&self, v: &View, direction: i32) -> View {}
-
-    #[java_method]
-    fn bring_child_to_front(&self, child: &View) {}
-
-    #[java_method]
-    fn show_context_menu_for_child(&self, original_view: &View) -> bool {}
-
-    #[java_method]
-    fn child_drawable_state_changed(&self, child: &View) {}
-
-    #[java_method]
-    fn request_disallow_intercept_touch_event(&self, disallow_intercept: bool) {}
-
-    #[java_method]
-    fn child_has_transient_state_changed(&self, child: &View, has_transient_state: bool) {}
-
-    #[java_method]
-    fn request_fit_system_windows(&self) {}
-
-    #[java_method(type_bound=(Self::VP, ViewParent))]
-    fn get_parent_for_accessibility(&self) -> Option<Self::VP> {}
-
-    #[java_method]
-    fn notify_subtree_accessibility_state_changed(
-        &self,
-        child: &View,
-        source: &View,
-        change_type: i32,
-    ) {
-    }
-
-    #[java_method]
-    fn can_resolve_layout_direction(&self) -> bool {}
-
-    #[java_method]
-    fn is_layout_direction_resolved(&self) -> bool {}
-
-    #[java_method]
-    fn get_layout_direction(&self) -> i32 {}
-
-    #[java_method]
-    fn can_resolve_text_direction(&self) -> bool {}
-
-    #[java_method]
-    fn is_text_direction_resolved(&self) -> bool {}
-
-    #[java_method]
-    fn get_text_direction(&self) -> i32 {}
-
-    #[java_method]
-    fn can_resolve_text_alignment(&self) -> bool {}
-
-    #[java_method]
-    fn is_text_alignment_resolved(&self) -> bool {}
-
-    #[java_method]
-    fn on_start_nested_scroll(&self, child: &View, target: &View, nested_scroll_axes: i32) -> bool {
-    }
-
-    #[java_method]
-    fn on_nested_scroll_accepted(&self, child: &View, target: &View, nested_scroll_axes: i32) {}
-
-    #[java_method]
-    fn on_stop_nested_scroll(&self, target: &View) {}
-
-    #[java_method]
-    fn on_nested_scroll(
-        &self,
-        target: &View,
-        dx_consumed: i32,
-        dy_consumed: i32,
-        dx_unconsumed: i32,
-        dy_unconsumed: i32,
-    ) {
-    }
-
-    #[java_method]
-    fn on_nested_fling(
-        &self,
-        target: &View,
-        velocity_x: f32,
-        velocity_y: f32,
-        consumed: bool,
-    ) -> bool {
-    }
-
-    #[java_method]
-    fn on_nested_pre_fling(&self, target: &View, velocity_x: f32, velocity_y: f32) -> bool {}
-
-    #[java_method]
-    fn on_nested_pre_perform_accessibility_action(
-        &self,
-        target: &View,
-        action: i32,
-        arguments: Option<Bundle>,
-    ) -> bool {
-    }
-}
-
 /**
 视图使用 LayoutParams 来告诉其父级它们希望如何布局。请参阅 ViewGroup 布局属性，了解此类支持的所有子视图属性的列表。
 基本 LayoutParams 类仅描述视图希望的宽度和高度。对于每个维度，它可以指定以下之一：
@@ -1049,87 +878,249 @@ impl ViewGroup_MarginLayoutParams {
     pub fn from_layout_params(source: &ViewGroup_LayoutParams) -> Self {}
 }
 
-#[doc(hidden)]
-#[allow(non_camel_case_types)]
-#[java_class(name = "android/view/WindowManager$LayoutParams", extends=ViewGroup_LayoutParams)]
-pub struct WindowManager_LayoutParams;
+/// 窗口的行为选项/标志，请参阅 WindowManager.LayoutParams 的 flags。
+#[java_flags]
+pub enum WindowFlags {
+    /// 窗口标志：只要此窗口对用户可见，就允许在屏幕打开时激活锁定屏幕。这可以单独使用，也可以与 FLAG_KEEP_SCREEN_ON 和/或 FLAG_SHOW_WHEN_LOCKED 结合使用
+    FLAG_ALLOW_LOCK_WHILE_SCREEN_ON = 0x00000001,
 
-impl WindowManager_LayoutParams {
-    /// 代表正常应用程序窗口的窗口类型的启动。
-    pub const FIRST_APPLICATION_WINDOW: i32 = 1;
+    /// 窗口标志：此窗口后面的所有内容都将变暗。使用 dimAmount 来控制暗淡程度。
+    FLAG_DIM_BEHIND = 0x00000002,
 
-    /// 窗口类型：作为整个应用程序的“基础”窗口的应用程序窗口；所有其他应用程序窗口将显示在其上方。在多用户系统中，仅显示在拥有该应用程序的用户窗口上。
-    pub const TYPE_BASE_APPLICATION: i32 = 1;
+    /// 窗口标志：为该窗口启用模糊功能。
+    FLAG_BLUR_BEHIND = 0x00000004,
 
-    /// 窗口类型：普通应用程序窗口。令牌必须是活动令牌，用于标识窗口所属用户。在多用户系统中，仅显示在所属用户的窗口上。
-    pub const TYPE_APPLICATION: i32 = 2;
+    /**
+    窗口标志：此窗口永远不会获得按键输入焦点，因此用户无法向其发送按键或其他按钮事件。这些事件将转至其后面的任何可获得焦点的窗口。此标志还将启用 FLAG_NOT_TOUCH_MODAL，无论是否明确设置。
+    设置此标志还意味着窗口将不需要与软输入法交互，因此它将按 Z 顺序排列并独立于任何活动输入法定位（通常这意味着它在输入法之上按 Z 顺序排列，因此它可以使用全屏显示其内容并在需要时覆盖输入法。您可以使用 FLAG_ALT_FOCUSABLE_IM 来修改此行为。
+    */
+    FLAG_NOT_FOCUSABLE = 0x00000008,
 
-    /// 窗口类型：应用程序启动时显示的特殊应用程序窗口。应用程序本身不使用它；系统使用它来显示某些内容，直到应用程序可以显示自己的窗口。在多用户系统中，显示在所有用户的窗口上。
-    pub const TYPE_APPLICATION_STARTING: i32 = 3;
+    /**
+    窗口标志：此窗口永远不能接收触摸事件。
+    此标志的目的是将触摸留给该窗口下方的某个窗口来处理（按 Z 顺序）。
+    从Android Build.VERSION_CODES#S开始，出于安全原因，触摸事件通过包含此标志的窗口(即。在该窗口的边界内)将仅在以下一项(或多项)为真的情况下被递送到该触摸消费窗口：
+    相同的UID：该窗口属于拥有该触摸消费窗口的同一UID。
+    受信任的窗口：此窗口受信任。可信窗口包括(但不限于)辅助窗口(TYPE_ACCESSIBILITY_OVERLAY)、输入法(TYPE_INPUT_METHOD)和辅助窗口(TYPE_VOICE_INTERACTION)。类型为_APPLICATION_OVERLAY的窗口不受信任，请参见下文。
+    不可见窗口：该窗口是视图#消失或视图#不可见。
+    全透明窗口：此窗口的LayoutParams#Alpha等于0。
+    一个具有足够透明度的SAW窗口：该窗口的类型为_APPLICATION_OVERLAY，其LayoutParams#Alpha小于或等于最大遮挡不透明度(见下文)，并且它是触摸路径中该UID的唯一类型为_APPLICATION_OVERLAY的窗口。
+    具有足够透明度的多个SAW窗口：从该UID开始的触摸路径中的多个重叠的TYPE_APPLICATION_OVERLAY窗口具有低于或等于最大遮挡不透明度的组合遮挡不透明度。有关如何计算该值的信息，请参见下面的组合遮挡不透明度一节。
+    如果这些情况都不成立，则不会传递触摸，并且会将一条消息记录到LogCAT。
+    最大遮挡不透明度此值为 0.8。如果应用希望从系统收集此值（而不是对其进行硬编码），则可能需要使用 android.hardware.input.InputManager#getMaximumObscuringOpacityForTouch()。
+    组合遮挡不透明度
+    一组窗口的组合遮挡不透明度是通过使用结合和交换运算将该集合中所有窗口的不透明度值组合而获得的，定义为：opacity({A,B}) = 1 - (1 - opacity(A))*(1 - opacity(B))，其中 ` `) = 1 - (1 - opacity(W1)) * ... * (1 - opacity(Wn))
+    */
+    FLAG_NOT_TOUCHABLE = 0x00000010,
 
-    /// 窗口类型：TYPE_APPLICATION 的变体，确保窗口管理器将等待此窗口绘制完毕后再显示应用。在多用户系统中，仅显示在拥有该应用的用户窗口上。
-    pub const TYPE_DRAWN_APPLICATION: i32 = 4;
+    /// 窗口标志：即使此窗口可聚焦（其 FLAG_NOT_FOCUSABLE 未设置），也允许将窗口外的任何指针事件发送到其后面的窗口。否则它将自己消耗所有指针事件，无论它们是否在窗口内。
+    FLAG_NOT_TOUCH_MODAL = 0x00000020,
 
-    /// 申请窗口类型的结束。
-    pub const LAST_APPLICATION_WINDOW: i32 = 99;
+    /// 窗口标志：设置后，如果按下触摸屏时设备处于休眠状态，您将收到此首次触摸事件。通常，首次触摸事件会被系统消耗，因为用户看不到他们按下的是什么。
+    FLAG_TOUCHABLE_WHEN_WAKING = 0x00000040,
 
-    /// 子窗口类型的开始。这些窗口的标记必须设置为它们所附加到的窗口。这些类型的窗口在 Z 顺序上紧挨着它们所附加到的窗口，并且它们的坐标空间相对于它们所附加到的窗口。
-    pub const FIRST_SUB_WINDOW: i32 = 1000;
+    /// 窗口标志：只要此窗口对用户可见，就保持设备屏幕开启且明亮。
+    FLAG_KEEP_SCREEN_ON = 0x00000080,
 
-    /// 窗口类型：位于应用程序窗口顶部的面板。这些窗口出现在其附属窗口的顶部。
-    pub const TYPE_APPLICATION_PANEL: i32 = Self::FIRST_SUB_WINDOW;
+    /**
+    附加窗口的窗口标志：将窗口放置在整个屏幕内，忽略来自父窗口的任何限制。
+    注意：在具有displayCutout的显示器上，可以将窗口放置，以便在必要时根据LayoutInDisplayCutOutMode避免显示屏区域。
+    */
+    FLAG_LAYOUT_IN_SCREEN = 0x00000100,
 
-    /// 窗口类型：用于显示媒体（如视频）的窗口。这些窗口显示在其附属窗口的后面。
-    pub const TYPE_APPLICATION_MEDIA: i32 = Self::FIRST_SUB_WINDOW + 1;
+    /// 窗口标志：允许窗口延伸到屏幕之外。
+    FLAG_LAYOUT_NO_LIMITS = 0x00000200,
 
-    /// 窗口类型：应用程序窗口顶部的子面板。这些窗口显示在其附属窗口和任何 TYPE_APPLICATION_PANEL 面板的顶部。
-    pub const TYPE_APPLICATION_SUB_PANEL: i32 = Self::FIRST_SUB_WINDOW + 2;
+    /**
+    窗口标志：显示此窗口时隐藏所有屏幕装饰（例如状态栏）。这允许窗口为自己使用整个显示空间 - 当设置了此标志的应用窗口位于顶层时，状态栏将被隐藏。全屏窗口将忽略窗口的 softInputMode 字段的 SOFT_INPUT_ADJUST_RESIZE 值；窗口将保持全屏并且不会调整大小。
+    您可以通过 android.R.attr#windowFullscreen 属性在您的主题中控制此标志；此属性会在标准全屏主题中自动为您设置，例如 android.R.style#Theme_NoTitleBar_Fullscreen、android.R.style#Theme_Black_NoTitleBar_Fullscreen、android.R.style#Theme_Light_NoTitleBar_Fullscreen、android.R.style#Theme_Holo_NoActionBar_Fullscreen、android.R.style#Theme_Holo_Light_NoActionBar_Fullscreen、android.R.style#Theme_DeviceDefault_NoActionBar_Fullscreen 和 android.R.style#Theme_DeviceDefault_Light_NoActionBar_Fullscreen。
+    */
+    FLAG_FULLSCREEN = 0x00000400,
 
-    /// 窗口类型：类似于 TYPE_APPLICATION_PANEL，但窗口的布局与顶层窗口一样，而不是作为其容器的子窗口。
-    pub const TYPE_APPLICATION_ATTACHED_DIALOG: i32 = Self::FIRST_SUB_WINDOW + 3;
+    /// 窗口标志：覆盖 FLAG_FULLSCREEN 并强制显示屏幕装饰（例如状态栏）。
+    FLAG_FORCE_NOT_FULLSCREEN = 0x00000800,
 
-    /// 窗口类型：用于在媒体窗口顶部显示覆盖层的窗口。这些窗口显示在 TYPE_APPLICATION_MEDIA 和应用程序窗口之间。它们应该是半透明的，以便于使用。这是一个非常丑陋的 hack，因此：
-    pub const TYPE_APPLICATION_MEDIA_OVERLAY: i32 = Self::FIRST_SUB_WINDOW + 4;
+    /// 窗口标志：将此窗口合成到屏幕时启用抖动。
+    FLAG_DITHER = 0x00001000,
 
-    /// 窗口类型：位于应用程序窗口及其子面板窗口上方的子面板。这些窗口显示在其附属窗口和任何 TYPE_APPLICATION_SUB_PANEL 面板的上方。
-    pub const TYPE_APPLICATION_ABOVE_SUB_PANEL: i32 = Self::FIRST_SUB_WINDOW + 5;
+    /**
+    窗口标志：将窗口内容视为安全，防止其出现在屏幕截图中或在非安全显示器上查看。
+    有关安全表面和安全显示的更多详细信息，请参阅 android.view.Display#FLAG_SECURE。
+    */
+    FLAG_SECURE = 0x00002000,
 
-    /// 子窗口类型的结束。
-    pub const LAST_SUB_WINDOW: i32 = 1999;
+    /// 窗口标志：一种特殊模式，其中布局参数用于在表面合成到屏幕时执行表面的缩放。
+    FLAG_SCALED = 0x00004000,
 
-    /// 启动系统特定的窗口类型。这些窗口类型通常不是由应用程序创建的。
-    pub const FIRST_SYSTEM_WINDOW: i32 = 2000;
+    /// 窗口标志：用于用户将屏幕贴在脸上时经常使用的窗口，它将积极过滤事件流以防止在这种情况下意外按压（对于特定窗口可能不是所需的），当检测到这样的事件流时，应用程序将收到一个 CANCEL 运动事件来指示这一点，因此应用程序可以通过在手指释放之前不对事件采取任何操作来相应地处理此问题。
+    FLAG_IGNORE_CHEEK_PRESSES = 0x00008000,
 
-    /// 窗口类型：状态栏。只能有一个状态栏窗口；它位于屏幕顶部，所有其他窗口都向下移动，因此它们位于其下方。在多用户系统中显示在所有用户的窗口上。
-    pub const TYPE_STATUS_BAR: i32 = Self::FIRST_SYSTEM_WINDOW;
+    /// 窗口标志：一个特殊选项，仅与 FLAG_LAYOUT_IN_SCREEN 结合使用。当请求在屏幕上布局时，您的窗口可能会出现在屏幕装饰（例如状态栏）的上方或后面。通过同时包含此标志，窗口管理器将报告所需的插入矩形，以确保您的内容不会被屏幕装饰覆盖。此标志通常由 Window 为您设置，如 Window#setFlags 中所述
+    FLAG_LAYOUT_INSET_DECOR = 0x00010000,
 
-    /// 窗口类型：搜索栏。只能有一个搜索栏窗口；它位于屏幕顶部。在多用户系统中显示在所有用户的窗口上。
-    pub const TYPE_SEARCH_BAR: i32 = Self::FIRST_SYSTEM_WINDOW + 1;
+    /**
+    窗口标志：设置后，反转窗口的输入法可聚焦性。
+    设置此标志的效果取决于是否设置了 FLAG_NOT_FOCUSABLE：如果未设置 FLAG_NOT_FOCUSABLE，即当窗口可聚焦时，设置此标志将阻止此窗口成为输入法的目标。因此，它将无法与输入法交互，并将位于输入法之上（除非其上方有另一个输入法目标）。
+    如果设置了 FLAG_NOT_FOCUSABLE，则设置此标志会要求窗口成为输入法目标，即使窗口无法聚焦。因此，它将位于输入法之下。注意：设置了 FLAG_NOT_FOCUSABLE 的窗口无法与输入法交互，无论此标志如何。
+    */
+    FLAG_ALT_FOCUSABLE_IM = 0x00020000,
 
-    /// 窗口类型：电话。这些是非应用程序窗口，用于为用户提供与电话的交互（尤其是来电）。这些窗口通常位于所有应用程序之上，但在状态栏后面。在多用户系统中显示在所有用户的窗口上。
-    #[deprecated(note = "适用于非系统应用。请改用 TYPE_APPLICATION_OVERLAY。")]
-    pub const TYPE_PHONE: i32 = Self::FIRST_SYSTEM_WINDOW + 2;
+    /// 窗口标志：如果您已设置 FLAG_NOT_TOUCH_MODAL，则可以设置此标志以接收单个特殊 MotionEvent，其动作为 MotionEvent#ACTION_OUTSIDE MotionEvent.ACTION_OUTSIDE，用于发生在窗口外的触摸。请注意，您不会收到完整的向下/移动/向上手势，只会收到第一个向下的位置作为 ACTION_OUTSIDE。
+    FLAG_WATCH_OUTSIDE_TOUCH = 0x00040000,
 
-    /// 窗口类型：系统窗口，例如低电量警报。这些窗口始终位于应用程序窗口之上。在多用户系统中，仅显示在拥有用户的窗口上。
-    #[deprecated(note = "适用于非系统应用。请改用 TYPE_APPLICATION_OVERLAY。")]
-    pub const TYPE_SYSTEM_ALERT: i32 = Self::FIRST_SYSTEM_WINDOW + 3;
+    /// 窗口标志：特殊标志，允许在屏幕锁定时显示窗口。这将使应用程序窗口优先于键盘保护或任何其他锁定屏幕。可以与 FLAG_KEEP_SCREEN_ON 一起使用，在显示键盘保护窗口之前直接打开屏幕并显示窗口。可以与 FLAG_DISMISS_KEYGUARD 一起使用，以自动完全关闭非安全键盘保护。此标志仅适用于最顶部的全屏窗口。
+    FLAG_SHOW_WHEN_LOCKED = 0x00080000,
 
-    /// 窗口类型：键盘保护窗口。在多用户系统中显示在所有用户的窗口上。
-    pub const TYPE_KEYGUARD: i32 = Self::FIRST_SYSTEM_WINDOW + 4;
+    /**
+    窗口标志：要求系统壁纸显示在窗口后面。窗口表面必须是半透明的，才能真正看到其后面的壁纸；此标志仅确保如果此窗口实际上有半透明区域，壁纸表面就会出现。
+    您可以通过 android.R.attr#windowShowWallpaper 属性在您的主题中控制此标志；此属性会在标准壁纸主题中自动为您设置，例如 android.R.style#Theme_Wallpaper、android.R.style#Theme_Wallpaper_NoTitleBar、android.R.style#Theme_Wallpaper_NoTitleBar_Fullscreen、android.R.style#Theme_Holo_Wallpaper、android.R.style#Theme_Holo_Wallpaper_NoTitleBar、android.R.style#Theme_DeviceDefault_Wallpaper 和 android.R.style#Theme_DeviceDefault_Wallpaper_NoTitleBar。
+    设置此标志后，发送到此窗口的所有触摸事件也会发送到壁纸，用于与动态壁纸交互。检查 LayoutParams#areWallpaperTouchEventsEnabled()，默认情况下设置为 ` `。在窗口上显示敏感信息时，如果您想禁用将触摸事件发送到壁纸，请使用 LayoutParams#setWallpaperTouchEventsEnabled(boolean)。
+    */
+    FLAG_SHOW_WALLPAPER = 0x00100000,
 
-    /// 窗口类型：瞬态通知。在多用户系统中，仅显示在拥有用户的窗口上。
-    #[deprecated(note = "适用于非系统应用。请改用 TYPE_APPLICATION_OVERLAY。")]
-    pub const TYPE_TOAST: i32 = Self::FIRST_SYSTEM_WINDOW + 5;
+    /// 窗口标志：当设置为添加窗口或使窗口可见时，一旦窗口显示出来，系统就会触发电源管理器的用户活动（就像用户唤醒了设备一样）来打开屏幕。
+    FLAG_TURN_SCREEN_ON = 0x00200000,
 
-    /// 窗口类型：系统覆盖窗口，需要显示在所有其他窗口之上。这些窗口不得获取输入焦点，否则会干扰键盘保护。在多用户系统中，仅显示在拥有用户的窗口上。
-    #[deprecated(note = "适用于非系统应用。请改用 TYPE_APPLICATION_OVERLAY。")]
-    pub const TYPE_SYSTEM_OVERLAY: i32 = Self::FIRST_SYSTEM_WINDOW + 6;
+    /// 窗口标志：设置后，仅当窗口不是安全锁定的键盘保护时，才会关闭键盘保护。由于这种键盘保护不是出于安全考虑，因此当用户导航到另一个窗口时，它将永远不会重新出现（与 FLAG_SHOW_WHEN_LOCKED 相反，FLAG_SHOW_WHEN_LOCKED 只会暂时隐藏安全和非安全键盘保护，但确保当用户移动到不隐藏它们的另一个 UI 时它们会重新出现）。如果键盘保护当前处于活动状态且是安全的（需要解锁凭证），则用户仍需要确认它才能看到此窗口，除非也设置了 FLAG_SHOW_WHEN_LOCKED。
+    FLAG_DISMISS_KEYGUARD = 0x00400000,
 
-    /// 窗口类型：优先手机 UI，即使键盘锁处于活动状态也需要显示。这些窗口不得获取输入焦点，否则会干扰键盘锁。在多用户系统中显示在所有用户的窗口上。
-    #[deprecated(note = "适用于非系统应用。请改用 TYPE_APPLICATION_OVERLAY。")]
-    pub const TYPE_PRIORITY_PHONE: i32 = Self::FIRST_SYSTEM_WINDOW + 7;
+    /// 窗口标志：设置后，窗口将接受超出其范围的触摸事件，并将其发送到也支持拆分触摸的其他窗口。如果未设置此标志，则第一个向下的指针将确定所有后续触摸将转到哪个窗口，直到所有指针都向上移动。如果设置了此标志，则每个向下的指针（不一定是第一个）将确定该指针的所有后续触摸将转到哪个窗口，直到该指针向上移动，从而允许将多个指针的触摸拆分到多个窗口。
+    FLAG_SPLIT_TOUCH = 0x00800000,
 
-    /// 窗口类型：在多用户系统中从状态栏滑出的面板显示在所有用户的窗口上。
+    /**
+    指示该窗口是否应加速硬件。请求硬件加速并不能保证它会发生。
+    此标志只能通过编程控制以启用硬件加速。要通过编程为给定窗口启用硬件加速，请执行以下操作：
+    Window w = activity.getWindow(); // 在 Activity 的 onCreate() 中
+    例如
+    w.setFlags(WindowManager.LayoutParams.FLAG_HARDWARE_ACCELERATED, WindowManager.LayoutParams.FLAG_HARDWARE_ACCELERATED);
+    重要的是要记住，必须在设置活动或对话框的内容视图之前设置此标志。
+    在使用 android.R.attr#hardwareAccelerated 在清单中启用硬件加速后，此标志不能用于禁用硬件加速。如果您需要有选择地以编程方式禁用硬件加速（例如用于自动测试），请确保在清单中将其关闭，并在需要时使用上述方法在活动或对话框中启用它。
+    如果活动或应用程序上的 android.R.attr#hardwareAccelerated android:hardwareAccelerated XML 属性设置为 true，则系统会自动设置此标志。
+    */
+    FLAG_HARDWARE_ACCELERATED = 0x01000000,
+
+    /**
+    窗口标志：允许窗口内容延伸到屏幕的过扫描区域（如果有）。窗口仍应正确定位其内容以将过扫描区域考虑在内。
+    可以通过android.R.attr#windowOverscan属性在您的主题中控制该标志;该属性是在标准过扫描主题中为您自动设置的，例如android.R.style#Theme_Holo_NoActionBar_Overscan、android.R.style#Theme_Holo_Light_NoActionBar_Overscan、android.R.style#Theme_DeviceDefault_NoActionBar_Overscan和android.R.style#Theme_DeviceDefault_Light_NoActionBar_Overscan。
+    当为窗口启用此标志时，其正常内容可能会被显示屏的过扫描区域在一定程度上遮挡。为了确保用户可以看到该内容的关键部分，您可以使用 View#setFitsSystemWindows(boolean) View.setFitsSystemWindows(boolean) 在视图层次结构中设置应应用适当偏移的点。（这可以通过直接调用此函数、使用视图层次结构中的 android.R.attr#fitsSystemWindows 属性或实现您自己的 View#fitSystemWindows(android.graphics.Rect) View.fitSystemWindows(Rect) 方法来完成）。
+    这种定位内容元素的机制与布局和 View#setSystemUiVisibility(int) View.setSystemUiVisibility(int) 的等效用法相同；这里有一个示例布局，它将在设置此过扫描标志的情况下正确定位其 UI 元素：
+    development/samples/ApiDemos/res/layout/overscan_activity.xml 完整版
+    */
+    FLAG_LAYOUT_IN_OVERSCAN = 0x02000000,
+
+    /**
+    窗口标志：请求一个半透明的状态栏，并带有系统提供的最少的背景保护。
+    您可以通过 android.R.attr#windowTranslucentStatus 属性在您的主题中控制此标志；此属性会在标准半透明装饰主题中自动为您设置，例如 android.R.style#Theme_Holo_NoActionBar_TranslucentDecor、android.R.style#Theme_Holo_Light_NoActionBar_TranslucentDecor、android.R.style#Theme_DeviceDefault_NoActionBar_TranslucentDecor 和 android.R.style#Theme_DeviceDefault_Light_NoActionBar_TranslucentDecor。
+    当为窗口启用此标志时，它会自动设置系统 UI 可见性标志 View#SYSTEM_UI_FLAG_LAYOUT_STABLE 和 View#SYSTEM_UI_FLAG_LAYOUT_FULLSCREEN。
+    注意：对于支持 android.content.pm.PackageManager#FEATURE_AUTOMOTIVE 的设备，可以忽略此标志。
+    */
+    FLAG_TRANSLUCENT_STATUS = 0x04000000,
+
+    /**
+    窗口标志：请求一个半透明的导航栏，并带有系统提供的最少的背景保护。
+    您可以通过 android.R.attr#windowTranslucentNavigation 属性在您的主题中控制此标志；此属性会在标准半透明装饰主题中自动为您设置，例如 android.R.style#Theme_Holo_NoActionBar_TranslucentDecor、android.R.style#Theme_Holo_Light_NoActionBar_TranslucentDecor、android.R.style#Theme_DeviceDefault_NoActionBar_TranslucentDecor 和 android.R.style#Theme_DeviceDefault_Light_NoActionBar_TranslucentDecor。
+    当为窗口启用此标志时，它会自动设置系统 UI 可见性标志 View#SYSTEM_UI_FLAG_LAYOUT_STABLE 和 View#SYSTEM_UI_FLAG_LAYOUT_HIDE_NAVIGATION。
+    注意：对于支持 android.content.pm.PackageManager#FEATURE_AUTOMOTIVE 的设备，汽车制造商可以禁用此标志。
+    */
+    FLAG_TRANSLUCENT_NAVIGATION = 0x08000000,
+
+    /// 本地焦点模式窗口的标志。本地焦点模式窗口可以使用 Window#setLocalFocus(boolean, boolean) 独立于窗口管理器控制焦点。通常，此模式下的窗口不会从窗口管理器获取触摸/按键事件，而只能通过使用 Window#injectInputEvent(InputEvent) 的本地注入来获取事件。
+    FLAG_LOCAL_FOCUS_MODE = 0x10000000,
+
+    /**
+    窗口标志：允许触摸在手势过程中从一个窗口滑入相邻窗口，而不是在手势持续期间被捕获。
+    此标志仅更改此窗口的触摸焦点行为。触摸可以滑出窗口但不一定滑回（除非具有触摸焦点的其他窗口允许）。
+    */
+    FLAG_SLIPPERY = 0x20000000,
+
+    /// 窗口标志：当请求使用附加窗口进行布局时，附加窗口可能会与父窗口的屏幕装饰（例如导航栏）重叠。通过包含此标志，窗口管理器将在父窗口的装饰框架内布局附加窗口，使其不与屏幕装饰重叠。
+    FLAG_LAYOUT_ATTACHED_IN_DECOR = 0x40000000,
+
+    /// 标志，指示此窗口负责绘制系统栏的背景。如果设置，系统栏将以透明背景绘制，并且此窗口中的相应区域将用Window#getStatusBarColor()和Window#getNavationBarColor()中指定的颜色填充。
+    FLAG_DRAWS_SYSTEM_BAR_BACKGROUNDS = 0x80000000,
+}
+
+#[doc(hidden)]
+#[allow(non_camel_case_types)]
+#[java_class(name = "android/view/WindowManager$LayoutParams", extends=ViewGroup_LayoutParams)]
+pub struct WindowManager_LayoutParams;
+
+impl WindowManager_LayoutParams {
+    /// 代表正常应用程序窗口的窗口类型的启动。
+    pub const FIRST_APPLICATION_WINDOW: i32 = 1;
+
+    /// 窗口类型：作为整个应用程序的“基础”窗口的应用程序窗口；所有其他应用程序窗口将显示在其上方。在多用户系统中，仅显示在拥有该应用程序的用户窗口上。
+    pub const TYPE_BASE_APPLICATION: i32 = 1;
+
+    /// 窗口类型：普通应用程序窗口。令牌必须是活动令牌，用于标识窗口所属用户。在多用户系统中，仅显示在所属用户的窗口上。
+    pub const TYPE_APPLICATION: i32 = 2;
+
+    /// 窗口类型：应用程序启动时显示的特殊应用程序窗口。应用程序本身不使用它；系统使用它来显示某些内容，直到应用程序可以显示自己的窗口。在多用户系统中，显示在所有用户的窗口上。
+    pub const TYPE_APPLICATION_STARTING: i32 = 3;
+
+    /// 窗口类型：TYPE_APPLICATION 的变体，确保窗口管理器将等待此窗口绘制完毕后再显示应用。在多用户系统中，仅显示在拥有该应用的用户窗口上。
+    pub const TYPE_DRAWN_APPLICATION: i32 = 4;
+
+    /// 申请窗口类型的结束。
+    pub const LAST_APPLICATION_WINDOW: i32 = 99;
+
+    /// 子窗口类型的开始。这些窗口的标记必须设置为它们所附加到的窗口。这些类型的窗口在 Z 顺序上紧挨着它们所附加到的窗口，并且它们的坐标空间相对于它们所附加到的窗口。
+    pub const FIRST_SUB_WINDOW: i32 = 1000;
+
+    /// 窗口类型：位于应用程序窗口顶部的面板。这些窗口出现在其附属窗口的顶部。
+    pub const TYPE_APPLICATION_PANEL: i32 = Self::FIRST_SUB_WINDOW;
+
+    /// 窗口类型：用于显示媒体（如视频）的窗口。这些窗口显示在其附属窗口的后面。
+    pub const TYPE_APPLICATION_MEDIA: i32 = Self::FIRST_SUB_WINDOW + 1;
+
+    /// 窗口类型：应用程序窗口顶部的子面板。这些窗口显示在其附属窗口和任何 TYPE_APPLICATION_PANEL 面板的顶部。
+    pub const TYPE_APPLICATION_SUB_PANEL: i32 = Self::FIRST_SUB_WINDOW + 2;
+
+    /// 窗口类型：类似于 TYPE_APPLICATION_PANEL，但窗口的布局与顶层窗口一样，而不是作为其容器的子窗口。
+    pub const TYPE_APPLICATION_ATTACHED_DIALOG: i32 = Self::FIRST_SUB_WINDOW + 3;
+
+    /// 窗口类型：用于在媒体窗口顶部显示覆盖层的窗口。这些窗口显示在 TYPE_APPLICATION_MEDIA 和应用程序窗口之间。它们应该是半透明的，以便于使用。这是一个非常丑陋的 hack，因此：
+    pub const TYPE_APPLICATION_MEDIA_OVERLAY: i32 = Self::FIRST_SUB_WINDOW + 4;
+
+    /// 窗口类型：位于应用程序窗口及其子面板窗口上方的子面板。这些窗口显示在其附属窗口和任何 TYPE_APPLICATION_SUB_PANEL 面板的上方。
+    pub const TYPE_APPLICATION_ABOVE_SUB_PANEL: i32 = Self::FIRST_SUB_WINDOW + 5;
+
+    /// 子窗口类型的结束。
+    pub const LAST_SUB_WINDOW: i32 = 1999;
+
+    /// 启动系统特定的窗口类型。这些窗口类型通常不是由应用程序创建的。
+    pub const FIRST_SYSTEM_WINDOW: i32 = 2000;
+
+    /// 窗口类型：状态栏。只能有一个状态栏窗口；它位于屏幕顶部，所有其他窗口都向下移动，因此它们位于其下方。在多用户系统中显示在所有用户的窗口上。
+    pub const TYPE_STATUS_BAR: i32 = Self::FIRST_SYSTEM_WINDOW;
+
+    /// 窗口类型：搜索栏。只能有一个搜索栏窗口；它位于屏幕顶部。在多用户系统中显示在所有用户的窗口上。
+    pub const TYPE_SEARCH_BAR: i32 = Self::FIRST_SYSTEM_WINDOW + 1;
+
+    /// 窗口类型：电话。这些是非应用程序窗口，用于为用户提供与电话的交互（尤其是来电）。这些窗口通常位于所有应用程序之上，但在状态栏后面。在多用户系统中显示在所有用户的窗口上。
+    #[deprecated(note = "适用于非系统应用。请改用 TYPE_APPLICATION_OVERLAY。")]
+    pub const TYPE_PHONE: i32 = Self::FIRST_SYSTEM_WINDOW + 2;
+
+    /// 窗口类型：系统窗口，例如低电量警报。这些窗口始终位于应用程序窗口之上。在多用户系统中，仅显示在拥有用户的窗口上。
+    #[deprecated(note = "适用于非系统应用。请改用 TYPE_APPLICATION_OVERLAY。")]
+    pub const TYPE_SYSTEM_ALERT: i32 = Self::FIRST_SYSTEM_WINDOW + 3;
+
+    /// 窗口类型：键盘保护窗口。在多用户系统中显示在所有用户的窗口上。
+    pub const TYPE_KEYGUARD: i32 = Self::FIRST_SYSTEM_WINDOW + 4;
+
+    /// 窗口类型：瞬态通知。在多用户系统中，仅显示在拥有用户的窗口上。
+    #[deprecated(note = "适用于非系统应用。请改用 TYPE_APPLICATION_OVERLAY。")]
+    pub const TYPE_TOAST: i32 = Self::FIRST_SYSTEM_WINDOW + 5;
+
+    /// 窗口类型：系统覆盖窗口，需要显示在所有其他窗口之上。这些窗口不得获取输入焦点，否则会干扰键盘保护。在多用户系统中，仅显示在拥有用户的窗口上。
+    #[deprecated(note = "适用于非系统应用。请改用 TYPE_APPLICATION_OVERLAY。")]
+    pub const TYPE_SYSTEM_OVERLAY: i32 = Self::FIRST_SYSTEM_WINDOW + 6;
+
+    /// 窗口类型：优先手机 UI，即使键盘锁处于活动状态也需要显示。这些窗口不得获取输入焦点，否则会干扰键盘锁。在多用户系统中显示在所有用户的窗口上。
+    #[deprecated(note = "适用于非系统应用。请改用 TYPE_APPLICATION_OVERLAY。")]
+    pub const TYPE_PRIORITY_PHONE: i32 = Self::FIRST_SYSTEM_WINDOW + 7;
+
+    /// 窗口类型：在多用户系统中从状态栏滑出的面板显示在所有用户的窗口上。
     pub const TYPE_SYSTEM_DIALOG: i32 = Self::FIRST_SYSTEM_WINDOW + 8;
 
     /// 窗口类型：键盘保护显示的对话框在多用户系统中显示在所有用户的窗口上。
@@ -1244,19 +1235,20 @@ impl WindowManager_LayoutParams {
     pub const MEMORY_TYPE_PUSH_BUFFERS: i32 = 3;
 
     /// 窗口标志：只要此窗口对用户可见，就允许在屏幕打开时激活锁定屏幕。这可以单独使用，也可以与 FLAG_KEEP_SCREEN_ON 和/或 FLAG_SHOW_WHEN_LOCKED 结合使用
-    pub const FLAG_ALLOW_LOCK_WHILE_SCREEN_ON: u32 = 0x00000001;
+    pub const FLAG_ALLOW_LOCK_WHILE_SCREEN_ON: WindowFlags =
+        WindowFlags::FLAG_ALLOW_LOCK_WHILE_SCREEN_ON;
 
     /// 窗口标志：此窗口后面的所有内容都将变暗。使用 dimAmount 来控制暗淡程度。
-    pub const FLAG_DIM_BEHIND: u32 = 0x00000002;
+    pub const FLAG_DIM_BEHIND: WindowFlags = WindowFlags::FLAG_DIM_BEHIND;
 
     /// 窗口标志：为该窗口启用模糊功能。
-    pub const FLAG_BLUR_BEHIND: u32 = 0x00000004;
+    pub const FLAG_BLUR_BEHIND: WindowFlags = WindowFlags::FLAG_BLUR_BEHIND;
 
     /**
     窗口标志：此窗口永远不会获得按键输入焦点，因此用户无法向其发送按键或其他按钮事件。这些事件将转至其后面的任何可获得焦点的窗口。此标志还将启用 FLAG_NOT_TOUCH_MODAL，无论是否明确设置。
     设置此标志还意味着窗口将不需要与软输入法交互，因此它将按 Z 顺序排列并独立于任何活动输入法定位（通常这意味着它在输入法之上按 Z 顺序排列，因此它可以使用全屏显示其内容并在需要时覆盖输入法。您可以使用 FLAG_ALT_FOCUSABLE_IM 来修改此行为。
     */
-    pub const FLAG_NOT_FOCUSABLE: u32 = 0x00000008;
+    pub const FLAG_NOT_FOCUSABLE: WindowFlags = WindowFlags::FLAG_NOT_FOCUSABLE;
 
     /**
     窗口标志：此窗口永远不能接收触摸事件。
@@ -1273,95 +1265,95 @@ impl WindowManager_LayoutParams {
     组合遮挡不透明度
     一组窗口的组合遮挡不透明度是通过使用结合和交换运算将该集合中所有窗口的不透明度值组合而获得的，定义为：opacity({A,B}) = 1 - (1 - opacity(A))*(1 - opacity(B))，其中 ` `) = 1 - (1 - opacity(W1)) * ... * (1 - opacity(Wn))
     */
-    pub const FLAG_NOT_TOUCHABLE: u32 = 0x00000010;
+    pub const FLAG_NOT_TOUCHABLE: WindowFlags = WindowFlags::FLAG_NOT_TOUCHABLE;
 
     /// 窗口标志：即使此窗口可聚焦（其 FLAG_NOT_FOCUSABLE 未设置），也允许将窗口外的任何指针事件发送到其后面的窗口。否则它将自己消耗所有指针事件，无论它们是否在窗口内。
-    pub const FLAG_NOT_TOUCH_MODAL: u32 = 0x00000020;
+    pub const FLAG_NOT_TOUCH_MODAL: WindowFlags = WindowFlags::FLAG_NOT_TOUCH_MODAL;
 
     /// 窗口标志：设置后，如果按下触摸屏时设备处于休眠状态，您将收到此首次触摸事件。通常，首次触摸事件会被系统消耗，因为用户看不到他们按下的是什么。
     #[deprecated(note = "该标志无效。")]
-    pub const FLAG_TOUCHABLE_WHEN_WAKING: u32 = 0x00000040;
+    pub const FLAG_TOUCHABLE_WHEN_WAKING: WindowFlags = WindowFlags::FLAG_TOUCHABLE_WHEN_WAKING;
 
     /// 窗口标志：只要此窗口对用户可见，就保持设备屏幕开启且明亮。
-    pub const FLAG_KEEP_SCREEN_ON: u32 = 0x00000080;
+    pub const FLAG_KEEP_SCREEN_ON: WindowFlags = WindowFlags::FLAG_KEEP_SCREEN_ON;
 
     /**
     附加窗口的窗口标志：将窗口放置在整个屏幕内，忽略来自父窗口的任何限制。
     注意：在具有displayCutout的显示器上，可以将窗口放置，以便在必要时根据LayoutInDisplayCutOutMode避免显示屏区域。
     */
-    pub const FLAG_LAYOUT_IN_SCREEN: u32 = 0x00000100;
+    pub const FLAG_LAYOUT_IN_SCREEN: WindowFlags = WindowFlags::FLAG_LAYOUT_IN_SCREEN;
 
     /// 窗口标志：允许窗口延伸到屏幕之外。
-    pub const FLAG_LAYOUT_NO_LIMITS: u32 = 0x00000200;
+    pub const FLAG_LAYOUT_NO_LIMITS: WindowFlags = WindowFlags::FLAG_LAYOUT_NO_LIMITS;
 
     /**
     窗口标志：显示此窗口时隐藏所有屏幕装饰（例如状态栏）。这允许窗口为自己使用整个显示空间 - 当设置了此标志的应用窗口位于顶层时，状态栏将被隐藏。全屏窗口将忽略窗口的 softInputMode 字段的 SOFT_INPUT_ADJUST_RESIZE 值；窗口将保持全屏并且不会调整大小。
     您可以通过 android.R.attr#windowFullscreen 属性在您的主题中控制此标志；此属性会在标准全屏主题中自动为您设置，例如 android.R.style#Theme_NoTitleBar_Fullscreen、android.R.style#Theme_Black_NoTitleBar_Fullscreen、android.R.style#Theme_Light_NoTitleBar_Fullscreen、android.R.style#Theme_Holo_NoActionBar_Fullscreen、android.R.style#Theme_Holo_Light_NoActionBar_Fullscreen、android.R.style#Theme_DeviceDefault_NoActionBar_Fullscreen 和 android.R.style#Theme_DeviceDefault_Light_NoActionBar_Fullscreen。
     */
     #[deprecated(note = "将WindowInsetsController#hide(int)与Type#statusBars()一起使用。")]
-    pub const FLAG_FULLSCREEN: u32 = 0x00000400;
+    pub const FLAG_FULLSCREEN: WindowFlags = WindowFlags::FLAG_FULLSCREEN;
 
     /// 窗口标志：覆盖 FLAG_FULLSCREEN 并强制显示屏幕装饰（例如状态栏）。
     #[deprecated(note = "该值“意外”成为 API，不应被第三方应用程序使用。")]
-    pub const FLAG_FORCE_NOT_FULLSCREEN: u32 = 0x00000800;
+    pub const FLAG_FORCE_NOT_FULLSCREEN: WindowFlags = WindowFlags::FLAG_FORCE_NOT_FULLSCREEN;
 
     /// 窗口标志：将此窗口合成到屏幕时启用抖动。
     #[deprecated(note = "此标志不再使用。")]
-    pub const FLAG_DITHER: u32 = 0x00001000;
+    pub const FLAG_DITHER: WindowFlags = WindowFlags::FLAG_DITHER;
 
     /**
     窗口标志：将窗口内容视为安全，防止其出现在屏幕截图中或在非安全显示器上查看。
     有关安全表面和安全显示的更多详细信息，请参阅 android.view.Display#FLAG_SECURE。
     */
-    pub const FLAG_SECURE: u32 = 0x00002000;
+    pub const FLAG_SECURE: WindowFlags = WindowFlags::FLAG_SECURE;
 
     /// 窗口标志：一种特殊模式，其中布局参数用于在表面合成到屏幕时执行表面的缩放。
-    pub const FLAG_SCALED: u32 = 0x00004000;
+    pub const FLAG_SCALED: WindowFlags = WindowFlags::FLAG_SCALED;
 
     /// 窗口标志：用于用户将屏幕贴在脸上时经常使用的窗口，它将积极过滤事件流以防止在这种情况下意外按压（对于特定窗口可能不是所需的），当检测到这样的事件流时，应用程序将收到一个 CANCEL 运动事件来指示这一点，因此应用程序可以通过在手指释放之前不对事件采取任何操作来相应地处理此问题。
-    pub const FLAG_IGNORE_CHEEK_PRESSES: u32 = 0x00008000;
+    pub const FLAG_IGNORE_CHEEK_PRESSES: WindowFlags = WindowFlags::FLAG_IGNORE_CHEEK_PRESSES;
 
     /// 窗口标志：一个特殊选项，仅与 FLAG_LAYOUT_IN_SCREEN 结合使用。当请求在屏幕上布局时，您的窗口可能会出现在屏幕装饰（例如状态栏）的上方或后面。通过同时包含此标志，窗口管理器将报告所需的插入矩形，以确保您的内容不会被屏幕装饰覆盖。此标志通常由 Window 为您设置，如 Window#setFlags 中所述
     #[deprecated(note = "插图将始终传送到您的应用程序。")]
-    pub const FLAG_LAYOUT_INSET_DECOR: u32 = 0x00010000;
+    pub const FLAG_LAYOUT_INSET_DECOR: WindowFlags = WindowFlags::FLAG_LAYOUT_INSET_DECOR;
 
     /**
     窗口标志：设置后，反转窗口的输入法可聚焦性。
     设置此标志的效果取决于是否设置了 FLAG_NOT_FOCUSABLE：如果未设置 FLAG_NOT_FOCUSABLE，即当窗口可聚焦时，设置此标志将阻止此窗口成为输入法的目标。因此，它将无法与输入法交互，并将位于输入法之上（除非其上方有另一个输入法目标）。
     如果设置了 FLAG_NOT_FOCUSABLE，则设置此标志会要求窗口成为输入法目标，即使窗口无法聚焦。因此，它将位于输入法之下。注意：设置了 FLAG_NOT_FOCUSABLE 的窗口无法与输入法交互，无论此标志如何。
     */
-    pub const FLAG_ALT_FOCUSABLE_IM: u32 = 0x00020000;
+    pub const FLAG_ALT_FOCUSABLE_IM: WindowFlags = WindowFlags::FLAG_ALT_FOCUSABLE_IM;
 
     /// 窗口标志：如果您已设置 FLAG_NOT_TOUCH_MODAL，则可以设置此标志以接收单个特殊 MotionEvent，其动作为 MotionEvent#ACTION_OUTSIDE MotionEvent.ACTION_OUTSIDE，用于发生在窗口外的触摸。请注意，您不会收到完整的向下/移动/向上手势，只会收到第一个向下的位置作为 ACTION_OUTSIDE。
-    pub const FLAG_WATCH_OUTSIDE_TOUCH: u32 = 0x00040000;
+    pub const FLAG_WATCH_OUTSIDE_TOUCH: WindowFlags = WindowFlags::FLAG_WATCH_OUTSIDE_TOUCH;
 
     /// 窗口标志：特殊标志，允许在屏幕锁定时显示窗口。这将使应用程序窗口优先于键盘保护或任何其他锁定屏幕。可以与 FLAG_KEEP_SCREEN_ON 一起使用，在显示键盘保护窗口之前直接打开屏幕并显示窗口。可以与 FLAG_DISMISS_KEYGUARD 一起使用，以自动完全关闭非安全键盘保护。此标志仅适用于最顶部的全屏窗口。
     #[deprecated(
         note = "请使用 android.R.attr#showWhenLocked 或 android.app.Activity#setShowWhenLocked(boolean) 来防止意外的双重生命周期事件。"
     )]
-    pub const FLAG_SHOW_WHEN_LOCKED: u32 = 0x00080000;
+    pub const FLAG_SHOW_WHEN_LOCKED: WindowFlags = WindowFlags::FLAG_SHOW_WHEN_LOCKED;
 
     /**
     窗口标志：要求系统壁纸显示在窗口后面。窗口表面必须是半透明的，才能真正看到其后面的壁纸；此标志仅确保如果此窗口实际上有半透明区域，壁纸表面就会出现。
     您可以通过 android.R.attr#windowShowWallpaper 属性在您的主题中控制此标志；此属性会在标准壁纸主题中自动为您设置，例如 android.R.style#Theme_Wallpaper、android.R.style#Theme_Wallpaper_NoTitleBar、android.R.style#Theme_Wallpaper_NoTitleBar_Fullscreen、android.R.style#Theme_Holo_Wallpaper、android.R.style#Theme_Holo_Wallpaper_NoTitleBar、android.R.style#Theme_DeviceDefault_Wallpaper 和 android.R.style#Theme_DeviceDefault_Wallpaper_NoTitleBar。
     设置此标志后，发送到此窗口的所有触摸事件也会发送到壁纸，用于与动态壁纸交互。检查 LayoutParams#areWallpaperTouchEventsEnabled()，默认情况下设置为 ` `。在窗口上显示敏感信息时，如果您想禁用将触摸事件发送到壁纸，请使用 LayoutParams#setWallpaperTouchEventsEnabled(boolean)。
     */
-    pub const FLAG_SHOW_WALLPAPER: u32 = 0x00100000;
+    pub const FLAG_SHOW_WALLPAPER: WindowFlags = WindowFlags::FLAG_SHOW_WALLPAPER;
 
     /// 窗口标志：当设置为添加窗口或使窗口可见时，一旦窗口显示出来，系统就会触发电源管理器的用户活动（就像用户唤醒了设备一样）来打开屏幕。
     #[deprecated(
         note = "请使用 android.R.attr#turnScreenOn 或 android.app.Activity#setTurnScreenOn(boolean) 来防止意外的双重生命周期事件。"
     )]
-    pub const FLAG_TURN_SCREEN_ON: u32 = 0x00200000;
+    pub const FLAG_TURN_SCREEN_ON: WindowFlags = WindowFlags::FLAG_TURN_SCREEN_ON;
 
     /// 窗口标志：设置后，仅当窗口不是安全锁定的键盘保护时，才会关闭键盘保护。由于这种键盘保护不是出于安全考虑，因此当用户导航到另一个窗口时，它将永远不会重新出现（与 FLAG_SHOW_WHEN_LOCKED 相反，FLAG_SHOW_WHEN_LOCKED 只会暂时隐藏安全和非安全键盘保护，但确保当用户移动到不隐藏它们的另一个 UI 时它们会重新出现）。如果键盘保护当前处于活动状态且是安全的（需要解锁凭证），则用户仍需要确认它才能看到此窗口，除非也设置了 FLAG_SHOW_WHEN_LOCKED。
     #[deprecated(
         note = "改用 FLAG_SHOW_WHEN_LOCKED 或 KeyguardManager#requestDismissKeyguard。由于只要窗口上带有此标志的活动处于焦点状态，keyguard 就会一直被关闭，因此 keyguard 无法防止意外触摸屏幕，这是不希望的。"
     )]
-    pub const FLAG_DISMISS_KEYGUARD: u32 = 0x00400000;
+    pub const FLAG_DISMISS_KEYGUARD: WindowFlags = WindowFlags::FLAG_DISMISS_KEYGUARD;
 
     /// 窗口标志：设置后，窗口将接受超出其范围的触摸事件，并将其发送到也支持拆分触摸的其他窗口。如果未设置此标志，则第一个向下的指针将确定所有后续触摸将转到哪个窗口，直到所有指针都向上移动。如果设置了此标志，则每个向下的指针（不一定是第一个）将确定该指针的所有后续触摸将转到哪个窗口，直到该指针向上移动，从而允许将多个指针的触摸拆分到多个窗口。
-    pub const FLAG_SPLIT_TOUCH: u32 = 0x00800000;
+    pub const FLAG_SPLIT_TOUCH: WindowFlags = WindowFlags::FLAG_SPLIT_TOUCH;
 
     /**
     指示该窗口是否应加速硬件。请求硬件加速并不能保证它会发生。
@@ -1373,7 +1365,7 @@ impl WindowManager_LayoutParams {
     在使用 android.R.attr#hardwareAccelerated 在清单中启用硬件加速后，此标志不能用于禁用硬件加速。如果您需要有选择地以编程方式禁用硬件加速（例如用于自动测试），请确保在清单中将其关闭，并在需要时使用上述方法在活动或对话框中启用它。
     如果活动或应用程序上的 android.R.attr#hardwareAccelerated android:hardwareAccelerated XML 属性设置为 true，则系统会自动设置此标志。
     */
-    pub const FLAG_HARDWARE_ACCELERATED: u32 = 0x01000000;
+    pub const FLAG_HARDWARE_ACCELERATED: WindowFlags = WindowFlags::FLAG_HARDWARE_ACCELERATED;
 
     //noinspection SpellCheckingInspection
     /**
@@ -1384,7 +1376,7 @@ impl WindowManager_LayoutParams {
     development/samples/ApiDemos/res/layout/overscan_activity.xml 完整版
     */
     #[deprecated(note = "从Android 11开始，任何Android产品都不再设置过范围的区域。")]
-    pub const FLAG_LAYOUT_IN_OVERSCAN: u32 = 0x02000000;
+    pub const FLAG_LAYOUT_IN_OVERSCAN: WindowFlags = WindowFlags::FLAG_LAYOUT_IN_OVERSCAN;
 
     /**
     窗口标志：请求一个半透明的状态栏，并带有系统提供的最少的背景保护。
@@ -1393,7 +1385,7 @@ impl WindowManager_LayoutParams {
     注意：对于支持 android.content.pm.PackageManager#FEATURE_AUTOMOTIVE 的设备，可以忽略此标志。
     */
     #[deprecated(note = "改用半透明颜色的 Window#setStatusBarColor(int)。")]
-    pub const FLAG_TRANSLUCENT_STATUS: u32 = 0x04000000;
+    pub const FLAG_TRANSLUCENT_STATUS: WindowFlags = WindowFlags::FLAG_TRANSLUCENT_STATUS;
 
     /**
     窗口标志：请求一个半透明的导航栏，并带有系统提供的最少的背景保护。
@@ -1402,23 +1394,25 @@ impl WindowManager_LayoutParams {
     注意：对于支持 android.content.pm.PackageManager#FEATURE_AUTOMOTIVE 的设备，汽车制造商可以禁用此标志。
     */
     #[deprecated(note = "改用半透明颜色的 Window#setNavigationBarColor(int)。")]
-    pub const FLAG_TRANSLUCENT_NAVIGATION: u32 = 0x08000000;
+    pub const FLAG_TRANSLUCENT_NAVIGATION: WindowFlags = WindowFlags::FLAG_TRANSLUCENT_NAVIGATION;
 
     /// 本地焦点模式窗口的标志。本地焦点模式窗口可以使用 Window#setLocalFocus(boolean, boolean) 独立于窗口管理器控制焦点。通常，此模式下的窗口不会从窗口管理器获取触摸/按键事件，而只能通过使用 Window#injectInputEvent(InputEvent) 的本地注入来获取事件。
-    pub const FLAG_LOCAL_FOCUS_MODE: u32 = 0x10000000;
+    pub const FLAG_LOCAL_FOCUS_MODE: WindowFlags = WindowFlags::FLAG_LOCAL_FOCUS_MODE;
 
     /**
     窗口标志：允许触摸在手势过程中从一个窗口滑入相邻窗口，而不是在手势持续期间被捕获。
     此标志仅更改此窗口的触摸焦点行为。触摸可以滑出窗口但不一定滑回（除非具有触摸焦点的其他窗口允许）。
     */
-    pub const FLAG_SLIPPERY: u32 = 0x20000000;
+    pub const FLAG_SLIPPERY: WindowFlags = WindowFlags::FLAG_SLIPPERY;
 
     /// 窗口标志：当请求使用附加窗口进行布局时，附加窗口可能会与父窗口的屏幕装饰（例如导航栏）重叠。通过包含此标志，窗口管理器将在父窗口的装饰框架内布局附加窗口，使其不与屏幕装饰重叠。
     #[deprecated(note = "使用 setFitInsetsTypes(int) 来确定附加窗口是否与系统栏重叠。")]
-    pub const FLAG_LAYOUT_ATTACHED_IN_DECOR: u32 = 0x40000000;
+    pub const FLAG_LAYOUT_ATTACHED_IN_DECOR: WindowFlags =
+        WindowFlags::FLAG_LAYOUT_ATTACHED_IN_DECOR;
 
     /// 标志，指示此窗口负责绘制系统栏的背景。如果设置，系统栏将以透明背景绘制，并且此窗口中的相应区域将用Window#getStatusBarColor()和Window#getNavationBarColor()中指定的颜色填充。
-    pub const FLAG_DRAWS_SYSTEM_BAR_BACKGROUNDS: u32 = 0x80000000;
+    pub const FLAG_DRAWS_SYSTEM_BAR_BACKGROUNDS: WindowFlags =
+        WindowFlags::FLAG_DRAWS_SYSTEM_BAR_BACKGROUNDS;
 
     /// 在系统进程中，我们全局不使用硬件加速，因为那里有许多线程在处理 UI，它们之间会发生冲突。如果 UI 的某些部分确实需要使用硬件加速，可以设置此标志来强制使用。这基本上是针对锁屏的。如果其他人也使用它，那么你可能错了。
     pub const PRIVATE_FLAG_FORCE_HARDWARE_ACCELERATED: i32 = 1 << 1;
@@ -1770,11 +1764,11 @@ impl WindowManager_LayoutParams {
 
     /// 各种行为选项/标志。默认为无。
     #[java_field]
-    pub fn get_flags(&self) -> u32 {}
+    pub fn get_flags(&self) -> WindowFlags {}
 
     /// 各种行为选项/标志。默认为无。
     #[java_field]
-    pub fn set_flags(&self, value: u32) {}
+    pub fn set_flags(&self, value: WindowFlags) {}
 
     /// 控制平台私有的标志。
     #[java_field]
@@ -2020,7 +2014,7 @@ impl WindowManager_LayoutParams {
 
     /// 窗口标题与标题栏中显示的内容不同步，因此我们单独跟踪当前显示的标题以提供可访问性。
     #[java_field]
-    pub fn get_accessibility_title<CS: CharSequence>(&self) -> CS {}
+    pub fn get_accessibility_title(&self) -> CharSequenceImpl {}
 
     /// 窗口标题与标题栏中显示的内容不同步，因此我们单独跟踪当前显示的标题以提供可访问性。
     #[java_field]
@@ -2139,7 +2133,7 @@ impl WindowManager_LayoutParams {
     pub fn set_title<CS: CharSequence>(&self, title: Option<CS>) {}
 
     #[java_method]
-    pub fn get_title<CS: CharSequence>(&self) -> Option<CS> {}
+    pub fn get_title(&self) -> Option<CharSequenceImpl> {}
 
     /**
     根据输入视图的高度（视觉 z 位置）设置表面插图。
@@ -2534,241 +2528,1269 @@ impl Display {
     #[java_method]
     pub fn clear_user_preferred_display_mode(&self) {}
 
-    /**
-    返回此显示器是否可用于显示广色域内容。这并不一定意味着设备本身可以渲染广色域内容。要确保可以生成广色域内容，请参阅 Configuration.isScreenWideColorGamut()。
-    */
-    #[java_method]
-    pub fn is_wide_color_gamut(&self) -> bool {}
+    /**
+    返回此显示器是否可用于显示广色域内容。这并不一定意味着设备本身可以渲染广色域内容。要确保可以生成广色域内容，请参阅 Configuration.isScreenWideColorGamut()。
+    */
+    #[java_method]
+    pub fn is_wide_color_gamut(&self) -> bool {}
+
+    /**
+    获取应用 VSYNC 偏移量（以纳秒为单位）。这是一个正值，表示 Choreographer 提供的 VSYNC 事件相对于显示刷新的相位偏移量。
+    例如，如果 Choreographer 报告刷新发生在时间 N，则它实际上发生在 (N - appVsyncOffset)。应用通常不需要知道这一点。它仅适用于细粒度的 A/V 同步。
+    */
+    #[java_method]
+    pub fn get_app_vsync_offset_nanos(&self) -> i64 {}
+
+    //noinspection SpellCheckingInspection
+    /**
+    这是缓冲区必须提前多久排队等待在给定时间进行演示。如果您希望缓冲区在时间 N 出现在屏幕上，则必须在 (N - presentationDeadline) 之前提交缓冲区。
+    可以使用 android.opengl.EGLExt.eglPresentationTimeANDROID 设置 GLES 渲染所需的演示时间。对于视频解码，请使用 android.media.MediaCodec.releaseOutputBuffer(int, long)。时间以纳秒为单位表示，使用系统单调时钟 (System.nanoTime)。
+    */
+    #[java_method]
+    pub fn get_presentation_deadline_nanos(&self) -> i64 {}
+
+    /**
+    确定是否应将 WindowConfiguration.getMaxBounds() 报告为显示尺寸。当应用需要沙盒化时，最大边界字段可能小于逻辑尺寸。取决于 com.android.server.wm.ConfigurationContainer.providesMaxBounds() 中设置的 WindowConfiguration.getMaxBounds()。
+    在大多数情况下，此值反映当前 DisplayArea 的大小。当应应用最大边界时，返回 true。
+    */
+    #[java_method]
+    pub fn should_report_max_bounds(&self) -> bool {}
+
+    /**
+    获取显示器的状态，例如是打开还是关闭。
+    返回：显示器的状态：STATE_OFF、STATE_ON、STATE_DOZE、STATE_DOZE_SUSPEND、STATE_ON_SUSPEND 或 STATE_UNKNOWN 之一。
+    */
+    #[java_method]
+    pub fn get_state(&self) -> i32 {}
+
+    /**
+    如果指定的 UID 有权访问此显示，则返回 true。
+    `uid` UID。
+    */
+    #[java_method]
+    pub fn has_access(&self, uid: i32) -> bool {}
+
+    /// 如果显示是公共演示文稿显示，则返回true。
+    #[java_method]
+    pub fn is_public_presentation(&self) -> bool {}
+
+    /// 如果显示器是受信任的显示器，则为 true。
+    #[java_method]
+    pub fn is_trusted(&self) -> bool {}
+
+    /// 如果显示器可以从另一个显示器窃取顶部焦点，则为 true。
+    #[java_method]
+    pub fn can_steal_top_focus(&self) -> bool {}
+
+    #[doc(hidden)]
+    #[java_method]
+    pub fn type_to_string(r#type: i32) -> String {}
+
+    #[doc(hidden)]
+    #[java_method]
+    pub fn state_to_string(state: i32) -> String {}
+
+    /**
+    如果在指定的显示器电源状态下可以暂停显示更新，则返回 true。在 SUSPEND 状态下，绝对禁止更新。
+    `state` 状态。
+    */
+    #[java_method]
+    pub fn is_suspended_state(state: i32) -> bool {}
+
+    /**
+    如果在指定的显示器电源状态下显示器可能处于降低的操作模式，则返回 true。
+    `state` 状态。
+    */
+    #[java_method]
+    pub fn is_doze_state(state: i32) {}
+
+    /**
+    如果显示器处于活动状态（例如 STATE_ON 或 STATE_VR），则返回 true。
+    `state` 状态。
+    */
+    #[java_method]
+    pub fn is_active_state(state: i32) {}
+
+    /**
+    如果显示器处于关闭状态（例如 STATE_OFF），则返回 true。
+    `state` 状态。
+    */
+    #[java_method]
+    pub fn is_off_state(state: i32) -> bool {}
+
+    /**
+    如果显示器处于开启状态（例如 STATE_ON 或 STATE_VR 或 STATE_ON_SUSPEND），则返回 true。
+    `state` 状态。
+    */
+    #[java_method]
+    pub fn is_on_state(state: i32) -> bool {}
+
+    /**
+    如果指定的宽度有效，则返回 true。
+    `width` 宽度。
+    */
+    #[java_method]
+    pub fn is_width_valid(width: i32) -> bool {}
+
+    /**
+    如果指定的高度有效，则返回 true。
+    `height` 高度。
+    */
+    #[java_method]
+    pub fn is_height_valid(height: i32) -> bool {}
+
+    /**
+    如果指定的刷新率有效，则返回 true。
+    `refresh_rate` 刷新率。
+    */
+    #[java_method]
+    pub fn is_refresh_rate_valid(refresh_rate: f32) -> bool {}
+}
+
+/// 输入事件的通用基类。
+#[java_class(name = "android/view/InputEvent")]
+pub struct InputEvent;
+
+impl InputEvent {
+    /**
+    获取此事件所来自设备的 ID。ID 为零表示事件不是来自物理设备并映射到默认键盘映射。其他数字是任意的，您不应该依赖这些值。
+    返回：设备 ID。
+    */
+    #[java_method]
+    pub fn get_device_id(&self) -> i32 {}
+
+    /**
+    获取事件源。
+    返回：事件源或输入设备。如果未知，则返回 SOURCE_UNKNOWN。
+    */
+    #[java_method]
+    pub fn get_source(&self) -> i32 {}
+
+    /**
+    修改事件的来源。
+    `source` 新来源。
+    */
+    #[java_method]
+    pub fn set_source(&self, source: i32) {}
+
+    /**
+    确定事件是否来自给定的源。
+    返回：事件是否来自给定的源。
+    `source` 要检查的输入源。这可以是特定的设备类型，例如 InputDevice.SOURCE_TOUCH_NAVIGATION，也可以是更通用的设备类，例如 InputDevice.SOURCE_CLASS_POINTER。
+    */
+    #[java_method]
+    pub fn is_from_source(&self, source: i32) -> bool {}
+
+    /**
+    获取事件的显示 ID。
+    返回：与事件关联的显示 ID。
+    */
+    #[java_method]
+    pub fn get_display_id(&self) -> i32 {}
+
+    /**
+    修改与事件关联的显示 ID
+    `display_id`
+    */
+    #[java_method]
+    pub fn set_display_id(&self, display_id: i32) {}
+
+    /**
+    回收事件。此方法仅应由系统使用，因为应用程序不希望回收 KeyEvent 对象，但可以回收 MotionEvent 对象。
+    有关详细信息，请参阅 KeyEvent.recycle()。
+    */
+    #[java_method]
+    pub fn recycle(&self) {}
+
+    /**
+    在将事件分发给应用程序后，如果合适，则有条件地回收事件。如果事件是 MotionEvent，则回收该事件。如果事件是 KeyEvent，则不回收该事件，因为应用程序希望按键事件是不可变的，因此一旦将事件分发给应用程序，我们就不能再回收它了。
+    */
+    #[java_method]
+    pub fn recycle_if_needed_after_dispatch(&self) {}
+
+    /**
+    获取一个私有标志，该标志指示系统何时检测到此输入事件可能与先前传递的输入事件的序列不一致，例如，当发送了按键释放事件但按键未按下时，或者当发送了指针移动事件但指针未按下时。
+    返回：如果此事件被污染，则返回 True。
+    */
+    #[java_method]
+    pub fn is_tainted(&self) -> bool {}
+
+    /**
+    设置一个私有标志，指示系统何时检测到此输入事件可能与先前传递的输入事件的序列不一致，例如，当发送了按键释放事件但按键未按下时，或者当发送了指针移动事件但指针未按下时。
+    `tainted` 如果此事件被污染，则为 True。
+    */
+    #[java_method]
+    pub fn set_tainted(&self, tainted: bool) {}
+
+    /**
+    以 android.os.SystemClock.uptimeMillis 时间基准查询此事件发生的时间。
+    返回：以 android.os.SystemClock.uptimeMillis 时间基准返回此事件发生的时间。
+    */
+    #[java_method]
+    pub fn get_event_time(&self) -> i64 {}
+
+    /**
+    查询此事件发生的时间，以 android.os.SystemClock.uptimeMillis 时间为基础，但精度为纳秒（而不是毫秒）。该值以纳秒为精度，但可能不具有纳秒的精度。
+    返回：返回此事件发生的时间，以 android.os.SystemClock.uptimeMillis 时间为基础，但精度为纳秒（而不是毫秒）。
+    */
+    #[java_method]
+    pub fn get_event_time_nanos(&self) -> i64 {}
+
+    /**
+    将输入事件标记为已取消。
+    */
+    #[java_method]
+    pub fn cancel(&self) {}
+
+    /**
+    获取此事件的唯一序列号。进程创建或接收的每个输入事件都有唯一的序列号。此外，每次回收事件对象时都会获得一个新的序列号。序列号仅保证在进程内本地唯一。打包事件时不会保留序列号。
+    返回：此事件的唯一序列号。
+    */
+    #[java_method]
+    pub fn get_sequence_number(&self) -> i32 {}
+
+    //noinspection SpellCheckingInspection
+    /**
+    获取此事件的 ID。此 ID 在事件创建时生成，并保存到事件的最后阶段。它不会因为事件跨越进程边界而改变，但在进行修改复制时应该会改变。为了避免将应用程序使用情况暴露给其他进程，此 ID 由 CSPRNG 生成。因此，虽然 ID 冲突的可能性相当低，但不能 100% 保证此 ID 的唯一性。经验法则是不要依赖生产逻辑的唯一性，而要依赖跟踪事件（例如日志记录和分析）的良好来源。
+    返回：此事件的 ID。
+    */
+    #[java_method]
+    pub fn get_id(&self) -> i32 {}
+
+    #[doc(hidden)]
+    #[java_method]
+    pub fn describe_contents(&self) -> i32 {}
+}
+
+//noinspection SpellCheckingInspection
+/// 按键事件的键码，即 KeyEvent 中的 KEYCODE_* 常量。
+#[java_constants(prefix = "KEYCODE_")]
+pub enum KeyCode {
+    /// 键码常量：未知键码。
+    #[java_name = "KEYCODE_UNKNOWN"]
+    UnknownKey = 0,
+
+    /// 键码常量：软左键。通常位于手机显示屏下方，用作多功能特征键，用于选择显示在显示屏左下角的软件定义功能。
+    SoftLeft = 1,
+
+    /// 键码常量：软右键。通常位于手机显示屏下方，用作多功能特征键，用于选择显示在显示屏右下角的软件定义功能。
+    SoftRight = 2,
+
+    /// 键码常量：主页键。此键由框架处理，永远不会传递给应用程序。
+    Home = 3,
+
+    /// 键码常量：返回键。
+    Back = 4,
+
+    /// 键码常量：呼叫键。
+    Call = 5,
+
+    /// 键码常量：结束呼叫键。
+    Endcall = 6,
+
+    /// 键码常量：'0'键。
+    #[java_name = "KEYCODE_0"]
+    Key0 = 7,
+
+    /// 键码常量：'1'键。
+    #[java_name = "KEYCODE_1"]
+    Key1 = 8,
+
+    /// 键码常量：'2'键。
+    #[java_name = "KEYCODE_2"]
+    Key2 = 9,
+
+    /// 键码常量：'3'键。
+    #[java_name = "KEYCODE_3"]
+    Key3 = 10,
+
+    /// 键码常量：'4'键。
+    #[java_name = "KEYCODE_4"]
+    Key4 = 11,
+
+    /// 键码常量：'5'键。
+    #[java_name = "KEYCODE_5"]
+    Key5 = 12,
+
+    /// 键码常量：'6'键。
+    #[java_name = "KEYCODE_6"]
+    Key6 = 13,
+
+    /// 键码常量：'7'键。
+    #[java_name = "KEYCODE_7"]
+    Key7 = 14,
+
+    /// 键码常量：'8'键。
+    #[java_name = "KEYCODE_8"]
+    Key8 = 15,
+
+    /// 键码常量：'9'键。
+    #[java_name = "KEYCODE_9"]
+    Key9 = 16,
+
+    /// 键码常量：'*'键。
+    Star = 17,
+
+    /// 键码常量：'#'键。
+    Pound = 18,
+
+    /// 键码常量：方向键向上键。也可以从轨迹球运动合成。
+    DpadUp = 19,
+
+    /// 键码常量：方向键向下键。也可以从轨迹球运动合成。
+    DpadDown = 20,
+
+    /// 键码常量：方向键左键。也可以从轨迹球运动合成。
+    DpadLeft = 21,
+
+    /// 键码常量：方向键右键。也可以从轨迹球运动合成。
+    DpadRight = 22,
+
+    /// 键码常量：方向键中心键。也可以从轨迹球运动合成。
+    DpadCenter = 23,
+
+    /// 键码常量：音量向上键。调整扬声器音量。
+    VolumeUp = 24,
+
+    /// 键码常量：音量减键。调整扬声器音量减小。
+    VolumeDown = 25,
+
+    /// 键码常量：电源键。
+    Power = 26,
+
+    /// 键码常量：相机键。用于启动相机应用程序或拍照。
+    Camera = 27,
+
+    /// 键码常量：清除键。
+    Clear = 28,
+
+    /// 键码常量：'A'键。
+    A = 29,
+
+    /// 键码常量：'B'键。
+    B = 30,
+
+    /// 键码常量：'C'键。
+    C = 31,
+
+    /// 键码常量：'D'键。
+    D = 32,
+
+    /// 键码常量：'E'键。
+    E = 33,
+
+    /// 键码常量：'F'键。
+    F = 34,
+
+    /// 键码常量：'G'键。
+    G = 35,
+
+    /// 键码常量：'H'键。
+    H = 36,
+
+    /// 键码常量：'I'键。
+    I = 37,
+
+    /// 键码常量：'J'键。
+    J = 38,
+
+    /// 键码常量：'K'键。
+    K = 39,
+
+    /// 键码常量：'L'键。
+    L = 40,
+
+    /// 键码常量：'M'键。
+    M = 41,
+
+    /// 键码常量：'N'键。
+    N = 42,
+
+    /// 键码常量：'O'键。
+    O = 43,
+
+    /// 键码常量：'P'键。
+    P = 44,
+
+    /// 键码常量：'Q'键。
+    Q = 45,
+
+    /// 键码常量：'R'键。
+    R = 46,
+
+    /// 键码常量：'S'键。
+    S = 47,
+
+    /// 键码常量：'T'键。
+    T = 48,
+
+    /// 键码常量：'U'键。
+    U = 49,
+
+    /// 键码常量：'V'键。
+    V = 50,
+
+    /// 键码常量：'W'键。
+    W = 51,
+
+    /// 键码常量：'X'键。
+    X = 52,
+
+    /// 键码常量：'Y'键。
+    Y = 53,
+
+    /// 键码常量：'Z'键。
+    Z = 54,
+
+    /// 键码常量：','键。
+    Comma = 55,
+
+    /// 键码常量：'.'键。
+    Period = 56,
+
+    /// 键码常量：左Alt修饰键。
+    AltLeft = 57,
+
+    /// 键码常量：右Alt修饰键。
+    AltRight = 58,
+
+    /// 键码常量：左Shift修饰键。
+    ShiftLeft = 59,
+
+    /// 键码常量：右Shift修饰键。
+    ShiftRight = 60,
+
+    /// 键码常量：Tab键。
+    Tab = 61,
+
+    /// 键码常量：空格键。
+    Space = 62,
+
+    /// 键码常量：符号修饰键。用于输入替代符号。
+    Sym = 63,
+
+    /// 键码常量：资源管理器特殊功能键。用于启动浏览器应用程序。
+    Explorer = 64,
+
+    /// 键码常量：信封特殊功能键。用于启动邮件应用程序。
+    Envelope = 65,
+
+    /// 键码常量：Enter键。
+    Enter = 66,
+
+    /// 键码常量：退格键。删除插入点前的字符，与KEYCODE_FORWARD_DEL不同。
+    Del = 67,
+
+    /// 键码常量：'`'（反引号）键。
+    Grave = 68,
+
+    /// 键码常量：'-'。
+    Minus = 69,
+
+    /// 键码常量：'='键。
+    Equals = 70,
+
+    /// 键码常量：'[' 键。
+    LeftBracket = 71,
+
+    /// 键码常量：']'键。
+    RightBracket = 72,
+
+    /// 键码常量：'\'键。
+    Backslash = 73,
+
+    /// 键码常量：';'键。
+    Semicolon = 74,
+
+    /// 键码常量：'''（单引号）键。
+    Apostrophe = 75,
+
+    /// 键码常量：'/'键。
+    Slash = 76,
+
+    /// 键码常量：'@'键。
+    At = 77,
+
+    /// 键码常量：数字修饰键。用于输入数字符号。这个键不是Num Lock；它更像是KEYCODE_ALT_LEFT，并被android.text.method.MetaKeyKeyListener解释为ALT键。
+    Num = 78,
+
+    /// 键码常量：耳机钩键。用于挂断电话并停止媒体。
+    Headsethook = 79,
+
+    /// 键码常量：相机对焦键。用于对焦相机。
+    Focus = 80,
+
+    /// 键码常量：'+'键。
+    Plus = 81,
+
+    /// 键码常量：菜单键。
+    Menu = 82,
+
+    /// 键码常量：通知键。
+    Notification = 83,
+
+    /// 键码常量：搜索键。
+    Search = 84,
+
+    /// 键码常量：媒体播放/暂停键。
+    MediaPlayPause = 85,
+
+    /// 键码常量：媒体停止键。
+    MediaStop = 86,
+
+    /// 键码常量：媒体播放下一曲键。
+    MediaNext = 87,
+
+    /// 键码常量：媒体播放上一曲键。
+    MediaPrevious = 88,
+
+    /// 键码常量：媒体倒带键。
+    MediaRewind = 89,
+
+    /// 键码常量：媒体快进键。
+    MediaFastForward = 90,
+
+    /// 键码常量：静音键。用于麦克风的静音键（不同于KEYCODE_VOLUME_MUTE，那是扬声器静音键）。
+    Mute = 91,
+
+    /// 键码常量：Page Up键。
+    PageUp = 92,
+
+    /// 键码常量：Page Down键。
+    PageDown = 93,
+
+    /// 键码常量：图片符号修饰键。用于切换符号集（表情符号、颜文字）。
+    Pictsymbols = 94,
+
+    /// 切换符号集（表情符号、颜文字）
+    /// 键码常量：切换字符集修饰键。用于切换字符集（汉字、片假名）。
+    SwitchCharset = 95,
+
+    /// 键码常量：A按钮键。在游戏控制器上，A按钮应为标有A的按钮或控制器按钮底部行的第一个按钮。
+    ButtonA = 96,
+
+    /// 键码常量：B按钮键。在游戏控制器上，B按钮应为标有B的按钮或控制器按钮底部行的第二个按钮。
+    ButtonB = 97,
+
+    /// 键码常量：C按钮键。在游戏控制器上，C按钮应为标有C的按钮或控制器按钮底部行的第三个按钮。
+    ButtonC = 98,
+
+    /// 键码常量：X按钮键。在游戏控制器上，X按钮应为标有X的按钮或控制器按钮顶部行的第一个按钮。
+    ButtonX = 99,
+
+    /// 键码常量：Y按钮键。在游戏控制器上，Y按钮应为标有Y的按钮或控制器按钮顶部行的第二个按钮。
+    ButtonY = 100,
+
+    /// 键码常量：Z按钮键。在游戏控制器上，Z按钮应为标有Z的按钮或控制器按钮顶部行的第三个按钮。
+    ButtonZ = 101,
+
+    /// 键码常量：L1按钮键。在游戏控制器上，L1按钮应为标有L1（或L）的按钮或左上角的触发器按钮。
+    ButtonL1 = 102,
+
+    /// 键码常量：R1按钮键。在游戏控制器上，R1按钮应为标有R1（或R）的按钮或右上角的触发器按钮。
+    ButtonR1 = 103,
+
+    /// 键码常量：L2按钮键。在游戏控制器上，L2按钮应为标有L2的按钮或左下角的触发器按钮。
+    ButtonL2 = 104,
+
+    /// 键码常量：左拇指按钮键。在游戏控制器上，左拇指按钮表示按下左（或唯一）操纵杆。
+    ButtonThumbl = 106,
+
+    /// 键码常量：右拇指按钮键。在游戏控制器上，右拇指按钮表示按下了右操纵杆。
+    ButtonThumbr = 107,
+
+    /// 键码常量：R2 按钮键。在游戏控制器上，R2 按钮应该是标有 R2 的按钮或右下角触发器按钮。
+    ButtonR2 = 105,
+
+    /// 键码常量：开始按钮键。在游戏控制器上，标有“开始”的按钮。
+    ButtonStart = 108,
+
+    /// 键码常量：选择按钮键。在游戏控制器上，标有“选择”的按钮。
+    ButtonSelect = 109,
+
+    /// 键码常量：模式按钮键。在游戏控制器上，标有“模式”的按钮。
+    ButtonMode = 110,
+
+    /// 键码常量：Esc 键。
+    Escape = 111,
+
+    /// 键码常量：向前删除键。与 KEYCODE_DEL 不同，它删除插入点前面的字符。
+    ForwardDel = 112,
+
+    /// 键码常量：左 Control 修饰键。
+    CtrlLeft = 113,
+
+    /// 键码常量：右 Control 修饰键。
+    CtrlRight = 114,
+
+    /// 键码常量：大写锁定键。
+    CapsLock = 115,
+
+    /// 键码常量：滚动锁定键。
+    ScrollLock = 116,
+
+    /// 键码常量：左 Meta 修饰键。
+    MetaLeft = 117,
+
+    /// 键码常量：右 Meta 修饰键。
+    MetaRight = 118,
+
+    /// 键码常量：功能修饰键。
+    Function = 119,
+
+    /// 键码常量：系统请求/打印屏幕键。
+    Sysrq = 120,
+
+    /// 键码常量：Break / Pause 键。
+    Break = 121,
+
+    /// 键码常量：Home 移动键。用于滚动或移动光标到 行 的开始或列表的顶部。
+    MoveHome = 122,
+
+    /// 键码常量：End 移动键。用于滚动或移动光标到 行 的末尾或列表的底部。
+    MoveEnd = 123,
+
+    /// 键码常量：Insert 键。切换插入/覆盖编辑模式。
+    Insert = 124,
+
+    /// 键码常量：前进键。在历史堆栈中向前导航。与 KEYCODE_BACK 互补。
+    Forward = 125,
+
+    /// 键码常量：播放媒体键。
+    MediaPlay = 126,
+
+    /// 键码常量：暂停媒体键。
+    MediaPause = 127,
+
+    /// 键码常量：关闭媒体键。例如，可用于关闭 CD 托盘。
+    MediaClose = 128,
+
+    /// 键码常量：弹出媒体键。例如，可用于弹出 CD 托盘。
+    MediaEject = 129,
+
+    /// 键码常量：录制媒体键。
+    MediaRecord = 130,
+
+    /// 键码常量：F1 键。
+    F1 = 131,
+
+    /// 键码常量：F2 键。
+    F2 = 132,
+
+    /// 键码常量：F3 键。
+    F3 = 133,
+
+    /// 键码常量：F4 键。
+    F4 = 134,
+
+    /// 键码常量：F5 键。
+    F5 = 135,
+
+    /// 键码常量：F6 键。
+    F6 = 136,
+
+    /// 键码常量：F7 键。
+    F7 = 137,
+
+    /// 键码常量：F8 键。
+    F8 = 138,
+
+    /// 键码常量：F9 键。
+    F9 = 139,
+
+    /// 键码常量：F10 键。
+    F10 = 140,
+
+    /// 键码常量：F11 键。
+    F11 = 141,
+
+    /// 键码常量：数字键盘'('键
+    NumpadLeftParen = 162,
+
+    /// 键码常量：数字键盘')'键。
+    NumpadRightParen = 163,
+
+    /// 键码常量：F12 键。
+    F12 = 142,
+
+    /// 键码常量：Num Lock 键。这是 Num Lock 键，与 KEYCODE_NUM 不同。此键会改变数字键盘上其他键的行为。
+    NumLock = 143,
+
+    /// 键码常量：数字键盘 '0' 键。
+    #[java_name = "KEYCODE_NUMPAD_0"]
+    Numpad0 = 144,
+
+    /// 键码常量：数字键盘 '1' 键。
+    #[java_name = "KEYCODE_NUMPAD_1"]
+    Numpad1 = 145,
+
+    /// 键码常量：数字键盘 '2' 键。
+    #[java_name = "KEYCODE_NUMPAD_2"]
+    Numpad2 = 146,
+
+    /// 键码常量：数字键盘 '3' 键。
+    #[java_name = "KEYCODE_NUMPAD_3"]
+    Numpad3 = 147,
+
+    /// 键码常量：数字键盘 '4' 键。
+    #[java_name = "KEYCODE_NUMPAD_4"]
+    Numpad4 = 148,
+
+    /// 键码常量：数字键盘 '5' 键。
+    #[java_name = "KEYCODE_NUMPAD_5"]
+    Numpad5 = 149,
+
+    /// 键码常量：数字键盘 '6' 键。
+    #[java_name = "KEYCODE_NUMPAD_6"]
+    Numpad6 = 150,
+
+    /// 键码常量：数字键盘 '7' 键。
+    #[java_name = "KEYCODE_NUMPAD_7"]
+    Numpad7 = 151,
+
+    /// 键码常量：数字键盘 '8' 键。
+    #[java_name = "KEYCODE_NUMPAD_8"]
+    Numpad8 = 152,
+
+    /// 键码常量：数字键盘 '9' 键。
+    #[java_name = "KEYCODE_NUMPAD_9"]
+    Numpad9 = 153,
+
+    /// 键码常量：数字键盘 '/' 键（用于除法）。
+    NumpadDivide = 154,
+
+    /// 键码常量：数字键盘 '*' 键（用于乘法）。
+    NumpadMultiply = 155,
+
+    /// 键码常量：数字键盘 '-' 键（用于减法）。
+    NumpadSubtract = 156,
+
+    /// 键码常量：数字键盘 '+' 键（用于加法）。
+    NumpadAdd = 157,
+
+    /// 键码常量：数字键盘 '.' 键（用于小数或数字分组）。
+    NumpadDot = 158,
+
+    /// 键码常量：数字键盘 ',' 键（用于小数或数字分组）。
+    NumpadComma = 159,
+
+    /// 键码常量：数字键盘 Enter 键。
+    NumpadEnter = 160,
+
+    /// 键码常量：绿色“可编程”键。在电视遥控器上，用作上下文/可编程键。
+    ProgGreen = 184,
+
+    /// 键码常量：数字键盘 '=' 键。
+    NumpadEquals = 161,
+
+    /// 键码常量：音量静音键。用于扬声器的静音键（与 KEYCODE_MUTE 不同，后者是麦克风的静音键）。此键通常应实现为切换键，即第一次按下时静音扬声器，第二次按下时恢复原始音量。
+    VolumeMute = 164,
+
+    /// 键码常量：信息键。通常在电视遥控器上，用于显示与当前正在查看的内容相关的附加信息。
+    Info = 165,
+
+    /// 键码常量：频道上键。在电视遥控器上，用于增加电视频道。
+    ChannelUp = 166,
+
+    /// 键码常量：频道下键。在电视遥控器上，用于减少电视频道。
+    ChannelDown = 167,
+
+    /// 键码常量：放大键。
+    ZoomIn = 168,
+
+    /// 键码常量：缩小键。
+    ZoomOut = 169,
+
+    /// 键码常量：电视键。在电视遥控器上，切换到观看直播电视。
+    Tv = 170,
+
+    /// 键码常量：窗口键。在电视遥控器上，切换画中画模式或其他窗口功能。在 Android Wear 设备上，触发显示偏移。
+    Window = 171,
+
+    /// 键码常量：指南键。在电视遥控器上，显示节目指南。
+    Guide = 172,
+
+    /// 键码常量：DVR 键。在某些电视遥控器上，切换到录制的节目的 DVR 模式。
+    Dvr = 173,
+
+    /// 键码常量：书签键。在某些电视遥控器上，用于标记内容或网页为书签。
+    Bookmark = 174,
+
+    /// 键码常量：切换字幕键。在电视节目期间，切换闭路字幕文本的模式。
+    Captions = 175,
+
+    /// 键码常量：设置键。启动系统设置活动。
+    Settings = 176,
+
+    /// 键码常量：语言切换键。切换当前输入语言，例如在 QWERTY 键盘上切换英语和日语。在某些设备上，按 Shift+空格键可以执行相同的功能。
+    LanguageSwitch = 204,
+
+    /// 键码常量：电视电源键。在HDMI电视面板设备和不支持HDMI的Android TV设备上，切换设备的电源状态。在HDMI源设备上，通过HDMI-CEC切换HDMI连接电视的电源状态，并使源设备跟随此电源状态。
+    TvPower = 177,
+
+    /// 键码常量：电视输入键。在电视遥控器上，在电视屏幕上切换输入。
+    TvInput = 178,
+
+    /// 键码常量：机顶盒电源键。在电视遥控器上，切换外部机顶盒的电源。
+    StbPower = 179,
+
+    /// 键码常量：机顶盒输入键。在电视遥控器上，切换外部机顶盒的输入模式。
+    StbInput = 180,
+
+    /// 键码常量：A/V接收器电源键。在电视遥控器上，切换外部A/V接收器的电源。
+    AvrPower = 181,
+
+    /// 键码常量：A/V接收器输入键。在电视遥控器上，切换外部A/V接收器的输入模式。
+    AvrInput = 182,
+
+    /// 键码常量：红色“可编程”键。在电视遥控器上，作为上下文/可编程键使用。
+    ProgRed = 183,
+
+    /// 键码常量：黄色“可编程”键。在电视遥控器上，作为上下文/可编程键使用。
+    ProgYellow = 185,
+
+    /// 键码常量：蓝色“可编程”键。在电视遥控器上，作为上下文/可编程键使用。
+    ProgBlue = 186,
+
+    /// 键码常量：应用程序切换键。应该显示应用程序切换器对话框。
+    AppSwitch = 187,
+
+    /// 键码常量：通用游戏板按钮#1。
+    #[java_name = "KEYCODE_BUTTON_1"]
+    Button1 = 188,
+
+    /// 键码常量：通用游戏板按钮#2。
+    #[java_name = "KEYCODE_BUTTON_2"]
+    Button2 = 189,
+
+    /// 键码常量：日语全角/半角键。
+    ZenkakuHankaku = 211,
+
+    /// 键码常量：日语字母数字键。
+    Eisu = 212,
+
+    /// 键码常量：日语非转换键。
+    Muhenkan = 213,
+
+    /// 键码常量：日语转换键。
+    Henkan = 214,
+
+    /// 键码常量：通用游戏板按钮#3。
+    #[java_name = "KEYCODE_BUTTON_3"]
+    Button3 = 190,
+
+    /// 键码常量：通用游戏板按钮#4。
+    #[java_name = "KEYCODE_BUTTON_4"]
+    Button4 = 191,
+
+    /// 键码常量：通用游戏板按钮#5。
+    #[java_name = "KEYCODE_BUTTON_5"]
+    Button5 = 192,
+
+    /// 键码常量：通用游戏板按钮#6。
+    #[java_name = "KEYCODE_BUTTON_6"]
+    Button6 = 193,
+
+    /// 键码常量：通用游戏板按钮#7。
+    #[java_name = "KEYCODE_BUTTON_7"]
+    Button7 = 194,
+
+    /// 键码常量：通用游戏板按钮#8。
+    #[java_name = "KEYCODE_BUTTON_8"]
+    Button8 = 195,
+
+    /// 键码常量：通用游戏板按钮#9。
+    #[java_name = "KEYCODE_BUTTON_9"]
+    Button9 = 196,
+
+    /// 键码常量：通用游戏板按钮#10。
+    #[java_name = "KEYCODE_BUTTON_10"]
+    Button10 = 197,
+
+    /// 键码常量：通用游戏板按钮#11。
+    #[java_name = "KEYCODE_BUTTON_11"]
+    Button11 = 198,
+
+    /// 键码常量：通用游戏板按钮#12。
+    #[java_name = "KEYCODE_BUTTON_12"]
+    Button12 = 199,
+
+    /// 键码常量：通用游戏板按钮#13。
+    #[java_name = "KEYCODE_BUTTON_13"]
+    Button13 = 200,
+
+    /// 键码常量：通用游戏板按钮#14。
+    #[java_name = "KEYCODE_BUTTON_14"]
+    Button14 = 201,
+
+    /// 键码常量：通用游戏板按钮#15。
+    #[java_name = "KEYCODE_BUTTON_15"]
+    Button15 = 202,
+
+    /// 键码常量：通用游戏板按钮#16。
+    #[java_name = "KEYCODE_BUTTON_16"]
+    Button16 = 203,
+
+    /// 键码常量：礼仪模式键。在某些设置（如在拥挤的火车上）中，打开和关闭静音或振动模式，使设备表现得更加礼貌。在某些设备上，此键可能仅在长按时才有效。
+    MannerMode = 205,
+
+    /// 键码常量：3D模式键。在2D和3D模式之间切换显示。
+    #[java_name = "KEYCODE_3D_MODE"]
+    Key3dMode = 206,
+
+    /// 键码常量：联系人特殊功能键。用于启动地址簿应用程序。
+    Contacts = 207,
+
+    /// 键码常量：日历特殊功能键。用于启动日历应用程序。
+    Calendar = 208,
+
+    /// 键码常量：音轨键。切换音轨。
+    MediaAudioTrack = 222,
+
+    /// 键码常量：睡眠键。使设备进入睡眠状态。行为类似于 KEYCODE_POWER，但如果设备已处于睡眠状态，则不起作用。
+    Sleep = 223,
+
+    /// 键码常量：唤醒键。唤醒设备。行为有点类似于 KEYCODE_POWER，但如果设备已唤醒，则不起作用。
+    Wakeup = 224,
+
+    /// 键码常量：音乐特殊功能键。用于启动音乐播放器应用程序。
+    Music = 209,
+
+    /// 键码常量：计算器特殊功能键。用于启动计算器应用程序。
+    Calculator = 210,
+
+    /// 键码常量：日本假名/平假名键。
+    KatakanaHiragana = 215,
+
+    /// 键码常量：日本日元键。
+    Yen = 216,
+
+    /// 键码常量：日本Ro键。
+    Ro = 217,
+
+    /// 键码常量：日本假名键。
+    Kana = 218,
+
+    /// 键码常量：辅助键。启动全局辅助活动。不会传递给应用程序。
+    Assist = 219,
+
+    /// 键码常量：亮度减小键。降低屏幕亮度。
+    BrightnessDown = 220,
+
+    /// 键码常量：亮度增大键。提高屏幕亮度。
+    BrightnessUp = 221,
+
+    /// 键码常量：配对键。启动外设配对模式。对于配对遥控器或游戏控制器特别有用，尤其是如果没有其他输入模式可用时。
+    Pairing = 225,
+
+    /// 键码常量：媒体顶层菜单键。跳转到媒体菜单的顶部。
+    MediaTopMenu = 226,
+
+    /// 键码常量：‘11’键。
+    #[java_name = "KEYCODE_11"]
+    Key11 = 227,
+
+    /// 键码常量：“12”键。
+    #[java_name = "KEYCODE_12"]
+    Key12 = 228,
+
+    /// 键码常量：上一个频道键。跳转到最后一个观看的频道。
+    LastChannel = 229,
+
+    /// 键码常量：电视数据服务键。显示数据服务，如天气、体育等。
+    TvDataService = 230,
+
+    /// 键码常量：语音助手键。启动全局语音助手活动。不会传递给应用程序。
+    VoiceAssist = 231,
+
+    /// 键码常量：收音机键。切换电视服务/收音机服务。
+    TvRadioService = 232,
+
+    /// 键码常量：电视图文键。显示电视图文服务。
+    TvTeletext = 233,
+
+    /// 键码常量：数字输入键。当每个数字键被分配用于选择单独的频道时，启动输入多位频道号。对应于 CEC 用户控制代码的数字输入模式 (0x1D)。
+    TvNumberEntry = 234,
+
+    /// 键码常量：模拟地面广播键。切换到模拟地面广播服务。
+    TvTerrestrialAnalog = 235,
+
+    /// 键码常量：数字地面广播键。切换到数字地面广播服务。
+    TvTerrestrialDigital = 236,
+
+    /// 键码常量：卫星键。切换到数字卫星广播服务。
+    TvSatellite = 237,
+
+    /// 键码常量：BS键。切换到日本可用的BS数字卫星广播服务。
+    TvSatelliteBs = 238,
+
+    /// 键码常量：CS键。切换到日本可用的CS数字卫星广播服务。
+    TvSatelliteCs = 239,
+
+    /// 键码常量：BS/CS键。在BS和CS数字卫星服务之间切换。
+    TvSatelliteService = 240,
+
+    /// 键码常量：切换网络键。切换选择广播服务。
+    TvNetwork = 241,
+
+    /// 键码常量：天线/电缆键。在天线和电缆之间切换广播输入源。
+    TvAntennaCable = 242,
+
+    /// 键码常量：HDMI #1 键。切换到 HDMI 输入 #1。
+    #[java_name = "KEYCODE_TV_INPUT_HDMI_1"]
+    TvInputHdmi1 = 243,
+
+    /// 键码常量：HDMI #2 键。切换到 HDMI 输入 #2。
+    #[java_name = "KEYCODE_TV_INPUT_HDMI_2"]
+    TvInputHdmi2 = 244,
+
+    /// 键码常量：HDMI #3 键。切换到 HDMI 输入 #3。
+    #[java_name = "KEYCODE_TV_INPUT_HDMI_3"]
+    TvInputHdmi3 = 245,
+
+    /// 键码常量：HDMI #4 键。切换到 HDMI 输入 #4。
+    #[java_name = "KEYCODE_TV_INPUT_HDMI_4"]
+    TvInputHdmi4 = 246,
+
+    /// 键码常量：复合 #1 键。切换到复合视频输入 #1。
+    #[java_name = "KEYCODE_TV_INPUT_COMPOSITE_1"]
+    TvInputComposite1 = 247,
+
+    /// 键码常量：复合 #2 键。切换到复合视频输入 #2。
+    #[java_name = "KEYCODE_TV_INPUT_COMPOSITE_2"]
+    TvInputComposite2 = 248,
+
+    /// 键码常量：分量 #1 键。切换到分量视频输入 #1。
+    #[java_name = "KEYCODE_TV_INPUT_COMPONENT_1"]
+    TvInputComponent1 = 249,
+
+    /// 键码常量：分量 #2 键。切换到分量视频输入 #2。
+    #[java_name = "KEYCODE_TV_INPUT_COMPONENT_2"]
+    TvInputComponent2 = 250,
+
+    /// 键码常量：VGA #1 键。切换到 VGA（模拟 RGB）输入 #1。
+    #[java_name = "KEYCODE_TV_INPUT_VGA_1"]
+    TvInputVga1 = 251,
+
+    /// 键码常量：音频描述键。开启/关闭音频描述。
+    TvAudioDescription = 252,
+
+    /// 键码常量：音频描述混合音量调高键。与正常音频音量相比，增大音频描述音量。
+    TvAudioDescriptionMixUp = 253,
+
+    /// 键码常量：音频描述混音音量减小键。与正常音频音量相比，降低音频描述音量。
+    TvAudioDescriptionMixDown = 254,
+
+    /// 键码常量：缩放模式键。更改缩放模式（正常、全屏、缩放、宽缩放等）
+    TvZoomMode = 255,
+
+    /// 键码常量：内容菜单键。进入标题列表。对应于CEC用户控制代码的“内容菜单”（0x0B）
+    TvContentsMenu = 256,
+
+    /// 键码常量：媒体上下文菜单键。进入媒体内容的上下文菜单。对应于CEC用户控制代码的“媒体上下文相关菜单”（0x11）。
+    TvMediaContextMenu = 257,
+
+    /// 键码常量：定时器编程键。进入定时器录制菜单。对应于CEC用户控制代码的“定时器编程”（0x54）。
+    TvTimerProgramming = 258,
+
+    /// 键码常量：帮助键。
+    Help = 259,
+
+    /// 键码常量：导航到上一个键。在有序的项目集合中向后移动一个项目。
+    NavigatePrevious = 260,
+
+    /// 键码常量：导航到下一个键。在有序的项目集合中前进到下一个项目。
+    NavigateNext = 261,
+
+    /// 键码常量：导航进入键。激活当前具有焦点的项目或展开到导航层级的下一个级别。
+    NavigateIn = 262,
+
+    /// 键码常量：导航退出键。退出导航层级的一个级别或折叠当前具有焦点的项目。
+    NavigateOut = 263,
+
+    /// 键码常量：Wear手表上的主要电源/重置按钮的主要茎键。
+    StemPrimary = 264,
+
+    /// 键码常量：Wear的通用茎键1
+    #[java_name = "KEYCODE_STEM_1"]
+    Stem1 = 265,
+
+    /// 键码常量：方向键向上-向左
+    DpadUpLeft = 268,
+
+    /// 键码常量：方向键向下向左
+    DpadDownLeft = 269,
+
+    /// 键码常量：方向键右上
+    DpadUpRight = 270,
+
+    /// 键码常量：方向键右下
+    DpadDownRight = 271,
+
+    /// 键码常量：Wear 的通用茎键 2
+    #[java_name = "KEYCODE_STEM_2"]
+    Stem2 = 266,
+
+    /// 键码常量：Wear 的通用茎键 3
+    #[java_name = "KEYCODE_STEM_3"]
+    Stem3 = 267,
+
+    /// 键码常量：跳过向前的媒体键。
+    MediaSkipForward = 272,
+
+    /// 键码常量：跳过向后的媒体键。
+    MediaSkipBackward = 273,
+
+    /// 键码常量：逐帧向前媒体键。每次向前移动一帧媒体。
+    MediaStepForward = 274,
+
+    /// 键码常量：逐帧向后媒体键。每次向后移动一帧媒体。
+    MediaStepBackward = 275,
+
+    /// 键码常量：除非持有唤醒锁，否则使设备进入休眠状态。
+    SoftSleep = 276,
+
+    /// 键码常量：剪切键。
+    Cut = 277,
+
+    /// 键码常量：复制键。
+    Copy = 278,
+
+    /// 键码常量：粘贴键。
+    Paste = 279,
+
+    /// 键码常量：由系统用于向上导航
+    SystemNavigationUp = 280,
+
+    /// 键码常量：由系统用于向下导航
+    SystemNavigationDown = 281,
 
-    /**
-    获取应用 VSYNC 偏移量（以纳秒为单位）。这是一个正值，表示 Choreographer 提供的 VSYNC 事件相对于显示刷新的相位偏移量。
-    例如，如果 Choreographer 报告刷新发生在时间 N，则它实际上发生在 (N - appVsyncOffset)。应用通常不需要知道这一点。它仅适用于细粒度的 A/V 同步。
-    */
-    #[java_method]
-    pub fn get_app_vsync_offset_nanos(&self) -> i64 {}
+    /// 键码常量：由系统用于向左导航
+    SystemNavigationLeft = 282,
 
-    //noinspection SpellCheckingInspection
-    /**
-    这是缓冲区必须提前多久排队等待在给定时间进行演示。如果您希望缓冲区在时间 N 出现在屏幕上，则必须在 (N - presentationDeadline) 之前提交缓冲区。
-    可以使用 android.opengl.EGLExt.eglPresentationTimeANDROID 设置 GLES 渲染所需的演示时间。对于视频解码，请使用 android.media.MediaCodec.releaseOutputBuffer(int, long)。时间以纳秒为单位表示，使用系统单调时钟 (System.nanoTime)。
-    */
-    #[java_method]
-    pub fn get_presentation_deadline_nanos(&self) -> i64 {}
+    /// 键码常量：由系统用于向右导航
+    SystemNavigationRight = 283,
 
-    /**
-    确定是否应将 WindowConfiguration.getMaxBounds() 报告为显示尺寸。当应用需要沙盒化时，最大边界字段可能小于逻辑尺寸。取决于 com.android.server.wm.ConfigurationContainer.providesMaxBounds() 中设置的 WindowConfiguration.getMaxBounds()。
-    在大多数情况下，此值反映当前 DisplayArea 的大小。当应应用最大边界时，返回 true。
-    */
-    #[java_method]
-    pub fn should_report_max_bounds(&self) -> bool {}
+    /// 键码常量：显示所有应用
+    AllApps = 284,
 
-    /**
-    获取显示器的状态，例如是打开还是关闭。
-    返回：显示器的状态：STATE_OFF、STATE_ON、STATE_DOZE、STATE_DOZE_SUSPEND、STATE_ON_SUSPEND 或 STATE_UNKNOWN 之一。
-    */
-    #[java_method]
-    pub fn get_state(&self) -> i32 {}
+    /// 键码常量：刷新键。
+    Refresh = 285,
 
-    /**
-    如果指定的 UID 有权访问此显示，则返回 true。
-    `uid` UID。
-    */
-    #[java_method]
-    pub fn has_access(&self, uid: i32) -> bool {}
+    /// 键码常量：点赞键。应用可以使用此键让用户对内容进行点赞。
+    ThumbsUp = 286,
 
-    /// 如果显示是公共演示文稿显示，则返回true。
-    #[java_method]
-    pub fn is_public_presentation(&self) -> bool {}
+    /// 键码常量：反对键。应用可利用此功能让用户反对内容。
+    ThumbsDown = 287,
 
-    /// 如果显示器是受信任的显示器，则为 true。
-    #[java_method]
-    pub fn is_trusted(&self) -> bool {}
+    /// 键码常量：用于切换当前正在使用内容的 android.accounts.Account。系统可能会使用该代码来全局设置账户。
+    ProfileSwitch = 288,
 
-    /// 如果显示器可以从另一个显示器窃取顶部焦点，则为 true。
-    #[java_method]
-    pub fn can_steal_top_focus(&self) -> bool {}
+    /// 键码常量：视频应用键 #1。
+    #[java_name = "KEYCODE_VIDEO_APP_1"]
+    VideoApp1 = 289,
 
-    #[doc(hidden)]
-    #[java_method]
-    pub fn type_to_string(r#type: i32) -> String {}
+    /// 键码常量：视频应用键 #2。
+    #[java_name = "KEYCODE_VIDEO_APP_2"]
+    VideoApp2 = 290,
 
-    #[doc(hidden)]
-    #[java_method]
-    pub fn state_to_string(state: i32) -> String {}
+    /// 键码常量：视频应用键 #3。
+    #[java_name = "KEYCODE_VIDEO_APP_3"]
+    VideoApp3 = 291,
 
-    /**
-    如果在指定的显示器电源状态下可以暂停显示更新，则返回 true。在 SUSPEND 状态下，绝对禁止更新。
-    `state` 状态。
-    */
-    #[java_method]
-    pub fn is_suspended_state(state: i32) -> bool {}
+    /// 键码常量：视频应用键 #4。
+    #[java_name = "KEYCODE_VIDEO_APP_4"]
+    VideoApp4 = 292,
 
-    /**
-    如果在指定的显示器电源状态下显示器可能处于降低的操作模式，则返回 true。
-    `state` 状态。
-    */
-    #[java_method]
-    pub fn is_doze_state(state: i32) {}
+    /// 键码常量：视频应用键 #5。
+    #[java_name = "KEYCODE_VIDEO_APP_5"]
+    VideoApp5 = 293,
 
-    /**
-    如果显示器处于活动状态（例如 STATE_ON 或 STATE_VR），则返回 true。
-    `state` 状态。
-    */
-    #[java_method]
-    pub fn is_active_state(state: i32) {}
+    /// 键码常量：视频应用键 #6。
+    #[java_name = "KEYCODE_VIDEO_APP_6"]
+    VideoApp6 = 294,
 
-    /**
-    如果显示器处于关闭状态（例如 STATE_OFF），则返回 true。
-    `state` 状态。
-    */
-    #[java_method]
-    pub fn is_off_state(state: i32) -> bool {}
+    /// 键码常量：视频应用键 #7。
+    #[java_name = "KEYCODE_VIDEO_APP_7"]
+    VideoApp7 = 295,
 
-    /**
-    如果显示器处于开启状态（例如 STATE_ON 或 STATE_VR 或 STATE_ON_SUSPEND），则返回 true。
-    `state` 状态。
-    */
-    #[java_method]
-    pub fn is_on_state(state: i32) -> bool {}
+    /// 键码常量：视频应用键 #8。
+    #[java_name = "KEYCODE_VIDEO_APP_8"]
+    VideoApp8 = 296,
 
-    /**
-    如果指定的宽度有效，则返回 true。
-    `width` 宽度。
-    */
-    #[java_method]
-    pub fn is_width_valid(width: i32) -> bool {}
+    /// 键码常量：特色应用键 #1。
+    #[java_name = "KEYCODE_FEATURED_APP_1"]
+    FeaturedApp1 = 297,
 
-    /**
-    如果指定的高度有效，则返回 true。
-    `height` 高度。
-    */
-    #[java_method]
-    pub fn is_height_valid(height: i32) -> bool {}
+    /// 键码常量：特色应用键 #2。
+    #[java_name = "KEYCODE_FEATURED_APP_2"]
+    FeaturedApp2 = 298,
 
-    /**
-    如果指定的刷新率有效，则返回 true。
-    `refresh_rate` 刷新率。
-    */
-    #[java_method]
-    pub fn is_refresh_rate_valid(refresh_rate: f32) -> bool {}
-}
+    /// 键码常量：特色应用键 #3。
+    #[java_name = "KEYCODE_FEATURED_APP_3"]
+    FeaturedApp3 = 299,
 
-/// 输入事件的通用基类。
-#[java_class(name = "android/view/InputEvent")]
-pub struct InputEvent;
+    /// 键码常量：特色应用键 #4。
+    #[java_name = "KEYCODE_FEATURED_APP_4"]
+    FeaturedApp4 = 300,
 
-impl InputEvent {
-    /**
-    获取此事件所来自设备的 ID。ID 为零表示事件不是来自物理设备并映射到默认键盘映射。其他数字是任意的，您不应该依赖这些值。
-    返回：设备 ID。
-    */
-    #[java_method]
-    pub fn get_device_id(&self) -> i32 {}
+    /// 键码常量：演示应用键 #1。
+    #[java_name = "KEYCODE_DEMO_APP_1"]
+    DemoApp1 = 301,
 
-    /**
-    获取事件源。
-    返回：事件源或输入设备。如果未知，则返回 SOURCE_UNKNOWN。
-    */
-    #[java_method]
-    pub fn get_source(&self) -> i32 {}
+    /// 键码常量：演示应用键 #2。
+    #[java_name = "KEYCODE_DEMO_APP_2"]
+    DemoApp2 = 302,
 
-    /**
-    修改事件的来源。
-    `source` 新来源。
-    */
-    #[java_method]
-    pub fn set_source(&self, source: i32) {}
+    /// 键码常量：演示应用键 #3。
+    #[java_name = "KEYCODE_DEMO_APP_3"]
+    DemoApp3 = 303,
 
-    /**
-    确定事件是否来自给定的源。
-    返回：事件是否来自给定的源。
-    `source` 要检查的输入源。这可以是特定的设备类型，例如 InputDevice.SOURCE_TOUCH_NAVIGATION，也可以是更通用的设备类，例如 InputDevice.SOURCE_CLASS_POINTER。
-    */
-    #[java_method]
-    pub fn is_from_source(&self, source: i32) -> bool {}
+    /// 键码常量：演示应用键 #4。
+    #[java_name = "KEYCODE_DEMO_APP_4"]
+    DemoApp4 = 304,
 
-    /**
-    获取事件的显示 ID。
-    返回：与事件关联的显示 ID。
-    */
-    #[java_method]
-    pub fn get_display_id(&self) -> i32 {}
+    /// 键码常量：键盘背光调暗
+    KeyboardBacklightDown = 305,
 
-    /**
-    修改与事件关联的显示 ID
-    `display_id`
-    */
-    #[java_method]
-    pub fn set_display_id(&self, display_id: i32) {}
+    /// 键码常量：键盘背光调亮
+    KeyboardBacklightUp = 306,
 
-    /**
-    回收事件。此方法仅应由系统使用，因为应用程序不希望回收 KeyEvent 对象，但可以回收 MotionEvent 对象。
-    有关详细信息，请参阅 KeyEvent.recycle()。
-    */
-    #[java_method]
-    pub fn recycle(&self) {}
+    /// 键码常量：键盘背光切换
+    KeyboardBacklightToggle = 307,
 
-    /**
-    在将事件分发给应用程序后，如果合适，则有条件地回收事件。如果事件是 MotionEvent，则回收该事件。如果事件是 KeyEvent，则不回收该事件，因为应用程序希望按键事件是不可变的，因此一旦将事件分发给应用程序，我们就不能再回收它了。
-    */
-    #[java_method]
-    pub fn recycle_if_needed_after_dispatch(&self) {}
+    /// 键码常量：触控笔笔杆上的主要按钮。这通常是最靠近触控笔尖的按钮。
+    StylusButtonPrimary = 308,
 
-    /**
-    获取一个私有标志，该标志指示系统何时检测到此输入事件可能与先前传递的输入事件的序列不一致，例如，当发送了按键释放事件但按键未按下时，或者当发送了指针移动事件但指针未按下时。
-    返回：如果此事件被污染，则返回 True。
-    */
-    #[java_method]
-    pub fn is_tainted(&self) -> bool {}
+    /// 键码常量：触控笔笔杆上的第二个按钮。这通常是从触控笔尖算起的第二个按钮。
+    StylusButtonSecondary = 309,
 
-    /**
-    设置一个私有标志，指示系统何时检测到此输入事件可能与先前传递的输入事件的序列不一致，例如，当发送了按键释放事件但按键未按下时，或者当发送了指针移动事件但指针未按下时。
-    `tainted` 如果此事件被污染，则为 True。
-    */
-    #[java_method]
-    pub fn set_tainted(&self, tainted: bool) {}
+    /// 键码常量：触控笔笔杆上的第三个按钮。这通常是从触控笔尖开始的第三个按钮。
+    StylusButtonTertiary = 310,
 
-    /**
-    以 android.os.SystemClock.uptimeMillis 时间基准查询此事件发生的时间。
-    返回：以 android.os.SystemClock.uptimeMillis 时间基准返回此事件发生的时间。
-    */
-    #[java_method]
-    pub fn get_event_time(&self) -> i64 {}
+    /// 键码常量：触控笔尾部的按钮。此按钮的使用通常与橡皮擦的功能无关。
+    StylusButtonTail = 311,
 
-    /**
-    查询此事件发生的时间，以 android.os.SystemClock.uptimeMillis 时间为基础，但精度为纳秒（而不是毫秒）。该值以纳秒为精度，但可能不具有纳秒的精度。
-    返回：返回此事件发生的时间，以 android.os.SystemClock.uptimeMillis 时间为基础，但精度为纳秒（而不是毫秒）。
-    */
-    #[java_method]
-    pub fn get_event_time_nanos(&self) -> i64 {}
+    /// 键码常量：打开最近使用的应用程序视图（又称概览）。此键由框架处理，永远不会传递给应用程序。
+    RecentApps = 312,
 
-    /**
-    将输入事件标记为已取消。
-    */
-    #[java_method]
-    pub fn cancel(&self) {}
+    /// 键码常量：用户可以通过系统自定义其用途的按钮。用户可自定义键 #1。
+    #[java_name = "KEYCODE_MACRO_1"]
+    Macro1 = 313,
 
-    /**
-    获取此事件的唯一序列号。进程创建或接收的每个输入事件都有唯一的序列号。此外，每次回收事件对象时都会获得一个新的序列号。序列号仅保证在进程内本地唯一。打包事件时不会保留序列号。
-    返回：此事件的唯一序列号。
-    */
-    #[java_method]
-    pub fn get_sequence_number(&self) -> i32 {}
+    /// 键码常量：用户可以通过系统自定义其用途的按钮。用户可自定义键 #2。
+    #[java_name = "KEYCODE_MACRO_2"]
+    Macro2 = 314,
 
-    //noinspection SpellCheckingInspection
-    /**
-    获取此事件的 ID。此 ID 在事件创建时生成，并保存到事件的最后阶段。它不会因为事件跨越进程边界而改变，但在进行修改复制时应该会改变。为了避免将应用程序使用情况暴露给其他进程，此 ID 由 CSPRNG 生成。因此，虽然 ID 冲突的可能性相当低，但不能 100% 保证此 ID 的唯一性。经验法则是不要依赖生产逻辑的唯一性，而要依赖跟踪事件（例如日志记录和分析）的良好来源。
-    返回：此事件的 ID。
-    */
-    #[java_method]
-    pub fn get_id(&self) -> i32 {}
+    /// 键码常量：用户可以通过系统自定义其用途的按钮。用户可自定义键 #3。
+    #[java_name = "KEYCODE_MACRO_3"]
+    Macro3 = 315,
 
-    #[doc(hidden)]
-    #[java_method]
-    pub fn describe_contents(&self) -> i32 {}
+    /// 键码常量：用户可以通过系统自定义其用途的按钮。用户可自定义键 #4。
+    #[java_name = "KEYCODE_MACRO_4"]
+    Macro4 = 316,
 }
 
-//noinspection SpellCheckingInspection
 /**
 用于报告按键和按钮事件的对象。每次按键都由一系列按键事件描述。按键以 ACTION_DOWN 按键事件开始。如果按键保持的时间足够长，以至于重复，则初始按下之后是其他按键事件，其中 ACTION_DOWN 和非零 getRepeatCount() 值。最后一个按键事件是按键弹起的 ACTION_UP。
 如果取消按键，则按键弹起事件将设置 FLAG_CANCELED 标志。按键事件通常伴随着按键代码 (getKeyCode())、扫描代码 (getScanCode()) 和元状态 (getMetaState())。按键代码常量在此类中定义。扫描代码常量是从操作系统获得的原始设备特定代码，因此除非使用 KeyCharacterMap 进行解释，否则通常对应用程序没有意义。
@@ -2783,979 +3805,979 @@ pub struct KeyEvent;
 
 impl KeyEvent {
     /// 键码常量：未知键码。
-    pub const KEYCODE_UNKNOWN: i32 = 0;
+    pub const KEYCODE_UNKNOWN: KeyCode = KeyCode::UnknownKey;
 
     /// 键码常量：软左键。通常位于手机显示屏下方，用作多功能特征键，用于选择显示在显示屏左下角的软件定义功能。
-    pub const KEYCODE_SOFT_LEFT: i32 = 1;
+    pub const KEYCODE_SOFT_LEFT: KeyCode = KeyCode::SoftLeft;
 
     /// 键码常量：软右键。通常位于手机显示屏下方，用作多功能特征键，用于选择显示在显示屏右下角的软件定义功能。
-    pub const KEYCODE_SOFT_RIGHT: i32 = 2;
+    pub const KEYCODE_SOFT_RIGHT: KeyCode = KeyCode::SoftRight;
 
     /// 键码常量：主页键。此键由框架处理，永远不会传递给应用程序。
-    pub const KEYCODE_HOME: i32 = 3;
+    pub const KEYCODE_HOME: KeyCode = KeyCode::Home;
 
     /// 键码常量：返回键。
-    pub const KEYCODE_BACK: i32 = 4;
+    pub const KEYCODE_BACK: KeyCode = KeyCode::Back;
 
     /// 键码常量：呼叫键。
-    pub const KEYCODE_CALL: i32 = 5;
+    pub const KEYCODE_CALL: KeyCode = KeyCode::Call;
 
     //noinspection SpellCheckingInspection
     /// 键码常量：结束呼叫键。
-    pub const KEYCODE_ENDCALL: i32 = 6;
+    pub const KEYCODE_ENDCALL: KeyCode = KeyCode::Endcall;
 
     /// 键码常量：'0'键。
-    pub const KEYCODE_0: i32 = 7;
+    pub const KEYCODE_0: KeyCode = KeyCode::Key0;
 
     /// 键码常量：'1'键。
-    pub const KEYCODE_1: i32 = 8;
+    pub const KEYCODE_1: KeyCode = KeyCode::Key1;
 
     /// 键码常量：'2'键。
-    pub const KEYCODE_2: i32 = 9;
+    pub const KEYCODE_2: KeyCode = KeyCode::Key2;
 
     /// 键码常量：'3'键。
-    pub const KEYCODE_3: i32 = 10;
+    pub const KEYCODE_3: KeyCode = KeyCode::Key3;
 
     /// 键码常量：'4'键。
-    pub const KEYCODE_4: i32 = 11;
+    pub const KEYCODE_4: KeyCode = KeyCode::Key4;
 
     /// 键码常量：'5'键。
-    pub const KEYCODE_5: i32 = 12;
+    pub const KEYCODE_5: KeyCode = KeyCode::Key5;
 
     /// 键码常量：'6'键。
-    pub const KEYCODE_6: i32 = 13;
+    pub const KEYCODE_6: KeyCode = KeyCode::Key6;
 
     /// 键码常量：'7'键。
-    pub const KEYCODE_7: i32 = 14;
+    pub const KEYCODE_7: KeyCode = KeyCode::Key7;
 
     /// 键码常量：'8'键。
-    pub const KEYCODE_8: i32 = 15;
+    pub const KEYCODE_8: KeyCode = KeyCode::Key8;
 
     /// 键码常量：'9'键。
-    pub const KEYCODE_9: i32 = 16;
+    pub const KEYCODE_9: KeyCode = KeyCode::Key9;
 
     /// 键码常量：'*'键。
-    pub const KEYCODE_STAR: i32 = 17;
+    pub const KEYCODE_STAR: KeyCode = KeyCode::Star;
 
     /// 键码常量：'#'键。
-    pub const KEYCODE_POUND: i32 = 18;
+    pub const KEYCODE_POUND: KeyCode = KeyCode::Pound;
 
     //noinspection SpellCheckingInspection
     /// 键码常量：方向键向上键。也可以从轨迹球运动合成。
-    pub const KEYCODE_DPAD_UP: i32 = 19;
+    pub const KEYCODE_DPAD_UP: KeyCode = KeyCode::DpadUp;
 
     //noinspection SpellCheckingInspection
     /// 键码常量：方向键向下键。也可以从轨迹球运动合成。
-    pub const KEYCODE_DPAD_DOWN: i32 = 20;
+    pub const KEYCODE_DPAD_DOWN: KeyCode = KeyCode::DpadDown;
 
     //noinspection SpellCheckingInspection
     /// 键码常量：方向键左键。也可以从轨迹球运动合成。
-    pub const KEYCODE_DPAD_LEFT: i32 = 21;
+    pub const KEYCODE_DPAD_LEFT: KeyCode = KeyCode::DpadLeft;
 
     //noinspection SpellCheckingInspection
     /// 键码常量：方向键右键。也可以从轨迹球运动合成。
-    pub const KEYCODE_DPAD_RIGHT: i32 = 22;
+    pub const KEYCODE_DPAD_RIGHT: KeyCode = KeyCode::DpadRight;
 
     //noinspection SpellCheckingInspection
     /// 键码常量：方向键中心键。也可以从轨迹球运动合成。
-    pub const KEYCODE_DPAD_CENTER: i32 = 23;
+    pub const KEYCODE_DPAD_CENTER: KeyCode = KeyCode::DpadCenter;
 
     /// 键码常量：音量向上键。调整扬声器音量。
-    pub const KEYCODE_VOLUME_UP: i32 = 24;
+    pub const KEYCODE_VOLUME_UP: KeyCode = KeyCode::VolumeUp;
 
     /// 键码常量：音量减键。调整扬声器音量减小。
-    pub const KEYCODE_VOLUME_DOWN: i32 = 25;
+    pub const KEYCODE_VOLUME_DOWN: KeyCode = KeyCode::VolumeDown;
 
     /// 键码常量：电源键。
-    pub const KEYCODE_POWER: i32 = 26;
+    pub const KEYCODE_POWER: KeyCode = KeyCode::Power;
 
     /// 键码常量：相机键。用于启动相机应用程序或拍照。
-    pub const KEYCODE_CAMERA: i32 = 27;
+    pub const KEYCODE_CAMERA: KeyCode = KeyCode::Camera;
 
     /// 键码常量：清除键。
-    pub const KEYCODE_CLEAR: i32 = 28;
+    pub const KEYCODE_CLEAR: KeyCode = KeyCode::Clear;
 
     /// 键码常量：'A'键。
-    pub const KEYCODE_A: i32 = 29;
+    pub const KEYCODE_A: KeyCode = KeyCode::A;
 
     /// 键码常量：'B'键。
-    pub const KEYCODE_B: i32 = 30;
+    pub const KEYCODE_B: KeyCode = KeyCode::B;
 
     /// 键码常量：'C'键。
-    pub const KEYCODE_C: i32 = 31;
+    pub const KEYCODE_C: KeyCode = KeyCode::C;
 
     /// 键码常量：'D'键。
-    pub const KEYCODE_D: i32 = 32;
+    pub const KEYCODE_D: KeyCode = KeyCode::D;
 
     /// 键码常量：'E'键。
-    pub const KEYCODE_E: i32 = 33;
+    pub const KEYCODE_E: KeyCode = KeyCode::E;
 
     /// 键码常量：'F'键。
-    pub const KEYCODE_F: i32 = 34;
+    pub const KEYCODE_F: KeyCode = KeyCode::F;
 
     /// 键码常量：'G'键。
-    pub const KEYCODE_G: i32 = 35;
+    pub const KEYCODE_G: KeyCode = KeyCode::G;
 
     /// 键码常量：'H'键。
-    pub const KEYCODE_H: i32 = 36;
+    pub const KEYCODE_H: KeyCode = KeyCode::H;
 
     /// 键码常量：'I'键。
-    pub const KEYCODE_I: i32 = 37;
+    pub const KEYCODE_I: KeyCode = KeyCode::I;
 
     /// 键码常量：'J'键。
-    pub const KEYCODE_J: i32 = 38;
+    pub const KEYCODE_J: KeyCode = KeyCode::J;
 
     /// 键码常量：'K'键。
-    pub const KEYCODE_K: i32 = 39;
+    pub const KEYCODE_K: KeyCode = KeyCode::K;
 
     /// 键码常量：'L'键。
-    pub const KEYCODE_L: i32 = 40;
+    pub const KEYCODE_L: KeyCode = KeyCode::L;
 
     /// 键码常量：'M'键。
-    pub const KEYCODE_M: i32 = 41;
+    pub const KEYCODE_M: KeyCode = KeyCode::M;
 
     /// 键码常量：'N'键。
-    pub const KEYCODE_N: i32 = 42;
+    pub const KEYCODE_N: KeyCode = KeyCode::N;
 
     /// 键码常量：'O'键。
-    pub const KEYCODE_O: i32 = 43;
+    pub const KEYCODE_O: KeyCode = KeyCode::O;
 
     /// 键码常量：'P'键。
-    pub const KEYCODE_P: i32 = 44;
+    pub const KEYCODE_P: KeyCode = KeyCode::P;
 
     /// 键码常量：'Q'键。
-    pub const KEYCODE_Q: i32 = 45;
+    pub const KEYCODE_Q: KeyCode = KeyCode::Q;
 
     /// 键码常量：'R'键。
-    pub const KEYCODE_R: i32 = 46;
+    pub const KEYCODE_R: KeyCode = KeyCode::R;
 
     /// 键码常量：'S'键。
-    pub const KEYCODE_S: i32 = 47;
+    pub const KEYCODE_S: KeyCode = KeyCode::S;
 
     /// 键码常量：'T'键。
-    pub const KEYCODE_T: i32 = 48;
+    pub const KEYCODE_T: KeyCode = KeyCode::T;
 
     /// 键码常量：'U'键。
-    pub const KEYCODE_U: i32 = 49;
+    pub const KEYCODE_U: KeyCode = KeyCode::U;
 
     /// 键码常量：'V'键。
-    pub const KEYCODE_V: i32 = 50;
+    pub const KEYCODE_V: KeyCode = KeyCode::V;
 
     /// 键码常量：'W'键。
-    pub const KEYCODE_W: i32 = 51;
+    pub const KEYCODE_W: KeyCode = KeyCode::W;
 
     /// 键码常量：'X'键。
-    pub const KEYCODE_X: i32 = 52;
+    pub const KEYCODE_X: KeyCode = KeyCode::X;
 
     /// 键码常量：'Y'键。
-    pub const KEYCODE_Y: i32 = 53;
+    pub const KEYCODE_Y: KeyCode = KeyCode::Y;
 
     /// 键码常量：'Z'键。
-    pub const KEYCODE_Z: i32 = 54;
+    pub const KEYCODE_Z: KeyCode = KeyCode::Z;
 
     /// 键码常量：','键。
-    pub const KEYCODE_COMMA: i32 = 55;
+    pub const KEYCODE_COMMA: KeyCode = KeyCode::Comma;
 
     /// 键码常量：'.'键。
-    pub const KEYCODE_PERIOD: i32 = 56;
+    pub const KEYCODE_PERIOD: KeyCode = KeyCode::Period;
 
     /// 键码常量：左Alt修饰键。
-    pub const KEYCODE_ALT_LEFT: i32 = 57;
+    pub const KEYCODE_ALT_LEFT: KeyCode = KeyCode::AltLeft;
 
     /// 键码常量：右Alt修饰键。
-    pub const KEYCODE_ALT_RIGHT: i32 = 58;
+    pub const KEYCODE_ALT_RIGHT: KeyCode = KeyCode::AltRight;
 
     /// 键码常量：左Shift修饰键。
-    pub const KEYCODE_SHIFT_LEFT: i32 = 59;
+    pub const KEYCODE_SHIFT_LEFT: KeyCode = KeyCode::ShiftLeft;
 
     /// 键码常量：右Shift修饰键。
-    pub const KEYCODE_SHIFT_RIGHT: i32 = 60;
+    pub const KEYCODE_SHIFT_RIGHT: KeyCode = KeyCode::ShiftRight;
 
     /// 键码常量：Tab键。
-    pub const KEYCODE_TAB: i32 = 61;
+    pub const KEYCODE_TAB: KeyCode = KeyCode::Tab;
 
     /// 键码常量：空格键。
-    pub const KEYCODE_SPACE: i32 = 62;
+    pub const KEYCODE_SPACE: KeyCode = KeyCode::Space;
 
     /// 键码常量：符号修饰键。用于输入替代符号。
-    pub const KEYCODE_SYM: i32 = 63;
+    pub const KEYCODE_SYM: KeyCode = KeyCode::Sym;
 
     /// 键码常量：资源管理器特殊功能键。用于启动浏览器应用程序。
-    pub const KEYCODE_EXPLORER: i32 = 64;
+    pub const KEYCODE_EXPLORER: KeyCode = KeyCode::Explorer;
 
     /// 键码常量：信封特殊功能键。用于启动邮件应用程序。
-    pub const KEYCODE_ENVELOPE: i32 = 65;
+    pub const KEYCODE_ENVELOPE: KeyCode = KeyCode::Envelope;
 
     /// 键码常量：Enter键。
-    pub const KEYCODE_ENTER: i32 = 66;
+    pub const KEYCODE_ENTER: KeyCode = KeyCode::Enter;
 
     /// 键码常量：退格键。删除插入点前的字符，与KEYCODE_FORWARD_DEL不同。
-    pub const KEYCODE_DEL: i32 = 67;
+    pub const KEYCODE_DEL: KeyCode = KeyCode::Del;
 
     /// 键码常量：'`'（反引号）键。
-    pub const KEYCODE_GRAVE: i32 = 68;
+    pub const KEYCODE_GRAVE: KeyCode = KeyCode::Grave;
 
     /// 键码常量：'-'。
-    pub const KEYCODE_MINUS: i32 = 69;
+    pub const KEYCODE_MINUS: KeyCode = KeyCode::Minus;
 
     /// 键码常量：'='键。
-    pub const KEYCODE_EQUALS: i32 = 70;
+    pub const KEYCODE_EQUALS: KeyCode = KeyCode::Equals;
 
     /// 键码常量：'[' 键。
-    pub const KEYCODE_LEFT_BRACKET: i32 = 71;
+    pub const KEYCODE_LEFT_BRACKET: KeyCode = KeyCode::LeftBracket;
 
     /// 键码常量：']'键。
-    pub const KEYCODE_RIGHT_BRACKET: i32 = 72;
+    pub const KEYCODE_RIGHT_BRACKET: KeyCode = KeyCode::RightBracket;
 
     /// 键码常量：'\'键。
-    pub const KEYCODE_BACKSLASH: i32 = 73;
+    pub const KEYCODE_BACKSLASH: KeyCode = KeyCode::Backslash;
 
     /// 键码常量：';'键。
-    pub const KEYCODE_SEMICOLON: i32 = 74;
+    pub const KEYCODE_SEMICOLON: KeyCode = KeyCode::Semicolon;
 
     /// 键码常量：'''（单引号）键。
-    pub const KEYCODE_APOSTROPHE: i32 = 75;
+    pub const KEYCODE_APOSTROPHE: KeyCode = KeyCode::Apostrophe;
 
     /// 键码常量：'/'键。
-    pub const KEYCODE_SLASH: i32 = 76;
+    pub const KEYCODE_SLASH: KeyCode = KeyCode::Slash;
 
     /// 键码常量：'@'键。
-    pub const KEYCODE_AT: i32 = 77;
+    pub const KEYCODE_AT: KeyCode = KeyCode::At;
 
     /// 键码常量：数字修饰键。用于输入数字符号。这个键不是Num Lock；它更像是KEYCODE_ALT_LEFT，并被android.text.method.MetaKeyKeyListener解释为ALT键。
-    pub const KEYCODE_NUM: i32 = 78;
+    pub const KEYCODE_NUM: KeyCode = KeyCode::Num;
 
     //noinspection SpellCheckingInspection
     /// 键码常量：耳机钩键。用于挂断电话并停止媒体。
-    pub const KEYCODE_HEADSETHOOK: i32 = 79;
+    pub const KEYCODE_HEADSETHOOK: KeyCode = KeyCode::Headsethook;
 
     /// 键码常量：相机对焦键。用于对焦相机。
-    pub const KEYCODE_FOCUS: i32 = 80;
+    pub const KEYCODE_FOCUS: KeyCode = KeyCode::Focus;
 
     /// 键码常量：'+'键。
-    pub const KEYCODE_PLUS: i32 = 81;
+    pub const KEYCODE_PLUS: KeyCode = KeyCode::Plus;
 
     /// 键码常量：菜单键。
-    pub const KEYCODE_MENU: i32 = 82;
+    pub const KEYCODE_MENU: KeyCode = KeyCode::Menu;
 
     /// 键码常量：通知键。
-    pub const KEYCODE_NOTIFICATION: i32 = 83;
+    pub const KEYCODE_NOTIFICATION: KeyCode = KeyCode::Notification;
 
     /// 键码常量：搜索键。
-    pub const KEYCODE_SEARCH: i32 = 84;
+    pub const KEYCODE_SEARCH: KeyCode = KeyCode::Search;
 
     /// 键码常量：媒体播放/暂停键。
-    pub const KEYCODE_MEDIA_PLAY_PAUSE: i32 = 85;
+    pub const KEYCODE_MEDIA_PLAY_PAUSE: KeyCode = KeyCode::MediaPlayPause;
 
     /// 键码常量：媒体停止键。
-    pub const KEYCODE_MEDIA_STOP: i32 = 86;
+    pub const KEYCODE_MEDIA_STOP: KeyCode = KeyCode::MediaStop;
 
     /// 键码常量：媒体播放下一曲键。
-    pub const KEYCODE_MEDIA_NEXT: i32 = 87;
+    pub const KEYCODE_MEDIA_NEXT: KeyCode = KeyCode::MediaNext;
 
     /// 键码常量：媒体播放上一曲键。
-    pub const KEYCODE_MEDIA_PREVIOUS: i32 = 88;
+    pub const KEYCODE_MEDIA_PREVIOUS: KeyCode = KeyCode::MediaPrevious;
 
     /// 键码常量：媒体倒带键。
-    pub const KEYCODE_MEDIA_REWIND: i32 = 89;
+    pub const KEYCODE_MEDIA_REWIND: KeyCode = KeyCode::MediaRewind;
 
     /// 键码常量：媒体快进键。
-    pub const KEYCODE_MEDIA_FAST_FORWARD: i32 = 90;
+    pub const KEYCODE_MEDIA_FAST_FORWARD: KeyCode = KeyCode::MediaFastForward;
 
     /// 键码常量：静音键。用于麦克风的静音键（不同于KEYCODE_VOLUME_MUTE，那是扬声器静音键）。
-    pub const KEYCODE_MUTE: i32 = 91;
+    pub const KEYCODE_MUTE: KeyCode = KeyCode::Mute;
 
     /// 键码常量：Page Up键。
-    pub const KEYCODE_PAGE_UP: i32 = 92;
+    pub const KEYCODE_PAGE_UP: KeyCode = KeyCode::PageUp;
 
     /// 键码常量：Page Down键。
-    pub const KEYCODE_PAGE_DOWN: i32 = 93;
+    pub const KEYCODE_PAGE_DOWN: KeyCode = KeyCode::PageDown;
 
     //noinspection SpellCheckingInspection
     /// 键码常量：图片符号修饰键。用于切换符号集（表情符号、颜文字）。
-    pub const KEYCODE_PICTSYMBOLS: i32 = 94;
+    pub const KEYCODE_PICTSYMBOLS: KeyCode = KeyCode::Pictsymbols;
 
     //noinspection SpellCheckingInspection
     /// 切换符号集（表情符号、颜文字）
     /// 键码常量：切换字符集修饰键。用于切换字符集（汉字、片假名）。
-    pub const KEYCODE_SWITCH_CHARSET: i32 = 95; // 切换字符集（汉字、片假名）
+    pub const KEYCODE_SWITCH_CHARSET: KeyCode = KeyCode::SwitchCharset; // 切换字符集（汉字、片假名）
 
     /// 键码常量：A按钮键。在游戏控制器上，A按钮应为标有A的按钮或控制器按钮底部行的第一个按钮。
-    pub const KEYCODE_BUTTON_A: i32 = 96;
+    pub const KEYCODE_BUTTON_A: KeyCode = KeyCode::ButtonA;
 
     /// 键码常量：B按钮键。在游戏控制器上，B按钮应为标有B的按钮或控制器按钮底部行的第二个按钮。
-    pub const KEYCODE_BUTTON_B: i32 = 97;
+    pub const KEYCODE_BUTTON_B: KeyCode = KeyCode::ButtonB;
 
     /// 键码常量：C按钮键。在游戏控制器上，C按钮应为标有C的按钮或控制器按钮底部行的第三个按钮。
-    pub const KEYCODE_BUTTON_C: i32 = 98;
+    pub const KEYCODE_BUTTON_C: KeyCode = KeyCode::ButtonC;
 
     /// 键码常量：X按钮键。在游戏控制器上，X按钮应为标有X的按钮或控制器按钮顶部行的第一个按钮。
-    pub const KEYCODE_BUTTON_X: i32 = 99;
+    pub const KEYCODE_BUTTON_X: KeyCode = KeyCode::ButtonX;
 
     /// 键码常量：Y按钮键。在游戏控制器上，Y按钮应为标有Y的按钮或控制器按钮顶部行的第二个按钮。
-    pub const KEYCODE_BUTTON_Y: i32 = 100;
+    pub const KEYCODE_BUTTON_Y: KeyCode = KeyCode::ButtonY;
 
     /// 键码常量：Z按钮键。在游戏控制器上，Z按钮应为标有Z的按钮或控制器按钮顶部行的第三个按钮。
-    pub const KEYCODE_BUTTON_Z: i32 = 101;
+    pub const KEYCODE_BUTTON_Z: KeyCode = KeyCode::ButtonZ;
 
     /// 键码常量：L1按钮键。在游戏控制器上，L1按钮应为标有L1（或L）的按钮或左上角的触发器按钮。
-    pub const KEYCODE_BUTTON_L1: i32 = 102;
+    pub const KEYCODE_BUTTON_L1: KeyCode = KeyCode::ButtonL1;
 
     /// 键码常量：R1按钮键。在游戏控制器上，R1按钮应为标有R1（或R）的按钮或右上角的触发器按钮。
-    pub const KEYCODE_BUTTON_R1: i32 = 103;
+    pub const KEYCODE_BUTTON_R1: KeyCode = KeyCode::ButtonR1;
 
     /// 键码常量：L2按钮键。在游戏控制器上，L2按钮应为标有L2的按钮或左下角的触发器按钮。
-    pub const KEYCODE_BUTTON_L2: i32 = 104;
+    pub const KEYCODE_BUTTON_L2: KeyCode = KeyCode::ButtonL2;
 
     //noinspection SpellCheckingInspection
     /// 键码常量：左拇指按钮键。在游戏控制器上，左拇指按钮表示按下左（或唯一）操纵杆。
-    pub const KEYCODE_BUTTON_THUMBL: i32 = 106;
+    pub const KEYCODE_BUTTON_THUMBL: KeyCode = KeyCode::ButtonThumbl;
 
     //noinspection SpellCheckingInspection
     /// 键码常量：右拇指按钮键。在游戏控制器上，右拇指按钮表示按下了右操纵杆。
-    pub const KEYCODE_BUTTON_THUMBR: i32 = 107;
+    pub const KEYCODE_BUTTON_THUMBR: KeyCode = KeyCode::ButtonThumbr;
 
     /// 键码常量：R2 按钮键。在游戏控制器上，R2 按钮应该是标有 R2 的按钮或右下角触发器按钮。
-    pub const KEYCODE_BUTTON_R2: i32 = 105;
+    pub const KEYCODE_BUTTON_R2: KeyCode = KeyCode::ButtonR2;
 
     /// 键码常量：开始按钮键。在游戏控制器上，标有“开始”的按钮。
-    pub const KEYCODE_BUTTON_START: i32 = 108;
+    pub const KEYCODE_BUTTON_START: KeyCode = KeyCode::ButtonStart;
 
     /// 键码常量：选择按钮键。在游戏控制器上，标有“选择”的按钮。
-    pub const KEYCODE_BUTTON_SELECT: i32 = 109;
+    pub const KEYCODE_BUTTON_SELECT: KeyCode = KeyCode::ButtonSelect;
 
     /// 键码常量：模式按钮键。在游戏控制器上，标有“模式”的按钮。
-    pub const KEYCODE_BUTTON_MODE: i32 = 110;
+    pub const KEYCODE_BUTTON_MODE: KeyCode = KeyCode::ButtonMode;
 
     /// 键码常量：Esc 键。
-    pub const KEYCODE_ESCAPE: i32 = 111;
+    pub const KEYCODE_ESCAPE: KeyCode = KeyCode::Escape;
 
     /// 键码常量：向前删除键。与 KEYCODE_DEL 不同，它删除插入点前面的字符。
-    pub const KEYCODE_FORWARD_DEL: i32 = 112;
+    pub const KEYCODE_FORWARD_DEL: KeyCode = KeyCode::ForwardDel;
 
     /// 键码常量：左 Control 修饰键。
-    pub const KEYCODE_CTRL_LEFT: i32 = 113;
+    pub const KEYCODE_CTRL_LEFT: KeyCode = KeyCode::CtrlLeft;
 
     /// 键码常量：右 Control 修饰键。
-    pub const KEYCODE_CTRL_RIGHT: i32 = 114;
+    pub const KEYCODE_CTRL_RIGHT: KeyCode = KeyCode::CtrlRight;
 
     /// 键码常量：大写锁定键。
-    pub const KEYCODE_CAPS_LOCK: i32 = 115;
+    pub const KEYCODE_CAPS_LOCK: KeyCode = KeyCode::CapsLock;
 
     /// 键码常量：滚动锁定键。
-    pub const KEYCODE_SCROLL_LOCK: i32 = 116;
+    pub const KEYCODE_SCROLL_LOCK: KeyCode = KeyCode::ScrollLock;
 
     /// 键码常量：左 Meta 修饰键。
-    pub const KEYCODE_META_LEFT: i32 = 117;
+    pub const KEYCODE_META_LEFT: KeyCode = KeyCode::MetaLeft;
 
     /// 键码常量：右 Meta 修饰键。
-    pub const KEYCODE_META_RIGHT: i32 = 118;
+    pub const KEYCODE_META_RIGHT: KeyCode = KeyCode::MetaRight;
 
     /// 键码常量：功能修饰键。
-    pub const KEYCODE_FUNCTION: i32 = 119;
+    pub const KEYCODE_FUNCTION: KeyCode = KeyCode::Function;
 
     //noinspection SpellCheckingInspection
     /// 键码常量：系统请求/打印屏幕键。
-    pub const KEYCODE_SYSRQ: i32 = 120;
+    pub const KEYCODE_SYSRQ: KeyCode = KeyCode::Sysrq;
 
     /// 键码常量：Break / Pause 键。
-    pub const KEYCODE_BREAK: i32 = 121;
+    pub const KEYCODE_BREAK: KeyCode = KeyCode::Break;
 
     /// 键码常量：Home 移动键。用于滚动或移动光标到 行 的开始或列表的顶部。
-    pub const KEYCODE_MOVE_HOME: i32 = 122;
+    pub const KEYCODE_MOVE_HOME: KeyCode = KeyCode::MoveHome;
 
     /// 键码常量：End 移动键。用于滚动或移动光标到 行 的末尾或列表的底部。
-    pub const KEYCODE_MOVE_END: i32 = 123;
+    pub const KEYCODE_MOVE_END: KeyCode = KeyCode::MoveEnd;
 
     /// 键码常量：Insert 键。切换插入/覆盖编辑模式。
-    pub const KEYCODE_INSERT: i32 = 124;
+    pub const KEYCODE_INSERT: KeyCode = KeyCode::Insert;
 
     /// 键码常量：前进键。在历史堆栈中向前导航。与 KEYCODE_BACK 互补。
-    pub const KEYCODE_FORWARD: i32 = 125;
+    pub const KEYCODE_FORWARD: KeyCode = KeyCode::Forward;
 
     /// 键码常量：播放媒体键。
-    pub const KEYCODE_MEDIA_PLAY: i32 = 126;
+    pub const KEYCODE_MEDIA_PLAY: KeyCode = KeyCode::MediaPlay;
 
     /// 键码常量：暂停媒体键。
-    pub const KEYCODE_MEDIA_PAUSE: i32 = 127;
+    pub const KEYCODE_MEDIA_PAUSE: KeyCode = KeyCode::MediaPause;
 
     /// 键码常量：关闭媒体键。例如，可用于关闭 CD 托盘。
-    pub const KEYCODE_MEDIA_CLOSE: i32 = 128;
+    pub const KEYCODE_MEDIA_CLOSE: KeyCode = KeyCode::MediaClose;
 
     /// 键码常量：弹出媒体键。例如，可用于弹出 CD 托盘。
-    pub const KEYCODE_MEDIA_EJECT: i32 = 129;
+    pub const KEYCODE_MEDIA_EJECT: KeyCode = KeyCode::MediaEject;
 
     /// 键码常量：录制媒体键。
-    pub const KEYCODE_MEDIA_RECORD: i32 = 130;
+    pub const KEYCODE_MEDIA_RECORD: KeyCode = KeyCode::MediaRecord;
 
     /// 键码常量：F1 键。
-    pub const KEYCODE_F1: i32 = 131;
+    pub const KEYCODE_F1: KeyCode = KeyCode::F1;
 
     /// 键码常量：F2 键。
-    pub const KEYCODE_F2: i32 = 132;
+    pub const KEYCODE_F2: KeyCode = KeyCode::F2;
 
     /// 键码常量：F3 键。
-    pub const KEYCODE_F3: i32 = 133;
+    pub const KEYCODE_F3: KeyCode = KeyCode::F3;
 
     /// 键码常量：F4 键。
-    pub const KEYCODE_F4: i32 = 134;
+    pub const KEYCODE_F4: KeyCode = KeyCode::F4;
 
     /// 键码常量：F5 键。
-    pub const KEYCODE_F5: i32 = 135;
+    pub const KEYCODE_F5: KeyCode = KeyCode::F5;
 
     /// 键码常量：F6 键。
-    pub const KEYCODE_F6: i32 = 136;
+    pub const KEYCODE_F6: KeyCode = KeyCode::F6;
 
     /// 键码常量：F7 键。
-    pub const KEYCODE_F7: i32 = 137;
+    pub const KEYCODE_F7: KeyCode = KeyCode::F7;
 
     /// 键码常量：F8 键。
-    pub const KEYCODE_F8: i32 = 138;
+    pub const KEYCODE_F8: KeyCode = KeyCode::F8;
 
     /// 键码常量：F9 键。
-    pub const KEYCODE_F9: i32 = 139;
+    pub const KEYCODE_F9: KeyCode = KeyCode::F9;
 
     /// 键码常量：F10 键。
-    pub const KEYCODE_F10: i32 = 140;
+    pub const KEYCODE_F10: KeyCode = KeyCode::F10;
 
     /// 键码常量：F11 键。
-    pub const KEYCODE_F11: i32 = 141;
+    pub const KEYCODE_F11: KeyCode = KeyCode::F11;
 
     /// 键码常量：数字键盘'('键
-    pub const KEYCODE_NUMPAD_LEFT_PAREN: i32 = 162;
+    pub const KEYCODE_NUMPAD_LEFT_PAREN: KeyCode = KeyCode::NumpadLeftParen;
 
     /// 键码常量：数字键盘')'键。
-    pub const KEYCODE_NUMPAD_RIGHT_PAREN: i32 = 163;
+    pub const KEYCODE_NUMPAD_RIGHT_PAREN: KeyCode = KeyCode::NumpadRightParen;
 
     /// 键码常量：F12 键。
-    pub const KEYCODE_F12: i32 = 142;
+    pub const KEYCODE_F12: KeyCode = KeyCode::F12;
 
     /// 键码常量：Num Lock 键。这是 Num Lock 键，与 KEYCODE_NUM 不同。此键会改变数字键盘上其他键的行为。
-    pub const KEYCODE_NUM_LOCK: i32 = 143;
+    pub const KEYCODE_NUM_LOCK: KeyCode = KeyCode::NumLock;
 
     /// 键码常量：数字键盘 '0' 键。
-    pub const KEYCODE_NUMPAD_0: i32 = 144;
+    pub const KEYCODE_NUMPAD_0: KeyCode = KeyCode::Numpad0;
 
     /// 键码常量：数字键盘 '1' 键。
-    pub const KEYCODE_NUMPAD_1: i32 = 145;
+    pub const KEYCODE_NUMPAD_1: KeyCode = KeyCode::Numpad1;
 
     /// 键码常量：数字键盘 '2' 键。
-    pub const KEYCODE_NUMPAD_2: i32 = 146;
+    pub const KEYCODE_NUMPAD_2: KeyCode = KeyCode::Numpad2;
 
     /// 键码常量：数字键盘 '3' 键。
-    pub const KEYCODE_NUMPAD_3: i32 = 147;
+    pub const KEYCODE_NUMPAD_3: KeyCode = KeyCode::Numpad3;
 
     /// 键码常量：数字键盘 '4' 键。
-    pub const KEYCODE_NUMPAD_4: i32 = 148;
+    pub const KEYCODE_NUMPAD_4: KeyCode = KeyCode::Numpad4;
 
     /// 键码常量：数字键盘 '5' 键。
-    pub const KEYCODE_NUMPAD_5: i32 = 149;
+    pub const KEYCODE_NUMPAD_5: KeyCode = KeyCode::Numpad5;
 
     /// 键码常量：数字键盘 '6' 键。
-    pub const KEYCODE_NUMPAD_6: i32 = 150;
+    pub const KEYCODE_NUMPAD_6: KeyCode = KeyCode::Numpad6;
 
     /// 键码常量：数字键盘 '7' 键。
-    pub const KEYCODE_NUMPAD_7: i32 = 151;
+    pub const KEYCODE_NUMPAD_7: KeyCode = KeyCode::Numpad7;
 
     /// 键码常量：数字键盘 '8' 键。
-    pub const KEYCODE_NUMPAD_8: i32 = 152;
+    pub const KEYCODE_NUMPAD_8: KeyCode = KeyCode::Numpad8;
 
     /// 键码常量：数字键盘 '9' 键。
-    pub const KEYCODE_NUMPAD_9: i32 = 153;
+    pub const KEYCODE_NUMPAD_9: KeyCode = KeyCode::Numpad9;
 
     /// 键码常量：数字键盘 '/' 键（用于除法）。
-    pub const KEYCODE_NUMPAD_DIVIDE: i32 = 154;
+    pub const KEYCODE_NUMPAD_DIVIDE: KeyCode = KeyCode::NumpadDivide;
 
     /// 键码常量：数字键盘 '*' 键（用于乘法）。
-    pub const KEYCODE_NUMPAD_MULTIPLY: i32 = 155;
+    pub const KEYCODE_NUMPAD_MULTIPLY: KeyCode = KeyCode::NumpadMultiply;
 
     /// 键码常量：数字键盘 '-' 键（用于减法）。
-    pub const KEYCODE_NUMPAD_SUBTRACT: i32 = 156;
+    pub const KEYCODE_NUMPAD_SUBTRACT: KeyCode = KeyCode::NumpadSubtract;
 
     /// 键码常量：数字键盘 '+' 键（用于加法）。
-    pub const KEYCODE_NUMPAD_ADD: i32 = 157;
+    pub const KEYCODE_NUMPAD_ADD: KeyCode = KeyCode::NumpadAdd;
 
     /// 键码常量：数字键盘 '.' 键（用于小数或数字分组）。
-    pub const KEYCODE_NUMPAD_DOT: i32 = 158;
+    pub const KEYCODE_NUMPAD_DOT: KeyCode = KeyCode::NumpadDot;
 
     /// 键码常量：数字键盘 ',' 键（用于小数或数字分组）。
-    pub const KEYCODE_NUMPAD_COMMA: i32 = 159;
+    pub const KEYCODE_NUMPAD_COMMA: KeyCode = KeyCode::NumpadComma;
 
     /// 键码常量：数字键盘 Enter 键。
-    pub const KEYCODE_NUMPAD_ENTER: i32 = 160;
+    pub const KEYCODE_NUMPAD_ENTER: KeyCode = KeyCode::NumpadEnter;
 
     /// 键码常量：绿色“可编程”键。在电视遥控器上，用作上下文/可编程键。
-    pub const KEYCODE_PROG_GREEN: i32 = 184;
+    pub const KEYCODE_PROG_GREEN: KeyCode = KeyCode::ProgGreen;
 
     /// 键码常量：数字键盘 '=' 键。
-    pub const KEYCODE_NUMPAD_EQUALS: i32 = 161;
+    pub const KEYCODE_NUMPAD_EQUALS: KeyCode = KeyCode::NumpadEquals;
 
     /// 键码常量：音量静音键。用于扬声器的静音键（与 KEYCODE_MUTE 不同，后者是麦克风的静音键）。此键通常应实现为切换键，即第一次按下时静音扬声器，第二次按下时恢复原始音量。
-    pub const KEYCODE_VOLUME_MUTE: i32 = 164;
+    pub const KEYCODE_VOLUME_MUTE: KeyCode = KeyCode::VolumeMute;
 
     /// 键码常量：信息键。通常在电视遥控器上，用于显示与当前正在查看的内容相关的附加信息。
-    pub const KEYCODE_INFO: i32 = 165;
+    pub const KEYCODE_INFO: KeyCode = KeyCode::Info;
 
     /// 键码常量：频道上键。在电视遥控器上，用于增加电视频道。
-    pub const KEYCODE_CHANNEL_UP: i32 = 166;
+    pub const KEYCODE_CHANNEL_UP: KeyCode = KeyCode::ChannelUp;
 
     /// 键码常量：频道下键。在电视遥控器上，用于减少电视频道。
-    pub const KEYCODE_CHANNEL_DOWN: i32 = 167;
+    pub const KEYCODE_CHANNEL_DOWN: KeyCode = KeyCode::ChannelDown;
 
     /// 键码常量：放大键。
-    pub const KEYCODE_ZOOM_IN: i32 = 168;
+    pub const KEYCODE_ZOOM_IN: KeyCode = KeyCode::ZoomIn;
 
     /// 键码常量：缩小键。
-    pub const KEYCODE_ZOOM_OUT: i32 = 169;
+    pub const KEYCODE_ZOOM_OUT: KeyCode = KeyCode::ZoomOut;
 
     /// 键码常量：电视键。在电视遥控器上，切换到观看直播电视。
-    pub const KEYCODE_TV: i32 = 170;
+    pub const KEYCODE_TV: KeyCode = KeyCode::Tv;
 
     /// 键码常量：窗口键。在电视遥控器上，切换画中画模式或其他窗口功能。在 Android Wear 设备上，触发显示偏移。
-    pub const KEYCODE_WINDOW: i32 = 171;
+    pub const KEYCODE_WINDOW: KeyCode = KeyCode::Window;
 
     /// 键码常量：指南键。在电视遥控器上，显示节目指南。
-    pub const KEYCODE_GUIDE: i32 = 172;
+    pub const KEYCODE_GUIDE: KeyCode = KeyCode::Guide;
 
     /// 键码常量：DVR 键。在某些电视遥控器上，切换到录制的节目的 DVR 模式。
-    pub const KEYCODE_DVR: i32 = 173;
+    pub const KEYCODE_DVR: KeyCode = KeyCode::Dvr;
 
     /// 键码常量：书签键。在某些电视遥控器上，用于标记内容或网页为书签。
-    pub const KEYCODE_BOOKMARK: i32 = 174;
+    pub const KEYCODE_BOOKMARK: KeyCode = KeyCode::Bookmark;
 
     /// 键码常量：切换字幕键。在电视节目期间，切换闭路字幕文本的模式。
-    pub const KEYCODE_CAPTIONS: i32 = 175;
+    pub const KEYCODE_CAPTIONS: KeyCode = KeyCode::Captions;
 
     /// 键码常量：设置键。启动系统设置活动。
-    pub const KEYCODE_SETTINGS: i32 = 176;
+    pub const KEYCODE_SETTINGS: KeyCode = KeyCode::Settings;
 
     /// 键码常量：语言切换键。切换当前输入语言，例如在 QWERTY 键盘上切换英语和日语。在某些设备上，按 Shift+空格键可以执行相同的功能。
-    pub const KEYCODE_LANGUAGE_SWITCH: i32 = 204;
+    pub const KEYCODE_LANGUAGE_SWITCH: KeyCode = KeyCode::LanguageSwitch;
 
     /// 键码常量：电视电源键。在HDMI电视面板设备和不支持HDMI的Android TV设备上，切换设备的电源状态。在HDMI源设备上，通过HDMI-CEC切换HDMI连接电视的电源状态，并使源设备跟随此电源状态。
-    pub const KEYCODE_TV_POWER: i32 = 177;
+    pub const KEYCODE_TV_POWER: KeyCode = KeyCode::TvPower;
 
     /// 键码常量：电视输入键。在电视遥控器上，在电视屏幕上切换输入。
-    pub const KEYCODE_TV_INPUT: i32 = 178;
+    pub const KEYCODE_TV_INPUT: KeyCode = KeyCode::TvInput;
 
     /// 键码常量：机顶盒电源键。在电视遥控器上，切换外部机顶盒的电源。
-    pub const KEYCODE_STB_POWER: i32 = 179;
+    pub const KEYCODE_STB_POWER: KeyCode = KeyCode::StbPower;
 
     /// 键码常量：机顶盒输入键。在电视遥控器上，切换外部机顶盒的输入模式。
-    pub const KEYCODE_STB_INPUT: i32 = 180;
+    pub const KEYCODE_STB_INPUT: KeyCode = KeyCode::StbInput;
 
     /// 键码常量：A/V接收器电源键。在电视遥控器上，切换外部A/V接收器的电源。
-    pub const KEYCODE_AVR_POWER: i32 = 181;
+    pub const KEYCODE_AVR_POWER: KeyCode = KeyCode::AvrPower;
 
     /// 键码常量：A/V接收器输入键。在电视遥控器上，切换外部A/V接收器的输入模式。
-    pub const KEYCODE_AVR_INPUT: i32 = 182;
+    pub const KEYCODE_AVR_INPUT: KeyCode = KeyCode::AvrInput;
 
     /// 键码常量：红色“可编程”键。在电视遥控器上，作为上下文/可编程键使用。
-    pub const KEYCODE_PROG_RED: i32 = 183;
+    pub const KEYCODE_PROG_RED: KeyCode = KeyCode::ProgRed;
 
     /// 键码常量：黄色“可编程”键。在电视遥控器上，作为上下文/可编程键使用。
-    pub const KEYCODE_PROG_YELLOW: i32 = 185;
+    pub const KEYCODE_PROG_YELLOW: KeyCode = KeyCode::ProgYellow;
 
     /// 键码常量：蓝色“可编程”键。在电视遥控器上，作为上下文/可编程键使用。
-    pub const KEYCODE_PROG_BLUE: i32 = 186;
+    pub const KEYCODE_PROG_BLUE: KeyCode = KeyCode::ProgBlue;
 
     /// 键码常量：应用程序切换键。应该显示应用程序切换器对话框。
-    pub const KEYCODE_APP_SWITCH: i32 = 187;
+    pub const KEYCODE_APP_SWITCH: KeyCode = KeyCode::AppSwitch;
 
     /// 键码常量：通用游戏板按钮#1。
-    pub const KEYCODE_BUTTON_1: i32 = 188;
+    pub const KEYCODE_BUTTON_1: KeyCode = KeyCode::Button1;
 
     /// 键码常量：通用游戏板按钮#2。
-    pub const KEYCODE_BUTTON_2: i32 = 189;
+    pub const KEYCODE_BUTTON_2: KeyCode = KeyCode::Button2;
 
     //noinspection SpellCheckingInspection
     /// 键码常量：日语全角/半角键。
-    pub const KEYCODE_ZENKAKU_HANKAKU: i32 = 211;
+    pub const KEYCODE_ZENKAKU_HANKAKU: KeyCode = KeyCode::ZenkakuHankaku;
 
     //noinspection SpellCheckingInspection
     /// 键码常量：日语字母数字键。
-    pub const KEYCODE_EISU: i32 = 212;
+    pub const KEYCODE_EISU: KeyCode = KeyCode::Eisu;
 
     //noinspection SpellCheckingInspection
     /// 键码常量：日语非转换键。
-    pub const KEYCODE_MUHENKAN: i32 = 213;
+    pub const KEYCODE_MUHENKAN: KeyCode = KeyCode::Muhenkan;
 
     //noinspection SpellCheckingInspection
     /// 键码常量：日语转换键。
-    pub const KEYCODE_HENKAN: i32 = 214;
+    pub const KEYCODE_HENKAN: KeyCode = KeyCode::Henkan;
 
     /// 键码常量：通用游戏板按钮#3。
-    pub const KEYCODE_BUTTON_3: i32 = 190;
+    pub const KEYCODE_BUTTON_3: KeyCode = KeyCode::Button3;
 
     /// 键码常量：通用游戏板按钮#4。
-    pub const KEYCODE_BUTTON_4: i32 = 191;
+    pub const KEYCODE_BUTTON_4: KeyCode = KeyCode::Button4;
 
     /// 键码常量：通用游戏板按钮#5。
-    pub const KEYCODE_BUTTON_5: i32 = 192;
+    pub const KEYCODE_BUTTON_5: KeyCode = KeyCode::Button5;
 
     /// 键码常量：通用游戏板按钮#6。
-    pub const KEYCODE_BUTTON_6: i32 = 193;
+    pub const KEYCODE_BUTTON_6: KeyCode = KeyCode::Button6;
 
     /// 键码常量：通用游戏板按钮#7。
-    pub const KEYCODE_BUTTON_7: i32 = 194;
+    pub const KEYCODE_BUTTON_7: KeyCode = KeyCode::Button7;
 
     /// 键码常量：通用游戏板按钮#8。
-    pub const KEYCODE_BUTTON_8: i32 = 195;
+    pub const KEYCODE_BUTTON_8: KeyCode = KeyCode::Button8;
 
     /// 键码常量：通用游戏板按钮#9。
-    pub const KEYCODE_BUTTON_9: i32 = 196;
+    pub const KEYCODE_BUTTON_9: KeyCode = KeyCode::Button9;
 
     /// 键码常量：通用游戏板按钮#10。
-    pub const KEYCODE_BUTTON_10: i32 = 197;
+    pub const KEYCODE_BUTTON_10: KeyCode = KeyCode::Button10;
 
     /// 键码常量：通用游戏板按钮#11。
-    pub const KEYCODE_BUTTON_11: i32 = 198;
+    pub const KEYCODE_BUTTON_11: KeyCode = KeyCode::Button11;
 
     /// 键码常量：通用游戏板按钮#12。
-    pub const KEYCODE_BUTTON_12: i32 = 199;
+    pub const KEYCODE_BUTTON_12: KeyCode = KeyCode::Button12;
 
     /// 键码常量：通用游戏板按钮#13。
-    pub const KEYCODE_BUTTON_13: i32 = 200;
+    pub const KEYCODE_BUTTON_13: KeyCode = KeyCode::Button13;
 
     /// 键码常量：通用游戏板按钮#14。
-    pub const KEYCODE_BUTTON_14: i32 = 201;
+    pub const KEYCODE_BUTTON_14: KeyCode = KeyCode::Button14;
 
     /// 键码常量：通用游戏板按钮#15。
-    pub const KEYCODE_BUTTON_15: i32 = 202;
+    pub const KEYCODE_BUTTON_15: KeyCode = KeyCode::Button15;
 
     /// 键码常量：通用游戏板按钮#16。
-    pub const KEYCODE_BUTTON_16: i32 = 203;
+    pub const KEYCODE_BUTTON_16: KeyCode = KeyCode::Button16;
 
     /// 键码常量：礼仪模式键。在某些设置（如在拥挤的火车上）中，打开和关闭静音或振动模式，使设备表现得更加礼貌。在某些设备上，此键可能仅在长按时才有效。
-    pub const KEYCODE_MANNER_MODE: i32 = 205;
+    pub const KEYCODE_MANNER_MODE: KeyCode = KeyCode::MannerMode;
 
     /// 键码常量：3D模式键。在2D和3D模式之间切换显示。
-    pub const KEYCODE_3D_MODE: i32 = 206;
+    pub const KEYCODE_3D_MODE: KeyCode = KeyCode::Key3dMode;
 
     /// 键码常量：联系人特殊功能键。用于启动地址簿应用程序。
-    pub const KEYCODE_CONTACTS: i32 = 207;
+    pub const KEYCODE_CONTACTS: KeyCode = KeyCode::Contacts;
 
     /// 键码常量：日历特殊功能键。用于启动日历应用程序。
-    pub const KEYCODE_CALENDAR: i32 = 208;
+    pub const KEYCODE_CALENDAR: KeyCode = KeyCode::Calendar;
 
     /// 键码常量：音轨键。切换音轨。
-    pub const KEYCODE_MEDIA_AUDIO_TRACK: i32 = 222;
+    pub const KEYCODE_MEDIA_AUDIO_TRACK: KeyCode = KeyCode::MediaAudioTrack;
 
     /// 键码常量：睡眠键。使设备进入睡眠状态。行为类似于 KEYCODE_POWER，但如果设备已处于睡眠状态，则不起作用。
-    pub const KEYCODE_SLEEP: i32 = 223;
+    pub const KEYCODE_SLEEP: KeyCode = KeyCode::Sleep;
 
     /// 键码常量：唤醒键。唤醒设备。行为有点类似于 KEYCODE_POWER，但如果设备已唤醒，则不起作用。
-    pub const KEYCODE_WAKEUP: i32 = 224;
+    pub const KEYCODE_WAKEUP: KeyCode = KeyCode::Wakeup;
 
     /// 键码常量：音乐特殊功能键。用于启动音乐播放器应用程序。
-    pub const KEYCODE_MUSIC: i32 = 209;
+    pub const KEYCODE_MUSIC: KeyCode = KeyCode::Music;
 
     /// 键码常量：计算器特殊功能键。用于启动计算器应用程序。
-    pub const KEYCODE_CALCULATOR: i32 = 210;
+    pub const KEYCODE_CALCULATOR: KeyCode = KeyCode::Calculator;
 
     /// 键码常量：日本假名/平假名键。
-    pub const KEYCODE_KATAKANA_HIRAGANA: i32 = 215;
+    pub const KEYCODE_KATAKANA_HIRAGANA: KeyCode = KeyCode::KatakanaHiragana;
 
     /// 键码常量：日本日元键。
-    pub const KEYCODE_YEN: i32 = 216;
+    pub const KEYCODE_YEN: KeyCode = KeyCode::Yen;
 
     /// 键码常量：日本Ro键。
-    pub const KEYCODE_RO: i32 = 217;
+    pub const KEYCODE_RO: KeyCode = KeyCode::Ro;
 
     /// 键码常量：日本假名键。
-    pub const KEYCODE_KANA: i32 = 218;
+    pub const KEYCODE_KANA: KeyCode = KeyCode::Kana;
 
     /// 键码常量：辅助键。启动全局辅助活动。不会传递给应用程序。
-    pub const KEYCODE_ASSIST: i32 = 219;
+    pub const KEYCODE_ASSIST: KeyCode = KeyCode::Assist;
 
     /// 键码常量：亮度减小键。降低屏幕亮度。
-    pub const KEYCODE_BRIGHTNESS_DOWN: i32 = 220;
+    pub const KEYCODE_BRIGHTNESS_DOWN: KeyCode = KeyCode::BrightnessDown;
 
     /// 键码常量：亮度增大键。提高屏幕亮度。
-    pub const KEYCODE_BRIGHTNESS_UP: i32 = 221;
+    pub const KEYCODE_BRIGHTNESS_UP: KeyCode = KeyCode::BrightnessUp;
 
     /// 键码常量：配对键。启动外设配对模式。对于配对遥控器或游戏控制器特别有用，尤其是如果没有其他输入模式可用时。
-    pub const KEYCODE_PAIRING: i32 = 225;
+    pub const KEYCODE_PAIRING: KeyCode = KeyCode::Pairing;
 
     /// 键码常量：媒体顶层菜单键。跳转到媒体菜单的顶部。
-    pub const KEYCODE_MEDIA_TOP_MENU: i32 = 226;
+    pub const KEYCODE_MEDIA_TOP_MENU: KeyCode = KeyCode::MediaTopMenu;
 
     /// 键码常量：‘11’键。
-    pub const KEYCODE_11: i32 = 227;
+    pub const KEYCODE_11: KeyCode = KeyCode::Key11;
 
     /// 键码常量：“12”键。
-    pub const KEYCODE_12: i32 = 228;
+    pub const KEYCODE_12: KeyCode = KeyCode::Key12;
 
     /// 键码常量：上一个频道键。跳转到最后一个观看的频道。
-    pub const KEYCODE_LAST_CHANNEL: i32 = 229;
+    pub const KEYCODE_LAST_CHANNEL: KeyCode = KeyCode::LastChannel;
 
     /// 键码常量：电视数据服务键。显示数据服务，如天气、体育等。
-    pub const KEYCODE_TV_DATA_SERVICE: i32 = 230;
+    pub const KEYCODE_TV_DATA_SERVICE: KeyCode = KeyCode::TvDataService;
 
     /// 键码常量：语音助手键。启动全局语音助手活动。不会传递给应用程序。
-    pub const KEYCODE_VOICE_ASSIST: i32 = 231;
+    pub const KEYCODE_VOICE_ASSIST: KeyCode = KeyCode::VoiceAssist;
 
     /// 键码常量：收音机键。切换电视服务/收音机服务。
-    pub const KEYCODE_TV_RADIO_SERVICE: i32 = 232;
+    pub const KEYCODE_TV_RADIO_SERVICE: KeyCode = KeyCode::TvRadioService;
 
     /// 键码常量：电视图文键。显示电视图文服务。
-    pub const KEYCODE_TV_TELETEXT: i32 = 233;
+    pub const KEYCODE_TV_TELETEXT: KeyCode = KeyCode::TvTeletext;
 
     /// 键码常量：数字输入键。当每个数字键被分配用于选择单独的频道时，启动输入多位频道号。对应于 CEC 用户控制代码的数字输入模式 (0x1D)。
-    pub const KEYCODE_TV_NUMBER_ENTRY: i32 = 234;
+    pub const KEYCODE_TV_NUMBER_ENTRY: KeyCode = KeyCode::TvNumberEntry;
 
     /// 键码常量：模拟地面广播键。切换到模拟地面广播服务。
-    pub const KEYCODE_TV_TERRESTRIAL_ANALOG: i32 = 235;
+    pub const KEYCODE_TV_TERRESTRIAL_ANALOG: KeyCode = KeyCode::TvTerrestrialAnalog;
 
     /// 键码常量：数字地面广播键。切换到数字地面广播服务。
-    pub const KEYCODE_TV_TERRESTRIAL_DIGITAL: i32 = 236;
+    pub const KEYCODE_TV_TERRESTRIAL_DIGITAL: KeyCode = KeyCode::TvTerrestrialDigital;
 
     /// 键码常量：卫星键。切换到数字卫星广播服务。
-    pub const KEYCODE_TV_SATELLITE: i32 = 237;
+    pub const KEYCODE_TV_SATELLITE: KeyCode = KeyCode::TvSatellite;
 
     /// 键码常量：BS键。切换到日本可用的BS数字卫星广播服务。
-    pub const KEYCODE_TV_SATELLITE_BS: i32 = 238;
+    pub const KEYCODE_TV_SATELLITE_BS: KeyCode = KeyCode::TvSatelliteBs;
 
     /// 键码常量：CS键。切换到日本可用的CS数字卫星广播服务。
-    pub const KEYCODE_TV_SATELLITE_CS: i32 = 239;
+    pub const KEYCODE_TV_SATELLITE_CS: KeyCode = KeyCode::TvSatelliteCs;
 
     /// 键码常量：BS/CS键。在BS和CS数字卫星服务之间切换。
-    pub const KEYCODE_TV_SATELLITE_SERVICE: i32 = 240;
+    pub const KEYCODE_TV_SATELLITE_SERVICE: KeyCode = KeyCode::TvSatelliteService;
 
     /// 键码常量：切换网络键。切换选择广播服务。
-    pub const KEYCODE_TV_NETWORK: i32 = 241;
+    pub const KEYCODE_TV_NETWORK: KeyCode = KeyCode::TvNetwork;
 
     /// 键码常量：天线/电缆键。在天线和电缆之间切换广播输入源。
-    pub const KEYCODE_TV_ANTENNA_CABLE: i32 = 242;
+    pub const KEYCODE_TV_ANTENNA_CABLE: KeyCode = KeyCode::TvAntennaCable;
 
     /// 键码常量：HDMI #1 键。切换到 HDMI 输入 #1。
-    pub const KEYCODE_TV_INPUT_HDMI_1: i32 = 243;
+    pub const KEYCODE_TV_INPUT_HDMI_1: KeyCode = KeyCode::TvInputHdmi1;
 
     /// 键码常量：HDMI #2 键。切换到 HDMI 输入 #2。
-    pub const KEYCODE_TV_INPUT_HDMI_2: i32 = 244;
+    pub const KEYCODE_TV_INPUT_HDMI_2: KeyCode = KeyCode::TvInputHdmi2;
 
     /// 键码常量：HDMI #3 键。切换到 HDMI 输入 #3。
-    pub const KEYCODE_TV_INPUT_HDMI_3: i32 = 245;
+    pub const KEYCODE_TV_INPUT_HDMI_3: KeyCode = KeyCode::TvInputHdmi3;
 
     /// 键码常量：HDMI #4 键。切换到 HDMI 输入 #4。
-    pub const KEYCODE_TV_INPUT_HDMI_4: i32 = 246;
+    pub const KEYCODE_TV_INPUT_HDMI_4: KeyCode = KeyCode::TvInputHdmi4;
 
     /// 键码常量：复合 #1 键。切换到复合视频输入 #1。
-    pub const KEYCODE_TV_INPUT_COMPOSITE_1: i32 = 247;
+    pub const KEYCODE_TV_INPUT_COMPOSITE_1: KeyCode = KeyCode::TvInputComposite1;
 
     /// 键码常量：复合 #2 键。切换到复合视频输入 #2。
-    pub const KEYCODE_TV_INPUT_COMPOSITE_2: i32 = 248;
+    pub const KEYCODE_TV_INPUT_COMPOSITE_2: KeyCode = KeyCode::TvInputComposite2;
 
     /// 键码常量：分量 #1 键。切换到分量视频输入 #1。
-    pub const KEYCODE_TV_INPUT_COMPONENT_1: i32 = 249;
+    pub const KEYCODE_TV_INPUT_COMPONENT_1: KeyCode = KeyCode::TvInputComponent1;
 
     /// 键码常量：分量 #2 键。切换到分量视频输入 #2。
-    pub const KEYCODE_TV_INPUT_COMPONENT_2: i32 = 250;
+    pub const KEYCODE_TV_INPUT_COMPONENT_2: KeyCode = KeyCode::TvInputComponent2;
 
     /// 键码常量：VGA #1 键。切换到 VGA（模拟 RGB）输入 #1。
-    pub const KEYCODE_TV_INPUT_VGA_1: i32 = 251;
+    pub const KEYCODE_TV_INPUT_VGA_1: KeyCode = KeyCode::TvInputVga1;
 
     /// 键码常量：音频描述键。开启/关闭音频描述。
-    pub const KEYCODE_TV_AUDIO_DESCRIPTION: i32 = 252;
+    pub const KEYCODE_TV_AUDIO_DESCRIPTION: KeyCode = KeyCode::TvAudioDescription;
 
     /// 键码常量：音频描述混合音量调高键。与正常音频音量相比，增大音频描述音量。
-    pub const KEYCODE_TV_AUDIO_DESCRIPTION_MIX_UP: i32 = 253;
+    pub const KEYCODE_TV_AUDIO_DESCRIPTION_MIX_UP: KeyCode = KeyCode::TvAudioDescriptionMixUp;
 
     /// 键码常量：音频描述混音音量减小键。与正常音频音量相比，降低音频描述音量。
-    pub const KEYCODE_TV_AUDIO_DESCRIPTION_MIX_DOWN: i32 = 254;
+    pub const KEYCODE_TV_AUDIO_DESCRIPTION_MIX_DOWN: KeyCode = KeyCode::TvAudioDescriptionMixDown;
 
     /// 键码常量：缩放模式键。更改缩放模式（正常、全屏、缩放、宽缩放等）
-    pub const KEYCODE_TV_ZOOM_MODE: i32 = 255;
+    pub const KEYCODE_TV_ZOOM_MODE: KeyCode = KeyCode::TvZoomMode;
 
     /// 键码常量：内容菜单键。进入标题列表。对应于CEC用户控制代码的“内容菜单”（0x0B）
-    pub const KEYCODE_TV_CONTENTS_MENU: i32 = 256;
+    pub const KEYCODE_TV_CONTENTS_MENU: KeyCode = KeyCode::TvContentsMenu;
 
     /// 键码常量：媒体上下文菜单键。进入媒体内容的上下文菜单。对应于CEC用户控制代码的“媒体上下文相关菜单”（0x11）。
-    pub const KEYCODE_TV_MEDIA_CONTEXT_MENU: i32 = 257;
+    pub const KEYCODE_TV_MEDIA_CONTEXT_MENU: KeyCode = KeyCode::TvMediaContextMenu;
 
     /// 键码常量：定时器编程键。进入定时器录制菜单。对应于CEC用户控制代码的“定时器编程”（0x54）。
-    pub const KEYCODE_TV_TIMER_PROGRAMMING: i32 = 258;
+    pub const KEYCODE_TV_TIMER_PROGRAMMING: KeyCode = KeyCode::TvTimerProgramming;
 
     /// 键码常量：帮助键。
-    pub const KEYCODE_HELP: i32 = 259;
+    pub const KEYCODE_HELP: KeyCode = KeyCode::Help;
 
     /// 键码常量：导航到上一个键。在有序的项目集合中向后移动一个项目。
-    pub const KEYCODE_NAVIGATE_PREVIOUS: i32 = 260;
+    pub const KEYCODE_NAVIGATE_PREVIOUS: KeyCode = KeyCode::NavigatePrevious;
 
     /// 键码常量：导航到下一个键。在有序的项目集合中前进到下一个项目。
-    pub const KEYCODE_NAVIGATE_NEXT: i32 = 261;
+    pub const KEYCODE_NAVIGATE_NEXT: KeyCode = KeyCode::NavigateNext;
 
     /// 键码常量：导航进入键。激活当前具有焦点的项目或展开到导航层级的下一个级别。
-    pub const KEYCODE_NAVIGATE_IN: i32 = 262;
+    pub const KEYCODE_NAVIGATE_IN: KeyCode = KeyCode::NavigateIn;
 
     /// 键码常量：导航退出键。退出导航层级的一个级别或折叠当前具有焦点的项目。
-    pub const KEYCODE_NAVIGATE_OUT: i32 = 263;
+    pub const KEYCODE_NAVIGATE_OUT: KeyCode = KeyCode::NavigateOut;
 
     /// 键码常量：Wear手表上的主要电源/重置按钮的主要茎键。
-    pub const KEYCODE_STEM_PRIMARY: i32 = 264;
+    pub const KEYCODE_STEM_PRIMARY: KeyCode = KeyCode::StemPrimary;
 
     /// 键码常量：Wear的通用茎键1
-    pub const KEYCODE_STEM_1: i32 = 265;
+    pub const KEYCODE_STEM_1: KeyCode = KeyCode::Stem1;
 
     //noinspection SpellCheckingInspection
     /// 键码常量：方向键向上-向左
-    pub const KEYCODE_DPAD_UP_LEFT: i32 = 268;
+    pub const KEYCODE_DPAD_UP_LEFT: KeyCode = KeyCode::DpadUpLeft;
 
     //noinspection SpellCheckingInspection
     /// 键码常量：方向键向下向左
-    pub const KEYCODE_DPAD_DOWN_LEFT: i32 = 269;
+    pub const KEYCODE_DPAD_DOWN_LEFT: KeyCode = KeyCode::DpadDownLeft;
 
     //noinspection SpellCheckingInspection
     /// 键码常量：方向键右上
-    pub const KEYCODE_DPAD_UP_RIGHT: i32 = 270;
+    pub const KEYCODE_DPAD_UP_RIGHT: KeyCode = KeyCode::DpadUpRight;
 
     //noinspection SpellCheckingInspection
     /// 键码常量：方向键右下
-    pub const KEYCODE_DPAD_DOWN_RIGHT: i32 = 271;
+    pub const KEYCODE_DPAD_DOWN_RIGHT: KeyCode = KeyCode::DpadDownRight;
 
     /// 键码常量：Wear 的通用茎键 2
-    pub const KEYCODE_STEM_2: i32 = 266;
+    pub const KEYCODE_STEM_2: KeyCode = KeyCode::Stem2;
 
     /// 键码常量：Wear 的通用茎键 3
-    pub const KEYCODE_STEM_3: i32 = 267;
+    pub const KEYCODE_STEM_3: KeyCode = KeyCode::Stem3;
 
     /// 键码常量：跳过向前的媒体键。
-    pub const KEYCODE_MEDIA_SKIP_FORWARD: i32 = 272;
+    pub const KEYCODE_MEDIA_SKIP_FORWARD: KeyCode = KeyCode::MediaSkipForward;
 
     /// 键码常量：跳过向后的媒体键。
-    pub const KEYCODE_MEDIA_SKIP_BACKWARD: i32 = 273;
+    pub const KEYCODE_MEDIA_SKIP_BACKWARD: KeyCode = KeyCode::MediaSkipBackward;
 
     /// 键码常量：逐帧向前媒体键。每次向前移动一帧媒体。
-    pub const KEYCODE_MEDIA_STEP_FORWARD: i32 = 274;
+    pub const KEYCODE_MEDIA_STEP_FORWARD: KeyCode = KeyCode::MediaStepForward;
 
     /// 键码常量：逐帧向后媒体键。每次向后移动一帧媒体。
-    pub const KEYCODE_MEDIA_STEP_BACKWARD: i32 = 275;
+    pub const KEYCODE_MEDIA_STEP_BACKWARD: KeyCode = KeyCode::MediaStepBackward;
 
     /// 键码常量：除非持有唤醒锁，否则使设备进入休眠状态。
-    pub const KEYCODE_SOFT_SLEEP: i32 = 276;
+    pub const KEYCODE_SOFT_SLEEP: KeyCode = KeyCode::SoftSleep;
 
     /// 键码常量：剪切键。
-    pub const KEYCODE_CUT: i32 = 277;
+    pub const KEYCODE_CUT: KeyCode = KeyCode::Cut;
 
     /// 键码常量：复制键。
-    pub const KEYCODE_COPY: i32 = 278;
+    pub const KEYCODE_COPY: KeyCode = KeyCode::Copy;
 
     /// 键码常量：粘贴键。
-    pub const KEYCODE_PASTE: i32 = 279;
+    pub const KEYCODE_PASTE: KeyCode = KeyCode::Paste;
 
     /// 键码常量：由系统用于向上导航
-    pub const KEYCODE_SYSTEM_NAVIGATION_UP: i32 = 280;
+    pub const KEYCODE_SYSTEM_NAVIGATION_UP: KeyCode = KeyCode::SystemNavigationUp;
 
     /// 键码常量：由系统用于向下导航
-    pub const KEYCODE_SYSTEM_NAVIGATION_DOWN: i32 = 281;
+    pub const KEYCODE_SYSTEM_NAVIGATION_DOWN: KeyCode = KeyCode::SystemNavigationDown;
 
     /// 键码常量：由系统用于向左导航
-    pub const KEYCODE_SYSTEM_NAVIGATION_LEFT: i32 = 282;
+    pub const KEYCODE_SYSTEM_NAVIGATION_LEFT: KeyCode = KeyCode::SystemNavigationLeft;
 
     /// 键码常量：由系统用于向右导航
-    pub const KEYCODE_SYSTEM_NAVIGATION_RIGHT: i32 = 283;
+    pub const KEYCODE_SYSTEM_NAVIGATION_RIGHT: KeyCode = KeyCode::SystemNavigationRight;
 
     /// 键码常量：显示所有应用
-    pub const KEYCODE_ALL_APPS: i32 = 284;
+    pub const KEYCODE_ALL_APPS: KeyCode = KeyCode::AllApps;
 
     /// 键码常量：刷新键。
-    pub const KEYCODE_REFRESH: i32 = 285;
+    pub const KEYCODE_REFRESH: KeyCode = KeyCode::Refresh;
 
     /// 键码常量：点赞键。应用可以使用此键让用户对内容进行点赞。
-    pub const KEYCODE_THUMBS_UP: i32 = 286;
+    pub const KEYCODE_THUMBS_UP: KeyCode = KeyCode::ThumbsUp;
 
     /// 键码常量：反对键。应用可利用此功能让用户反对内容。
-    pub const KEYCODE_THUMBS_DOWN: i32 = 287;
+    pub const KEYCODE_THUMBS_DOWN: KeyCode = KeyCode::ThumbsDown;
 
     /// 键码常量：用于切换当前正在使用内容的 android.accounts.Account。系统可能会使用该代码来全局设置账户。
-    pub const KEYCODE_PROFILE_SWITCH: i32 = 288;
+    pub const KEYCODE_PROFILE_SWITCH: KeyCode = KeyCode::ProfileSwitch;
 
     /// 键码常量：视频应用键 #1。
-    pub const KEYCODE_VIDEO_APP_1: i32 = 289;
+    pub const KEYCODE_VIDEO_APP_1: KeyCode = KeyCode::VideoApp1;
 
     /// 键码常量：视频应用键 #2。
-    pub const KEYCODE_VIDEO_APP_2: i32 = 290;
+    pub const KEYCODE_VIDEO_APP_2: KeyCode = KeyCode::VideoApp2;
 
     /// 键码常量：视频应用键 #3。
-    pub const KEYCODE_VIDEO_APP_3: i32 = 291;
+    pub const KEYCODE_VIDEO_APP_3: KeyCode = KeyCode::VideoApp3;
 
     /// 键码常量：视频应用键 #4。
-    pub const KEYCODE_VIDEO_APP_4: i32 = 292;
+    pub const KEYCODE_VIDEO_APP_4: KeyCode = KeyCode::VideoApp4;
 
     /// 键码常量：视频应用键 #5。
-    pub const KEYCODE_VIDEO_APP_5: i32 = 293;
+    pub const KEYCODE_VIDEO_APP_5: KeyCode = KeyCode::VideoApp5;
 
     /// 键码常量：视频应用键 #6。
-    pub const KEYCODE_VIDEO_APP_6: i32 = 294;
+    pub const KEYCODE_VIDEO_APP_6: KeyCode = KeyCode::VideoApp6;
 
     /// 键码常量：视频应用键 #7。
-    pub const KEYCODE_VIDEO_APP_7: i32 = 295;
+    pub const KEYCODE_VIDEO_APP_7: KeyCode = KeyCode::VideoApp7;
 
     /// 键码常量：视频应用键 #8。
-    pub const KEYCODE_VIDEO_APP_8: i32 = 296;
+    pub const KEYCODE_VIDEO_APP_8: KeyCode = KeyCode::VideoApp8;
 
     /// 键码常量：特色应用键 #1。
-    pub const KEYCODE_FEATURED_APP_1: i32 = 297;
+    pub const KEYCODE_FEATURED_APP_1: KeyCode = KeyCode::FeaturedApp1;
 
     /// 键码常量：特色应用键 #2。
-    pub const KEYCODE_FEATURED_APP_2: i32 = 298;
+    pub const KEYCODE_FEATURED_APP_2: KeyCode = KeyCode::FeaturedApp2;
 
     /// 键码常量：特色应用键 #3。
-    pub const KEYCODE_FEATURED_APP_3: i32 = 299;
+    pub const KEYCODE_FEATURED_APP_3: KeyCode = KeyCode::FeaturedApp3;
 
     /// 键码常量：特色应用键 #4。
-    pub const KEYCODE_FEATURED_APP_4: i32 = 300;
+    pub const KEYCODE_FEATURED_APP_4: KeyCode = KeyCode::FeaturedApp4;
 
     /// 键码常量：演示应用键 #1。
-    pub const KEYCODE_DEMO_APP_1: i32 = 301;
+    pub const KEYCODE_DEMO_APP_1: KeyCode = KeyCode::DemoApp1;
 
     /// 键码常量：演示应用键 #2。
-    pub const KEYCODE_DEMO_APP_2: i32 = 302;
+    pub const KEYCODE_DEMO_APP_2: KeyCode = KeyCode::DemoApp2;
 
     /// 键码常量：演示应用键 #3。
-    pub const KEYCODE_DEMO_APP_3: i32 = 303;
+    pub const KEYCODE_DEMO_APP_3: KeyCode = KeyCode::DemoApp3;
 
     /// 键码常量：演示应用键 #4。
-    pub const KEYCODE_DEMO_APP_4: i32 = 304;
+    pub const KEYCODE_DEMO_APP_4: KeyCode = KeyCode::DemoApp4;
 
     /// 键码常量：键盘背光调暗
-    pub const KEYCODE_KEYBOARD_BACKLIGHT_DOWN: i32 = 305;
+    pub const KEYCODE_KEYBOARD_BACKLIGHT_DOWN: KeyCode = KeyCode::KeyboardBacklightDown;
 
     /// 键码常量：键盘背光调亮
-    pub const KEYCODE_KEYBOARD_BACKLIGHT_UP: i32 = 306;
+    pub const KEYCODE_KEYBOARD_BACKLIGHT_UP: KeyCode = KeyCode::KeyboardBacklightUp;
 
     /// 键码常量：键盘背光切换
-    pub const KEYCODE_KEYBOARD_BACKLIGHT_TOGGLE: i32 = 307;
+    pub const KEYCODE_KEYBOARD_BACKLIGHT_TOGGLE: KeyCode = KeyCode::KeyboardBacklightToggle;
 
     /// 键码常量：触控笔笔杆上的主要按钮。这通常是最靠近触控笔尖的按钮。
-    pub const KEYCODE_STYLUS_BUTTON_PRIMARY: i32 = 308;
+    pub const KEYCODE_STYLUS_BUTTON_PRIMARY: KeyCode = KeyCode::StylusButtonPrimary;
 
     /// 键码常量：触控笔笔杆上的第二个按钮。这通常是从触控笔尖算起的第二个按钮。
-    pub const KEYCODE_STYLUS_BUTTON_SECONDARY: i32 = 309;
+    pub const KEYCODE_STYLUS_BUTTON_SECONDARY: KeyCode = KeyCode::StylusButtonSecondary;
 
     /// 键码常量：触控笔笔杆上的第三个按钮。这通常是从触控笔尖开始的第三个按钮。
-    pub const KEYCODE_STYLUS_BUTTON_TERTIARY: i32 = 310;
+    pub const KEYCODE_STYLUS_BUTTON_TERTIARY: KeyCode = KeyCode::StylusButtonTertiary;
 
     /// 键码常量：触控笔尾部的按钮。此按钮的使用通常与橡皮擦的功能无关。
-    pub const KEYCODE_STYLUS_BUTTON_TAIL: i32 = 311;
+    pub const KEYCODE_STYLUS_BUTTON_TAIL: KeyCode = KeyCode::StylusButtonTail;
 
     /// 键码常量：打开最近使用的应用程序视图（又称概览）。此键由框架处理，永远不会传递给应用程序。
-    pub const KEYCODE_RECENT_APPS: i32 = 312;
+    pub const KEYCODE_RECENT_APPS: KeyCode = KeyCode::RecentApps;
 
     /// 键码常量：用户可以通过系统自定义其用途的按钮。用户可自定义键 #1。
-    pub const KEYCODE_MACRO_1: i32 = 313;
+    pub const KEYCODE_MACRO_1: KeyCode = KeyCode::Macro1;
 
     /// 键码常量：用户可以通过系统自定义其用途的按钮。用户可自定义键 #2。
-    pub const KEYCODE_MACRO_2: i32 = 314;
+    pub const KEYCODE_MACRO_2: KeyCode = KeyCode::Macro2;
 
     /// 键码常量：用户可以通过系统自定义其用途的按钮。用户可自定义键 #3。
-    pub const KEYCODE_MACRO_3: i32 = 315;
+    pub const KEYCODE_MACRO_3: KeyCode = KeyCode::Macro3;
 
     /// 键码常量：用户可以通过系统自定义其用途的按钮。用户可自定义键 #4。
-    pub const KEYCODE_MACRO_4: i32 = 316;
+    pub const KEYCODE_MACRO_4: KeyCode = KeyCode::Macro4;
 
     /// 最后一个 KEYCODE 的整数值。随着新的键码添加到 KeyEvent，该值会增加。
-    pub const LAST_KEYCODE: i32 = Self::KEYCODE_MACRO_4;
+    pub const LAST_KEYCODE: KeyCode = Self::KEYCODE_MACRO_4;
 
     #[doc(hidden)]
     #[deprecated(note = "现在键码数量已超过 MAX_KEYCODE。请使用 getMaxKeyCode()。")]
@@ -3914,7 +4936,7 @@ impl KeyEvent {
     `code` 按键代码。
     */
     #[java_constructor]
-    pub fn new(action: i32, code: i32) -> Self {}
+    pub fn new(action: i32, code: KeyCode) -> Self {}
 
     /**
     创建一个新的按键事件。
@@ -3929,7 +4951,7 @@ impl KeyEvent {
         down_time: i64,
         event_time: i64,
         action: i32,
-        code: i32,
+        code: KeyCode,
         repeat: i32,
     ) -> Self {
     }
@@ -3948,7 +4970,7 @@ impl KeyEvent {
         flags: u32,
         source: i32,
         display_id: i32,
-        characters: String,
+        characters: &str,
     ) -> Self {
     }
 
@@ -4019,35 +5041,35 @@ impl KeyEvent {
     返回：如果键码是游戏手柄按钮（例如 KEYCODE_BUTTON_A），则返回 True。
     */
     #[java_method]
-    pub fn is_gamepad_button(key_code: i32) -> bool {}
+    pub fn is_gamepad_button(key_code: KeyCode) -> bool {}
 
     /**
     默认情况下，按键是否会触发对焦点视图的点击。
     */
     #[java_method]
-    pub fn is_confirm_key(key_code: i32) -> bool {}
+    pub fn is_confirm_key(key_code: KeyCode) -> bool {}
 
     /**
     返回此键是否将被发送到android.media.session.MediaSession。若未处理则回调。
     */
     #[java_method]
-    pub fn is_media_session_key(key_code: i32) -> bool {}
+    pub fn is_media_session_key(key_code: KeyCode) -> bool {}
 
     /// 这是系统键吗？系统键不能用作菜单快捷键。
     #[java_method]
-    pub fn is_system_key(key_code: i32) -> bool {}
+    pub fn is_system_key(key_code: KeyCode) -> bool {}
 
     #[doc(hidden)]
     #[java_method]
-    pub fn is_wake_key(key_code: i32) -> bool {}
+    pub fn is_wake_key(key_code: KeyCode) -> bool {}
 
     #[doc(hidden)]
     #[java_method]
-    pub fn is_meta_key(key_code: i32) -> bool {}
+    pub fn is_meta_key(key_code: KeyCode) -> bool {}
 
     #[doc(hidden)]
     #[java_method]
-    pub fn is_alt_key(key_code: i32) -> bool {}
+    pub fn is_alt_key(key_code: KeyCode) -> bool {}
 
     #[doc(hidden)]
     #[java_method]
@@ -4104,7 +5126,7 @@ impl KeyEvent {
     返回：如果键码是 KEYCODE_SHIFT_LEFT、KEYCODE_SHIFT_RIGHT、KEYCODE_ALT_LEFT、KEYCODE_ALT_RIGHT、KEYCODE_CTRL_LEFT、KEYCODE_CTRL_RIGHT、KEYCODE_META_LEFT 或 KEYCODE_META_RIGHT、KEYCODE_SYM、KEYCODE_NUM、KEYCODE_FUNCTION 之一，则返回 True。
     */
     #[java_method]
-    pub fn is_modifier_key(key_code: i32) -> bool {}
+    pub fn is_modifier_key(key_code: KeyCode) -> bool {}
 
     /**
     规范化指定的元状态。元状态被规范化，这样如果设置了左或右修饰符元状态位，则结果还将包括该修饰符的通用位。如果指定的元状态包含 META_ALT_LEFT_ON，则结果除了 META_ALT_LEFT_ON 和输入中指定的其他位之外，还将包含 META_ALT_ON。
@@ -4235,7 +5257,7 @@ impl KeyEvent {
     /// 检索按键事件的按键代码。这是按下的物理按键，而不是 Unicode 字符。
     /// 返回：事件的按键代码。
     #[java_method]
-    pub fn get_key_code(&self) -> i32 {}
+    pub fn get_key_code(&self) -> KeyCode {}
 
     /// 对于 ACTION_MULTIPLE 事件（其键代码为 KEYCODE_UNKNOWN）的特殊情况，这是与该事件关联的原始字符串。在所有其他情况下，它为空。
     /// 返回：返回与该事件关联的 1 个或多个字符的字符串。
@@ -4345,7 +5367,7 @@ impl KeyEvent {
     `key_code` 键码。
     */
     #[java_method]
-    pub fn key_code_to_string(key_code: i32) -> String {}
+    pub fn key_code_to_string(key_code: KeyCode) -> String {}
 
     /**
     通过其符号名称（例如“KEYCODE_A”）或等效数字常量（例如“29”）获取键码。对于符号名称，从 Build.VERSION_CODES.Q 开始，前缀“KEYCODE_”是可选的。
@@ -4353,7 +5375,7 @@ impl KeyEvent {
     `symbolic_name` 键码的符号名称。
     */
     #[java_method]
-    pub fn key_code_from_string(symbolic_name: String) -> i32 {}
+    pub fn key_code_from_string(symbolic_name: &str) -> KeyCode {}
 
     /**
     返回一个字符串，该字符串表示指定的组合元键修饰符状态标志的符号名称，例如“0”、“META_SHIFT_ON”、“META_ALT_ON|META_SHIFT_ON”或等效数字常量，例如“0x10000000”（如果未知）。
@@ -4366,7 +5388,7 @@ impl KeyEvent {
 
 /// 当视图被点击并保持时调用的回调的接口定义。
 #[allow(non_camel_case_types)]
-#[java_interface(name = "android/view/View$OnLongClickListener")]
+#[java_interface(name = "android/view/View$OnLongClickListener", builder)]
 pub trait View_OnLongClickListener {
     /**
     当单击并按住某个视图时调用。
@@ -4376,30 +5398,12 @@ pub trait View_OnLongClickListener {
     fn on_long_click(&self, v: View) -> bool;
 }
 
-#[doc(hidden)]
-#[allow(non_camel_case_types)]
-#[java_class(name = "android/view/View$OnLongClickListenerImpl")]
-pub struct View_OnLongClickListenerImpl(Box<dyn Fn(View) -> bool + Send + Sync>);
-
-impl Default for View_OnLongClickListenerImplDefault {
-    fn default() -> Self {
-        Self(Box::new(|v| unimplemented!("{:?}", v)))
-    }
-}
-
-#[java_implement]
-impl View_OnLongClickListener for View_OnLongClickListenerImpl {
-    fn on_long_click(&self, v: View) -> bool {
-        self.0(v)
-    }
-}
-
 /**
 当硬件按键事件被分派到此视图时，要调用的回调的接口定义。回调将在按键事件被提供给视图之前被调用。
 这仅适用于硬件键盘；软件输入法没有义务触发此侦听器。
 */
 #[allow(non_camel_case_types)]
-#[java_interface(name = "android/view/View$OnKeyListener")]
+#[java_interface(name = "android/view/View$OnKeyListener", builder)]
 pub trait View_OnKeyListener {
     /**
     当硬件按键被分派到视图时调用。这允许侦听器有机会在目标视图之前做出响应。软件键盘中的按键通常不会触发此方法，尽管有些情况下可能会选择这样做。
@@ -4409,38 +5413,7 @@ pub trait View_OnKeyListener {
     `key_code` 按下的物理按键的代码
     `event` 包含有关事件的完整信息的 KeyEvent 对象。
     */
-    fn on_key(&self, v: View, key_code: i32, event: KeyEvent) -> bool;
-}
-
-#[doc(hidden)]
-#[allow(non_camel_case_types)]
-#[java_class(name = "android/widget/TextView$OnEditorActionListenerImpl")]
-pub struct View_OnKeyListenerImpl(Box<dyn Fn(View, i32, KeyEvent) -> bool + Send + Sync>);
-
-impl Default for View_OnKeyListenerImplDefault {
-    fn default() -> Self {
-        Self(Box::new(|v, key_code, event| {
-            unimplemented!("{:?}, {}, {:?}", v, key_code, event)
-        }))
-    }
-}
-
-impl View_OnKeyListenerImpl {
-    pub fn from_fn(
-        func: impl Fn(/* v */ View, /* key_code */ i32, /* event */ KeyEvent) -> bool
-        + Send
-        + Sync
-        + 'static,
-    ) -> Result<Arc<Self>> {
-        Self::new(View_OnKeyListenerImplDefault(Box::new(func)))
-    }
-}
-
-#[java_implement]
-impl View_OnKeyListener for View_OnKeyListenerImpl {
-    fn on_key(&self, v: View, key_code: i32, event: KeyEvent) -> bool {
-        self.0(v, key_code, event)
-    }
+    fn on_key(&self, v: View, key_code: KeyCode, event: KeyEvent) -> bool;
 }
 
 /**
@@ -4596,7 +5569,7 @@ impl Window {
     返回：WindowManager ViewManager。
     */
     #[java_method]
-    pub fn get_window_manager<WM: WindowManager>(&self) -> WM {}
+    pub fn get_window_manager(&self) -> WindowManagerImpl {}
 
     #[doc(hidden)]
     #[java_method]
@@ -4687,7 +5660,7 @@ impl Window {
     `flags` 要设置的标志位。
     */
     #[java_method]
-    pub fn add_flags(&self, flags: i32) {}
+    pub fn add_flags(&self, flags: WindowFlags) {}
 
     /**
     添加私有标志位。请参阅各个标志以了解所需的权限。
@@ -4712,7 +5685,7 @@ impl Window {
     `mask` 要修改的窗口标志位。
     */
     #[java_method]
-    pub fn set_flags(&self, flags: i32, mask: i32) {}
+    pub fn set_flags(&self, flags: WindowFlags, mask: WindowFlags) {}
 
     /**
     设置窗口的请求颜色模式。请求的颜色模式可能会覆盖窗口的像素格式。
@@ -5060,7 +6033,7 @@ impl Window {
     `event` 用于帮助检查的键。
     */
     #[java_method]
-    pub fn is_shortcut_key(&self, key_code: i32, event: &KeyEvent) -> bool {}
+    pub fn is_shortcut_key(&self, key_code: KeyCode, event: &KeyEvent) -> bool {}
 
     #[doc(hidden)]
     #[java_method]
@@ -5461,14 +6434,11 @@ pub struct Surface;
 /// 测试android.view
 #[cfg(feature = "test_android_view")]
 pub fn test() {
-    use crate::{
-        android::app::Activity,
-        java::lang::{CharSequenceExt, CharSequenceImpl},
-    };
+    use crate::{android::app::Activity, java::lang::CharSequenceExt};
     let act = Activity::fetch().unwrap();
     let view = View::new(&act);
     assert!(view.to_string().starts_with("android.view.View"));
-    view.announce_for_accessibility(&"通知".to_char_sequence::<CharSequenceImpl>().unwrap());
+    view.announce_for_accessibility(&"通知".to_char_sequence().unwrap());
     view.request_focus();
     view.clear_focus();
     assert!(view.find_focus().is_some());
@@ -5479,17 +6449,17 @@ pub fn test() {
     view.set_y(30f32);
     assert_eq!(30f32, view.get_y());
     assert!(view.find_view_by_id(0).is_some());
-    view.set_content_description(Some("测试".to_char_sequence::<CharSequenceImpl>().unwrap()));
+    view.set_content_description(Some("测试".to_char_sequence().unwrap()));
     assert_eq!(
         "测试".to_char_sequence().ok(),
-        view.get_content_description::<CharSequenceImpl>()
+        view.get_content_description()
     );
     view.set_id(3);
     assert_eq!(3, view.get_id());
-    let l = View_OnClickListenerImpl::from_fn(|_| {
-        println!("View is clicked.");
-    })
-    .unwrap();
+    let l = View_OnClickListenerImpl::builder()
+        .on_click(|_| println!("View is clicked."))
+        .build()
+        .unwrap();
     view.set_on_click_listener(l.as_ref());
     view.set_visibility(View::GONE);
     assert_eq!(View::GONE, view.get_visibility());
@@ -5531,4 +6501,10 @@ pub fn test() {
     let key_event = KeyEvent::new(KeyEvent::ACTION_DOWN, KeyEvent::KEYCODE_0);
     assert_eq!(KeyEvent::ACTION_DOWN, key_event.get_action());
     assert_eq!(KeyEvent::KEYCODE_0, key_event.get_key_code());
+    assert!(KeyCode::null().is_err());
+    assert!(WindowFlags::null().is_err());
+    assert_eq!("java/lang/Integer", <KeyCode as JType>::CLASS);
+    assert_eq!("I", <KeyCode as JType>::OBJECT_SIG);
+    assert_eq!("java/lang/Integer", <WindowFlags as JType>::CLASS);
+    assert_eq!("I", <WindowFlags as JType>::OBJECT_SIG);
 }
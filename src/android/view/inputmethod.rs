@@ -13,9 +13,13 @@
 
 use crate::{
     JObjNew, JObjRef, JProxy, JType,
-    android::{content::Context, os::Bundle, text::InputType},
-    java::lang::CharSequence,
-    java_class, java_field, java_implement, java_method,
+    android::{
+        content::Context,
+        os::Bundle,
+        text::{InputType, InputTypeFlags},
+    },
+    java::lang::{CharSequence, CharSequenceImpl},
+    java_class, java_field, java_flags, java_implement, java_method,
 };
 
 //noinspection SpellCheckingInspection
@@ -218,7 +222,7 @@ impl InputMethodManager {
     pub fn prepare_stylus_handwriting_delegation_with_package(
         &self,
         delegator_view: &super::View,
-        delegate_package_name: String,
+        delegate_package_name: &str,
     ) {
     }
 
@@ -240,7 +244,7 @@ impl InputMethodManager {
     pub fn accept_stylus_handwriting_delegation_with_package(
         &self,
         delegate_view: &super::View,
-        delegator_package_name: String,
+        delegator_package_name: &str,
     ) -> bool {
     }
 
@@ -349,7 +353,7 @@ impl InputMethodManager {
     `data` 包含在命令中的任何数据。
     */
     #[java_method]
-    pub fn send_app_private_command(&self, view: &super::View, action: String, data: &Bundle) {}
+    pub fn send_app_private_command(&self, view: &super::View, action: &str, data: &Bundle) {}
 
     /**
     显示 IME 选择器弹出窗口。需要 PackageManager.FEATURE_INPUT_METHODS 功能，可使用 PackageManager.hasSystemFeature(String) 检测。
@@ -389,7 +393,7 @@ impl InputMethodManager {
     `imi_id` 将显示其子类型设置的输入法。如果 imiId 为空，则将显示所有输入法的子类型。
     */
     #[java_method]
-    pub fn show_input_method_and_subtype_enabler(imi_id: Option<String>) {}
+    pub fn show_input_method_and_subtype_enabler(imi_id: Option<&str>) {}
 
     /// 通知用户已使用此输入法采取了某些操作。
     #[deprecated(note = "只是为了避免可能出现的应用兼容性问题。")]
@@ -426,6 +430,67 @@ impl InputMethodManager {
     pub fn get_display_id(&self) -> i32 {}
 }
 
+/// EditorInfo 的 imeOptions 中的操作和标志。
+#[java_flags]
+pub enum ImeOptions {
+    /// imeOptions 中的一组位，提供与“enter”键相关的替代操作。这既有助于 IME 提供有关 Enter 键将执行的操作的 更好反馈，也允许它提供用于提供该命令的替代机制。
+    IME_MASK_ACTION = 0x000000ff,
+
+    /// IME_MASK_ACTION 的位：没有与此编辑器相关联的特定操作，如果可以，让编辑器自己提出操作。
+    IME_ACTION_UNSPECIFIED = 0x00000000,
+
+    /// IME_MASK_ACTION 的位：没有可用的操作。
+    IME_ACTION_NONE = 0x00000001,
+
+    /// IME_MASK_ACTION 的位：操作键执行“前往”操作，将用户带到他们输入的文本的目标。通常用于输入 URL 等情况。
+    IME_ACTION_GO = 0x00000002,
+
+    /// IME_MASK_ACTION 的位：操作键执行“搜索”操作，将用户带到他们所输入文本的搜索结果（在适当的上下文中）。
+    IME_ACTION_SEARCH = 0x00000003,
+
+    /// IME_MASK_ACTION 的位：操作键执行“发送”操作，将文本传送到目标。这通常用于在即时通讯或短信中编写消息时，这些消息是即时发送的。
+    IME_ACTION_SEND = 0x00000004,
+
+    /// IME_MASK_ACTION 的位：操作键执行“下一步”操作，将用户带到下一个接受文本的字段。
+    IME_ACTION_NEXT = 0x00000005,
+
+    /// IME_MASK_ACTION 的位：操作键执行“完成”操作，通常意味着没有更多内容可输入并且 IME 将被关闭。
+    IME_ACTION_DONE = 0x00000006,
+
+    /// IME_MASK_ACTION 的位：类似于 IME_ACTION_NEXT，但用于移动到上一个字段。这通常不会用于指定操作（因为它排除了 IME_ACTION_NEXT），但如果设置了 IME_FLAG_NAVIGATE_PREVIOUS，则可以返回给应用程序。
+    IME_ACTION_PREVIOUS = 0x00000007,
+
+    /**
+    imeOptions 标志：用于要求 IME 不应根据用户在此文本编辑对象上键入的内容更新任何个性化数据，如打字历史记录和个性化语言模型。典型的用例是：当应用程序处于特殊模式时，预计用户的活动不会记录在应用程序的历史记录中。一些 Web 浏览器和聊天应用程序可能有这种模式。当存储打字历史记录没有多大意义时。在打字游戏中指定此标志可能有助于避免打字历史记录被 用户 在日常生活中不太可能输入的单词填满。另一个示例是，当应用程序已经知道预期的输入不是有效单词时（例如，促销代码在任何自然语言中都不是有效单词）。
+    应用程序需要注意该标志并不是一种保证，并且某些 IME 可能不尊重它。
+    */
+    IME_FLAG_NO_PERSONALIZED_LEARNING = 0x1000000,
+
+    /// imeOptions 标志：用于请求 IME 永不进入全屏模式。默认情况下，IME 可能会在它们认为合适时进入全屏模式，例如在横向的小屏幕上，显示软件键盘可能会遮挡屏幕的很大一部分，以至于剩余部分太小而无法有意义地显示应用程序 UI。如果设置了此标志，兼容的 IME 将永远不会进入全屏模式，并且始终会留出一些空间来显示应用程序 UI。应用程序需要注意，该标志并不是保证，有些 IME 可能会忽略它。
+    IME_FLAG_NO_FULLSCREEN = 0x2000000,
+
+    /// imeOptions 标志：类似于 IME_FLAG_NAVIGATE_NEXT，但指定向后导航可以关注一些有趣的内容。如果用户选择 IME 的向后导航功能，这将在应用程序中显示为 InputConnection#performEditorAction(int) InputConnection.performEditorAction(int) 处的 IME_ACTION_PREVIOUS。
+    IME_FLAG_NAVIGATE_PREVIOUS = 0x4000000,
+
+    /// imeOptions 标志：用于指定向前导航可以关注的有趣内容。这类似于使用 IME_ACTION_NEXT，但允许 IME 为多行（带有回车键）并提供向前导航。请注意，某些 IME 可能无法执行此操作，尤其是在空间较小的小屏幕上运行时。在这种情况下，它不需要为此选项显示 UI。与 IME_ACTION_NEXT 一样，如果用户选择 IME 的向前导航功能，这将显示在应用程序中的 InputConnection#performEditorAction(int) InputConnection.performEditorAction(int)。
+    IME_FLAG_NAVIGATE_NEXT = 0x8000000,
+
+    /// imeOptions 标志：用于指定 IME 不需要显示其提取的文本 UI。对于可能全屏的输入法（通常在横向模式下），这允许它们变小，并通过全屏 IME 中的透明 UI 部分让部分应用程序显示在后面。用户可见的 UI 部分可能对触摸没有响应，因为 IME 将接收触摸事件，这可能会让用户感到困惑；请使用 IME_FLAG_NO_FULLSCREEN 获得更好的体验。不鼓励使用此标志，将来可能会弃用。在某些情况下，它的含义不明确，并且可能无法在旧版本的平台上正常工作。
+    IME_FLAG_NO_EXTRACT_UI = 0x10000000,
+
+    /// imeOptions 标志：与 IME_MASK_ACTION 屏蔽的操作之一结合使用，这表示当输入法为全屏时，该操作不应作为提取文本右侧的附属按钮提供。请注意，通过设置此标志，可能会出现用户永远无法使用该操作的情况。设置此标志通常意味着您认为在全屏模式下，由于几乎没有空间显示文本，因此不值得占用一些屏幕空间来显示该操作，而应该用它来显示更多文本。
+    IME_FLAG_NO_ACCESSORY_ACTION = 0x20000000,
+
+    /// imeOptions 标志：与 IME_MASK_ACTION 屏蔽的操作之一结合使用。如果未设置此标志，IME 通常会用提供的操作替换“enter”键。此标志表示该操作不应以内联方式替代“enter”键。通常这是因为该操作具有如此重大的影响或不可恢复性，因此应避免意外按下它，例如发送消息。请注意，android.widget.TextView 会在多行文本视图上自动为您设置此标志。
+    IME_FLAG_NO_ENTER_ACTION = 0x40000000,
+
+    /// imeOptions 标志：用于请求能够输入 ASCII 字符的 IME。此标志的目的是确保用户可以在 android.widget.TextView 中输入罗马字母字符。它通常用于输入账户 ID 或密码。很多时候，IME 已经能够输入 ASCII，即使没有被告知也是如此（此类 IME 在某种意义上已经尊重此标志），但有些情况下这不是默认的。例如，使用不同脚本（如阿拉伯语、希腊语、希伯来语或俄语）的语言的用户通常拥有默认无法输入 ASCII 字符的键盘。应用程序需要注意，该标志并不是保证，有些 IME 可能不尊重它。但是，强烈建议 IME 作者尊重此标志，尤其是当他们的 IME 最终可能处于仅启用使用非 ASCII 的语言的状态时。
+    IME_FLAG_FORCE_ASCII = 0x80000000,
+
+    /// imeOptions 的通用未指定类型。
+    IME_NULL = 0x00000000,
+}
+
 //noinspection SpellCheckingInspection
 /// EditorInfo 描述了输入法正在与之通信的文本编辑对象（通常是 EditText）的几个属性，最重要的是它包含的文本内容类型和当前光标位置。
 #[java_class(name = "android/view/inputmethod/EditorInfo")]
@@ -436,80 +501,81 @@ impl InputType for EditorInfo {}
 
 impl EditorInfo {
     /// imeOptions 中的一组位，提供与“enter”键相关的替代操作。这既有助于 IME 提供有关 Enter 键将执行的操作的 更好反馈，也允许它提供用于提供该命令的替代机制。
-    pub const IME_MASK_ACTION: u32 = 0x000000ff;
+    pub const IME_MASK_ACTION: ImeOptions = ImeOptions::IME_MASK_ACTION;
 
     /// IME_MASK_ACTION 的位：没有与此编辑器相关联的特定操作，如果可以，让编辑器自己提出操作。
-    pub const IME_ACTION_UNSPECIFIED: u32 = 0x00000000;
+    pub const IME_ACTION_UNSPECIFIED: ImeOptions = ImeOptions::IME_ACTION_UNSPECIFIED;
 
     /// IME_MASK_ACTION 的位：没有可用的操作。
-    pub const IME_ACTION_NONE: u32 = 0x00000001;
+    pub const IME_ACTION_NONE: ImeOptions = ImeOptions::IME_ACTION_NONE;
 
     /// IME_MASK_ACTION 的位：操作键执行“前往”操作，将用户带到他们输入的文本的目标。通常用于输入 URL 等情况。
-    pub const IME_ACTION_GO: u32 = 0x00000002;
+    pub const IME_ACTION_GO: ImeOptions = ImeOptions::IME_ACTION_GO;
 
     /// IME_MASK_ACTION 的位：操作键执行“搜索”操作，将用户带到他们所输入文本的搜索结果（在适当的上下文中）。
-    pub const IME_ACTION_SEARCH: u32 = 0x00000003;
+    pub const IME_ACTION_SEARCH: ImeOptions = ImeOptions::IME_ACTION_SEARCH;
 
     /// IME_MASK_ACTION 的位：操作键执行“发送”操作，将文本传送到目标。这通常用于在即时通讯或短信中编写消息时，这些消息是即时发送的。
-    pub const IME_ACTION_SEND: u32 = 0x00000004;
+    pub const IME_ACTION_SEND: ImeOptions = ImeOptions::IME_ACTION_SEND;
 
     /// IME_MASK_ACTION 的位：操作键执行“下一步”操作，将用户带到下一个接受文本的字段。
-    pub const IME_ACTION_NEXT: u32 = 0x00000005;
+    pub const IME_ACTION_NEXT: ImeOptions = ImeOptions::IME_ACTION_NEXT;
 
     /// IME_MASK_ACTION 的位：操作键执行“完成”操作，通常意味着没有更多内容可输入并且 IME 将被关闭。
-    pub const IME_ACTION_DONE: u32 = 0x00000006;
+    pub const IME_ACTION_DONE: ImeOptions = ImeOptions::IME_ACTION_DONE;
 
     /// IME_MASK_ACTION 的位：类似于 IME_ACTION_NEXT，但用于移动到上一个字段。这通常不会用于指定操作（因为它排除了 IME_ACTION_NEXT），但如果设置了 IME_FLAG_NAVIGATE_PREVIOUS，则可以返回给应用程序。
-    pub const IME_ACTION_PREVIOUS: u32 = 0x00000007;
+    pub const IME_ACTION_PREVIOUS: ImeOptions = ImeOptions::IME_ACTION_PREVIOUS;
 
     /**
     imeOptions 标志：用于要求 IME 不应根据用户在此文本编辑对象上键入的内容更新任何个性化数据，如打字历史记录和个性化语言模型。典型的用例是：当应用程序处于特殊模式时，预计用户的活动不会记录在应用程序的历史记录中。一些 Web 浏览器和聊天应用程序可能有这种模式。当存储打字历史记录没有多大意义时。在打字游戏中指定此标志可能有助于避免打字历史记录被 用户 在日常生活中不太可能输入的单词填满。另一个示例是，当应用程序已经知道预期的输入不是有效单词时（例如，促销代码在任何自然语言中都不是有效单词）。
     应用程序需要注意该标志并不是一种保证，并且某些 IME 可能不尊重它。
     */
-    pub const IME_FLAG_NO_PERSONALIZED_LEARNING: u32 = 0x1000000;
+    pub const IME_FLAG_NO_PERSONALIZED_LEARNING: ImeOptions =
+        ImeOptions::IME_FLAG_NO_PERSONALIZED_LEARNING;
 
     /// imeOptions 标志：用于请求 IME 永不进入全屏模式。默认情况下，IME 可能会在它们认为合适时进入全屏模式，例如在横向的小屏幕上，显示软件键盘可能会遮挡屏幕的很大一部分，以至于剩余部分太小而无法有意义地显示应用程序 UI。如果设置了此标志，兼容的 IME 将永远不会进入全屏模式，并且始终会留出一些空间来显示应用程序 UI。应用程序需要注意，该标志并不是保证，有些 IME 可能会忽略它。
-    pub const IME_FLAG_NO_FULLSCREEN: u32 = 0x2000000;
+    pub const IME_FLAG_NO_FULLSCREEN: ImeOptions = ImeOptions::IME_FLAG_NO_FULLSCREEN;
 
     /// imeOptions 标志：类似于 IME_FLAG_NAVIGATE_NEXT，但指定向后导航可以关注一些有趣的内容。如果用户选择 IME 的向后导航功能，这将在应用程序中显示为 InputConnection#performEditorAction(int) InputConnection.performEditorAction(int) 处的 IME_ACTION_PREVIOUS。
-    pub const IME_FLAG_NAVIGATE_PREVIOUS: u32 = 0x4000000;
+    pub const IME_FLAG_NAVIGATE_PREVIOUS: ImeOptions = ImeOptions::IME_FLAG_NAVIGATE_PREVIOUS;
 
     /// imeOptions 标志：用于指定向前导航可以关注的有趣内容。这类似于使用 IME_ACTION_NEXT，但允许 IME 为多行（带有回车键）并提供向前导航。请注意，某些 IME 可能无法执行此操作，尤其是在空间较小的小屏幕上运行时。在这种情况下，它不需要为此选项显示 UI。与 IME_ACTION_NEXT 一样，如果用户选择 IME 的向前导航功能，这将显示在应用程序中的 InputConnection#performEditorAction(int) InputConnection.performEditorAction(int)。
-    pub const IME_FLAG_NAVIGATE_NEXT: u32 = 0x8000000;
+    pub const IME_FLAG_NAVIGATE_NEXT: ImeOptions = ImeOptions::IME_FLAG_NAVIGATE_NEXT;
 
     /// imeOptions 标志：用于指定 IME 不需要显示其提取的文本 UI。对于可能全屏的输入法（通常在横向模式下），这允许它们变小，并通过全屏 IME 中的透明 UI 部分让部分应用程序显示在后面。用户可见的 UI 部分可能对触摸没有响应，因为 IME 将接收触摸事件，这可能会让用户感到困惑；请使用 IME_FLAG_NO_FULLSCREEN 获得更好的体验。不鼓励使用此标志，将来可能会弃用。在某些情况下，它的含义不明确，并且可能无法在旧版本的平台上正常工作。
-    pub const IME_FLAG_NO_EXTRACT_UI: u32 = 0x10000000;
+    pub const IME_FLAG_NO_EXTRACT_UI: ImeOptions = ImeOptions::IME_FLAG_NO_EXTRACT_UI;
 
     /// imeOptions 标志：与 IME_MASK_ACTION 屏蔽的操作之一结合使用，这表示当输入法为全屏时，该操作不应作为提取文本右侧的附属按钮提供。请注意，通过设置此标志，可能会出现用户永远无法使用该操作的情况。设置此标志通常意味着您认为在全屏模式下，由于几乎没有空间显示文本，因此不值得占用一些屏幕空间来显示该操作，而应该用它来显示更多文本。
-    pub const IME_FLAG_NO_ACCESSORY_ACTION: u32 = 0x20000000;
+    pub const IME_FLAG_NO_ACCESSORY_ACTION: ImeOptions = ImeOptions::IME_FLAG_NO_ACCESSORY_ACTION;
 
     /// imeOptions 标志：与 IME_MASK_ACTION 屏蔽的操作之一结合使用。如果未设置此标志，IME 通常会用提供的操作替换“enter”键。此标志表示该操作不应以内联方式替代“enter”键。通常这是因为该操作具有如此重大的影响或不可恢复性，因此应避免意外按下它，例如发送消息。请注意，android.widget.TextView 会在多行文本视图上自动为您设置此标志。
-    pub const IME_FLAG_NO_ENTER_ACTION: u32 = 0x40000000;
+    pub const IME_FLAG_NO_ENTER_ACTION: ImeOptions = ImeOptions::IME_FLAG_NO_ENTER_ACTION;
 
     /// imeOptions 标志：用于请求能够输入 ASCII 字符的 IME。此标志的目的是确保用户可以在 android.widget.TextView 中输入罗马字母字符。它通常用于输入账户 ID 或密码。很多时候，IME 已经能够输入 ASCII，即使没有被告知也是如此（此类 IME 在某种意义上已经尊重此标志），但有些情况下这不是默认的。例如，使用不同脚本（如阿拉伯语、希腊语、希伯来语或俄语）的语言的用户通常拥有默认无法输入 ASCII 字符的键盘。应用程序需要注意，该标志并不是保证，有些 IME 可能不尊重它。但是，强烈建议 IME 作者尊重此标志，尤其是当他们的 IME 最终可能处于仅启用使用非 ASCII 的语言的状态时。
-    pub const IME_FLAG_FORCE_ASCII: u32 = 0x80000000;
+    pub const IME_FLAG_FORCE_ASCII: ImeOptions = ImeOptions::IME_FLAG_FORCE_ASCII;
 
     /// internalImeOptions 的标志：当包含此 EditorInfo 的应用程序窗口使用 Configuration#ORIENTATION_PORTRAIT 模式时设置标志。
     pub const IME_INTERNAL_FLAG_APP_WINDOW_PORTRAIT: u32 = 0x00000001;
 
     /// imeOptions 的通用未指定类型。
-    pub const IME_NULL: u32 = 0x00000000;
+    pub const IME_NULL: ImeOptions = ImeOptions::IME_NULL;
 
     /// 文本框的内容类型，其位由InputType定义。
     #[java_field]
-    pub fn get_input_type(&self) -> i32 {}
+    pub fn get_input_type(&self) -> InputTypeFlags {}
 
     /// 文本框的内容类型，其位由InputType定义。
     #[java_field]
-    pub fn set_input_type(&self, value: i32) {}
+    pub fn set_input_type(&self, value: InputTypeFlags) {}
 
     /// 扩展编辑器的类型信息，以帮助 IME 更好地与其集成。
     #[java_field]
-    pub fn get_ime_options(&self) -> u32 {}
+    pub fn get_ime_options(&self) -> ImeOptions {}
 
     /// 扩展编辑器的类型信息，以帮助 IME 更好地与其集成。
     #[java_field]
-    pub fn set_ime_options(&self, value: u32) {}
+    pub fn set_ime_options(&self, value: ImeOptions) {}
 
     /// 提供特定 IME 实现私有的附加信息选项的字符串。该字符串必须限定在实现所拥有的包内，以确保实现之间不存在冲突，但除此之外，您可以在其中放入任何内容以与 IME 通信。例如，您可以有一个提供参数（如“com.example.myapp.SpecialMode=3”）的字符串。此字段可以从 TextView 的 android.R.attr.privateImeOptions 属性中填写。
     #[java_field]
@@ -561,7 +627,7 @@ impl EditorInfo {
 
     /// 文本视图的“提示”文本，通常在文本为空时以内联显示，以告诉用户要输入什么。
     #[java_field]
-    pub fn get_hint_text<CS: CharSequence>(&self) -> Option<CS> {}
+    pub fn get_hint_text(&self) -> Option<CharSequenceImpl> {}
 
     /// 文本视图的“提示”文本，通常在文本为空时以内联显示，以告诉用户要输入什么。
     #[java_field]
@@ -569,7 +635,7 @@ impl EditorInfo {
 
     /// 向用户显示描述他们正在编写的文本的标签。
     #[java_field]
-    pub fn get_label<CS: CharSequence>(&self) -> Option<CS> {}
+    pub fn get_label(&self) -> Option<CharSequenceImpl> {}
 
     /// 向用户显示描述他们正在编写的文本的标签。
     #[java_field]
@@ -642,11 +708,11 @@ impl EditorInfo {
     `flags` 提供控制如何返回文本的其他选项。可以是 0 或 InputConnection。GET_TEXT_WITH_STYLES。
     */
     #[java_method]
-    pub fn get_initial_text_before_cursor<CS: CharSequence>(
+    pub fn get_initial_text_before_cursor(
         &self,
         length: i32,
         flags: u32,
-    ) -> Option<CS> {
+    ) -> Option<CharSequenceImpl> {
     }
 
     /**
@@ -655,7 +721,7 @@ impl EditorInfo {
     `flags` 提供控制文本返回方式的其他选项。可以是 0 或 InputConnection。GET_TEXT_WITH_STYLES。
     */
     #[java_method]
-    pub fn get_initial_selected_text<CS: CharSequence>(&self, flags: u32) -> Option<CS> {}
+    pub fn get_initial_selected_text(&self, flags: u32) -> Option<CharSequenceImpl> {}
 
     /**
     获取当前光标位置后的文本长度字符。当不支持该协议时，可能为空。
@@ -664,11 +730,11 @@ impl EditorInfo {
     `flags` 提供控制如何返回文本的其他选项。可以是 0 或 InputConnection.GET_TEXT_WITH_STYLES。
     */
     #[java_method]
-    pub fn get_initial_text_after_cursor<CS: CharSequence>(
+    pub fn get_initial_text_after_cursor(
         &self,
         length: i32,
         flags: u32,
-    ) -> Option<CS> {
+    ) -> Option<CharSequenceImpl> {
     }
 
     /**
@@ -708,7 +774,7 @@ impl EditorInfo {
     kindofEquals actionLabel、hintText 和 label 与 TextUtils 进行比较。equals 不考虑 Spans。
     */
     #[java_method(type_bound=(Self, JType))]
-    pub fn kindof_equals(&self, that: Option<Self>) -> bool {}
+    pub fn kindof_equals(&self, that: Option<&Self>) -> bool {}
 }
 
 //noinspection SpellCheckingInspection
@@ -718,7 +784,7 @@ pub fn test() {
     use crate::{android::app::Activity, java::lang::ObjectExt};
     let context = Activity::fetch().unwrap();
     let imm: InputMethodManager = context
-        .get_system_service(Context::INPUT_METHOD_SERVICE.to_string())
+        .get_system_service(Context::INPUT_METHOD_SERVICE)
         .unwrap()
         .cast()
         .unwrap();
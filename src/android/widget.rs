@@ -15,13 +15,12 @@ use crate::{
     JObjNew, JObjRef, JProxy, JType, Result,
     android::{
         content::Context,
-        text::TextWatcher,
+        text::{InputTypeFlags, TextWatcher},
         view::{KeyEvent, ViewGroup, ViewGroup_LayoutParams, ViewGroup_MarginLayoutParams},
     },
-    java::lang::CharSequence,
-    java_class, java_constructor, java_field, java_implement, java_interface, java_method,
+    java::lang::{CharSequence, CharSequenceImpl},
+    java_class, java_constructor, java_field, java_interface, java_method,
 };
-use std::sync::Arc;
 
 //noinspection SpellCheckingInspection
 /**
@@ -55,13 +54,6 @@ impl EditText {
     */
     #[java_method]
     pub fn set_selection(&self, start: i32, stop: i32) -> Result<()> {}
-
-    /**
-    子类会重写此功能以指定它们默认具有 KeyListener，即使在 XML 选项中没有特别调用。
-    */
-    pub fn get_default_editable(&self) -> bool {
-        self._based.get_default_editable()
-    }
 }
 
 /**
@@ -111,7 +103,7 @@ impl TextView {
     返回：文本视图显示的文本。
     */
     #[java_method]
-    pub fn get_text<CS: CharSequence>(&self) -> Option<CS> {}
+    pub fn get_text(&self) -> Option<CharSequenceImpl> {}
 
     /**
     设置 TextView 的文本为空时显示的文本。Null 表示使用普通的空文本。Hint 目前不参与确定视图的大小。
@@ -124,7 +116,7 @@ impl TextView {
     返回TextView的文本为空时显示的提示。
     */
     #[java_method]
-    pub fn get_hint<CS: CharSequence>(&self) -> Option<CS> {}
+    pub fn get_hint(&self) -> Option<CharSequenceImpl> {}
 
     /**
     子类会重写此功能以指定它们默认具有 KeyListener，即使在 XML 选项中没有特别调用。
@@ -138,13 +130,13 @@ impl TextView {
     `type` 输入类型。
     */
     #[java_method]
-    pub fn set_input_type(&self, r#type: i32) {}
+    pub fn set_input_type(&self, r#type: InputTypeFlags) {}
 
     /**
     获取可编辑内容的类型。
     */
     #[java_method]
-    pub fn get_input_type(&self) -> i32 {}
+    pub fn get_input_type(&self) -> InputTypeFlags {}
 
     /**
     将 TextWatcher 添加到此 TextView 的文本发生变化时调用其方法的列表中。
@@ -173,7 +165,7 @@ impl TextView {
 在编辑器上执行操作时调用的回调的接口定义。
 */
 #[allow(non_camel_case_types)]
-#[java_interface(name = "android/widget/TextView$OnEditorActionListener")]
+#[java_interface(name = "android/widget/TextView$OnEditorActionListener", builder)]
 pub trait TextView_OnEditorActionListener {
     /**
     执行操作时调用。
@@ -185,43 +177,6 @@ pub trait TextView_OnEditorActionListener {
     fn on_editor_action(&self, v: TextView, action_id: i32, event: Option<KeyEvent>) -> bool;
 }
 
-#[doc(hidden)]
-#[allow(non_camel_case_types)]
-#[java_class(name = "android/widget/TextView$OnEditorActionListenerImpl")]
-pub struct TextView_OnEditorActionListenerImpl(
-    Box<dyn Fn(TextView, i32, Option<KeyEvent>) -> bool + Send + Sync>,
-);
-
-impl Default for TextView_OnEditorActionListenerImplDefault {
-    fn default() -> Self {
-        Self(Box::new(|v, action_id, event| {
-            unimplemented!("{:?}, {}, {:?}", v, action_id, event)
-        }))
-    }
-}
-
-impl TextView_OnEditorActionListenerImpl {
-    pub fn from_fn(
-        func: impl Fn(
-            /* v */ TextView,
-            /* action_id */ i32,
-            /* event */ Option<KeyEvent>,
-        ) -> bool
-        + Send
-        + Sync
-        + 'static,
-    ) -> Result<Arc<Self>> {
-        Self::new(TextView_OnEditorActionListenerImplDefault(Box::new(func)))
-    }
-}
-
-#[java_implement]
-impl TextView_OnEditorActionListener for TextView_OnEditorActionListenerImpl {
-    fn on_editor_action(&self, v: TextView, action_id: i32, event: Option<KeyEvent>) -> bool {
-        self.0(v, action_id, event)
-    }
-}
-
 /// 要在活动中显示按钮，请将按钮添加到活动的布局 XML 文件：
 ///   &lt;Button
 ///       android:id="@+id/button_id"
@@ -355,32 +310,35 @@ pub fn test() {
             app::Activity,
             text::{InputType, InputTypeImpl, TextWatcherImpl},
         },
-        java::lang::{CharSequenceExt, CharSequenceImpl},
+        java::lang::CharSequenceExt,
     };
     let act = Activity::fetch().unwrap();
     let edit = EditText::new(&act);
     assert!(edit.to_string().starts_with("android.widget.EditText"));
     edit.select_all();
     // let _ = edit.set_selection(0,2);
-    let editor_listener = TextView_OnEditorActionListenerImpl::from_fn(|_, _, _| true).unwrap();
+    let editor_listener = TextView_OnEditorActionListenerImpl::builder()
+        .on_editor_action(|_, _, _| true)
+        .build()
+        .unwrap();
     edit.set_on_editor_action_listener(editor_listener.as_ref());
 
     let text = TextView::new(&act);
     assert!(text.to_string().starts_with("android.widget.TextView"));
-    text.set_text("你好".to_char_sequence::<CharSequenceImpl>().ok());
+    text.set_text("你好".to_char_sequence().ok());
     assert_eq!(
         "你好".to_char_sequence().ok(),
-        text.get_text::<CharSequenceImpl>()
+        text.get_text()
     );
-    text.set_hint("世界".to_char_sequence::<CharSequenceImpl>().ok());
+    text.set_hint("世界".to_char_sequence().ok());
     assert_eq!(
-        "世界".to_char_sequence::<CharSequenceImpl>().ok(),
+        "世界".to_char_sequence().ok(),
         text.get_hint()
     );
     text.set_input_type(InputTypeImpl::TYPE_CLASS_DATETIME);
     assert_eq!(InputTypeImpl::TYPE_CLASS_DATETIME, text.get_input_type());
     let button = Button::new(&act);
-    button.set_text("测试按钮".to_char_sequence::<CharSequenceImpl>().ok());
+    button.set_text("测试按钮".to_char_sequence().ok());
     let layout = LinearLayout::new(&act);
     layout.set_orientation(LinearLayout::VERTICAL);
     assert_eq!(LinearLayout::VERTICAL, layout.get_orientation());
@@ -390,18 +348,18 @@ pub fn test() {
         1.0,
     );
     assert_eq!(1.0, params.get_weight());
-    let watcher = TextWatcherImpl::from_fn(
-        |s, start, count, after| {
+    let watcher = TextWatcherImpl::builder()
+        .before_text_changed(|s, start, count, after| {
             dbg!((s, start, count, after));
-        },
-        |s, start, before, count| {
+        })
+        .on_text_changed(|s, start, before, count| {
             dbg!((s, start, before, count));
-        },
-        |s| {
+        })
+        .after_text_changed(|s| {
             dbg!(s);
-        },
-    )
-    .unwrap();
+        })
+        .build()
+        .unwrap();
     button.add_text_changed_listener(watcher.as_ref());
     button.remove_text_changed_listener(watcher.as_ref());
 }
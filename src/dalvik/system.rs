@@ -12,10 +12,15 @@
  */
 
 use crate::{
-    JObjNew, JObjRef, JType,
+    JObjNew, JObjRef, JType, Result,
     java::{lang::ClassLoader, nio::ByteBuffer},
     java_class, java_constructor,
 };
+use droid_wrap_utils::{
+    ClassLoaderSource, DroidWrapError, GlobalRef, app_class_loader, java_object_same,
+    register_natives,
+};
+use std::sync::RwLock;
 
 /**
 类加载器，用于从包含 classes.dex 条目的 .jar 和 .apk 文件中加载类。这可用于执行未作为应用程序的一部分安装的代码。
@@ -23,7 +28,7 @@ use crate::{
 File dexOutputDir = context.getCodeCacheDir();
 不要在外部存储上缓存优化的类。外部存储不提供保护应用程序免受代码注入攻击所需的访问控制。
 */
-#[java_class(name = "dalvik/system/DexClassLoader")]
+#[java_class(name = "dalvik/system/DexClassLoader", extends = ClassLoader)]
 pub struct DexClassLoader;
 
 impl DexClassLoader {
@@ -36,18 +41,18 @@ impl DexClassLoader {
     */
     #[java_constructor]
     pub fn new(
-        dex_path: String,
-        optimized_directory: String,
-        library_search_path: String,
+        dex_path: &str,
+        optimized_directory: &str,
+        library_search_path: &str,
         parent: &ClassLoader,
-    ) -> Self {
+    ) -> Result<Self> {
     }
 }
 
 /**
 从包含 DEX 文件的缓冲区加载类的 ClassLoader 实现。这可用于执行尚未写入本地文件系统的代码。
 */
-#[java_class(name = "dalvik/system/InMemoryDexClassLoader")]
+#[java_class(name = "dalvik/system/InMemoryDexClassLoader", extends = ClassLoader)]
 pub struct InMemoryDexClassLoader;
 
 impl InMemoryDexClassLoader {
@@ -57,23 +62,168 @@ impl InMemoryDexClassLoader {
     `parent` 用于委托的父类加载器。
     */
     #[java_constructor]
-    pub fn new(dex_buffer: &ByteBuffer, parent: &ClassLoader) -> Self {}
+    pub fn new(dex_buffer: &ByteBuffer, parent: &ClassLoader) -> Result<Self> {}
+}
+
+/// 插件中的类使用的类加载器，声明为静态变量后可以用作`java_class(loader = ...)`。
+/// 加载`Plugin`时绑定插件的类加载器，`Plugin`被丢弃后解除绑定，此时通过它查找类会返回`DroidWrapError::PluginNotLoaded`错误。
+///
+/// # 示例
+///
+/// ```ignore
+/// static FEATURE: PluginLoader = PluginLoader::new("feature");
+///
+/// #[java_class(name = "com/example/feature/Greeter", loader = FEATURE)]
+/// pub struct Greeter;
+///
+/// impl Greeter {
+///     #[java_constructor]
+///     pub fn new() -> Result<Self> {}
+/// }
+///
+/// let plugin = Plugin::from_file(&FEATURE, "/data/data/com.example/files/feature.dex")?;
+/// let greeter = Greeter::new()?;
+/// drop(plugin);
+/// ```
+pub struct PluginLoader {
+    name: &'static str,
+    loader: RwLock<Option<GlobalRef>>,
+}
+
+impl PluginLoader {
+    /// 创建没有绑定插件的类加载器。
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: 插件的名称，用于错误信息。
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            loader: RwLock::new(None),
+        }
+    }
+
+    /// 是否已经绑定了插件。
+    pub fn is_loaded(&self) -> bool {
+        self.loader.read().unwrap().is_some()
+    }
+}
+
+impl ClassLoaderSource for PluginLoader {
+    fn class_loader(&self) -> Result<GlobalRef> {
+        self.loader
+            .read()
+            .unwrap()
+            .clone()
+            .ok_or(DroidWrapError::PluginNotLoaded(self.name))
+    }
+}
+
+/// 动态加载的插件（dex、jar或apk），持有插件的类加载器并绑定到`PluginLoader`，插件中的类通过绑定了此加载器的`java_class`封装类型使用。
+/// 丢弃`Plugin`时解除绑定，之后插件中的类在没有其他引用时可以被虚拟机卸载。
+pub struct Plugin {
+    slot: &'static PluginLoader,
+    loader: ClassLoader,
+}
+
+impl Plugin {
+    /// 从文件加载插件，父加载器为应用的类加载器。
+    ///
+    /// # Arguments
+    ///
+    /// * `slot`: 绑定的类加载器，已经绑定了其他插件时会被替换。
+    /// * `path`: dex、jar或apk文件的路径，文件必须位于应用的私有目录中，并且从API 34开始必须是只读的。
+    ///
+    /// returns: Result<Plugin>
+    pub fn from_file(slot: &'static PluginLoader, path: &str) -> Result<Self> {
+        let loader = DexClassLoader::new(path, "", "", &Self::parent()?)?;
+        Ok(Self::bind(slot, loader.into()))
+    }
+
+    /// 从内存中的dex加载插件，内容会复制到java堆中，因此`dex`不需要在加载后保持有效。
+    ///
+    /// # Arguments
+    ///
+    /// * `slot`: 绑定的类加载器，已经绑定了其他插件时会被替换。
+    /// * `dex`: dex文件的内容。
+    ///
+    /// returns: Result<Plugin>
+    pub fn from_bytes(slot: &'static PluginLoader, dex: &[u8]) -> Result<Self> {
+        let buffer = ByteBuffer::wrap(dex)?;
+        let loader = InMemoryDexClassLoader::new(&buffer, &Self::parent()?)?;
+        Ok(Self::bind(slot, loader.into()))
+    }
+
+    /// 插件的类加载器。
+    pub fn class_loader(&self) -> &ClassLoader {
+        &self.loader
+    }
+
+    fn parent() -> Result<ClassLoader> {
+        ClassLoader::_new(&app_class_loader()?, ())
+    }
+
+    fn bind(slot: &'static PluginLoader, loader: ClassLoader) -> Self {
+        *slot.loader.write().unwrap() = loader.java_ref().ok();
+        // 为插件中的类注册`java_native(loader = ...)`实现的本地方法，失败的类已经记录在日志中，不影响插件的加载。
+        let _ = register_natives();
+        Self { slot, loader }
+    }
+}
+
+impl Drop for Plugin {
+    fn drop(&mut self) {
+        let mut slot = self.slot.loader.write().unwrap();
+        // 只解除自己的绑定，绑定可能已经被之后加载的插件替换。
+        if let Some(loader) = slot.as_ref()
+            && self
+                .loader
+                .java_ref()
+                .and_then(|r| java_object_same(&r, loader))
+                .unwrap_or_default()
+        {
+            *slot = None;
+        }
+    }
+}
+
+#[cfg(feature = "test_dalvik_system")]
+static TEST_PLUGIN: PluginLoader = PluginLoader::new("test");
+
+#[cfg(feature = "test_dalvik_system")]
+#[java_class(name = "rust/PluginTest", loader = TEST_PLUGIN)]
+struct PluginTest;
+
+#[cfg(feature = "test_dalvik_system")]
+impl PluginTest {
+    #[java_constructor]
+    fn new() -> Result<Self> {}
 }
 
 /// 测试dalvik.system
 #[cfg(feature = "test_dalvik_system")]
 pub fn test() {
     use crate::android::app::Activity;
+    use droid_wrap_utils::{DexClass, write_dex};
     let context = Activity::fetch().unwrap();
-    let loader = DexClassLoader::new(
-        "c.dex".to_string(),
-        "".to_string(),
-        "".to_string(),
-        &context.get_class_loader(),
-    );
+    let loader = DexClassLoader::new("c.dex", "", "", &context.get_class_loader()).unwrap();
     assert!(
         loader
             .to_string()
             .starts_with("dalvik.system.DexClassLoader")
     );
+
+    assert!(PluginTest::new().is_err());
+    let dex = write_dex(&[DexClass::new("rust/PluginTest", "java/lang/Object").constructor("()V")]);
+    let plugin = Plugin::from_bytes(&TEST_PLUGIN, &dex).unwrap();
+    assert!(TEST_PLUGIN.is_loaded());
+    assert!(
+        PluginTest::new()
+            .unwrap()
+            .to_string()
+            .starts_with("rust.PluginTest")
+    );
+    drop(plugin);
+    assert!(!TEST_PLUGIN.is_loaded());
+    assert!(PluginTest::new().is_err());
 }
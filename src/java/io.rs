@@ -43,7 +43,11 @@ File 类的实例是不可变的；也就是说，一旦创建，File 对象所
 java.nio.file 包定义了 Java 虚拟机访问文件、文件属性和文件系统的接口和类。此 API 可用于克服 java.io.File 类的许多限制。
 toPath 方法可用于获取使用 File 对象所表示的抽象路径来定位文件的 Path。生成的 Path 可与 java.nio.file.Files 类一起使用，以提供对其他文件操作、文件属性和 I/O 异常的更高效和广泛的访问，以帮助诊断对文件的操作时出现的错误
 */
-#[java_class(name = "java/io/File", extends = super::lang::Object)]
+#[java_class(
+    name = "java/io/File",
+    extends = super::lang::Object,
+    implements = [Comparable<File>]
+)]
 pub struct File;
 
 impl File {
@@ -56,7 +60,7 @@ impl File {
     `pathname` 路径名字符串
     */
     #[java_constructor]
-    pub fn new(pathname: String) -> Result<Self> {}
+    pub fn new(pathname: &str) -> Result<Self> {}
 
     /**
     根据父路径名字符串和子路径名字符串创建一个新的 File 实例。
@@ -68,7 +72,7 @@ impl File {
     `child` 子路径名字符串
     */
     #[java_constructor]
-    pub fn new_with_parent(parent: Option<String>, child: String) -> Result<Self> {}
+    pub fn new_with_parent(parent: Option<&str>, child: &str) -> Result<Self> {}
 
     /**
     根据父抽象路径名和子路径名字符串创建新的 File 实例。如果父路径为 null，则创建新的 File 实例，就像通过对给定的子路径名字符串调用单参数 File 构造函数一样。
@@ -79,7 +83,7 @@ impl File {
     `child` 子路径名字符串
     */
     #[java_constructor]
-    pub fn new_with_parent_file(parent: Option<Self>, child: String) -> Result<Self> {}
+    pub fn new_with_parent_file(parent: Option<&Self>, child: &str) -> Result<Self> {}
 
     /**
     系统相关的默认名称分隔符。此字段初始化为包含系统属性文件值的第一个字符。分隔符。
@@ -447,9 +451,9 @@ impl File {
     */
     #[java_method]
     pub fn create_temp_file(
-        prefix: String,
-        suffix: Option<String>,
-        directory: Option<Self>,
+        prefix: &str,
+        suffix: Option<&str>,
+        directory: Option<&Self>,
     ) -> Result<Self> {
     }
 
@@ -465,7 +469,7 @@ impl File {
     `suffix` 用于生成文件名称的后缀字符串；可以为 null，在这种情况下将使用后缀“.tmp”
      */
     #[java_method(overload = createTempFile)]
-    pub fn create_temp_file_default(prefix: String, suffix: Option<String>) -> Result<Self> {}
+    pub fn create_temp_file_default(prefix: &str, suffix: Option<&str>) -> Result<Self> {}
 
     /**
     返回一个从此抽象路径构造的 java.nio.file.Path 对象。生成的 Path 与默认文件系统相关联。第一次调用此方法就像调用它相当于评估表达式：
@@ -478,17 +482,6 @@ impl File {
     pub fn to_path<P: Path>(&self) -> Result<P> {}
 }
 
-impl Comparable<File> for File {
-    /**
-    按字典顺序比较两个抽象路径名。此方法定义的顺序取决于底层系统。
-    在 UNIX 系统上，字母大小写在比较路径名时很重要；在 Microsoft Windows 系统上则不然。
-    返回：如果参数等于此抽象路径名，则返回零；如果此抽象路径名按字典顺序小于参数，则返回小于零的值；如果此抽象路径名按字典顺序大于参数，则返回大于零的值
-    `pathname` 要与此抽象路径名进行比较的抽象路径名
-    */
-    #[java_method]
-    fn compare_to(&self, o: &File) -> Result<i32> {}
-}
-
 /**
 类的可序列化性由实现 java.io.Serializable 接口的类启用。
 警告：对不受信任的数据进行反序列化本质上是危险的，应避免。应仔细验证不受信任的数据。
@@ -529,14 +522,17 @@ pub fn test() {
     assert_eq!(String::from("/"), File::get_separator());
     assert_eq!(':', File::get_path_separator_char());
     assert_eq!(String::from(":"), File::get_path_separator());
-    let file = File::new("/data".to_string()).unwrap();
-    let file = File::new_with_parent(Some(file.to_string()), "/local".to_string()).unwrap();
-    let file = File::new_with_parent_file(Some(file), "/tmp".to_string()).unwrap();
+    let file = File::new("/data").unwrap();
+    let file = File::new_with_parent(Some(&file.to_string()), "/local").unwrap();
+    let file = File::new_with_parent_file(Some(&file), "/tmp").unwrap();
     assert_eq!("tmp", file.get_name());
     assert_eq!(Some("/data/local".to_string()), file.get_parent());
     assert!(file.get_parent_file().is_some());
     assert!(file.is_absolute());
     assert_eq!("/data/local/tmp", file.get_absolute_path());
+    let parent = file.get_parent_file().unwrap();
+    assert!(parent < file);
+    assert_eq!(Some(&file), [parent, file.clone()].iter().max());
     assert!(file.get_canonical_path().is_ok());
     assert!(file.get_canonical_file().is_ok());
     assert!(file.can_read().is_ok());
@@ -553,7 +549,7 @@ pub fn test() {
     assert!(file.mkdir().is_ok());
     assert!(file.mkdirs().is_ok());
     assert!(
-        file.rename_to(File::new("/data/local/tmp".to_string()).unwrap())
+        file.rename_to(File::new("/data/local/tmp").unwrap())
             .is_ok()
     );
     assert!(file.set_last_modified(0).is_ok());
@@ -568,11 +564,8 @@ pub fn test() {
     assert!(file.get_total_space().is_ok());
     assert!(file.get_free_space().is_ok());
     assert!(file.get_usable_space().is_ok());
-    println!(
-        "{:?}",
-        File::create_temp_file("droid".to_string(), None, None)
-    );
-    assert!(File::create_temp_file("droid".to_string(), None, None).is_ok());
-    assert!(File::create_temp_file_default("droid".to_string(), None).is_ok());
+    println!("{:?}", File::create_temp_file("droid", None, None));
+    assert!(File::create_temp_file("droid", None, None).is_ok());
+    assert!(File::create_temp_file_default("droid", None).is_ok());
     assert!(file.compare_to(&file).is_ok());
 }
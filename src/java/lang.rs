@@ -19,11 +19,10 @@ pub mod reflect;
 
 use {
     crate::{
-        JObjNew, JObjRef, JProxy, JType, java_class, java_constructor, java_implement,
-        java_interface, java_method,
+        JObjNew, JObjRef, JProxy, JType, java_class, java_constructor, java_enum, java_interface,
+        java_method,
     },
     droid_wrap_utils::{Result, vm_attach},
-    std::sync::Arc,
 };
 
 /**
@@ -60,6 +59,22 @@ impl ObjectExt for Object {
     }
 }
 
+/**
+按值向上转换为Object，适用于任意java对象，包括没有使用`extends`声明父类的类型。
+声明了`extends`链的类型也可以直接使用`Object::from`或`into()`。
+*/
+pub trait AnyObject: JObjRef {
+    /// 转换为Object，转换后仍然引用同一个java对象。
+    fn into_object(self) -> Result<Object>
+    where
+        Self: Sized,
+    {
+        Object::_new(&self.java_ref()?, ())
+    }
+}
+
+impl<T: JType> AnyObject for T {}
+
 // impl JObjRef for String {
 //     fn java_ref(&self) -> Result<GlobalRef> {
 //         let mut env = vm_attach()?;
@@ -133,20 +148,15 @@ pub trait CharSequenceExt {
     /**
     实现一个CharSequence类型。
     */
-    fn to_char_sequence<CS: CharSequence>(&self) -> Result<CS>
-    where
-        <CS as JObjNew>::Fields: Default;
+    fn to_char_sequence(&self) -> Result<CharSequenceImpl>;
 }
 
 impl<'a> CharSequenceExt for &'a str {
-    fn to_char_sequence<CS: CharSequence>(&self) -> Result<CS>
-    where
-        <CS as JObjNew>::Fields: Default,
-    {
+    fn to_char_sequence(&self) -> Result<CharSequenceImpl> {
         let env = vm_attach()?;
         let s = env.new_string(*self)?;
         let s = env.new_global_ref(&s)?;
-        CS::_new(&s, Default::default())
+        CharSequenceImpl::_new(&s, ())
     }
 }
 
@@ -213,13 +223,14 @@ impl Float {
 /**
 CharSequence 是可读的 char 值序列。此接口提供对许多不同种类的 char 序列的统一、只读访问。char 值表示基本多语言平面 (BMP) 中的字符或代理。有关详细信息，请参阅 Unicode 字符表示。此接口不会细化 equals 和 hashCode 方法的一般约定。因此，测试两个实现 CharSequence 的对象是否相等的结果通常是不确定的。每个对象可能由不同的类实现，并且不能保证每个类都能够测试其实例与另一个类的实例是否相等。因此，将任意 CharSequence 实例用作集合中的元素或映射中的键是不合适的。
 */
-#[java_interface(name = "java/lang/CharSequence")]
+#[java_interface(name = "java/lang/CharSequence", wrapper)]
 pub trait CharSequence {
     /**
     返回此字符序列的长度。长度是序列中的16位字符的数量。
     返回：此序列中的字符数量
     */
-    fn length(&self) -> i32;
+    #[java_method]
+    fn length(&self) -> i32 {}
 
     /**
     返回指定索引处的 char 值。索引范围从零到 length() - 1。序列的第一个 char 值位于索引零处，下一个位于索引一处，依此类推，就像数组索引一样。如果索引指定的 char 值是代理，则返回代理值。
@@ -227,17 +238,6 @@ pub trait CharSequence {
     抛出 IndexOutOfBoundsException 如果 index 参数为负数或不小于 length()
     `index` 要返回的 char 值的索引
     * */
-    fn char_at(&self, index: i32) -> Result<char>;
-}
-
-#[doc(hidden)]
-#[java_class(name = "java/lang/CharSequenceImpl", extends=Object)]
-pub struct CharSequenceImpl;
-
-impl CharSequence for CharSequenceImpl {
-    #[java_method]
-    fn length(&self) -> i32 {}
-
     #[java_method]
     fn char_at(&self, index: i32) -> Result<char> {}
 }
@@ -247,7 +247,7 @@ impl CharSequence for CharSequenceImpl {
 活动状态仅表示线程已启动且尚未停止。此外，Runnable 还提供了在不子类化 Thread 的情况下使类处于活动状态的方法。实现 Runnable 的类可以通过实例化 Thread 实例并将其自身作为目标传递，而无需子类化 Thread 即可运行。
 在大多数情况下，如果您只打算覆盖 run() 方法而不覆盖其他 Thread 方法，则应使用 Runnable 接口。这一点很重要，因为除非程序员打算修改或增强类的基本行为，否则不应子类化类。
 */
-#[java_interface(name = "java/lang/Runnable")]
+#[java_interface(name = "java/lang/Runnable", builder)]
 pub trait Runnable {
     /**
     当使用实现 Runnable 接口的对象创建线程时，启动该线程会导致在该单独执行的线程中调用该对象的 run 方法。
@@ -256,29 +256,6 @@ pub trait Runnable {
     fn run(&self);
 }
 
-#[doc(hidden)]
-#[java_class(name = "java/lang/RunnableImpl", extends=Object)]
-pub struct RunnableImpl(Box<dyn Fn() -> Result<()> + Sync + Send>);
-
-impl RunnableImpl {
-    pub fn from_fn(func: impl Fn() -> Result<()> + Sync + Send + 'static) -> Result<Arc<Self>> {
-        Self::new(RunnableImplDefault(Box::new(func)))
-    }
-}
-
-impl Default for RunnableImplDefault {
-    fn default() -> Self {
-        Self(Box::new(|| Ok(())))
-    }
-}
-
-#[java_implement]
-impl Runnable for RunnableImpl {
-    fn run(&self) {
-        self.0().unwrap();
-    }
-}
-
 /**
 System 类包含几个有用的类字段和方法。它无法实例化。 System 类提供的功能包括标准输入、标准输出和错误输出流；访问外部定义的属性和环境变量；加载文件和库的方法；以及用于快速复制数组一部分的实用方法。
 */
@@ -308,6 +285,53 @@ impl System {
     pub fn exit(status: i32) -> Result<()> {}
 }
 
+/**
+线程是程序中的执行线程。Java 虚拟机允许应用程序同时运行多个执行线程。
+*/
+#[java_class(name = "java/lang/Thread", extends = Object)]
+pub struct Thread;
+
+impl Thread {
+    /**
+    返回对当前正在执行的线程对象的引用。
+    返回：当前正在执行的线程。
+    */
+    #[java_method]
+    pub fn current_thread() -> Result<Self> {}
+
+    /**
+    返回此线程的名称。
+    */
+    #[java_method]
+    pub fn get_name(&self) -> String {}
+
+    /**
+    返回此线程的状态。此方法设计用于监视系统状态，而不是用于同步控制。
+    */
+    #[java_method]
+    pub fn get_state(&self) -> Result<Thread_State> {}
+}
+
+/**
+线程状态。线程在给定时间点只能处于一种状态。这些状态是虚拟机状态，不反映任何操作系统线程状态。
+*/
+#[allow(non_camel_case_types)]
+#[java_enum(name = "java/lang/Thread$State")]
+pub enum Thread_State {
+    /// 尚未启动的线程处于此状态。
+    New,
+    /// 在 Java 虚拟机中执行的线程处于此状态。
+    Runnable,
+    /// 被阻塞等待监视器锁的线程处于此状态。
+    Blocked,
+    /// 无限期等待另一个线程执行特定操作的线程处于此状态。
+    Waiting,
+    /// 等待另一个线程执行操作达指定等待时间的线程处于此状态。
+    TimedWaiting,
+    /// 已退出的线程处于此状态。
+    Terminated,
+}
+
 /**
 类加载器是负责加载类的对象。ClassLoader 类是一个抽象类。给定类的二进制名称，类加载器应尝试定位或生成构成该类定义的数据。典型的策略是将名称转换为文件名，然后从文件系统中读取该名称的“类文件”。
 每个 Class 对象都包含对定义它的 ClassLoader 的引用。数组类的类对象不是由类加载器创建的，而是由 Java 运行时根据需要自动创建的。Class.getClassLoader() 返回的数组类的类加载器与其元素类型的类加载器相同；如果元素类型是原始类型，则数组类没有类加载器。
@@ -360,6 +384,7 @@ pub struct ClassLoader;
 当我们说类的自然排序与 equals 一致时，我们的意思是自然排序的商是该类的 equals(Object) 方法定义的等价关系： {(x, y) 使得 x.equals(y)}。
 换句话说，当一个类的自然顺序与 equals 一致时，由 equals 方法的等价关系定义的等价类和由 compareTo 方法的商定义的等价类是相同的。此接口是 Java 集合框架的成员。
 */
+#[java_interface(name = "java/lang/Comparable")]
 pub trait Comparable<T>: JType {
     /**
     将此对象与指定对象进行比较以确定顺序。如果此对象小于、等于或大于指定对象，则返回负整数、零或正整数。
@@ -383,18 +408,68 @@ pub fn test() {
     assert_eq!("100", integer.to_string());
     let float = Float::value_of(423.3).unwrap();
     assert_eq!("423.3", float.to_string());
-    let cs = "hello".to_char_sequence::<CharSequenceImpl>().unwrap();
+    let object: &Object = float.as_ref();
+    assert_eq!(object.hash_code(), float.hash_code());
+    let objects: Vec<Object> = vec![integer.into(), float.into()];
+    assert_eq!("100", objects[0].to_string());
+    let cloned = objects[0].clone();
+    assert!(cloned.same_object(&objects[0]));
+    assert!(!objects[0].same_object(&objects[1]));
+    let set: std::collections::HashSet<Object> = objects.into_iter().chain([cloned]).collect();
+    assert_eq!(2, set.len());
+    assert_eq!(
+        "hello",
+        "hello"
+            .to_char_sequence()
+            .unwrap()
+            .into_object()
+            .unwrap()
+            .to_string()
+    );
+    let cs = "hello".to_char_sequence().unwrap();
     assert_eq!("hello", cs.to_string());
     assert_eq!(5, cs.length());
     assert_eq!('h', cs.char_at(0).unwrap());
     assert!(System::current_time_millis() > 0);
+    let thread = Thread::current_thread().unwrap();
+    assert_eq!(Thread_State::Runnable, thread.get_state().unwrap());
+    assert_eq!("TIMED_WAITING", Thread_State::TimedWaiting.to_string());
+    assert_eq!(3, Thread_State::Waiting.ordinal().unwrap());
     System::gc();
     let cl = ClassLoader::null().unwrap();
     assert_eq!(cl, ClassLoader::null().unwrap());
-    let func = RunnableImpl::from_fn(move || {
-        println!("Runnable is running.");
-        Ok(())
-    });
+    let func = RunnableImpl::builder()
+        .run(|| println!("Runnable is running."))
+        .build();
     let _ = dbg!(func);
+
+    use crate::java_subclass;
+    #[java_class(name = "rust/droid/test/Greeting", extends = Object)]
+    struct Greeting {
+        name: String,
+    }
+
+    impl Default for GreetingDefault {
+        fn default() -> Self {
+            Self {
+                name: "Java".to_string(),
+            }
+        }
+    }
+
+    #[java_subclass]
+    impl Greeting {
+        #[allow(clippy::inherent_to_string)]
+        fn to_string(&self) -> String {
+            format!("Hello, {}!", self.name)
+        }
+    }
+
+    let greeting = Greeting::new(GreetingDefault {
+        name: "Rust".to_string(),
+    })
+    .unwrap();
+    let obj: &Object = &greeting;
+    assert_eq!("Hello, Rust!", obj.to_string());
     // System::exit(0).unwrap();
 }
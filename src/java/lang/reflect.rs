@@ -54,23 +54,6 @@ impl Executable {
 #[java_class(name = "java/lang/reflect/Method", extends=Executable)]
 pub struct Method;
 
-impl Method {
-    /**
-    以字符串形式返回此 Method 对象所表示的方法的名称。
-    */
-    pub fn get_name(&self) -> String {
-        self._based.get_name()
-    }
-
-    /**
-    返回此对象所代表的可执行文件的形式参数数量（无论是显式声明、隐式声明还是均未声明）。
-    返回：此对象所代表的可执行文件的形式参数数量
-    */
-    pub fn get_parameter_count(&self) -> i32 {
-        self._based.get_parameter_count()
-    }
-}
-
 /// 测试java.lang.reflect
 #[cfg(feature = "test_java_lang_reflect")]
 pub fn test() {}
@@ -15,7 +15,7 @@
 #[cfg(feature = "java_nio_file")]
 pub mod file;
 
-use crate::{JObjNew, JObjRef, JType, java_class};
+use crate::{JObjNew, JObjRef, JType, Result, java_class, java_method};
 
 /**
 字节缓冲区。此类定义了六类针对字节缓冲区的操作：
@@ -57,9 +57,24 @@ bb.putInt(0xCAFEBABE).putShort(3).putShort(45);
 #[java_class(name = "java/nio/ByteBuffer")]
 pub struct ByteBuffer;
 
+impl ByteBuffer {
+    /**
+    将字节数组包装到缓冲区中。新缓冲区的容量和限制为数组的长度，位置为零。
+    `array` 支持新缓冲区的数组，内容会被复制到java堆中。
+    */
+    #[java_method]
+    pub fn wrap(array: &[u8]) -> Result<Self> {}
+
+    /// 返回此缓冲区的容量。
+    #[java_method]
+    pub fn capacity(&self) -> i32 {}
+}
+
 /// 测试java.nio
 #[cfg(feature = "test_java_nio")]
 pub fn test() {
     let buffer = ByteBuffer::null().unwrap();
     assert_eq!(buffer, ByteBuffer::null().unwrap());
+    let buffer = ByteBuffer::wrap(&[1, 2, 3]).unwrap();
+    assert_eq!(3, buffer.capacity());
 }
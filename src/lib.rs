@@ -60,4 +60,12 @@ pub mod java {
 }
 
 pub use droid_wrap_macros::*;
+/// 查询当前系统的API级别，用于选择兼容的实现。
+pub use droid_wrap_utils::{DroidWrapError, api_level, is_api_level};
+/// `android_main`使用`android-activity`后端时的应用句柄和事件。
+#[cfg(all(
+    target_os = "android",
+    any(feature = "native_activity", feature = "game_activity")
+))]
+pub use droid_wrap_utils::{App, AppEvent, Events, InputEvent, InputStatus, android_activity};
 droid_wrap_utils::import!();
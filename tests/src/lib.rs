@@ -25,6 +25,11 @@ fn main() {
         droid_wrap::android::content::test();
         println!("Test android.content successfully.");
     }
+    #[cfg(feature = "android_graphics")]
+    {
+        droid_wrap::android::graphics::test();
+        println!("Test android.graphics successfully.");
+    }
     #[cfg(feature = "android_hardware")]
     {
         droid_wrap::android::hardware::test();
@@ -11,15 +11,44 @@
  * See the License for the specific language governing permissions and limitations under the License.
  */
 
+//! 为`rust/CallMethodHook`等需要在运行时加载的类生成`classes.dex`。
+//! 默认使用`droid_wrap_build`直接生成dex，只需要NDK即可构建；启用`d8` feature时改为生成`.class`文件后调用D8转换，此时需要Android SDK和Java运行时。
+
 use cargo_emit::rerun_if_changed;
-use noak::{AccessFlags, writer::ClassWriter};
-use std::{collections::HashMap, env::var, fs::OpenOptions, io::Write, path::PathBuf};
+use std::env::var;
+#[cfg(feature = "d8")]
+use std::path::{Path, PathBuf};
+
+const CLASS: &str = "rust/CallMethodHook";
+const INTERFACE: &str = "java/lang/reflect/InvocationHandler";
+const INVOKE_SIG: &str =
+    "(Ljava/lang/Object;Ljava/lang/reflect/Method;[Ljava/lang/Object;)Ljava/lang/Object;";
+
+/// 生成`classes.dex`，新的类也在这里添加。
+#[cfg(not(feature = "d8"))]
+fn gen_dex() {
+    droid_wrap_build::DexBuilder::new()
+        .class(
+            droid_wrap_build::DexClass::new(CLASS, "java/lang/Object")
+                .interface(INTERFACE)
+                .constructor("()V")
+                .native_method("invoke", INVOKE_SIG, false),
+        )
+        .build("classes")
+        .expect("Failed to write classes.dex");
+}
 
+#[cfg(feature = "d8")]
 pub fn gen_class(
     class: &str,
     interfaces: &[&str],
-    method_map: &HashMap<&str, (bool, String)>,
+    method_map: &std::collections::HashMap<&str, (bool, String)>,
 ) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    use noak::{
+        AccessFlags,
+        writer::{ClassWriter, cpool::MethodRef},
+    };
+
     let mut bytes = Vec::new();
     ClassWriter::new()
         .version(noak::Version::V8)?
@@ -34,6 +63,32 @@ pub fn gen_class(
         })?
         .fields(|_| Ok(()))?
         .methods(|methods| {
+            // 无参数的构造函数，只调用父类的构造函数。
+            methods.begin(|m| {
+                m.access_flags(AccessFlags::PUBLIC)?
+                    .name("<init>")?
+                    .descriptor("()V")?
+                    .attributes(|attrs| {
+                        attrs.begin(|a| {
+                            a.code(|c| {
+                                c.max_stack(1)?
+                                    .max_locals(1)?
+                                    .instructions(|i| {
+                                        i.aload0()?
+                                            .invokespecial(MethodRef::by(
+                                                "java/lang/Object",
+                                                ("<init>", "()V"),
+                                            ))?
+                                            .return_()?;
+                                        Ok(())
+                                    })?
+                                    .exceptions(|_| Ok(()))?
+                                    .attributes(|_| Ok(()))
+                            })
+                        })?;
+                        Ok(())
+                    })
+            })?;
             for (name, (is_static, sig)) in method_map.iter() {
                 methods.begin(|m| {
                     let mut flags = AccessFlags::PUBLIC | AccessFlags::NATIVE;
@@ -54,35 +109,14 @@ pub fn gen_class(
     Ok(bytes)
 }
 
-//noinspection SpellCheckingInspection
-fn main() {
-    let target_os = var("CARGO_CFG_TARGET_OS").unwrap();
-
-    if target_os != "android" {
-        return;
-    }
-
-    rerun_if_changed!("build.rs");
-    let out_dir = PathBuf::from(var("OUT_DIR").unwrap());
-    const CLASS: &str = "rust/CallMethodHook";
+/// 使用D8把生成的`.class`文件转换为dex，作为直接生成dex的备用方案。
+#[cfg(feature = "d8")]
+fn gen_dex_with_d8(out_dir: &Path) {
     let java_class_path = out_dir.join("CallMethodHook.class");
-    let mut java_class_file = OpenOptions::new()
-        .create(true)
-        .write(true)
-        .open(&java_class_path)
-        .unwrap();
-
-    let mut methods = HashMap::new();
-    methods.insert(
-        "invoke",
-        (
-            false,
-            "(Ljava/lang/Object;Ljava/lang/reflect/Method;[Ljava/lang/Object;)Ljava/lang/Object;"
-                .to_string(),
-        ),
-    );
-    let class = gen_class(CLASS, &["java/lang/reflect/InvocationHandler"], &methods).unwrap();
-    java_class_file.write_all(&class).unwrap();
+    let mut methods = std::collections::HashMap::new();
+    methods.insert("invoke", (false, INVOKE_SIG.to_string()));
+    let class = gen_class(CLASS, &[INTERFACE], &methods).unwrap();
+    std::fs::write(&java_class_path, class).unwrap();
 
     let android_jar_path = android_build::android_jar(None).expect("Failed to find android.jar");
     let d8_jar_path = android_build::android_d8_jar(None).expect("Failed to find d8.jar");
@@ -93,9 +127,25 @@ fn main() {
         .arg("--classpath")
         .arg(android_jar_path)
         .arg("--output")
-        .arg(&out_dir)
+        .arg(out_dir)
         .arg(&java_class_path)
         .run()
         .expect("failed to acquire exit status for java d8.jar invocation")
         .success();
 }
+
+//noinspection SpellCheckingInspection
+fn main() {
+    let target_os = var("CARGO_CFG_TARGET_OS").unwrap();
+
+    if target_os != "android" {
+        return;
+    }
+
+    rerun_if_changed!("build.rs");
+
+    #[cfg(feature = "d8")]
+    gen_dex_with_d8(&PathBuf::from(var("OUT_DIR").unwrap()));
+    #[cfg(not(feature = "d8"))]
+    gen_dex();
+}
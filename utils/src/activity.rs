@@ -0,0 +1,210 @@
+/*
+ * Copyright (c) 2025. The RigelA open source project team and
+ * its contributors reserve all rights.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software distributed under the
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and limitations under the License.
+ */
+
+pub use android_activity::{self, AndroidApp, InputStatus, input::InputEvent};
+use android_activity::{MainEvent, PollEvent};
+use std::{
+    collections::VecDeque,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+/// 应用的生命周期事件，由`android-activity`的主线程事件转换而来。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AppEvent {
+    /// activity已启动。
+    Start,
+    /// activity已恢复，回到前台。
+    Resume,
+    /// activity已暂停，即将离开前台。
+    Pause,
+    /// activity已停止，不再可见。
+    Stop,
+    /// activity正在销毁，这是最后一个事件，应当尽快从`main`返回。
+    Destroy,
+    /// 系统要求保存状态。
+    SaveState,
+    /// 窗口已创建，可以开始绘制。
+    WindowCreated,
+    /// 窗口即将销毁，需要停止绘制。
+    WindowDestroyed,
+    /// 窗口大小已改变。
+    WindowResized,
+    /// 窗口需要重绘。
+    RedrawNeeded,
+    /// 窗口内容区域已改变。
+    ContentRectChanged,
+    /// 窗口的insets已改变。
+    InsetsChanged,
+    /// 窗口获得输入焦点。
+    FocusGained,
+    /// 窗口失去输入焦点。
+    FocusLost,
+    /// 设备配置已改变，例如屏幕方向或语言。
+    ConfigChanged,
+    /// 系统内存不足，应当释放不必要的资源。
+    LowMemory,
+    /// 有新的输入事件，可以使用`App::input_events`读取。
+    InputAvailable,
+}
+
+impl AppEvent {
+    fn from_main(event: &MainEvent<'_>) -> Option<Self> {
+        Some(match event {
+            MainEvent::Start => Self::Start,
+            MainEvent::Resume { .. } => Self::Resume,
+            MainEvent::Pause => Self::Pause,
+            MainEvent::Stop => Self::Stop,
+            MainEvent::Destroy => Self::Destroy,
+            MainEvent::SaveState { .. } => Self::SaveState,
+            MainEvent::InitWindow { .. } => Self::WindowCreated,
+            MainEvent::TerminateWindow { .. } => Self::WindowDestroyed,
+            MainEvent::WindowResized { .. } => Self::WindowResized,
+            MainEvent::RedrawNeeded { .. } => Self::RedrawNeeded,
+            MainEvent::ContentRectChanged { .. } => Self::ContentRectChanged,
+            MainEvent::InsetsChanged { .. } => Self::InsetsChanged,
+            MainEvent::GainedFocus => Self::FocusGained,
+            MainEvent::LostFocus => Self::FocusLost,
+            MainEvent::ConfigChanged { .. } => Self::ConfigChanged,
+            MainEvent::LowMemory => Self::LowMemory,
+            MainEvent::InputAvailable => Self::InputAvailable,
+            _ => return None,
+        })
+    }
+}
+
+/**
+应用句柄，`android_main`使用`android-activity`后端时传递给`main`函数。
+同时支持NativeActivity和GameActivity，通过`native_activity`或`game_activity`特性选择。
+*/
+#[derive(Debug, Clone)]
+pub struct App {
+    app: AndroidApp,
+    destroyed: Arc<AtomicBool>,
+}
+
+impl App {
+    #[doc(hidden)]
+    pub fn new(app: AndroidApp) -> Self {
+        Self {
+            app,
+            destroyed: Default::default(),
+        }
+    }
+
+    /// 获取`android-activity`的原始句柄，用于访问窗口、配置和资源等。
+    pub fn android_app(&self) -> &AndroidApp {
+        &self.app
+    }
+
+    /**
+    等待并获取一批生命周期事件，只能在`main`所在的线程调用。
+    `timeout` 最长等待时间，`None`表示一直等待直到有事件或被唤醒。
+    */
+    pub fn poll_events(&self, timeout: Option<Duration>) -> Vec<AppEvent> {
+        let mut events = Vec::new();
+        self.app.poll_events(timeout, |e| {
+            if let PollEvent::Main(e) = e
+                && let Some(e) = AppEvent::from_main(&e)
+            {
+                if e == AppEvent::Destroy {
+                    self.destroyed.store(true, Ordering::Release);
+                }
+                events.push(e);
+            }
+        });
+        events
+    }
+
+    /**
+    获取生命周期事件流，迭代时阻塞等待事件，收到`AppEvent::Destroy`后结束。
+    只能在`main`所在的线程使用。
+
+    # 示例
+
+    ```ignore
+    for event in app.events() {
+        match event {
+            AppEvent::Resume => println!("resumed"),
+            AppEvent::InputAvailable => app.input_events(|_| InputStatus::Unhandled),
+            _ => {}
+        }
+    }
+    ```
+    */
+    pub fn events(&self) -> Events<'_> {
+        Events {
+            app: self,
+            pending: VecDeque::new(),
+            finished: false,
+        }
+    }
+
+    /**
+    读取所有待处理的输入事件，只能在`main`所在的线程调用。
+    `handler` 处理每一个输入事件，返回是否已经处理，未处理的事件交给系统默认处理。
+    */
+    pub fn input_events<F>(&self, mut handler: F)
+    where
+        F: FnMut(&InputEvent<'_>) -> InputStatus,
+    {
+        match self.app.input_events_iter() {
+            Ok(mut iter) => while iter.next(&mut handler) {},
+            Err(e) => log::error!("Failed to read input events: {:?}", e),
+        }
+    }
+
+    /// 在`main`返回后继续处理事件，直到activity被销毁。
+    #[doc(hidden)]
+    pub fn wait_destroy(&self) {
+        if !self.is_destroyed() {
+            for _ in self.events() {}
+        }
+    }
+
+    /// activity是否已经销毁，即是否已经收到`AppEvent::Destroy`。
+    pub fn is_destroyed(&self) -> bool {
+        self.destroyed.load(Ordering::Acquire)
+    }
+}
+
+/// 生命周期事件流，参见`App::events`。
+#[derive(Debug)]
+pub struct Events<'a> {
+    app: &'a App,
+    pending: VecDeque<AppEvent>,
+    finished: bool,
+}
+
+impl Iterator for Events<'_> {
+    type Item = AppEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(e) = self.pending.pop_front() {
+                if e == AppEvent::Destroy {
+                    self.pending.clear();
+                    self.finished = true;
+                }
+                return Some(e);
+            }
+            if self.finished {
+                return None;
+            }
+            self.pending.extend(self.app.poll_events(None));
+        }
+    }
+}
@@ -0,0 +1,244 @@
+/*
+ * Copyright (c) 2025. The RigelA open source project team and
+ * its contributors reserve all rights.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software distributed under the
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and limitations under the License.
+ */
+
+use crate::{
+    JValue, Result, android_context, bind_proxy_handler, new_proxy, null_value, register_natives,
+    vm_attach,
+};
+pub use log::LevelFilter;
+use log::{Log, Metadata, Record, error};
+use parking_lot::Mutex;
+use std::{
+    backtrace::Backtrace,
+    ffi::CString,
+    fmt::Debug,
+    panic::{AssertUnwindSafe, catch_unwind},
+    thread::sleep,
+    time::Duration,
+};
+
+/// `main`发生panic时的处理方式。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanicPolicy {
+    /// 记录日志后立即结束进程。
+    Abort,
+    /// 记录日志后结束当前的activity。
+    Finish,
+    /// 记录日志并使用Toast向用户显示错误信息，然后结束当前的activity。
+    Toast,
+}
+
+/// `android_main`生成的入口使用的选项。
+#[doc(hidden)]
+#[derive(Debug, Clone)]
+pub struct MainOptions {
+    /// 日志的标签。
+    pub tag: &'static str,
+    /// 日志的级别。
+    pub level: LevelFilter,
+    /// panic的处理方式。
+    pub panic: PanicPolicy,
+    /// `main`返回错误时是否向用户显示错误信息。
+    pub show_error: bool,
+}
+
+/// `android_main`标记的`main`函数的返回值，可以是`()`或者错误类型实现了`Debug`的`Result`。
+pub trait MainResult {
+    /// 获取错误信息，没有错误时返回`None`。
+    fn error(self) -> Option<String>;
+}
+
+impl MainResult for () {
+    fn error(self) -> Option<String> {
+        None
+    }
+}
+
+impl<T, E: Debug> MainResult for std::result::Result<T, E> {
+    fn error(self) -> Option<String> {
+        self.err().map(|e| format!("{:?}", e))
+    }
+}
+
+/// Toast显示的时长，与`Toast.LENGTH_LONG`一致。
+const TOAST_DURATION: Duration = Duration::from_millis(3500);
+
+// 最近一次panic的信息，用于向用户显示。
+static LAST_PANIC: Mutex<Option<String>> = Mutex::new(None);
+
+struct Logger {
+    tag: CString,
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        write_log(&self.tag, record.level(), &record.args().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+#[cfg(target_os = "android")]
+fn write_log(tag: &CString, level: log::Level, message: &str) {
+    use std::ffi::{c_char, c_int};
+
+    #[link(name = "log")]
+    unsafe extern "C" {
+        fn __android_log_write(prio: c_int, tag: *const c_char, text: *const c_char) -> c_int;
+    }
+
+    // 优先级的值参考android/log.h中的android_LogPriority。
+    let prio = match level {
+        log::Level::Error => 6,
+        log::Level::Warn => 5,
+        log::Level::Info => 4,
+        log::Level::Debug => 3,
+        log::Level::Trace => 2,
+    };
+    let text = CString::new(message.replace('\0', "\\0")).unwrap_or_default();
+    unsafe {
+        __android_log_write(prio, tag.as_ptr(), text.as_ptr());
+    }
+}
+
+#[cfg(not(target_os = "android"))]
+fn write_log(tag: &CString, level: log::Level, message: &str) {
+    eprintln!("{} {}: {}", level, tag.to_string_lossy(), message);
+}
+
+/**
+初始化日志，在安卓平台上输出到logcat，其他平台输出到标准错误输出。
+如果已经设置了其他的日志实现，则只修改日志级别。
+
+# Arguments
+
+* `tag`: 日志的标签。
+* `level`: 日志的级别。
+*/
+pub fn init_logger(tag: &str, level: LevelFilter) {
+    let tag = CString::new(tag.replace('\0', "")).unwrap_or_default();
+    let _ = log::set_logger(Box::leak(Box::new(Logger { tag })));
+    log::set_max_level(level);
+}
+
+/// 结束当前的activity。
+pub fn finish_activity() -> Result<()> {
+    let mut env = vm_attach()?;
+    env.call_method(android_context(), "finish", "()V", &[])?;
+    Ok(())
+}
+
+/**
+在UI线程中使用Toast向用户显示一段文字，调用后立即返回。
+
+# Arguments
+
+* `text`: 显示的文字。
+*/
+pub fn show_toast(text: &str) -> Result<()> {
+    let text = text.to_owned();
+    let runnable = new_proxy(&["java.lang.Runnable"])?;
+    bind_proxy_handler(&runnable, move |env, _, _| {
+        let text = env.new_string(&text)?;
+        let toast = env
+            .call_static_method(
+                "android/widget/Toast",
+                "makeText",
+                "(Landroid/content/Context;Ljava/lang/CharSequence;I)Landroid/widget/Toast;",
+                &[
+                    (&android_context()).into(),
+                    (&text).into(),
+                    // Toast.LENGTH_LONG
+                    JValue::Int(1),
+                ],
+            )?
+            .l()?;
+        env.call_method(&toast, "show", "()V", &[])?;
+        null_value(env)
+    });
+    let mut env = vm_attach()?;
+    env.call_method(
+        android_context(),
+        "runOnUiThread",
+        "(Ljava/lang/Runnable;)V",
+        &[runnable.as_obj().into()],
+    )?;
+    Ok(())
+}
+
+// 显示错误信息并结束activity。
+fn show_error_and_finish(message: &str) {
+    if let Err(e) = show_toast(message) {
+        error!("Failed to show the error: {}", e);
+    } else {
+        sleep(TOAST_DURATION);
+    }
+    if let Err(e) = finish_activity() {
+        error!("Failed to finish the activity: {}", e);
+    }
+}
+
+/**
+运行`android_main`标记的`main`函数，由生成的入口调用。
+初始化日志，把panic的信息和调用栈写入日志，注册本地方法，然后按照`options`处理`main`的panic和返回的错误。
+
+# Arguments
+
+* `options`: 入口选项。
+* `main`: `main`函数。
+*/
+#[doc(hidden)]
+pub fn run_main<R: MainResult>(options: &MainOptions, main: impl FnOnce() -> R) {
+    init_logger(options.tag, options.level);
+    std::panic::set_hook(Box::new(|info| {
+        error!("{}\n{}", info, Backtrace::force_capture());
+        *LAST_PANIC.lock() = Some(info.payload_as_str().unwrap_or("Box<dyn Any>").to_owned());
+    }));
+    if let Err(e) = register_natives() {
+        error!("Failed to register native methods: {}", e);
+    }
+
+    match catch_unwind(AssertUnwindSafe(main)) {
+        Ok(r) => {
+            if let Some(e) = r.error() {
+                error!("The main function returned an error: {}", e);
+                if options.show_error {
+                    show_error_and_finish(&e);
+                }
+            }
+        }
+        // panic的信息已经由panic hook写入日志。
+        Err(_) => match options.panic {
+            PanicPolicy::Abort => std::process::abort(),
+            PanicPolicy::Finish => {
+                if let Err(e) = finish_activity() {
+                    error!("Failed to finish the activity: {}", e);
+                }
+            }
+            PanicPolicy::Toast => {
+                let message = LAST_PANIC.lock().take().unwrap_or_default();
+                show_error_and_finish(&format!(
+                    "The application has stopped unexpectedly: {}",
+                    message
+                ))
+            }
+        },
+    }
+}
@@ -12,7 +12,9 @@
  */
 
 use jni::errors::{Error as JniError, JniError as JniCallError};
+use log::error;
 use std::{
+    cell::RefCell,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
     result::Result as StdResult,
@@ -25,6 +27,16 @@ pub enum DroidWrapError {
     Jni(JniError),
     FromStr(String),
     Utf8(Utf8Error),
+    /// java子类对象没有绑定rust状态，或者状态已经被释放。
+    Subclass(String),
+    /// 整数不是常量组中的已知值，包含常量组的名称和整数值。
+    UnknownConstant(&'static str, i32),
+    /// java枚举对象不是rust中已知的常量（或者为null），包含枚举的名称和java中常量的名称。
+    UnknownEnumConstant(&'static str, String),
+    /// 当前系统的API级别不支持调用的方法，包含方法要求的最低级别、最高级别（包含）和当前级别。
+    UnsupportedApiLevel(i32, Option<i32>, i32),
+    /// 插件没有加载或者已经被卸载，包含插件的名称。
+    PluginNotLoaded(&'static str),
 }
 
 impl Clone for DroidWrapError {
@@ -62,7 +74,12 @@ impl Clone for DroidWrapError {
                 }),
             }),
             Self::Utf8(e) => Self::Utf8(e.to_owned()),
-            Self::FromStr(s) => Self::FromStr(s.to_owned())
+            Self::FromStr(s) => Self::FromStr(s.to_owned()),
+            Self::Subclass(s) => Self::Subclass(s.to_owned()),
+            Self::UnknownConstant(n, v) => Self::UnknownConstant(n, *v),
+            Self::UnknownEnumConstant(n, v) => Self::UnknownEnumConstant(n, v.to_owned()),
+            Self::UnsupportedApiLevel(s, u, c) => Self::UnsupportedApiLevel(*s, *u, *c),
+            Self::PluginNotLoaded(n) => Self::PluginNotLoaded(n),
         }
     }
 }
@@ -76,6 +93,22 @@ impl Display for DroidWrapError {
             Self::FromStr(e) => Display::fmt(e, f),
             Self::Jni(e) => Display::fmt(e, f),
             Self::Utf8(e) => Display::fmt(e, f),
+            Self::Subclass(e) => Display::fmt(e, f),
+            Self::UnknownConstant(n, v) => write!(f, "Unknown value `{}` of `{}`.", v, n),
+            Self::UnknownEnumConstant(n, v) => {
+                write!(f, "Unknown enum constant `{}` of `{}`.", v, n)
+            }
+            Self::UnsupportedApiLevel(s, Some(u), c) => write!(
+                f,
+                "Unsupported API level {}, the method requires API level {} to {}.",
+                c, s, u
+            ),
+            Self::UnsupportedApiLevel(s, None, c) => write!(
+                f,
+                "Unsupported API level {}, the method requires API level {} or later.",
+                c, s
+            ),
+            Self::PluginNotLoaded(n) => write!(f, "Plugin `{}` is not loaded.", n),
         }
     }
 }
@@ -94,3 +127,41 @@ impl From<Utf8Error> for DroidWrapError {
 
 /// 结果类型
 pub type Result<T> = StdResult<T, DroidWrapError>;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<DroidWrapError>> = const { RefCell::new(None) };
+}
+
+/// 记录生成的代码中被忽略的错误，用于`failure = "default"`策略，返回值不是`Option`或`Result`的方法失败时调用。
+///
+/// # 参数
+///
+/// * `method` - 失败的方法名称。
+/// * `err` - 错误。
+pub fn log_failure(method: &str, err: DroidWrapError) {
+    error!("Failed to call `{}`: {}", method, err);
+}
+
+/// 保存当前线程最近一次的错误，用于`failure = "last_error"`策略，可以使用`take_last_error`取出。
+///
+/// # 参数
+///
+/// * `err` - 错误。
+pub fn set_last_error(err: DroidWrapError) {
+    LAST_ERROR.with_borrow_mut(|i| *i = Some(err));
+}
+
+/// 取出当前线程最近一次被`failure = "last_error"`策略保存的错误，取出后会被清空。
+///
+/// # 示例
+///
+/// ```rust
+/// use droid_wrap_utils::take_last_error;
+///
+/// if let Some(err) = take_last_error() {
+///     println!("{}", err);
+/// }
+/// ```
+pub fn take_last_error() -> Option<DroidWrapError> {
+    LAST_ERROR.take()
+}
@@ -11,28 +11,50 @@
  * See the License for the specific language governing permissions and limitations under the License.
  */
 
+#[cfg(all(
+    target_os = "android",
+    any(feature = "native_activity", feature = "game_activity")
+))]
+mod activity;
+mod entry;
 mod error;
-
+mod loader;
+
+#[cfg(all(
+    target_os = "android",
+    any(feature = "native_activity", feature = "game_activity")
+))]
+pub use activity::*;
+pub use droid_wrap_build::{DexClass, write_dex};
+pub use entry::*;
 pub use error::*;
+pub use loader::*;
 
 pub use jni::{
     AttachGuard, JNIEnv, JavaVM, NativeMethod,
     errors::Error as JniError,
     objects::{
-        GlobalRef, JBooleanArray, JByteArray, JClass, JObject, JObjectArray, JString, JValueGen,
-        ReleaseMode,
+        GlobalRef, JBooleanArray, JByteArray, JClass, JObject, JObjectArray, JString, JValue,
+        JValueGen, JValueOwned, ReleaseMode,
     },
     sys::{jboolean, jbyte, jchar, jdouble, jfloat, jint, jlong, jshort, jsize},
 };
+use jni::signature::{Primitive, ReturnType, TypeSignature};
 use log::{debug, error, warn};
-use parking_lot::ReentrantMutex;
+use parking_lot::{Mutex, ReentrantMutex};
 use std::{
+    any::Any,
     cell::RefCell,
     collections::HashMap,
     fmt::Debug,
     hash::{DefaultHasher, Hash, Hasher},
+    ops::RangeBounds,
+    panic::{AssertUnwindSafe, catch_unwind},
     str::FromStr,
-    sync::{Arc, LazyLock, OnceLock},
+    sync::{
+        Arc, LazyLock, OnceLock, Weak,
+        atomic::{AtomicI64, AtomicUsize, Ordering},
+    },
 };
 
 // Rust 代理对象的哈希值映射到 Rust 函数
@@ -67,7 +89,7 @@ macro_rules! import {
         };
         pub use $crate::Result;
         use $crate::{
-            GlobalRef, JObject, impl_array, null_value, to_java_byte_array, to_java_object_array,
+            GlobalRef, JArgValue, JNIEnv, JObject, JValueOwned, impl_array, null_value, to_java_byte_array, to_java_object_array,
             unbind_proxy_handler, vm_attach,
         };
 
@@ -80,6 +102,22 @@ macro_rules! import {
             获取java对象引用。
             */
             fn java_ref(&self) -> Result<GlobalRef>;
+
+            /**
+            获取作为java方法参数或字段值时的值，默认为java对象引用，`java_constants`生成的类型为整数。
+            */
+            fn java_value(&self) -> Result<JArgValue> {
+                Ok(JArgValue::Object(self.java_ref()?))
+            }
+
+            /**
+            判断是否与`other`引用同一个java对象（相当于java中的`==`），与通过`equals`比较的`PartialEq`不同。
+            `other` 另一个java对象。
+            */
+            fn same_object(&self, other: &impl JObjRef) -> bool {
+                let call_fn = || $crate::java_object_same(self.java_ref()?, other.java_ref()?);
+                call_fn().unwrap_or_default()
+            }
         }
 
         /**
@@ -97,6 +135,24 @@ macro_rules! import {
             where
                 Self: Sized;
 
+            /**
+            从java方法的返回值或字段值创建本地对象，默认从java对象引用创建，`java_constants`生成的类型从整数创建。
+            `env` java环境。
+            `value` 返回值或字段值。
+            */
+            fn _from_value(env: &mut JNIEnv, value: JValueOwned) -> Result<Self>
+            where
+                Self: Sized,
+            {
+                Self::_new(env.new_global_ref(value.l()?)?.as_ref(), Default::default())
+            }
+
+            /**
+            失败策略为`default`或`last_error`时是否有可以返回的默认值（即`_fallback`），`java_enum`和`JavaBean`生成的类型为`false`。
+            生成的代码会在编译时检查此常量，没有默认值的类型只能返回`Result`或`Option`。
+            */
+            const HAS_FALLBACK: bool = true;
+
             /**
             创建空对象。
             */
@@ -105,9 +161,29 @@ macro_rules! import {
                 Self: Sized,
                 Self::Fields: Default,
             {
+                if let Some(null) = $crate::cached_null_value() {
+                    return Self::_new(&null, Default::default());
+                }
                 let mut env = vm_attach()?;
                 Self::_new(null_value(&mut env)?.as_ref(), Default::default())
             }
+
+            /**
+            方法调用失败时按照失败策略返回的默认值，默认为java的null，`java_constants`生成的类型为0对应的值，只用于`HAS_FALLBACK`为`true`的类型。
+            null的全局引用在第一次附加java虚拟机时缓存，因此只要曾经成功附加过java虚拟机就不会失败；
+            从未附加成功时无法创建null，只能panic，`failure_policy`的文档中说明了这一点。
+            */
+            #[doc(hidden)]
+            fn _fallback() -> Self
+            where
+                Self: Sized,
+                Self::Fields: Default,
+            {
+                match Self::null() {
+                    Ok(null) => null,
+                    Err(e) => panic!("The java virtual machine is unavailable: {}", e),
+                }
+            }
         }
 
         /**
@@ -122,6 +198,16 @@ macro_rules! import {
 
             /// 数组维度，0表示不是数组
             const DIM: u8 = 0;
+
+            /// 获取加载此类的类加载器，默认为应用的类加载器，可以使用`java_class(loader = ...)`指定。
+            fn class_loader() -> Result<GlobalRef> {
+                $crate::app_class_loader()
+            }
+
+            /// 使用`class_loader`返回的类加载器加载此类，生成的代码都通过它查找类。
+            fn java_class<'a>() -> Result<$crate::JClass<'a>> {
+                $crate::load_class_from(&Self::class_loader()?, Self::CLASS)
+            }
         }
 
         /**
@@ -148,6 +234,10 @@ macro_rules! import {
             fn java_ref(&self) -> Result<GlobalRef> {
                 self.java_ref()
             }
+
+            fn java_value(&self) -> Result<JArgValue> {
+                T::java_value(self)
+            }
         }
 
         impl<T: JObjNew> JObjNew for &T {
@@ -190,6 +280,10 @@ macro_rules! import {
             fn java_ref(&self) -> Result<GlobalRef> {
                 self.as_ref().java_ref()
             }
+
+            fn java_value(&self) -> Result<JArgValue> {
+                self.as_ref().java_value()
+            }
         }
 
         impl<T: JObjNew> JObjNew for Arc<T> {
@@ -209,6 +303,10 @@ macro_rules! import {
             fn java_ref(&self) -> Result<GlobalRef> {
                 self.as_ref().java_ref()
             }
+
+            fn java_value(&self) -> Result<JArgValue> {
+                self.as_ref().java_value()
+            }
         }
 
         impl<T: JObjNew> JObjNew for Rc<T> {
@@ -243,6 +341,38 @@ macro_rules! import {
     };
 }
 
+/// 调用java方法或设置字段时传递的值。
+#[derive(Debug)]
+pub enum JArgValue {
+    /// java对象的引用。
+    Object(GlobalRef),
+    /// 整数，用于`java_constants`生成的类型。
+    Int(jint),
+}
+
+impl JArgValue {
+    /// 创建java字符串，用于`&str`等借用的字符串参数。
+    pub fn string(s: &str) -> Result<Self> {
+        let env = vm_attach()?;
+        let s = env.new_string(s)?;
+        Ok(Self::Object(env.new_global_ref(&s)?))
+    }
+
+    /// java的null值，用于值为`None`的`Option<&T>`等参数。
+    pub fn null() -> Result<Self> {
+        let mut env = vm_attach()?;
+        Ok(Self::Object(null_value(&mut env)?))
+    }
+
+    /// 转换为JNI调用所需的值。
+    pub fn as_jvalue(&self) -> JValue<'_, '_> {
+        match self {
+            Self::Object(obj) => JValue::Object(obj.as_obj()),
+            Self::Int(i) => JValue::Int(*i),
+        }
+    }
+}
+
 /// 实现rust数组类型与java数组的自动关联
 ///
 /// # 示例
@@ -341,16 +471,22 @@ macro_rules! impl_array {
     };
 }
 
+// java虚拟机，由`JNI_OnLoad`或者第一次调用`android_vm`时从ndk上下文中获取
+static JAVA_VM: OnceLock<JavaVM> = OnceLock::new();
+
+/// null的全局引用，第一次附加java虚拟机时创建，之后不需要java环境也能创建null对象。
+static NULL_REF: OnceLock<GlobalRef> = OnceLock::new();
+
 /**
 获取android系统的java虚拟机。
 */
 pub fn android_vm<'a>() -> Result<&'static JavaVM> {
-    static JAVA_VM: LazyLock<Result<JavaVM>> = LazyLock::new(|| {
-        let ctx = ndk_context::android_context();
-        let vm = unsafe { JavaVM::from_raw(ctx.vm().cast()) }?;
-        Ok(vm)
-    });
-    JAVA_VM.as_ref().map_err(|e| e.to_owned())
+    if let Some(vm) = JAVA_VM.get() {
+        return Ok(vm);
+    }
+    let ctx = ndk_context::android_context();
+    let vm = unsafe { JavaVM::from_raw(ctx.vm().cast()) }?;
+    Ok(JAVA_VM.get_or_init(|| vm))
 }
 
 /// 获取vm，将vm附加到当前线程，随后操作java虚拟机。
@@ -368,7 +504,11 @@ pub fn android_vm<'a>() -> Result<&'static JavaVM> {
 #[inline(always)]
 pub fn vm_attach<'a>() -> Result<AttachGuard<'a>> {
     let vm = android_vm()?;
-    vm.attach_current_thread().map_err(|e| e.into())
+    let mut env = vm.attach_current_thread()?;
+    if NULL_REF.get().is_none() {
+        let _ = null_value(&mut env);
+    }
+    Ok(env)
 }
 
 /**
@@ -379,27 +519,140 @@ pub fn android_context<'a>() -> JObject<'a> {
     unsafe { JObject::from_raw(ctx.context().cast()) }
 }
 
+/**
+获取当前系统的API级别，即`Build.VERSION.SDK_INT`，读取成功后缓存结果。
+*/
+pub fn api_level() -> Result<i32> {
+    static API_LEVEL: OnceLock<i32> = OnceLock::new();
+    if let Some(level) = API_LEVEL.get() {
+        return Ok(*level);
+    }
+    let mut env = vm_attach()?;
+    let level = env
+        .get_static_field("android/os/Build$VERSION", "SDK_INT", "I")?
+        .i()?;
+    Ok(*API_LEVEL.get_or_init(|| level))
+}
+
+/**
+判断当前系统的API级别是否在指定的范围内，用于在运行时选择兼容的实现，无法获取API级别时返回`false`。
+
+# Arguments
+
+* `range`: API级别的范围。
+
+# 示例
+
+```ignore
+use droid_wrap_utils::is_api_level;
+if is_api_level(31..) {
+    // 使用API 31新增的方法
+} else {
+    // 使用旧的实现
+}
+```
+*/
+pub fn is_api_level<R: RangeBounds<i32>>(range: R) -> bool {
+    api_level().is_ok_and(|level| range.contains(&level))
+}
 
 /**
-加载一个java类。
+检查当前系统的API级别是否支持调用方法，由`java_method`的`since`和`until`参数生成的代码调用。
+
+# Arguments
+
+* `since`: 方法要求的最低API级别。
+* `until`: 方法支持的最高API级别（包含），`None`表示没有限制。
+*/
+#[doc(hidden)]
+pub fn check_api_level(since: i32, until: Option<i32>) -> Result<()> {
+    let level = api_level()?;
+    if level < since || until.is_some_and(|until| level > until) {
+        return Err(DroidWrapError::UnsupportedApiLevel(since, until, level));
+    }
+    Ok(())
+}
+
+
+/**
+使用应用的类加载器加载一个java类。
 */
 pub fn load_class<'a>(class_name: &str) -> Result<JClass<'a>> {
-    let mut env = vm_attach()?;
-    let activity = android_context();
-    let class_loader = env.call_method(
-        activity,
-        "getClassLoader",
-        "()Ljava/lang/ClassLoader;",
-        &[],
-    )?;
-    let cls_name = env.new_string(class_name)?;
-    let loaded_class = env.call_method(
-        class_loader.l()?,
-        "loadClass",
-        "(Ljava/lang/String;)Ljava/lang/Class;",
-        &[(&cls_name).into()],
-    )?;
-    Ok(loaded_class.l()?.into())
+    load_class_from(&app_class_loader()?, class_name)
+}
+
+/// 以非虚方式调用java对象的方法，即调用`class`中声明的实现，而不是对象的实际类型中重写的实现，相当于java中的`super.method(...)`。
+/// jni crate没有提供`CallNonvirtual<Type>MethodA`的封装，因此这里直接调用JNI函数表。
+///
+/// # Arguments
+///
+/// * `env`: JNI环境。
+/// * `obj`: java对象。
+/// * `class`: 提供方法实现的类，必须是`obj`的类或其父类。
+/// * `name`: 方法名称。
+/// * `sig`: 方法描述符。
+/// * `args`: 参数。
+///
+/// returns: `Result<JValueOwned>` 方法的返回值
+pub fn call_nonvirtual_method<'local, 'other_local, O>(
+    env: &mut JNIEnv<'local>,
+    obj: O,
+    class: &JClass,
+    name: &str,
+    sig: &str,
+    args: &[JValue],
+) -> Result<JValueOwned<'local>>
+where
+    O: AsRef<JObject<'other_local>>,
+{
+    let obj = obj.as_ref();
+    if obj.is_null() {
+        return Err(JniError::NullPtr("call_nonvirtual_method obj argument").into());
+    }
+    let parsed = TypeSignature::from_str(sig)?;
+    if parsed.args.len() != args.len() {
+        return Err(JniError::InvalidArgList(parsed).into());
+    }
+    let method_id = env.get_method_id(class, name, sig)?.into_raw();
+    let args = args.iter().map(|i| i.as_jni()).collect::<Vec<_>>();
+    let raw = env.get_raw();
+    let (obj, class) = (obj.as_raw(), class.as_raw());
+
+    macro_rules! call {
+        ($name:ident) => {
+            unsafe {
+                let f = (**raw)
+                    .$name
+                    .ok_or(JniError::JNIEnvMethodNotFound(stringify!($name)))?;
+                f(raw, obj, class, method_id, args.as_ptr())
+            }
+        };
+    }
+    let ret = match parsed.ret {
+        ReturnType::Object | ReturnType::Array => {
+            let obj = call!(CallNonvirtualObjectMethodA);
+            JValueOwned::Object(unsafe { JObject::from_raw(obj) })
+        }
+        ReturnType::Primitive(p) => match p {
+            Primitive::Boolean => JValueOwned::Bool(call!(CallNonvirtualBooleanMethodA)),
+            Primitive::Byte => JValueOwned::Byte(call!(CallNonvirtualByteMethodA)),
+            Primitive::Char => JValueOwned::Char(call!(CallNonvirtualCharMethodA)),
+            Primitive::Short => JValueOwned::Short(call!(CallNonvirtualShortMethodA)),
+            Primitive::Int => JValueOwned::Int(call!(CallNonvirtualIntMethodA)),
+            Primitive::Long => JValueOwned::Long(call!(CallNonvirtualLongMethodA)),
+            Primitive::Float => JValueOwned::Float(call!(CallNonvirtualFloatMethodA)),
+            Primitive::Double => JValueOwned::Double(call!(CallNonvirtualDoubleMethodA)),
+            Primitive::Void => {
+                call!(CallNonvirtualVoidMethodA);
+                JValueOwned::Void
+            }
+        },
+    };
+    if env.exception_check()? {
+        return Err(JniError::JavaException.into());
+    }
+
+    Ok(ret)
 }
 
 /// 创建一个java动态代理，用于在rust层实现java接口的方法。
@@ -556,6 +809,36 @@ pub fn unbind_proxy_handler(proxy: &GlobalRef) {
     };
 }
 
+/**
+向上转换为类本身或`extends`链上的祖先类`A`，由`java_class`实现。
+转换后仍然引用同一个java对象，按值转换时子类中的rust字段会被丢弃。
+*/
+pub trait JUpcast<A> {
+    /// 按值转换为`A`。
+    fn upcast(self) -> A;
+
+    /// 获取`A`的引用。
+    fn upcast_ref(&self) -> &A;
+}
+
+/**
+标记`A`是类在`extends`链上的祖先类（不包括类本身），由`java_class`实现。
+`java_class`会为每个类`B`实现`From<T: JExtends<B>>`，并为子类实现`AsRef<A>`，因此子类可以直接使用`into()`和`as_ref()`转换为任意祖先类。
+*/
+pub trait JExtends<A>: JUpcast<A> {}
+
+/**
+声明类的直接父类，由使用了`extends`的`java_class`实现，`java_method(nonvirtual)`通过它找到要调用的父类实现。
+*/
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` has no super class, `nonvirtual` methods require `extends = ...` in `java_class`",
+    label = "declared without `extends`"
+)]
+pub trait JSuper {
+    /// 直接父类。
+    type Super;
+}
+
 /**
 解析JObject类型。
 */
@@ -584,44 +867,22 @@ impl<T: FromStr> ParseJObjectType<T> for JObject<'_> {
 //noinspection SpellCheckingInspection
 fn load_rust_call_method_hook_class<'a>() -> Result<&'a GlobalRef> {
     #[cfg(target_os = "android")]
-    const BYTECODE: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/classes.dex"));
+    static DEX: EmbeddedDex = include_dex!("classes");
     #[cfg(not(target_os = "android"))]
-    const BYTECODE: &[u8] = &[];
-    const LOADER_CLASS: &str = "dalvik/system/InMemoryDexClassLoader";
+    static DEX: EmbeddedDex = EmbeddedDex::new(&[]);
     static INSTANCE: OnceLock<Result<GlobalRef>> = OnceLock::new();
 
-    INSTANCE.get_or_init(|| {
-        let mut env = vm_attach()?;
-        let byte_buffer = unsafe { env.new_direct_byte_buffer(BYTECODE.as_ptr() as *mut u8, BYTECODE.len()) }?;
-
-        let dex_class_loader = env
-            .new_object(
-                LOADER_CLASS,
-                "(Ljava/nio/ByteBuffer;Ljava/lang/ClassLoader;)V",
-                &[
-                    JValueGen::Object(&JObject::from(byte_buffer)),
-                    JValueGen::Object(&JObject::null()),
-                ],
-            )?;
-
-        let class = env.new_string("rust/CallMethodHook")?;
-        let class = env
-            .call_method(
-                &dex_class_loader,
-                "loadClass",
-                "(Ljava/lang/String;)Ljava/lang/Class;",
-                &[(&class).into()],
-            )?
-            .l()?;
-        let m = NativeMethod {
-            name: "invoke".into(),
-            sig: "(Ljava/lang/Object;Ljava/lang/reflect/Method;[Ljava/lang/Object;)Ljava/lang/Object;".into(),
-            fn_ptr: rust_callback as *mut _,
-        };
-        env.register_native_methods(Into::<&JClass<'_>>::into(&class), &[m])?;
-
-        Ok(env.new_global_ref(&class)?)
-    }).as_ref().map_err(|e| e.clone())
+    INSTANCE
+        .get_or_init(|| {
+            let m = NativeMethod {
+                name: "invoke".into(),
+                sig: "(Ljava/lang/Object;Ljava/lang/reflect/Method;[Ljava/lang/Object;)Ljava/lang/Object;".into(),
+                fn_ptr: rust_callback as *mut _,
+            };
+            DEX.register_natives("rust/CallMethodHook", &[m])
+        })
+        .as_ref()
+        .map_err(|e| e.clone())
 }
 
 //noinspection SpellCheckingInspection
@@ -701,6 +962,435 @@ unsafe extern "C" fn rust_callback<'a>(
     }
 }
 
+/// java子类中保存rust状态句柄的字段名称。
+pub const SUBCLASS_HANDLE_FIELD: &str = "__rustHandle";
+
+// 子类对象的句柄映射到 Rust 状态，默认不持有强引用，状态的生命周期由 Rust 端的 Arc 决定
+static SUBCLASS_OBJECTS: LazyLock<Mutex<HashMap<i64, SubclassState>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+static SUBCLASS_NEXT_HANDLE: AtomicI64 = AtomicI64::new(1);
+// 映射表达到此大小时清理已经释放的状态，清理后设为剩余数量的两倍，使清理的开销均摊到每次插入
+static SUBCLASS_PRUNE_AT: AtomicUsize = AtomicUsize::new(SUBCLASS_PRUNE_MIN);
+const SUBCLASS_PRUNE_MIN: usize = 64;
+
+struct SubclassState {
+    state: Weak<dyn Any + Send + Sync>,
+    // 调用`retain_subclass_state`后持有的强引用，直到`release_subclass_state`
+    retained: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+//noinspection SpellCheckingInspection
+/// 生成并加载一个java子类，随后为它注册本地方法。
+/// 类中会自动添加保存rust状态句柄的字段，类通过`InMemoryDexClassLoader`加载，父加载器为应用的类加载器，因此父类可以是应用中的类。
+///
+/// # Arguments
+///
+/// * `class`: 类定义。
+/// * `natives`: 本地方法，必须与类定义中的本地方法一致。
+///
+/// returns: Result<GlobalRef> 加载的类
+pub fn load_subclass(class: DexClass, natives: &[NativeMethod]) -> Result<GlobalRef> {
+    load_subclass_in(&app_class_loader()?, class, natives)
+}
+
+//noinspection SpellCheckingInspection
+/// 与`load_subclass`相同，但使用指定的父加载器，父类需要能够通过此加载器找到，例如插件中的类。
+///
+/// # Arguments
+///
+/// * `parent`: 父加载器。
+/// * `class`: 类定义。
+/// * `natives`: 本地方法，必须与类定义中的本地方法一致。
+///
+/// returns: Result<GlobalRef> 加载的类
+pub fn load_subclass_in(
+    parent: &GlobalRef,
+    class: DexClass,
+    natives: &[NativeMethod],
+) -> Result<GlobalRef> {
+    let name = class.name().to_string();
+    let dex = write_dex(&[class.field(SUBCLASS_HANDLE_FIELD, "J", false)]);
+    // 每个子类只加载一次，缓冲区在进程的整个生命周期内保持有效。
+    let dex = Box::leak(dex.into_boxed_slice());
+    let mut env = vm_attach()?;
+    let loader = new_in_memory_class_loader(&mut env, dex, parent.as_obj())?;
+    let class = load_class_with(&mut env, &loader, &name)?;
+    env.register_native_methods(&class, natives)?;
+
+    Ok(env.new_global_ref(&class)?)
+}
+
+/// 把rust状态绑定到java子类的对象上，本地方法被调用时可以通过`get_subclass_state`取回。
+///
+/// # Arguments
+///
+/// * `env`: JNI环境。
+/// * `obj`: 子类的对象。
+/// * `state`: rust状态，这里只保存弱引用，需要java长期持有时可以使用`retain_subclass_state`。
+///
+/// returns: Result<()>
+pub fn bind_subclass_state(
+    env: &mut JNIEnv,
+    obj: &JObject,
+    state: Weak<dyn Any + Send + Sync>,
+) -> Result<()> {
+    let handle = insert_subclass_state(state);
+    if let Err(e) = env.set_field(obj, SUBCLASS_HANDLE_FIELD, "J", JValue::Long(handle)) {
+        SUBCLASS_OBJECTS.lock().remove(&handle);
+        return Err(e.into());
+    }
+    Ok(())
+}
+
+/// 保存rust状态并分配句柄，同时清理rust端已经释放的状态，java对象不再被调用时映射表也不会无限增长。
+fn insert_subclass_state(state: Weak<dyn Any + Send + Sync>) -> i64 {
+    let handle = SUBCLASS_NEXT_HANDLE.fetch_add(1, Ordering::Relaxed);
+    let mut objects = SUBCLASS_OBJECTS.lock();
+    if objects.len() >= SUBCLASS_PRUNE_AT.load(Ordering::Relaxed) {
+        objects.retain(|_, i| i.state.strong_count() > 0);
+        SUBCLASS_PRUNE_AT.store(
+            (objects.len() * 2).max(SUBCLASS_PRUNE_MIN),
+            Ordering::Relaxed,
+        );
+    }
+    objects.insert(
+        handle,
+        SubclassState {
+            state,
+            retained: None,
+        },
+    );
+    handle
+}
+
+/// 让java子类的对象持有其rust状态的强引用，之后即使rust端不再持有`Arc`，java的调用也能找到状态。
+/// 适用于交给java长期持有的对象，例如注册的`BroadcastReceiver`或者投递到`Handler`的`Runnable`。
+/// rust状态中保存了java对象的全局引用，因此在调用`release_subclass_state`之前两者都不会被释放。
+///
+/// # Arguments
+///
+/// * `obj`: 子类的对象。
+///
+/// returns: Result<()>
+pub fn retain_subclass_state(obj: &GlobalRef) -> Result<()> {
+    let mut env = vm_attach()?;
+    let handle = env.get_field(obj, SUBCLASS_HANDLE_FIELD, "J")?.j()?;
+    set_subclass_retained(handle, true)
+}
+
+/// 释放`retain_subclass_state`持有的强引用，之后rust状态的生命周期重新由rust端的`Arc`决定。
+///
+/// # Arguments
+///
+/// * `obj`: 子类的对象。
+///
+/// returns: Result<()>
+pub fn release_subclass_state(obj: &GlobalRef) -> Result<()> {
+    let mut env = vm_attach()?;
+    let handle = env.get_field(obj, SUBCLASS_HANDLE_FIELD, "J")?.j()?;
+    set_subclass_retained(handle, false)
+}
+
+fn set_subclass_retained(handle: i64, retain: bool) -> Result<()> {
+    let mut objects = SUBCLASS_OBJECTS.lock();
+    let Some(object) = objects.get_mut(&handle) else {
+        return Err(DroidWrapError::Subclass(format!(
+            "The object `{}` has no rust state, it may not be created from rust or has been dropped.",
+            handle
+        )));
+    };
+    if !retain {
+        object.retained = None;
+        return Ok(());
+    }
+    let Some(state) = object.state.upgrade() else {
+        objects.remove(&handle);
+        return Err(DroidWrapError::Subclass(format!(
+            "The rust state of object `{}` has been dropped.",
+            handle
+        )));
+    };
+    object.retained = Some(state);
+    Ok(())
+}
+
+/// 获取java子类的对象上绑定的rust状态。
+///
+/// # Arguments
+///
+/// * `env`: JNI环境。
+/// * `obj`: 子类的对象。
+///
+/// returns: Result<Arc<T>>
+pub fn get_subclass_state<T: Any + Send + Sync>(env: &mut JNIEnv, obj: &JObject) -> Result<Arc<T>> {
+    let handle = env.get_field(obj, SUBCLASS_HANDLE_FIELD, "J")?.j()?;
+    let mut lock = SUBCLASS_OBJECTS.lock();
+    let Some(state) = lock.get(&handle).and_then(|i| i.state.upgrade()) else {
+        lock.remove(&handle);
+        return Err(DroidWrapError::Subclass(format!(
+            "The object `{}` has no rust state, it may not be created from rust or has been dropped.",
+            handle
+        )));
+    };
+    drop(lock);
+    state.downcast::<T>().map_err(|_| {
+        DroidWrapError::Subclass(format!(
+            "The state of object `{}` is not `{}`.",
+            handle,
+            std::any::type_name::<T>()
+        ))
+    })
+}
+
+/// 在java调用的本地方法中执行rust代码。
+/// 返回错误或者发生panic时，如果没有待处理的java异常，则抛出`RuntimeException`，然后返回默认值。
+///
+/// # Arguments
+///
+/// * `env`: JNI环境。
+/// * `f`: 要执行的函数。
+///
+/// returns: R
+pub fn native_call<'local, R: Default>(
+    env: &mut JNIEnv<'local>,
+    f: impl FnOnce(&mut JNIEnv<'local>) -> Result<R>,
+) -> R {
+    let msg = match catch_unwind(AssertUnwindSafe(|| f(env))) {
+        Ok(Ok(r)) => return r,
+        Ok(Err(e)) => e.to_string(),
+        Err(e) => match e.downcast_ref::<&str>() {
+            Some(s) => s.to_string(),
+            None => e.downcast_ref::<String>().cloned().unwrap_or_default(),
+        },
+    };
+    error!("{}", msg);
+    if !env.exception_check().unwrap_or_default() {
+        let _ = env.throw_new("java/lang/RuntimeException", msg);
+    }
+    R::default()
+}
+
+/// 在java子类的本地方法中执行rust代码，`f`会收到对象上绑定的rust状态。
+/// 如果对象还没有绑定状态（例如在父类的构造函数中调用了被重写的方法）或者状态已经被释放，则记录警告并返回默认值；
+/// 其他错误和panic的处理与`native_call`一致。
+///
+/// # Arguments
+///
+/// * `env`: JNI环境。
+/// * `this`: 子类的对象。
+/// * `f`: 要执行的函数。
+///
+/// returns: R
+pub fn subclass_call<'local, T: Any + Send + Sync, R: Default>(
+    env: &mut JNIEnv<'local>,
+    this: &JObject,
+    f: impl FnOnce(&mut JNIEnv<'local>, Arc<T>) -> Result<R>,
+) -> R {
+    native_call(env, |env| match get_subclass_state::<T>(env, this) {
+        Ok(state) => f(env, state),
+        Err(DroidWrapError::Subclass(e)) => {
+            warn!("{}", e);
+            Ok(R::default())
+        }
+        Err(e) => Err(e),
+    })
+}
+
+// 由`java_native`在库加载时提交的本地方法
+static PENDING_NATIVES: Mutex<Vec<PendingNative>> = Mutex::new(Vec::new());
+
+#[derive(Clone)]
+struct PendingNative {
+    class: &'static str,
+    // 加载类的类加载器，None表示应用的类加载器
+    loader: Option<fn() -> Result<GlobalRef>>,
+    method: fn() -> NativeMethod,
+    // 已经注册到的类加载器，插件重新加载后类加载器会改变，需要再次注册
+    registered: Option<GlobalRef>,
+}
+
+/// 提交一个等待注册的本地方法，`java_native`生成的代码在库加载时调用此函数，通常不需要手动调用。
+///
+/// # Arguments
+///
+/// * `class`: 声明本地方法的java类。
+/// * `loader`: 加载类的类加载器，`None`表示应用的类加载器。
+/// * `method`: 返回本地方法定义的函数，注册时才会调用。
+pub fn submit_native(
+    class: &'static str,
+    loader: Option<fn() -> Result<GlobalRef>>,
+    method: fn() -> NativeMethod,
+) {
+    PENDING_NATIVES.lock().push(PendingNative {
+        class,
+        loader,
+        method,
+        registered: None,
+    });
+}
+
+/// 注册所有已提交的本地方法，每个本地方法在同一个类加载器中只会注册一次。
+/// 类默认通过应用的类加载器加载，因此可以是应用中自己编写的java或kotlin类；指定了`loader`的本地方法通过对应的类加载器加载。
+/// 每个类单独注册，注册失败的类会记录日志并保留，下次调用时重试，例如插件中的类需要在加载插件之后再次调用此函数。
+/// 使用`android_main`时会在进入`main`之前自动调用，使用`jni_on_load!`时会在库加载时自动调用。
+///
+/// returns: Result<()> 最后一个注册失败的错误，插件没有加载的类不算作失败。
+pub fn register_natives() -> Result<()> {
+    let mut env = vm_attach()?;
+    register_pending_natives(&mut env, |env, native| {
+        let loader = match native.loader {
+            Some(loader) => loader()?,
+            None => app_class_loader()?,
+        };
+        let class = load_class_with(env, loader.as_obj(), native.class)?;
+        Ok((loader, class))
+    })
+}
+
+/// 在`JNI_OnLoad`中注册本地方法，由`jni_on_load!`生成的代码调用。
+/// 此时`android_main`可能还没有运行，因此保存`vm`供之后的`vm_attach`使用，应用中的类通过`FindClass`加载（使用调用`System.loadLibrary`的类的类加载器）。
+///
+/// # Arguments
+///
+/// * `vm`: `JNI_OnLoad`收到的java虚拟机。
+///
+/// returns: jint 需要的JNI版本，失败时为`JNI_ERR`。
+///
+/// # Safety
+///
+/// `vm`必须是有效的java虚拟机指针。
+#[doc(hidden)]
+pub unsafe fn register_natives_on_load(vm: *mut std::ffi::c_void) -> jint {
+    let Ok(vm) = (unsafe { JavaVM::from_raw(vm.cast()) }) else {
+        return jni::sys::JNI_ERR;
+    };
+    let vm = JAVA_VM.get_or_init(|| vm);
+    let mut env = match vm.attach_current_thread() {
+        Ok(env) => env,
+        Err(e) => {
+            error!("Failed to register native methods: {}", e);
+            return jni::sys::JNI_ERR;
+        }
+    };
+    let res = register_pending_natives(&mut env, |env, native| match native.loader {
+        Some(loader) => {
+            let loader = loader()?;
+            let class = load_class_with(env, loader.as_obj(), native.class)?;
+            Ok((loader, class))
+        }
+        None => {
+            let class = env.find_class(native.class)?;
+            let loader = env
+                .call_method(&class, "getClassLoader", "()Ljava/lang/ClassLoader;", &[])?
+                .l()?;
+            Ok((env.new_global_ref(loader)?, class))
+        }
+    });
+    if let Err(e) = res {
+        error!("Failed to register native methods: {}", e);
+    }
+    jni::sys::JNI_VERSION_1_6
+}
+
+// 按类和类加载器分组注册还没有注册到当前类加载器中的本地方法，`resolve`返回类加载器和加载的类。
+fn register_pending_natives<'a>(
+    env: &mut JNIEnv<'a>,
+    resolve: impl Fn(&mut JNIEnv<'a>, &PendingNative) -> Result<(GlobalRef, JClass<'a>)>,
+) -> Result<()> {
+    // 注册时可能执行java类的静态初始化，因此不能一直持有锁。
+    let natives = PENDING_NATIVES.lock().clone();
+    let mut groups = HashMap::<_, Vec<_>>::new();
+    for (index, native) in natives.iter().enumerate() {
+        let loader = native.loader.map_or(0, |i| i as usize);
+        groups
+            .entry((native.class, loader))
+            .or_default()
+            .push(index);
+    }
+
+    let mut res = Ok(());
+    let mut registered = Vec::new();
+    for ((class, _), indices) in groups {
+        let (loader, jclass) = match resolve(env, &natives[indices[0]]) {
+            Ok(i) => i,
+            Err(e) => {
+                if env.exception_check().unwrap_or(false) {
+                    let _ = env.exception_clear();
+                }
+                match e {
+                    DroidWrapError::PluginNotLoaded(_) => {
+                        debug!("Skip native methods for `{}`: {}", class, e)
+                    }
+                    e => {
+                        error!(
+                            "Failed to load `{}` to register native methods: {}",
+                            class, e
+                        );
+                        res = Err(e);
+                    }
+                }
+                continue;
+            }
+        };
+        let indices = indices
+            .into_iter()
+            .filter(|i| {
+                !natives[*i]
+                    .registered
+                    .as_ref()
+                    .is_some_and(|r| env.is_same_object(r, &loader).unwrap_or(false))
+            })
+            .collect::<Vec<_>>();
+        if indices.is_empty() {
+            continue;
+        }
+        let methods = indices
+            .iter()
+            .map(|i| (natives[*i].method)())
+            .collect::<Vec<_>>();
+        debug!("Register {} native methods for `{}`.", methods.len(), class);
+        match env.register_native_methods(&jclass, &methods) {
+            Ok(_) => registered.extend(indices.into_iter().map(|i| (i, loader.clone()))),
+            Err(e) => {
+                if env.exception_check().unwrap_or(false) {
+                    let _ = env.exception_clear();
+                }
+                error!("Failed to register native methods for `{}`: {}", class, e);
+                res = Err(e.into());
+            }
+        }
+    }
+
+    // 提交的列表只会追加，因此快照中的索引仍然有效。
+    let mut natives = PENDING_NATIVES.lock();
+    for (index, loader) in registered {
+        natives[index].registered = Some(loader);
+    }
+    res
+}
+
+/// 导出`JNI_OnLoad`，在`System.loadLibrary`加载库时注册`java_native`实现的本地方法，
+/// 这样java层在`android_main`运行之前，或者在没有`android_main`的库中也可以调用这些本地方法。
+/// 一个库中只能使用一次；GameActivity已经定义了自己的`JNI_OnLoad`，使用它时不能使用此宏，本地方法会在`android_main`中注册。
+///
+/// # 示例
+///
+/// ```ignore
+/// droid_wrap_utils::jni_on_load!();
+/// ```
+#[macro_export]
+macro_rules! jni_on_load {
+    () => {
+        #[unsafe(no_mangle)]
+        pub extern "system" fn JNI_OnLoad(
+            vm: *mut std::ffi::c_void,
+            _reserved: *mut std::ffi::c_void,
+        ) -> $crate::jint {
+            unsafe { $crate::register_natives_on_load(vm) }
+        }
+    };
+}
+
 /// 把java对象数组转换成Vec
 ///
 /// # 参数
@@ -820,6 +1510,80 @@ pub fn to_java_byte_array<'a>(
     Ok(arr2)
 }
 
+/// 可以放入java基本类型数组的值，用于`java_method`的`#[varargs]`参数。
+pub trait JPrimitiveArrayElement: Copy {
+    /// 创建元素为当前类型的java数组，并复制所有元素。
+    fn new_java_array<'a>(env: &mut JNIEnv<'a>, items: &[Self]) -> Result<JObject<'a>>;
+}
+
+macro_rules! impl_primitive_array_element {
+    ($ty:ty, $new:ident, $set:ident) => {
+        impl JPrimitiveArrayElement for $ty {
+            fn new_java_array<'a>(env: &mut JNIEnv<'a>, items: &[Self]) -> Result<JObject<'a>> {
+                let arr = env.$new(items.len() as _)?;
+                env.$set(&arr, 0, items)?;
+                Ok(arr.into())
+            }
+        }
+    };
+}
+
+impl_primitive_array_element!(jbyte, new_byte_array, set_byte_array_region);
+impl_primitive_array_element!(jchar, new_char_array, set_char_array_region);
+impl_primitive_array_element!(jshort, new_short_array, set_short_array_region);
+impl_primitive_array_element!(jint, new_int_array, set_int_array_region);
+impl_primitive_array_element!(jlong, new_long_array, set_long_array_region);
+impl_primitive_array_element!(jfloat, new_float_array, set_float_array_region);
+impl_primitive_array_element!(jdouble, new_double_array, set_double_array_region);
+impl_primitive_array_element!(jboolean, new_boolean_array, set_boolean_array_region);
+
+/// 创建java基本类型数组，作为可变参数（varargs）方法的最后一个参数传递。
+///
+/// # 参数
+///
+/// * `items` - 数组的元素。
+///
+/// # 示例
+///
+/// ```rust
+/// use droid_wrap_utils::{jint, to_java_primitive_varargs};
+///
+/// let arg = to_java_primitive_varargs::<jint>(&[1, 2, 3]).unwrap();
+/// ```
+pub fn to_java_primitive_varargs<T: JPrimitiveArrayElement>(items: &[T]) -> Result<JArgValue> {
+    let mut env = vm_attach()?;
+    let arr = T::new_java_array(&mut env, items)?;
+    Ok(JArgValue::Object(env.new_global_ref(arr)?))
+}
+
+/// 创建java对象数组，作为可变参数（varargs）方法的最后一个参数传递。
+///
+/// # 参数
+///
+/// * `items` - 数组的元素，null引用会作为null元素放入数组。
+/// * `element_sig` - 数组元素的类型描述符，例如`Ljava/lang/Object;`或`[I`。
+///
+/// # 示例
+///
+/// ```rust
+/// use droid_wrap_utils::{to_java_object_varargs, vm_attach};
+///
+/// let mut env = vm_attach().unwrap();
+/// let s = env.new_string("hello").unwrap();
+/// let s = env.new_global_ref(&s).unwrap();
+/// let arg = to_java_object_varargs(&[s], "Ljava/lang/Object;").unwrap();
+/// ```
+pub fn to_java_object_varargs(items: &[GlobalRef], element_sig: &str) -> Result<JArgValue> {
+    // FindClass需要的是类的内部名称，数组类型则直接使用描述符。
+    let element_class = element_sig
+        .strip_prefix('L')
+        .and_then(|i| i.strip_suffix(';'))
+        .unwrap_or(element_sig);
+    let mut env = vm_attach()?;
+    let arr = to_java_object_array(&mut env, items, element_class)?;
+    Ok(JArgValue::Object(env.new_global_ref(arr)?))
+}
+
 /// 获取null的全局引用值。
 ///
 /// # 参数
@@ -838,8 +1602,18 @@ pub fn to_java_byte_array<'a>(
 /// let null_value = null_value(&mut env);
 /// ```
 pub fn null_value(env: &mut JNIEnv) -> Result<GlobalRef> {
+    if let Some(null) = NULL_REF.get() {
+        return Ok(null.clone());
+    }
     let obj = JObject::null();
-    Ok(env.new_global_ref(&obj)?)
+    let null = env.new_global_ref(&obj)?;
+    Ok(NULL_REF.get_or_init(|| null).clone())
+}
+
+/// 获取缓存的null全局引用，在第一次附加java虚拟机之前返回`None`。
+#[doc(hidden)]
+pub fn cached_null_value() -> Option<GlobalRef> {
+    NULL_REF.get().cloned()
 }
 
 /// 获取boolean的包装对象的全局引用值。
@@ -981,3 +1755,89 @@ pub fn java_object_to_string<'a, O: AsRef<JObject<'a>>>(obj: O) -> Result<String
 
     Ok(s.to_str()?.to_string())
 }
+
+//noinspection SpellCheckingInspection
+/// 获取Java对象的哈希码，null的哈希码为0。
+///
+/// # 参数
+///
+/// * `obj`: Java对象。
+///
+/// # 返回值
+///
+/// 返回: `Result<i32>`
+///
+/// # 示例
+///
+/// ```
+/// use droid_wrap_utils::{vm_attach, java_object_hash_code};
+/// let mut env = vm_attach().unwrap();
+/// let obj = env.new_object("java/lang/Object", "()V", &[]).unwrap();
+/// let hash = java_object_hash_code(obj).unwrap();
+/// ```
+pub fn java_object_hash_code<'a, O: AsRef<JObject<'a>>>(obj: O) -> Result<i32> {
+    let obj = obj.as_ref();
+    if obj.is_null() {
+        return Ok(0);
+    }
+    let mut env = vm_attach()?;
+    let hash = env.call_method(obj, "hashCode", "()I", &[])?.i()?;
+
+    Ok(hash)
+}
+
+//noinspection SpellCheckingInspection
+/// 判断两个引用是否指向同一个Java对象（使用`IsSameObject`，相当于java中的`==`），不会调用`equals`。
+///
+/// # 参数
+///
+/// * `a`: 第一个对象。
+/// * `b`: 第二个对象。
+///
+/// # 返回值
+///
+/// 返回: `Result<bool>`
+///
+/// # 示例
+///
+/// ```
+/// use droid_wrap_utils::{vm_attach, java_object_same};
+/// let mut env = vm_attach().unwrap();
+/// let obj = env.new_object("java/lang/Object", "()V", &[]).unwrap();
+/// let same = java_object_same(&obj, &obj).unwrap();
+/// ```
+pub fn java_object_same<'a, O: AsRef<JObject<'a>>>(a: O, b: O) -> Result<bool> {
+    let env = vm_attach()?;
+    Ok(env.is_same_object(a.as_ref(), b.as_ref())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subclass_objects_shrink() {
+        let alive: Arc<dyn Any + Send + Sync> = Arc::new(0);
+        let alive_handle = insert_subclass_state(Arc::downgrade(&alive));
+        for i in 0..1000 {
+            let state: Arc<dyn Any + Send + Sync> = Arc::new(i);
+            insert_subclass_state(Arc::downgrade(&state));
+        }
+        let objects = SUBCLASS_OBJECTS.lock();
+        assert!(objects.len() <= SUBCLASS_PRUNE_MIN);
+        assert!(objects[&alive_handle].state.upgrade().is_some());
+    }
+
+    #[test]
+    fn test_subclass_state_retained() {
+        let state: Arc<dyn Any + Send + Sync> = Arc::new(0);
+        let handle = insert_subclass_state(Arc::downgrade(&state));
+        set_subclass_retained(handle, true).unwrap();
+        drop(state);
+        assert!(SUBCLASS_OBJECTS.lock()[&handle].state.upgrade().is_some());
+        set_subclass_retained(handle, false).unwrap();
+        let state = SUBCLASS_OBJECTS.lock().get(&handle).and_then(|i| i.state.upgrade());
+        assert!(state.is_none());
+        assert!(set_subclass_retained(handle, true).is_err());
+    }
+}
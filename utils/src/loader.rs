@@ -0,0 +1,211 @@
+/*
+ * Copyright (c) 2025. The RigelA open source project team and
+ * its contributors reserve all rights.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software distributed under the
+ * License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and limitations under the License.
+ */
+
+use crate::{Result, android_context, vm_attach};
+use jni::{
+    JNIEnv, NativeMethod,
+    objects::{GlobalRef, JClass, JObject},
+};
+use std::sync::OnceLock;
+
+/// 获取应用的类加载器，即`Context.getClassLoader()`，首次成功后缓存结果。
+/// 在native线程中`FindClass`只能找到系统类，应用中的类需要通过此加载器加载。
+/// 获取失败（例如Context还没有准备好）时不缓存错误，下次调用会重新获取。
+pub fn app_class_loader() -> Result<GlobalRef> {
+    static LOADER: OnceLock<GlobalRef> = OnceLock::new();
+    if let Some(loader) = LOADER.get() {
+        return Ok(loader.clone());
+    }
+    let mut env = vm_attach()?;
+    let loader = env
+        .call_method(
+            android_context(),
+            "getClassLoader",
+            "()Ljava/lang/ClassLoader;",
+            &[],
+        )?
+        .l()?;
+    let loader = env.new_global_ref(&loader)?;
+    Ok(LOADER.get_or_init(|| loader).clone())
+}
+
+/// 使用指定的类加载器加载一个java类。
+///
+/// # Arguments
+///
+/// * `loader`: 类加载器。
+/// * `name`: 类名，例如`com/example/Callback`。
+///
+/// returns: Result<JClass> 加载的类
+pub fn load_class_from<'a>(loader: &GlobalRef, name: &str) -> Result<JClass<'a>> {
+    let mut env = vm_attach()?;
+    load_class_with(&mut env, loader.as_obj(), name)
+}
+
+/// 可以作为`java_class(loader = ...)`的类加载器，生成的代码通过它加载类。
+/// 已经实现的类型有：类加载器对象本身（`GlobalRef`）、`EmbeddedDex`以及返回类加载器的函数。
+pub trait ClassLoaderSource {
+    /// 获取类加载器。
+    fn class_loader(&self) -> Result<GlobalRef>;
+}
+
+impl ClassLoaderSource for GlobalRef {
+    fn class_loader(&self) -> Result<GlobalRef> {
+        Ok(self.clone())
+    }
+}
+
+impl ClassLoaderSource for EmbeddedDex {
+    fn class_loader(&self) -> Result<GlobalRef> {
+        EmbeddedDex::class_loader(self).cloned()
+    }
+}
+
+impl<F: Fn() -> Result<GlobalRef>> ClassLoaderSource for F {
+    fn class_loader(&self) -> Result<GlobalRef> {
+        self()
+    }
+}
+
+//noinspection SpellCheckingInspection
+/// 创建从内存中加载dex的`InMemoryDexClassLoader`。
+///
+/// # Arguments
+///
+/// * `env`: JNI环境。
+/// * `dex`: dex文件的内容，java层直接引用这块内存，因此必须在类加载器的整个生命周期内保持有效。
+/// * `parent`: 父加载器，dex中的类可以使用父加载器能够找到的类。
+///
+/// returns: Result<JObject> 类加载器
+pub(crate) fn new_in_memory_class_loader<'a>(
+    env: &mut JNIEnv<'a>,
+    dex: &'static [u8],
+    parent: &JObject,
+) -> Result<JObject<'a>> {
+    let byte_buffer = unsafe { env.new_direct_byte_buffer(dex.as_ptr() as *mut u8, dex.len()) }?;
+    Ok(env.new_object(
+        "dalvik/system/InMemoryDexClassLoader",
+        "(Ljava/nio/ByteBuffer;Ljava/lang/ClassLoader;)V",
+        &[(&byte_buffer).into(), parent.into()],
+    )?)
+}
+
+/// 使用指定的类加载器加载类。
+///
+/// # Arguments
+///
+/// * `env`: JNI环境。
+/// * `loader`: 类加载器。
+/// * `name`: 类名，例如`com/example/Callback`。
+///
+/// returns: Result<JClass> 加载的类
+pub(crate) fn load_class_with<'a>(
+    env: &mut JNIEnv<'a>,
+    loader: &JObject,
+    name: &str,
+) -> Result<JClass<'a>> {
+    let name = env.new_string(name.replace('/', "."))?;
+    let class = env
+        .call_method(
+            loader,
+            "loadClass",
+            "(Ljava/lang/String;)Ljava/lang/Class;",
+            &[(&name).into()],
+        )?
+        .l()?;
+    Ok(class.into())
+}
+
+/// 嵌入到程序中的dex，一般在build.rs中使用`droid_wrap_build::DexBuilder`生成，然后使用`include_dex!`嵌入。
+/// 第一次使用时通过`InMemoryDexClassLoader`加载，父加载器为应用的类加载器，加载器在进程的整个生命周期内有效。
+/// 它实现了`ClassLoaderSource`，因此可以用作`java_class(loader = ...)`，为dex中的类编写封装类型。
+///
+/// # 示例
+///
+/// ```ignore
+/// use droid_wrap_utils::{EmbeddedDex, include_dex};
+///
+/// static GLUE: EmbeddedDex = include_dex!("glue");
+///
+/// let class = GLUE.register_natives("com/example/Callback", &natives)?;
+/// ```
+pub struct EmbeddedDex {
+    bytecode: &'static [u8],
+    loader: OnceLock<Result<GlobalRef>>,
+}
+
+impl EmbeddedDex {
+    /// 使用dex文件的内容创建。
+    pub const fn new(bytecode: &'static [u8]) -> Self {
+        Self {
+            bytecode,
+            loader: OnceLock::new(),
+        }
+    }
+
+    /// 获取加载此dex的类加载器，第一次调用时创建。
+    pub fn class_loader(&self) -> Result<&GlobalRef> {
+        self.loader
+            .get_or_init(|| {
+                let parent = app_class_loader()?;
+                let mut env = vm_attach()?;
+                let loader = new_in_memory_class_loader(&mut env, self.bytecode, parent.as_obj())?;
+                Ok(env.new_global_ref(&loader)?)
+            })
+            .as_ref()
+            .map_err(|e| e.clone())
+    }
+
+    /// 加载dex中的类。
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: 类名，例如`com/example/Callback`。
+    ///
+    /// returns: Result<GlobalRef> 加载的类
+    pub fn load_class(&self, name: &str) -> Result<GlobalRef> {
+        let loader = self.class_loader()?;
+        let mut env = vm_attach()?;
+        let class = load_class_with(&mut env, loader.as_obj(), name)?;
+        Ok(env.new_global_ref(&class)?)
+    }
+
+    /// 加载dex中的类，并为它注册本地方法。
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: 类名，例如`com/example/Callback`。
+    /// * `natives`: 本地方法，必须与类中声明的本地方法一致。
+    ///
+    /// returns: Result<GlobalRef> 加载的类
+    pub fn register_natives(&self, name: &str, natives: &[NativeMethod]) -> Result<GlobalRef> {
+        let class = self.load_class(name)?;
+        let mut env = vm_attach()?;
+        env.register_native_methods(<&JClass>::from(class.as_obj()), natives)?;
+        Ok(class)
+    }
+}
+
+/// 嵌入build.rs中使用`droid_wrap_build::DexBuilder::build`生成的`$OUT_DIR/<name>.dex`。
+///
+/// # 示例
+///
+/// ```ignore
+/// static GLUE: droid_wrap_utils::EmbeddedDex = droid_wrap_utils::include_dex!("glue");
+/// ```
+#[macro_export]
+macro_rules! include_dex {
+    ($name:literal) => {
+        $crate::EmbeddedDex::new(include_bytes!(concat!(env!("OUT_DIR"), "/", $name, ".dex")))
+    };
+}